@@ -33,32 +33,8 @@ pub async fn gc_ns(tx: &Transaction, ts: u64, ns: &str) -> Result<(), Error> {
 	for db in dbs.as_ref() {
 		// Trace for debugging
 		trace!("Performing garbage collection on {ns}:{} for timestamp {ts}", db.name);
-		// Fetch all tables
-		let tbs = tx.all_tb(ns, &db.name, None).await?;
-		// Get the database changefeed expiration
-		let db_cf_expiry = db.changefeed.map(|v| v.expiry.as_secs()).unwrap_or_default();
-		// Get the maximum table changefeed expiration
-		let tb_cf_expiry = tbs.as_ref().iter().fold(0, |acc, tb| match &tb.changefeed {
-			None => acc,
-			Some(cf) => {
-				if cf.expiry.is_zero() {
-					acc
-				} else {
-					acc.max(cf.expiry.as_secs())
-				}
-			}
-		});
-		// Calculate the maximum changefeed expiration
-		let cf_expiry = db_cf_expiry.max(tb_cf_expiry);
-		// Ignore this database if the expiry is greater
-		if ts < cf_expiry {
-			continue;
-		}
-		// Calculate the watermark expiry window
-		let watermark_ts = ts - cf_expiry;
 		// Calculate the watermark versionstamp
-		let watermark_vs =
-			tx.lock().await.get_versionstamp_from_timestamp(watermark_ts, ns, &db.name).await?;
+		let watermark_vs = watermark(tx, ts, ns, &db.name).await?;
 		// If a versionstamp exists, then garbage collect
 		if let Some(watermark_vs) = watermark_vs {
 			gc_range(tx, ns, &db.name, watermark_vs).await?;
@@ -69,6 +45,43 @@ pub async fn gc_ns(tx: &Transaction, ts: u64, ns: &str) -> Result<(), Error> {
 	Ok(())
 }
 
+// watermark returns the versionstamp before which change feed entries in the given database
+// are garbage collected at the given timestamp.
+pub(crate) async fn watermark(
+	tx: &Transaction,
+	ts: u64,
+	ns: &str,
+	db: &str,
+) -> Result<Option<VersionStamp>, Error> {
+	// Fetch the database
+	let dbs = tx.get_db(ns, db).await?;
+	// Fetch all tables
+	let tbs = tx.all_tb(ns, db, None).await?;
+	// Get the database changefeed expiration
+	let db_cf_expiry = dbs.changefeed.map(|v| v.expiry.as_secs()).unwrap_or_default();
+	// Get the maximum table changefeed expiration
+	let tb_cf_expiry = tbs.as_ref().iter().fold(0, |acc, tb| match &tb.changefeed {
+		None => acc,
+		Some(cf) => {
+			if cf.expiry.is_zero() {
+				acc
+			} else {
+				acc.max(cf.expiry.as_secs())
+			}
+		}
+	});
+	// Calculate the maximum changefeed expiration
+	let cf_expiry = db_cf_expiry.max(tb_cf_expiry);
+	// Nothing is collected if the expiry is greater
+	if ts < cf_expiry {
+		return Ok(None);
+	}
+	// Calculate the watermark expiry window
+	let watermark_ts = ts - cf_expiry;
+	// Calculate the watermark versionstamp
+	tx.lock().await.get_versionstamp_from_timestamp(watermark_ts, ns, db).await
+}
+
 // gc_db deletes all change feed entries in the given database that are older than the given watermark.
 #[instrument(level = "trace", target = "surrealdb::core::cfs", skip(tx))]
 pub async fn gc_range(tx: &Transaction, ns: &str, db: &str, vt: VersionStamp) -> Result<(), Error> {
@@ -23,6 +23,22 @@ pub async fn read(
 	start: ShowSince,
 	limit: Option<u32>,
 ) -> Result<Vec<ChangeSet>, Error> {
+	read_scanned(tx, ns, db, tb, start, limit).await.map(|(res, _)| res)
+}
+
+// Reads the change feed in the same way as `read`, but also returns the
+// last versionstamp which was scanned, including entries for other tables.
+//
+// Continuing from this versionstamp + 1 ensures that a table-filtered
+// reader still makes progress when a whole chunk belongs to other tables.
+pub(crate) async fn read_scanned(
+	tx: &Transaction,
+	ns: &str,
+	db: &str,
+	tb: Option<&str>,
+	start: ShowSince,
+	limit: Option<u32>,
+) -> Result<(Vec<ChangeSet>, Option<VersionStamp>), Error> {
 	// Calculate the start of the changefeed range
 	let beg = match start {
 		ShowSince::Versionstamp(x) => change::prefix_ts(ns, db, VersionStamp::from_u64(x))?,
@@ -49,12 +65,15 @@ pub async fn read(
 	let mut buf: Vec<TableMutations> = Vec::new();
 	// Create an empty buffer for the final changesets
 	let mut res = Vec::<ChangeSet>::new();
+	// Keep track of the last scanned versionstamp
+	let mut last: Option<VersionStamp> = None;
 	// iterate over _x and put decoded elements to r
 	for (k, v) in tx.scan(beg..end, limit, None).await? {
 		#[cfg(debug_assertions)]
 		trace!("Reading change feed entry: {}", k.sprint());
 		// Decode the changefeed entry key
 		let dec = crate::key::change::Cf::decode(&k).unwrap();
+		last = Some(dec.vs);
 		// Check the change is for the desired table
 		if tb.is_some_and(|tb| tb != dec.tb) {
 			continue;
@@ -83,5 +102,5 @@ pub async fn read(
 		res.push(ChangeSet(vs.unwrap(), db_mut));
 	}
 	// Return the results
-	Ok(res)
+	Ok((res, last))
 }
@@ -20,6 +20,8 @@ type PreparedWrite = (Vec<u8>, Vec<u8>, Vec<u8>, crate::kvs::Val);
 #[non_exhaustive]
 pub struct Writer {
 	buf: Buffer,
+	/// The number of mutations buffered for each table when each savepoint was set
+	save_points: Vec<HashMap<ChangeKey, usize>>,
 }
 
 #[non_exhaustive]
@@ -27,7 +29,7 @@ pub struct Buffer {
 	pub b: HashMap<ChangeKey, TableMutations>,
 }
 
-#[derive(Hash, Eq, PartialEq, Debug)]
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
 #[non_exhaustive]
 pub struct ChangeKey {
 	pub ns: String,
@@ -61,9 +63,34 @@ impl Writer {
 	pub(crate) fn new() -> Self {
 		Self {
 			buf: Buffer::new(),
+			save_points: Vec::new(),
 		}
 	}
 
+	/// Records the mutations which are buffered when a savepoint is set
+	pub(crate) fn new_save_point(&mut self) {
+		let lens = self.buf.b.iter().map(|(k, ms)| (k.clone(), ms.1.len())).collect();
+		self.save_points.push(lens);
+	}
+
+	/// Discards the mutations buffered since the last savepoint was set
+	pub(crate) fn rollback_to_save_point(&mut self) {
+		if let Some(lens) = self.save_points.pop() {
+			self.buf.b.retain(|k, ms| match lens.get(k) {
+				Some(len) => {
+					ms.1.truncate(*len);
+					true
+				}
+				None => false,
+			});
+		}
+	}
+
+	/// Keeps the mutations buffered since the last savepoint was set
+	pub(crate) fn release_last_save_point(&mut self) {
+		self.save_points.pop();
+	}
+
 	#[allow(clippy::too_many_arguments)]
 	pub(crate) fn record_cf_change(
 		&mut self,
@@ -51,6 +51,10 @@ pub static NORMAL_FETCH_SIZE: LazyLock<u32> =
 pub static EXPORT_BATCH_SIZE: LazyLock<u32> =
 	lazy_env_parse!("SURREAL_EXPORT_BATCH_SIZE", u32, 1000);
 
+/// The maximum number of rows that should be inserted at once for CSV and NDJSON imports.
+pub static IMPORT_BATCH_SIZE: LazyLock<usize> =
+	lazy_env_parse!("SURREAL_IMPORT_BATCH_SIZE", usize, 1000);
+
 /// The maximum number of keys that should be scanned at once for count queries.
 pub static COUNT_BATCH_SIZE: LazyLock<u32> =
 	lazy_env_parse!("SURREAL_COUNT_BATCH_SIZE", u32, 10_000);
@@ -0,0 +1,59 @@
+use crate::err::Error;
+use crate::iam::Auth;
+use crate::sql::{Thing, Value};
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(target_family = "wasm"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(target_family = "wasm")]
+use wasmtimer::std::{SystemTime, UNIX_EPOCH};
+
+/// An asynchronous event which has been queued for background processing
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct QueuedEvent {
+	/// The namespace in which this event was triggered
+	pub ns: String,
+	/// The database in which this event was triggered
+	pub db: String,
+	/// The table on which this event is defined
+	pub tb: String,
+	/// The name of the event definition
+	pub ev: String,
+	/// The record which triggered this event
+	pub id: Thing,
+	/// The type of the event: CREATE, UPDATE, or DELETE
+	pub event: Value,
+	/// The record before the change was made
+	pub before: Value,
+	/// The record after the change was made
+	pub after: Value,
+	/// The authentication of the session which triggered this event
+	pub auth: Auth,
+	/// The number of times this event has been attempted
+	pub attempts: u32,
+	/// The error from the last failed attempt
+	pub error: Option<String>,
+}
+
+impl QueuedEvent {
+	/// The document which the event clauses are processed against
+	pub fn value(&self) -> &Value {
+		match self.event.to_raw_string().as_str() {
+			"DELETE" => &self.before,
+			_ => &self.after,
+		}
+	}
+}
+
+/// Returns the current system time in milliseconds, used for
+/// ordering asynchronous events by the time they are next due.
+pub(crate) fn timestamp() -> Result<u64, Error> {
+	Ok(SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_err(|e| Error::Internal(format!("Clock may have gone backwards: {:?}", e.duration())))?
+		.as_millis() as u64)
+}
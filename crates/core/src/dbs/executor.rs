@@ -2,6 +2,7 @@ use crate::ctx::reason::Reason;
 use crate::ctx::Context;
 use crate::dbs::response::Response;
 use crate::dbs::Force;
+use crate::dbs::Notification;
 use crate::dbs::Options;
 use crate::dbs::QueryType;
 use crate::err::Error;
@@ -19,6 +20,8 @@ use crate::sql::value::Value;
 use crate::sql::Base;
 use crate::sql::ControlFlow;
 use crate::sql::FlowResult;
+use crate::sql::Ident;
+use async_channel::Receiver;
 use futures::{Stream, StreamExt};
 use reblessive::TreeStack;
 use std::pin::{pin, Pin};
@@ -33,6 +36,25 @@ use wasm_bindgen_futures::spawn_local as spawn;
 
 const TARGET: &str = "surrealdb::core::dbs";
 
+/// A savepoint set with a `SAVEPOINT` statement within a transaction block
+struct Savepoint {
+	/// The name of the savepoint, or `None` once the savepoint has been released
+	name: Option<Ident>,
+	/// The savepoint in the datastore transaction, see `Transactor::new_save_point`
+	save_point: usize,
+	/// The position of the result of the first statement following the savepoint
+	results: usize,
+	/// The number of live query notifications queued when the savepoint was set
+	notifications: usize,
+}
+
+impl Savepoint {
+	/// Checks if any savepoint has not been released
+	fn any_open(savepoints: &[Self]) -> bool {
+		savepoints.iter().any(|s| s.name.is_some())
+	}
+}
+
 pub struct Executor {
 	stack: TreeStack,
 	results: Vec<Response>,
@@ -178,6 +200,10 @@ impl Executor {
 		match stmt {
 			// These statements don't need a transaction.
 			Statement::Use(stmt) => self.execute_use_statement(stmt).map(|_| Value::None),
+			// These statements require a transaction block.
+			Statement::Savepoint(_) | Statement::Rollback(_) | Statement::Release(_) => {
+				Err(Error::SavepointOutsideTransaction)
+			}
 			stmt => {
 				let writeable = stmt.writeable();
 				let txn = Arc::new(kvs.transaction(writeable.into(), LockType::Optimistic).await?);
@@ -282,6 +308,10 @@ impl Executor {
 		let txn = Arc::new(txn);
 		let start_results = self.results.len();
 		let mut skip_remaining = false;
+		// The savepoints of this transaction, see `execute_savepoint_statement`
+		let mut savepoints: Vec<Savepoint> = Vec::new();
+		// The position of the result of a statement which failed within a savepoint
+		let mut failed: Option<usize> = None;
 
 		// loop over the statements until we hit a cancel or a commit statement.
 		while let Some(stmt) = stream.next().await {
@@ -329,6 +359,24 @@ impl Executor {
 				continue;
 			}
 
+			// A statement failed within a savepoint, so skip the statements until the
+			// transaction is recovered by rolling back to a savepoint.
+			if failed.is_some()
+				&& !matches!(
+					stmt,
+					Statement::Cancel(_)
+						| Statement::Commit(_)
+						| Statement::Rollback(_)
+						| Statement::Release(_)
+				) {
+				self.results.push(Response {
+					time: Duration::ZERO,
+					result: Err(Error::QueryNotExecuted),
+					query_type: QueryType::Other,
+				});
+				continue;
+			}
+
 			trace!(target: TARGET, statement = %stmt, "Executing statement");
 
 			let query_type = match stmt {
@@ -390,6 +438,20 @@ impl Executor {
 					return Ok(());
 				}
 				Statement::Commit(_) => {
+					// A transaction which was not recovered from a failure can not be committed
+					if let Some(failed) = failed {
+						let _ = txn.cancel().await;
+
+						for res in &mut self.results[start_results..failed] {
+							res.query_type = QueryType::Other;
+							res.result = Err(Error::QueryNotExecuted);
+						}
+
+						self.opt.sender = None;
+
+						return Ok(());
+					}
+
 					let mut lock = txn.lock().await;
 
 					// complete_changes and then commit.
@@ -440,6 +502,45 @@ impl Executor {
 					Err(e) => Err(e),
 				},
 				Statement::Use(stmt) => self.execute_use_statement(stmt).map(|_| Value::None),
+				Statement::Savepoint(stmt) => {
+					let save_point = txn.lock().await.new_save_point().await;
+					savepoints.push(Savepoint {
+						name: Some(stmt.name),
+						save_point,
+						results: self.results.len() + 1,
+						notifications: receiver.as_ref().map_or(0, |r| r.len()),
+					});
+					Ok(Value::None)
+				}
+				stmt @ (Statement::Rollback(_) | Statement::Release(_)) => {
+					match self
+						.execute_savepoint_statement(
+							&txn,
+							receiver.as_ref(),
+							&mut savepoints,
+							&mut failed,
+							stmt,
+						)
+						.await
+					{
+						Ok(v) => Ok(v),
+						// Without any savepoint to recover from, the error fails the transaction
+						Err(e) if failed.is_none() && !Savepoint::any_open(&savepoints) => {
+							let response = Response {
+								time: before.elapsed(),
+								result: Err(e),
+								query_type,
+							};
+							return self
+								.fail_transaction(&txn, stream, start_results, response)
+								.await;
+						}
+						Err(e) => {
+							failed.get_or_insert(self.results.len());
+							Err(e)
+						}
+					}
+				}
 				stmt => {
 					skip_remaining = matches!(stmt, Statement::Output(_));
 
@@ -452,39 +553,20 @@ impl Executor {
 						Err(ControlFlow::Break) | Err(ControlFlow::Continue) => {
 							Err(Error::InvalidControlFlow)
 						}
+						// Within a savepoint, the transaction can still be recovered from the error
+						Err(ControlFlow::Err(e)) if Savepoint::any_open(&savepoints) => {
+							failed = Some(self.results.len());
+							Err(*e)
+						}
 						Err(ControlFlow::Err(e)) => {
-							for res in &mut self.results[start_results..] {
-								res.query_type = QueryType::Other;
-								res.result = Err(Error::QueryNotExecuted);
-							}
-
-							// statement return an error. Consume all the other statement until we hit a cancel or commit.
-							self.results.push(Response {
+							let response = Response {
 								time: before.elapsed(),
 								result: Err(*e),
 								query_type,
-							});
-
-							let _ = txn.cancel().await;
-
-							self.opt.sender = None;
-
-							while let Some(stmt) = stream.next().await {
-								let stmt = stmt?;
-								if let Statement::Cancel(_) | Statement::Commit(_) = stmt {
-									return Ok(());
-								}
-
-								self.results.push(Response {
-									time: Duration::ZERO,
-									result: Err(Error::QueryNotExecuted),
-									query_type: QueryType::Other,
-								});
-							}
-
-							// ran out of statements before the transaction ended.
-							// Just break as we have nothing else we can do.
-							return Ok(());
+							};
+							return self
+								.fail_transaction(&txn, stream, start_results, response)
+								.await;
 						}
 					};
 
@@ -521,6 +603,122 @@ impl Executor {
 		Ok(())
 	}
 
+	/// Fails the transaction after a statement returned an error, consuming all the other
+	/// statements until we hit a cancel or commit.
+	async fn fail_transaction<S>(
+		&mut self,
+		txn: &Transaction,
+		mut stream: Pin<&mut S>,
+		start_results: usize,
+		response: Response,
+	) -> Result<(), Error>
+	where
+		S: Stream<Item = Result<Statement, Error>>,
+	{
+		for res in &mut self.results[start_results..] {
+			res.query_type = QueryType::Other;
+			res.result = Err(Error::QueryNotExecuted);
+		}
+
+		self.results.push(response);
+
+		let _ = txn.cancel().await;
+
+		self.opt.sender = None;
+
+		while let Some(stmt) = stream.next().await {
+			let stmt = stmt?;
+			if let Statement::Cancel(_) | Statement::Commit(_) = stmt {
+				return Ok(());
+			}
+
+			self.results.push(Response {
+				time: Duration::ZERO,
+				result: Err(Error::QueryNotExecuted),
+				query_type: QueryType::Other,
+			});
+		}
+
+		// ran out of statements before the transaction ended.
+		// Just break as we have nothing else we can do.
+		Ok(())
+	}
+
+	/// Executes a `ROLLBACK TO` or `RELEASE` statement within a transaction block.
+	///
+	/// Rolling back to a savepoint undoes the changes of the statements executed since the
+	/// savepoint was set, along with their change feed entries and live query notifications,
+	/// and keeps the savepoint set. Releasing a savepoint keeps the changes. After a statement
+	/// failed, the savepoint can not be released, and the changes must be explicitly rolled back
+	/// with `ROLLBACK TO`. This way a failed step of a transaction enclosed within a savepoint
+	/// does not fail the whole transaction, and its changes are never discarded silently.
+	async fn execute_savepoint_statement(
+		&mut self,
+		txn: &Transaction,
+		receiver: Option<&Receiver<Notification>>,
+		savepoints: &mut Vec<Savepoint>,
+		failed: &mut Option<usize>,
+		stmt: Statement,
+	) -> Result<Value, Error> {
+		let (name, rollback) = match stmt {
+			Statement::Rollback(stmt) => (stmt.name, true),
+			Statement::Release(stmt) => (stmt.name, false),
+			_ => return Err(fail!("Unexpected statement type encountered: {stmt:?}")),
+		};
+		// Find the latest savepoint with this name
+		let Some(pos) = savepoints.iter().rposition(|s| s.name.as_ref() == Some(&name)) else {
+			return Err(Error::SavepointNotFound {
+				name: name.0,
+			});
+		};
+		// The changes since a failed statement must be explicitly rolled back
+		if !rollback && failed.is_some() {
+			return Err(Error::SavepointFailed {
+				name: name.0,
+			});
+		}
+		let savepoint = &savepoints[pos];
+		if rollback {
+			// Roll back this savepoint, along with any savepoint set after it
+			let mut lock = txn.lock().await;
+			lock.rollback_to_save_point_at(savepoint.save_point).await?;
+			// The cached entries may have been changed since the savepoint was set
+			txn.clear_cache();
+			// Discard the notifications queued since the savepoint was set
+			if let (Some(recv), Some(send)) = (receiver, &self.opt.sender) {
+				let queued: Vec<_> = std::iter::from_fn(|| recv.try_recv().ok()).collect();
+				for notification in queued.into_iter().take(savepoint.notifications) {
+					let _ = send.try_send(notification);
+				}
+			}
+			// Update the results of the statements which were rolled back
+			for res in &mut self.results[savepoint.results..] {
+				if res.result.is_ok() {
+					res.query_type = QueryType::Other;
+					res.result = Err(Error::QueryRolledBack);
+				}
+			}
+			savepoints.truncate(pos);
+			*failed = None;
+			// The savepoint remains set after rolling back to it
+			let save_point = lock.new_save_point().await;
+			savepoints.push(Savepoint {
+				name: Some(name),
+				save_point,
+				results: self.results.len() + 1,
+				notifications: receiver.map_or(0, |r| r.len()),
+			});
+		} else {
+			// Release this savepoint, along with any savepoint set after it. The datastore
+			// savepoints are kept, as only the latest savepoint can be released from a datastore
+			// transaction, and they are only rolled back along with an earlier savepoint.
+			for savepoint in &mut savepoints[pos..] {
+				savepoint.name = None;
+			}
+		}
+		Ok(Value::None)
+	}
+
 	#[instrument(level = "debug", name = "executor", target = "surrealdb::core::dbs", skip_all)]
 	pub async fn execute(
 		kvs: &Datastore,
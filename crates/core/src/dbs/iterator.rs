@@ -412,6 +412,8 @@ impl Iterator {
 			}
 			// Process any SPLIT AT clause
 			self.output_split(stk, ctx, opt, stm, rs).await?;
+			// Process any window functions
+			self.output_window(stk, ctx, opt, stm, rs).await?;
 			// Process any GROUP BY clause
 			self.output_group(stk, ctx, opt, stm).await?;
 			// Process any ORDER BY clause
@@ -488,6 +490,11 @@ impl Iterator {
 			return false;
 		}
 
+		// If there are window functions we can't
+		if stm.expr().is_some_and(|e| e.has_window()) {
+			return false;
+		}
+
 		// If there is no specified order, we can
 		if stm.order().is_none() {
 			return true;
@@ -585,6 +592,33 @@ impl Iterator {
 		Ok(())
 	}
 
+	async fn output_window(
+		&mut self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		stm: &Statement<'_>,
+		rs: RecordStrategy,
+	) -> Result<(), Error> {
+		// Process any window functions
+		if let Results::Windows(w) = &mut self.results {
+			let values = w.output(stk, ctx, opt).await?;
+			// Collect the computed rows for the ORDER BY and LIMIT clauses
+			self.results = Results::prepare_collector(
+				#[cfg(storage)]
+				ctx,
+				stm,
+				self.start,
+				self.limit,
+			)?;
+			for val in values {
+				self.results.push(stk, ctx, opt, stm, rs, val).await?;
+			}
+		}
+		// Everything ok
+		Ok(())
+	}
+
 	async fn output_group(
 		&mut self,
 		stk: &mut Stk,
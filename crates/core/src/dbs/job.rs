@@ -0,0 +1,238 @@
+use crate::err::Error;
+use crate::sql::statements::info::InfoStructure;
+use crate::sql::{Datetime, Value};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// The status of the last run of a scheduled job
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[non_exhaustive]
+pub enum JobStatus {
+	Running,
+	Success,
+	Failure,
+}
+
+impl Display for JobStatus {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Running => f.write_str("running"),
+			Self::Success => f.write_str("success"),
+			Self::Failure => f.write_str("failure"),
+		}
+	}
+}
+
+/// The scheduling state of a job, shared by all nodes in the cluster
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[non_exhaustive]
+pub struct JobState {
+	/// The time in milliseconds at which the job is next due
+	pub next: u64,
+	/// The time at which the job was last started
+	pub last: Option<Datetime>,
+	/// The status of the last run of the job
+	pub status: Option<JobStatus>,
+	/// The error from the last failed run of the job
+	pub error: Option<String>,
+	/// The node which last ran the job
+	pub node: Option<Uuid>,
+}
+
+impl InfoStructure for JobState {
+	fn structure(self) -> Value {
+		Value::from(map! {
+			"next".to_string() => Value::from(Datetime::from(Utc.timestamp_millis_opt(self.next as i64).single().unwrap_or_default())),
+			"last".to_string(), if let Some(v) = self.last => Value::from(v),
+			"status".to_string(), if let Some(v) = self.status => Value::from(v.to_string()),
+			"error".to_string(), if let Some(v) = self.error => Value::from(v),
+			"node".to_string(), if let Some(v) = self.node => Value::from(v),
+		})
+	}
+}
+
+/// A standard five field cron expression, evaluated in UTC
+///
+/// The fields are minute, hour, day of month, month, and day of week,
+/// each of which can be a `*`, a value, a range `a-b`, a step `*/n` or
+/// `a-b/n`, or a comma separated list of these. When both the day of
+/// month and the day of week are restricted, either can match.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cron {
+	minutes: u64,
+	hours: u64,
+	days: u64,
+	months: u64,
+	weekdays: u64,
+	any_day: bool,
+	any_weekday: bool,
+}
+
+impl FromStr for Cron {
+	type Err = Error;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = match s.trim() {
+			"@yearly" | "@annually" => "0 0 1 1 *",
+			"@monthly" => "0 0 1 * *",
+			"@weekly" => "0 0 * * 0",
+			"@daily" | "@midnight" => "0 0 * * *",
+			"@hourly" => "0 * * * *",
+			v => v,
+		};
+		let fields = s.split_whitespace().collect::<Vec<_>>();
+		if fields.len() != 5 {
+			return Err(Error::InvalidCron(format!(
+				"expected 5 fields but found {}",
+				fields.len()
+			)));
+		}
+		// Sunday can be specified as either 0 or 7
+		let weekdays = parse_field(fields[4], 0, 7)?;
+		let weekdays = (weekdays | (weekdays >> 7)) & 0x7f;
+		Ok(Self {
+			minutes: parse_field(fields[0], 0, 59)?,
+			hours: parse_field(fields[1], 0, 23)?,
+			days: parse_field(fields[2], 1, 31)?,
+			months: parse_field(fields[3], 1, 12)?,
+			weekdays,
+			any_day: fields[2] == "*",
+			any_weekday: fields[4] == "*",
+		})
+	}
+}
+
+impl Cron {
+	/// Returns the first time matching this expression after the given time
+	pub fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+		// Start from the beginning of the following minute
+		let mut t = time.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+		// Give up if there is no match within the next few years
+		let limit = t.year() + 5;
+		while t.year() <= limit {
+			if !bit(self.months, t.month()) {
+				// Move to the start of the next month
+				let (y, m) = match t.month() {
+					12 => (t.year() + 1, 1),
+					m => (t.year(), m + 1),
+				};
+				t = Utc.with_ymd_and_hms(y, m, 1, 0, 0, 0).single()?;
+			} else if !self.day_matches(&t) {
+				// Move to the start of the next day
+				t = t.with_hour(0)?.with_minute(0)? + Duration::days(1);
+			} else if !bit(self.hours, t.hour()) {
+				// Move to the start of the next hour
+				t = t.with_minute(0)? + Duration::hours(1);
+			} else if !bit(self.minutes, t.minute()) {
+				t += Duration::minutes(1);
+			} else {
+				return Some(t);
+			}
+		}
+		None
+	}
+
+	fn day_matches(&self, t: &DateTime<Utc>) -> bool {
+		let day = bit(self.days, t.day());
+		let weekday = bit(self.weekdays, t.weekday().num_days_from_sunday());
+		match (self.any_day, self.any_weekday) {
+			(true, true) => true,
+			(true, false) => weekday,
+			(false, true) => day,
+			(false, false) => day || weekday,
+		}
+	}
+}
+
+fn bit(set: u64, v: u32) -> bool {
+	set & (1 << v) != 0
+}
+
+/// Parses a single cron field into a set of allowed values
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, Error> {
+	let invalid = || Error::InvalidCron(format!("invalid field '{field}'"));
+	let mut set = 0u64;
+	for part in field.split(',') {
+		let (range, step) = match part.split_once('/') {
+			Some((range, step)) => (range, step.parse::<u32>().map_err(|_| invalid())?),
+			None => (part, 1),
+		};
+		let (beg, end) = match range {
+			"*" => (min, max),
+			v => match v.split_once('-') {
+				Some((a, b)) => (
+					a.parse::<u32>().map_err(|_| invalid())?,
+					b.parse::<u32>().map_err(|_| invalid())?,
+				),
+				// A single value with a step runs until the end of the range
+				None if part.contains('/') => (v.parse::<u32>().map_err(|_| invalid())?, max),
+				None => {
+					let v = v.parse::<u32>().map_err(|_| invalid())?;
+					(v, v)
+				}
+			},
+		};
+		if step == 0 || beg < min || end > max || beg > end {
+			return Err(invalid());
+		}
+		for v in (beg..=end).step_by(step as usize) {
+			set |= 1 << v;
+		}
+	}
+	Ok(set)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+		Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+	}
+
+	#[test]
+	fn cron_next_after() {
+		let cron = Cron::from_str("*/15 * * * *").unwrap();
+		assert_eq!(cron.next_after(at(2024, 1, 1, 0, 0)), Some(at(2024, 1, 1, 0, 15)));
+		assert_eq!(cron.next_after(at(2024, 1, 1, 0, 50)), Some(at(2024, 1, 1, 1, 0)));
+		// Daily at 02:30
+		let cron = Cron::from_str("30 2 * * *").unwrap();
+		assert_eq!(cron.next_after(at(2024, 1, 1, 3, 0)), Some(at(2024, 1, 2, 2, 30)));
+		// Monday to Friday at 09:00, where 2024-01-06 is a Saturday
+		let cron = Cron::from_str("0 9 * * 1-5").unwrap();
+		assert_eq!(cron.next_after(at(2024, 1, 6, 0, 0)), Some(at(2024, 1, 8, 9, 0)));
+		// Sunday can be written as 7
+		let cron = Cron::from_str("0 0 * * 7").unwrap();
+		assert_eq!(cron.next_after(at(2024, 1, 1, 0, 0)), Some(at(2024, 1, 7, 0, 0)));
+		// Leap days
+		let cron = Cron::from_str("0 0 29 2 *").unwrap();
+		assert_eq!(cron.next_after(at(2024, 3, 1, 0, 0)), Some(at(2028, 2, 29, 0, 0)));
+		// Macros
+		let cron = Cron::from_str("@monthly").unwrap();
+		assert_eq!(cron.next_after(at(2024, 12, 15, 0, 0)), Some(at(2025, 1, 1, 0, 0)));
+		// Impossible dates never match
+		let cron = Cron::from_str("0 0 31 2 *").unwrap();
+		assert_eq!(cron.next_after(at(2024, 1, 1, 0, 0)), None);
+	}
+
+	#[test]
+	fn cron_invalid() {
+		for v in [
+			"",
+			"* * * *",
+			"60 * * * *",
+			"* 24 * * *",
+			"* * 0 * *",
+			"*/0 * * * *",
+			"5-1 * * * *",
+			"a * * * *",
+		] {
+			assert!(Cron::from_str(v).is_err(), "{v}");
+		}
+	}
+}
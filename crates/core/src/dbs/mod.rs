@@ -16,8 +16,11 @@ mod session;
 mod statement;
 mod store;
 mod variables;
+mod window;
 
 pub mod capabilities;
+pub mod event;
+pub mod job;
 pub mod node;
 
 pub use self::capabilities::Capabilities;
@@ -1,7 +1,7 @@
 use crate::cnf::MAX_COMPUTATION_DEPTH;
 use crate::dbs::Notification;
 use crate::err::Error;
-use crate::iam::{Action, Auth, ResourceKind};
+use crate::iam::{Action, Auth, Resource, ResourceKind};
 use crate::sql::statements::define::{DefineIndexStatement, DefineTableStatement};
 use crate::sql::Base;
 use async_channel::Sender;
@@ -424,6 +424,21 @@ impl Options {
 		self.auth.is_allowed(action, &res).map_err(Error::IamError)
 	}
 
+	/// Checks whether the grants of any custom roles
+	/// allow the current actor to perform the action
+	/// on all of the records in the specified table,
+	/// in which case table permissions are not needed.
+	pub fn is_table_granted(&self, action: Action, tb: &str) -> Result<bool, Error> {
+		// Most actors have no custom roles at all
+		if self.auth.grants().is_empty() {
+			return Ok(false);
+		}
+		// Check the grants against the specific table
+		let (ns, db) = self.ns_db()?;
+		let res = Resource::new(tb.to_owned(), ResourceKind::Table, (ns, db).into());
+		Ok(self.auth.is_allowed(action, &res).is_ok())
+	}
+
 	/// Checks the current server configuration, and
 	/// user authentication information to determine
 	/// whether we need to process table permissions
@@ -126,7 +126,7 @@ impl ExplainItem {
 				name: match rs {
 					RecordStrategy::Count => "Iterate Table Count",
 					RecordStrategy::KeysOnly => "Iterate Table Keys",
-					RecordStrategy::KeysAndValues => "Iterate Table",
+					RecordStrategy::KeysAndValues | RecordStrategy::Covering => "Iterate Table",
 				}
 				.into(),
 				details: vec![
@@ -138,7 +138,7 @@ impl ExplainItem {
 				name: match rs {
 					RecordStrategy::Count => "Iterate Range Count",
 					RecordStrategy::KeysOnly => "Iterate Range Keys",
-					RecordStrategy::KeysAndValues => "Iterate Range",
+					RecordStrategy::KeysAndValues | RecordStrategy::Covering => "Iterate Range",
 				}
 				.into(),
 				details: vec![
@@ -180,6 +180,7 @@ impl ExplainItem {
 						RecordStrategy::Count => "Iterate Index Count",
 						RecordStrategy::KeysOnly => "Iterate Index Keys",
 						RecordStrategy::KeysAndValues => "Iterate Index",
+						RecordStrategy::Covering => "Iterate Index Covering",
 					}
 					.into(),
 					details,
@@ -207,6 +208,7 @@ impl ExplainItem {
 					RecordStrategy::Count => "Count",
 					RecordStrategy::KeysOnly => "KeysOnly",
 					RecordStrategy::KeysAndValues => "KeysAndValues",
+					RecordStrategy::Covering => "Covering",
 				}
 				.into(),
 			)],
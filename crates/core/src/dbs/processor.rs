@@ -86,6 +86,7 @@ pub(super) enum Collected {
 	Count(usize),
 	IndexItem(IndexItemRecord),
 	IndexItemKey(IndexItemRecord),
+	IndexItemCovered(IndexItemRecord),
 }
 
 impl Collected {
@@ -114,6 +115,9 @@ impl Collected {
 			Self::Count(c) => Ok(Self::process_count(c)),
 			Self::IndexItem(i) => Self::process_index_item(opt, txn, i, rid_only).await,
 			Self::IndexItemKey(i) => Ok(Self::process_index_item_key(i)),
+			Self::IndexItemCovered(i) => {
+				Self::process_index_item(opt, txn, i.into_covered(), rid_only).await
+			}
 		}
 	}
 
@@ -478,7 +482,7 @@ pub(super) trait Collector {
 					RecordStrategy::KeysOnly => {
 						self.collect_range_keys(ctx, opt, &tb, v, sc).await?
 					}
-					RecordStrategy::KeysAndValues => {
+					RecordStrategy::KeysAndValues | RecordStrategy::Covering => {
 						self.collect_range(ctx, opt, &tb, v, sc).await?
 					}
 				},
@@ -489,7 +493,7 @@ pub(super) trait Collector {
 						RecordStrategy::KeysOnly => {
 							self.collect_table_keys(&ctx, opt, &v, sc).await?
 						}
-						RecordStrategy::KeysAndValues => {
+						RecordStrategy::KeysAndValues | RecordStrategy::Covering => {
 							self.collect_table(&ctx, opt, &v, sc).await?
 						}
 					}
@@ -914,7 +918,10 @@ pub(super) trait Collector {
 						self.collect_index_item_key(ctx, &txn, iterator).await?
 					}
 					RecordStrategy::KeysAndValues => {
-						self.collect_index_item_key_value(ctx, &txn, iterator).await?
+						self.collect_index_item_key_value(ctx, &txn, iterator, false).await?
+					}
+					RecordStrategy::Covering => {
+						self.collect_index_item_key_value(ctx, &txn, iterator, true).await?
 					}
 				}
 				// Everything ok
@@ -957,6 +964,7 @@ pub(super) trait Collector {
 		ctx: &Context,
 		txn: &Transaction,
 		mut iterator: ThingIterator,
+		covering: bool,
 	) -> Result<(), Error> {
 		while !ctx.is_done(true) {
 			let records: Vec<IndexItemRecord> =
@@ -968,7 +976,11 @@ pub(super) trait Collector {
 				if ctx.is_done(c % 100 == 0) {
 					break;
 				}
-				self.collect(Collected::IndexItem(r)).await?;
+				if covering {
+					self.collect(Collected::IndexItemCovered(r)).await?;
+				} else {
+					self.collect(Collected::IndexItem(r)).await?;
+				}
 			}
 		}
 		Ok(())
@@ -5,6 +5,7 @@ use crate::dbs::file::FileCollector;
 use crate::dbs::group::GroupsCollector;
 use crate::dbs::plan::Explanation;
 use crate::dbs::store::{MemoryCollector, MemoryOrdered, MemoryOrderedLimit, MemoryRandom};
+use crate::dbs::window::WindowsCollector;
 use crate::dbs::{Options, Statement};
 use crate::err::Error;
 use crate::idx::planner::RecordStrategy;
@@ -21,6 +22,7 @@ pub(super) enum Results {
 	#[cfg(storage)]
 	File(Box<FileCollector>),
 	Groups(GroupsCollector),
+	Windows(WindowsCollector),
 }
 
 impl Results {
@@ -34,6 +36,26 @@ impl Results {
 		if stm.expr().is_some() && stm.group().is_some() {
 			return Ok(Self::Groups(GroupsCollector::new(stm)));
 		}
+		// Every row is required before computing the window functions
+		if stm.expr().is_some_and(|e| e.has_window()) {
+			return Ok(Self::Windows(WindowsCollector::new(stm)));
+		}
+		Self::prepare_collector(
+			#[cfg(storage)]
+			ctx,
+			stm,
+			start,
+			limit,
+		)
+	}
+
+	/// Prepares the collector of rows which are fully computed
+	pub(super) fn prepare_collector(
+		#[cfg(storage)] ctx: &Context,
+		stm: &Statement<'_>,
+		start: Option<u32>,
+		limit: Option<u32>,
+	) -> Result<Self, Error> {
 		#[cfg(storage)]
 		if stm.tempfiles() {
 			if let Some(temp_dir) = ctx.temporary_directory() {
@@ -90,6 +112,9 @@ impl Results {
 			Self::Groups(g) => {
 				g.push(stk, ctx, opt, stm, rs, val).await?;
 			}
+			Self::Windows(w) => {
+				w.push(val);
+			}
 		}
 		Ok(())
 	}
@@ -103,7 +128,7 @@ impl Results {
 			Self::MemoryOrdered(c) => c.sort().await?,
 			Self::MemoryOrderedLimit(c) => c.sort(),
 			Self::MemoryRandom(c) => c.sort(),
-			Self::None | Self::Memory(_) | Self::Groups(_) => {}
+			Self::None | Self::Memory(_) | Self::Groups(_) | Self::Windows(_) => {}
 		}
 		Ok(())
 	}
@@ -117,7 +142,7 @@ impl Results {
 			Self::MemoryRandom(c) => c.sort(),
 			#[cfg(storage)]
 			Self::File(f) => f.sort(orders),
-			Self::None | Self::Groups(_) | Self::Memory(_) => {}
+			Self::None | Self::Groups(_) | Self::Windows(_) | Self::Memory(_) => {}
 		}
 	}
 
@@ -139,7 +164,7 @@ impl Results {
 			Self::MemoryRandom(c) => c.start_limit(start, limit),
 			#[cfg(storage)]
 			Self::File(f) => f.start_limit(start, limit),
-			Self::None | Self::Groups(_) => {}
+			Self::None | Self::Groups(_) | Self::Windows(_) => {}
 		}
 		Ok(())
 	}
@@ -158,6 +183,7 @@ impl Results {
 			#[cfg(storage)]
 			Self::File(e) => e.len(),
 			Self::Groups(g) => g.len(),
+			Self::Windows(w) => w.len(),
 		}
 	}
 
@@ -169,7 +195,7 @@ impl Results {
 			Self::MemoryRandom(c) => c.take_vec(),
 			#[cfg(storage)]
 			Self::File(f) => f.take_vec().await?,
-			Self::None | Self::Groups(_) => vec![],
+			Self::None | Self::Groups(_) | Self::Windows(_) => vec![],
 		})
 	}
 
@@ -189,6 +215,9 @@ impl Results {
 			Self::Groups(g) => {
 				g.explain(exp);
 			}
+			Self::Windows(w) => {
+				w.explain(exp);
+			}
 		}
 	}
 }
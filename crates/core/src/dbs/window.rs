@@ -0,0 +1,328 @@
+use crate::ctx::Context;
+use crate::dbs::plan::Explanation;
+use crate::dbs::{Options, Statement};
+use crate::err::Error;
+use crate::sql::order::OrderList;
+use crate::sql::value::Value;
+use crate::sql::window::{FrameBound, Window};
+use crate::sql::{Array, Field, FlowResultExt as _, Function, Idiom};
+use reblessive::tree::Stk;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::mem;
+use std::ops::Range;
+
+/// Collects the rows of a SELECT statement with fields computed over a window of rows.
+/// While each row is processed, only the inputs of the window functions are computed,
+/// the functions are computed once every row has been collected.
+pub(super) struct WindowsCollector {
+	/// The output path, the function and the window of each window field
+	fields: Vec<(Idiom, Function, Window)>,
+	/// Whether this is a single VALUE field expression
+	single: bool,
+	rows: Vec<Value>,
+}
+
+/// The inputs of a window function for a single row, as computed by `Window::input`
+struct Input {
+	/// The value which is aggregated, or which is returned by the navigation functions
+	value: Value,
+	/// The other arguments of the window function
+	args: Vec<Value>,
+	partition: Array,
+	order: Vec<Value>,
+}
+
+impl From<Value> for Input {
+	fn from(v: Value) -> Self {
+		let mut v = match v {
+			Value::Array(a) => a.0.into_iter(),
+			_ => Vec::new().into_iter(),
+		};
+		let value = v.next().unwrap_or_default();
+		let mut next = || match v.next() {
+			Some(Value::Array(a)) => a.0,
+			_ => Vec::new(),
+		};
+		Self {
+			value,
+			args: next(),
+			partition: Array(next()),
+			order: next(),
+		}
+	}
+}
+
+impl WindowsCollector {
+	pub(super) fn new(stm: &Statement<'_>) -> Self {
+		let mut fields = Vec::new();
+		let mut single = false;
+		if let Some(expr) = stm.expr() {
+			single = expr.single().is_some();
+			for field in expr.other() {
+				if let Field::Window {
+					expr: Value::Function(f),
+					window,
+					..
+				} = field
+				{
+					if let Some(name) = field.name() {
+						fields.push((name.into_owned(), f.as_ref().clone(), window.clone()));
+					}
+				}
+			}
+		}
+		Self {
+			fields,
+			single,
+			rows: Vec::new(),
+		}
+	}
+
+	pub(super) fn push(&mut self, val: Value) {
+		self.rows.push(val);
+	}
+
+	pub(super) fn len(&self) -> usize {
+		self.rows.len()
+	}
+
+	pub(super) async fn output(
+		&mut self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+	) -> Result<Vec<Value>, Error> {
+		let mut rows = mem::take(&mut self.rows);
+		for (idiom, func, window) in &self.fields {
+			// Collect the inputs stored in place of the output of the field
+			let inputs: Vec<Input> = rows
+				.iter()
+				.map(|row| match self.single {
+					true => Input::from(row.clone()),
+					false => Input::from(row.pick(idiom)),
+				})
+				.collect();
+			// Replace the inputs with the output of the function
+			let values = Self::compute(stk, ctx, opt, func, window, &inputs).await?;
+			for (row, val) in rows.iter_mut().zip(values) {
+				match self.single {
+					true => *row = val,
+					false => row.set(stk, ctx, opt, idiom, val).await?,
+				}
+			}
+		}
+		Ok(rows)
+	}
+
+	async fn compute(
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		func: &Function,
+		window: &Window,
+		inputs: &[Input],
+	) -> Result<Vec<Value>, Error> {
+		let mut out = vec![Value::None; inputs.len()];
+		// Split the rows into partitions, keeping the order in which they were collected
+		let mut partitions: BTreeMap<&Array, Vec<usize>> = BTreeMap::new();
+		for (i, input) in inputs.iter().enumerate() {
+			partitions.entry(&input.partition).or_default().push(i);
+		}
+		for mut rows in partitions.into_values() {
+			if let Some(orders) = &window.order {
+				rows.sort_by(|a, b| compare(orders, &inputs[*a].order, &inputs[*b].order));
+			}
+			let partition = Partition::new(window, inputs, rows);
+			// The result of the last aggregation, reused while the frame does not change
+			let mut last: Option<(Range<usize>, Value)> = None;
+			for pos in 0..partition.rows.len() {
+				let val = if func.is_window() {
+					partition.navigate(func, pos)?
+				} else {
+					let frame = partition.frame(pos);
+					match last {
+						Some((ref r, ref v)) if *r == frame => v.clone(),
+						_ => {
+							let vals: Vec<Value> = partition.rows[frame.clone()]
+								.iter()
+								.map(|i| inputs[*i].value.clone())
+								.collect();
+							let v = func
+								.aggregate(vals.into())?
+								.compute(stk, ctx, opt, None)
+								.await
+								.catch_return()?;
+							last = Some((frame, v.clone()));
+							v
+						}
+					}
+				};
+				out[partition.rows[pos]] = val;
+			}
+		}
+		Ok(out)
+	}
+
+	pub(super) fn explain(&self, exp: &mut Explanation) {
+		let mut explain = BTreeMap::new();
+		for (idiom, func, window) in &self.fields {
+			explain.insert(
+				Value::from(idiom.clone()).to_string(),
+				Value::from(format!("{func} OVER ({window})")),
+			);
+		}
+		exp.add_collector("Windows", vec![("idioms", explain.into())]);
+	}
+}
+
+/// The ordered rows of a partition
+struct Partition<'a> {
+	window: &'a Window,
+	inputs: &'a [Input],
+	/// The index of the inputs of each row
+	rows: Vec<usize>,
+	/// The first row which is equal to each row according to the ordering
+	peer_start: Vec<usize>,
+	/// The row following the last row which is equal to each row
+	peer_end: Vec<usize>,
+	/// The number of distinct rows according to the ordering, up to each row
+	dense_rank: Vec<usize>,
+}
+
+impl<'a> Partition<'a> {
+	fn new(window: &'a Window, inputs: &'a [Input], rows: Vec<usize>) -> Self {
+		let len = rows.len();
+		let mut peer_start = Vec::with_capacity(len);
+		let mut dense_rank = Vec::with_capacity(len);
+		for pos in 0..len {
+			// Without any ordering, every row of the partition is a peer
+			let peer = pos > 0
+				&& window.order.as_ref().is_none_or(|o| {
+					compare(o, &inputs[rows[pos - 1]].order, &inputs[rows[pos]].order)
+						== Ordering::Equal
+				});
+			if peer {
+				peer_start.push(peer_start[pos - 1]);
+				dense_rank.push(dense_rank[pos - 1]);
+			} else {
+				peer_start.push(pos);
+				dense_rank.push(dense_rank.last().map_or(1, |r| r + 1));
+			}
+		}
+		let mut peer_end = vec![len; len];
+		for pos in (0..len.saturating_sub(1)).rev() {
+			if peer_start[pos + 1] == peer_start[pos] {
+				peer_end[pos] = peer_end[pos + 1];
+			} else {
+				peer_end[pos] = pos + 1;
+			}
+		}
+		Self {
+			window,
+			inputs,
+			rows,
+			peer_start,
+			peer_end,
+			dense_rank,
+		}
+	}
+
+	/// The rows over which an aggregate function is computed for the given row. Without a frame,
+	/// these are all the rows of the partition up to the last peer of the row when the rows are
+	/// ordered, or the whole partition otherwise.
+	fn frame(&self, pos: usize) -> Range<usize> {
+		let len = self.rows.len();
+		let Some(frame) = &self.window.frame else {
+			return match self.window.order {
+				Some(_) => 0..self.peer_end[pos],
+				None => 0..len,
+			};
+		};
+		let bound = |b: &FrameBound| match b {
+			FrameBound::UnboundedPreceding => 0,
+			FrameBound::Preceding(n) => pos as i128 - *n as i128,
+			FrameBound::CurrentRow => pos as i128,
+			FrameBound::Following(n) => pos as i128 + *n as i128,
+			FrameBound::UnboundedFollowing => len as i128 - 1,
+		};
+		let start = bound(&frame.start).clamp(0, len as i128) as usize;
+		let end = (bound(&frame.end) + 1).clamp(0, len as i128) as usize;
+		start..end.max(start)
+	}
+
+	/// Computes a window function which does not aggregate values
+	fn navigate(&self, func: &Function, pos: usize) -> Result<Value, Error> {
+		let name = func.name().unwrap_or_default();
+		let input = &self.inputs[self.rows[pos]];
+		let value = |pos: usize| self.inputs[self.rows[pos]].value.clone();
+		Ok(match name {
+			"row_number" => (pos + 1).into(),
+			"rank" => (self.peer_start[pos] + 1).into(),
+			"dense_rank" => self.dense_rank[pos].into(),
+			"first_value" => {
+				let frame = self.frame(pos);
+				if frame.is_empty() {
+					Value::None
+				} else {
+					value(frame.start)
+				}
+			}
+			"last_value" => {
+				let frame = self.frame(pos);
+				if frame.is_empty() {
+					Value::None
+				} else {
+					value(frame.end - 1)
+				}
+			}
+			"lag" | "lead" => {
+				let offset = match input.args.first() {
+					None | Some(Value::None) => 1,
+					Some(Value::Number(n)) if n.is_int() && !n.is_negative() => n.to_usize(),
+					Some(v) => {
+						return Err(Error::InvalidArguments {
+							name: name.to_owned(),
+							message: format!(
+								"The offset must be a positive integer, but found '{v}'"
+							),
+						})
+					}
+				};
+				let target = match name {
+					"lag" => pos.checked_sub(offset),
+					_ => pos.checked_add(offset).filter(|p| *p < self.rows.len()),
+				};
+				match target {
+					Some(target) => value(target),
+					None => input.args.get(1).cloned().unwrap_or_default(),
+				}
+			}
+			_ => {
+				return Err(Error::InvalidFunction {
+					name: name.to_owned(),
+					message: "no such window function found".to_owned(),
+				})
+			}
+		})
+	}
+}
+
+/// Compares the ordering values of two rows
+fn compare(orders: &OrderList, a: &[Value], b: &[Value]) -> Ordering {
+	for (i, order) in orders.iter().enumerate() {
+		let (Some(a), Some(b)) = (a.get(i), b.get(i)) else {
+			continue;
+		};
+		// Reverse the ordering if DESC
+		let o = match order.direction {
+			true => a.compare(b, &[], order.collate, order.numeric),
+			false => b.compare(a, &[], order.collate, order.numeric),
+		};
+		match o {
+			Some(Ordering::Equal) | None => continue,
+			Some(o) => return o,
+		}
+	}
+	Ordering::Equal
+}
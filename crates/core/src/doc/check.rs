@@ -2,6 +2,7 @@ use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::dbs::Statement;
 use crate::dbs::Workable;
+use crate::doc::CursorDoc;
 use crate::doc::Document;
 use crate::doc::Permitted::*;
 use crate::err::Error;
@@ -355,8 +356,10 @@ impl Document {
 	) -> Result<(), Error> {
 		// Check if this record exists
 		if self.id.is_some() {
-			// Should we run permissions checks?
-			if opt.check_perms(Action::View)? {
+			// Should we run permissions checks, unless a custom role grants the table?
+			if opt.check_perms(Action::View)?
+				&& !opt.is_table_granted(Action::View, &self.id()?.tb)?
+			{
 				// Get the table for this document
 				let table = self.tb(ctx, opt).await?;
 				// Get the correct document to check
@@ -382,6 +385,41 @@ impl Document {
 		// Carry on
 		Ok(())
 	}
+	/// Checks that this record can be viewed by the
+	/// user, based on the `select` permissions for the
+	/// table, and that it matches the `WHERE` condition
+	/// of the statement, without processing the rest of
+	/// the statement. This function is used to compute
+	/// values over many index matches, such as search
+	/// ranks and facets, from visible records only. The
+	/// document is returned with only the fields which
+	/// the user is permitted to view.
+	pub(crate) async fn check_visible(
+		&mut self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		stm: &Statement<'_>,
+	) -> Result<Option<&CursorDoc>, Error> {
+		// Check the table permissions
+		match self.check_permissions_view(stk, ctx, opt, stm).await {
+			Err(Error::Ignore) => return Ok(None),
+			res => res?,
+		}
+		// Process the permitted document
+		let current = match self.reduced(stk, ctx, opt, Current).await? {
+			true => &self.current_reduced,
+			false => &self.current,
+		};
+		// Check if the WHERE condition is truthy
+		if let Some(cond) = stm.cond() {
+			if !cond.compute(stk, ctx, opt, Some(current)).await.catch_return()?.is_truthy() {
+				return Ok(None);
+			}
+		}
+		// The document is visible
+		Ok(Some(current))
+	}
 	/// Checks the `PERMISSIONS` clause on the table
 	/// for this record, returning immediately if the
 	/// permissions are `NONE`. This function does not
@@ -396,8 +434,8 @@ impl Document {
 	) -> Result<(), Error> {
 		// Check if this record exists
 		if self.id.is_some() {
-			// Should we run permissions checks?
-			if opt.check_perms(stm.into())? {
+			// Should we run permissions checks, unless a custom role grants the table?
+			if opt.check_perms(stm.into())? && !opt.is_table_granted(stm.into(), &self.id()?.tb)? {
 				// Get the table for this document
 				let table = self.tb(ctx, opt).await?;
 				// Get the permissions for this table
@@ -425,8 +463,8 @@ impl Document {
 	) -> Result<(), Error> {
 		// Check if this record exists
 		if self.id.is_some() {
-			// Should we run permissions checks?
-			if opt.check_perms(stm.into())? {
+			// Should we run permissions checks, unless a custom role grants the table?
+			if opt.check_perms(stm.into())? && !opt.is_table_granted(stm.into(), &self.id()?.tb)? {
 				// Check that record authentication matches session
 				if opt.auth.is_record() {
 					let ns = opt.ns()?;
@@ -1,4 +1,5 @@
 use crate::ctx::{Context, MutableContext};
+use crate::dbs::event::{timestamp, QueuedEvent};
 use crate::dbs::Options;
 use crate::dbs::Statement;
 use crate::doc::Document;
@@ -6,6 +7,7 @@ use crate::err::Error;
 use crate::sql::value::Value;
 use crate::sql::FlowResultExt as _;
 use reblessive::tree::Stk;
+use uuid::Uuid;
 
 impl Document {
 	/// Processes any DEFINE EVENT clauses which
@@ -13,6 +15,9 @@ impl Document {
 	/// record belongs to. This functions loops
 	/// through the events and processes them all
 	/// within the currently running transaction.
+	/// Asynchronous events are instead queued, and
+	/// are processed in the background once the
+	/// transaction has been committed.
 	pub(super) async fn process_table_events(
 		&mut self,
 		stk: &mut Stk,
@@ -49,18 +54,39 @@ impl Document {
 			};
 			// Configure the context
 			let mut ctx = MutableContext::new(ctx);
-			ctx.add_value("event", evt.into());
+			ctx.add_value("event", evt.clone().into());
 			ctx.add_value("value", doc.doc.as_arc());
-			ctx.add_value("after", after);
-			ctx.add_value("before", before);
+			ctx.add_value("after", after.clone());
+			ctx.add_value("before", before.clone());
 			// Freeze the context
 			let ctx = ctx.freeze();
 			// Process conditional clause
 			let val = ev.when.compute(stk, &ctx, opt, Some(doc)).await.catch_return()?;
 			// Execute event if value is truthy
 			if val.is_truthy() {
-				for v in ev.then.iter() {
-					v.compute(stk, &ctx, opt, Some(doc)).await.catch_return()?;
+				if ev.asynchronous.is_some() {
+					// Get the NS and DB
+					let (ns, db) = opt.ns_db()?;
+					// Queue the event for background processing
+					let val = QueuedEvent {
+						ns: ns.to_owned(),
+						db: db.to_owned(),
+						tb: ev.what.to_raw(),
+						ev: ev.name.to_raw(),
+						id: self.inner_id()?,
+						event: evt,
+						before: before.as_ref().clone(),
+						after: after.as_ref().clone(),
+						auth: opt.auth.as_ref().clone(),
+						attempts: 0,
+						error: None,
+					};
+					let key = crate::key::root::eq::new(timestamp()?, Uuid::now_v7());
+					ctx.tx().set(key, revision::to_vec(&val)?, None).await?;
+				} else {
+					for v in ev.then.iter() {
+						v.compute(stk, &ctx, opt, Some(doc)).await.catch_return()?;
+					}
 				}
 			}
 		}
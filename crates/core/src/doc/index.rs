@@ -3,7 +3,9 @@ use crate::dbs::Options;
 use crate::dbs::{Force, Statement};
 use crate::doc::{CursorDoc, Document};
 use crate::err::Error;
+use crate::idx::entry::IndexEntry;
 use crate::idx::ft::FtIndex;
+use crate::idx::trees::geohash::GeohashIndex;
 use crate::idx::trees::mtree::MTreeIndex;
 use crate::idx::IndexKeyBase;
 use crate::key;
@@ -11,7 +13,7 @@ use crate::key;
 use crate::kvs::ConsumeResult;
 use crate::kvs::TransactionType;
 use crate::sql::array::Array;
-use crate::sql::index::{HnswParams, Index, MTreeParams, SearchParams};
+use crate::sql::index::{GeohashParams, HnswParams, Index, MTreeParams, SearchParams};
 use crate::sql::statements::DefineIndexStatement;
 use crate::sql::{FlowResultExt as _, Part, Thing, Value};
 use reblessive::tree::Stk;
@@ -94,6 +96,7 @@ impl Document {
 			Index::Search(p) => ic.index_full_text(stk, ctx, p).await?,
 			Index::MTree(p) => ic.index_mtree(stk, ctx, p).await?,
 			Index::Hnsw(p) => ic.index_hnsw(ctx, p).await?,
+			Index::Geohash(p) => ic.index_geohash(ctx, p).await?,
 		}
 		Ok(())
 	}
@@ -102,6 +105,8 @@ impl Document {
 	/// Eg. IF the index is composed of the columns `name` and `instrument`
 	/// Given this doc: { "id": 1, "instrument":"piano", "name":"Tobie" }
 	/// It will return: ["Tobie", "piano"]
+	/// The values of the included fields (if any) follow the values of the columns.
+	/// Returns `None` if there is no document, or if it does not match the condition of a partial index.
 	pub(crate) async fn build_opt_values(
 		stk: &mut Stk,
 		ctx: &Context,
@@ -112,8 +117,15 @@ impl Document {
 		if !doc.doc.as_ref().is_some() {
 			return Ok(None);
 		}
-		let mut o = Vec::with_capacity(ix.cols.len());
-		for i in ix.cols.iter() {
+		// A partial index only contains the records matching its condition
+		if let Some(cond) = &ix.cond {
+			if !cond.compute(stk, ctx, opt, Some(doc)).await.catch_return()?.is_truthy() {
+				return Ok(None);
+			}
+		}
+		let include = ix.include.as_ref().map(|i| i.len()).unwrap_or(0);
+		let mut o = Vec::with_capacity(ix.cols.len() + include);
+		for i in ix.cols.iter().chain(ix.include.iter().flat_map(|i| i.iter())) {
 			let v = i.compute(stk, ctx, opt, Some(doc)).await.catch_return()?;
 			o.push(v);
 		}
@@ -304,10 +316,11 @@ impl<'a> IndexOperation<'a> {
 		let mut txn = txn.lock().await;
 		// Delete the old index data
 		if let Some(o) = self.o.take() {
+			let entry = IndexEntry::new(self.ix, self.rid, &o).encode()?;
 			let i = Indexable::new(o, self.ix);
 			for o in i {
 				let key = self.get_unique_index_key(&o)?;
-				match txn.delc(key, Some(revision::to_vec(self.rid)?)).await {
+				match txn.delc(key, Some(entry.clone())).await {
 					Err(Error::TxConditionNotMet) => Ok(()),
 					Err(e) => Err(e),
 					Ok(v) => Ok(v),
@@ -316,14 +329,15 @@ impl<'a> IndexOperation<'a> {
 		}
 		// Create the new index data
 		if let Some(n) = self.n.take() {
+			let entry = IndexEntry::new(self.ix, self.rid, &n).encode()?;
 			let i = Indexable::new(n, self.ix);
 			for n in i {
 				if !n.is_all_none_or_null() {
 					let key = self.get_unique_index_key(&n)?;
-					if txn.putc(key, revision::to_vec(self.rid)?, None).await.is_err() {
+					if txn.putc(key, entry.clone(), None).await.is_err() {
 						let key = self.get_unique_index_key(&n)?;
 						let val = txn.get(key, None).await?.unwrap();
-						let rid: Thing = revision::from_slice(&val)?;
+						let rid = IndexEntry::decode(self.ix, &val)?.into_rid();
 						return self.err_index_exists(rid, n);
 					}
 				}
@@ -339,10 +353,11 @@ impl<'a> IndexOperation<'a> {
 		let mut txn = txn.lock().await;
 		// Delete the old index data
 		if let Some(o) = self.o.take() {
+			let entry = IndexEntry::new(self.ix, self.rid, &o).encode()?;
 			let i = Indexable::new(o, self.ix);
 			for o in i {
 				let key = self.get_non_unique_index_key(&o)?;
-				match txn.delc(key, Some(revision::to_vec(self.rid)?)).await {
+				match txn.delc(key, Some(entry.clone())).await {
 					Err(Error::TxConditionNotMet) => Ok(()),
 					Err(e) => Err(e),
 					Ok(v) => Ok(v),
@@ -351,13 +366,14 @@ impl<'a> IndexOperation<'a> {
 		}
 		// Create the new index data
 		if let Some(n) = self.n.take() {
+			let entry = IndexEntry::new(self.ix, self.rid, &n).encode()?;
 			let i = Indexable::new(n, self.ix);
 			for n in i {
 				let key = self.get_non_unique_index_key(&n)?;
-				if txn.putc(key, revision::to_vec(self.rid)?, None).await.is_err() {
+				if txn.putc(key, entry.clone(), None).await.is_err() {
 					let key = self.get_non_unique_index_key(&n)?;
 					let val = txn.get(key, None).await?.unwrap();
-					let rid: Thing = revision::from_slice(&val)?;
+					let rid = IndexEntry::decode(self.ix, &val)?.into_rid();
 					return self.err_index_exists(rid, n);
 				}
 			}
@@ -427,6 +443,32 @@ impl<'a> IndexOperation<'a> {
 		if let Some(n) = self.n.take() {
 			hnsw.index_document(&ctx.tx(), &self.rid.id, &n).await?;
 		}
+		// Train the quantizer once the index contains enough vectors
+		if hnsw.schedule_training() {
+			// The training runs in the background, outside of this transaction
+			#[cfg(not(target_family = "wasm"))]
+			if let Some(ib) = ctx.get_index_builder() {
+				ib.train_hnsw(ctx, self.opt.clone(), self.ix.clone(), p.clone());
+				return Ok(());
+			}
+			hnsw.train(&ctx.tx()).await?;
+		}
+		Ok(())
+	}
+
+	async fn index_geohash(&mut self, ctx: &Context, p: &GeohashParams) -> Result<(), Error> {
+		let txn = ctx.tx();
+		let (ns, db) = self.opt.ns_db()?;
+		let ikb = IndexKeyBase::new(ns, db, self.ix)?;
+		let gh = GeohashIndex::new(ikb, p);
+		// Delete the old index data
+		if let Some(o) = self.o.take() {
+			gh.remove_document(&txn, &self.rid.id, &o).await?;
+		}
+		// Create the new index data
+		if let Some(n) = self.n.take() {
+			gh.index_document(&txn, &self.rid.id, &n).await?;
+		}
 		Ok(())
 	}
 }
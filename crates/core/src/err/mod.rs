@@ -262,6 +262,10 @@ pub enum Error {
 	#[error("Invalid regular expression: {0:?}")]
 	InvalidRegex(String),
 
+	/// Invalid cron expression
+	#[error("Invalid cron expression: {0}")]
+	InvalidCron(String),
+
 	/// Invalid timeout
 	#[error("Invalid timeout: {0:?} seconds")]
 	InvalidTimeout(u64),
@@ -288,6 +292,26 @@ pub enum Error {
 		message: String,
 	},
 
+	/// The query was executed, but its changes were rolled back to a savepoint
+	#[error("The query was rolled back to a savepoint")]
+	QueryRolledBack,
+
+	/// The savepoint does not exist in the current transaction
+	#[error("The savepoint '{name}' does not exist in the current transaction")]
+	SavepointNotFound {
+		name: String,
+	},
+
+	/// The savepoint can not be released, as a statement failed since it was set
+	#[error("The savepoint '{name}' can not be released after a statement failed, use ROLLBACK TO to discard the changes")]
+	SavepointFailed {
+		name: String,
+	},
+
+	/// A savepoint statement was used outside of a transaction
+	#[error("Savepoints can only be used within a transaction")]
+	SavepointOutsideTransaction,
+
 	/// The permissions do not allow for changing to the specified namespace
 	#[error("You don't have permission to change to the {ns} namespace")]
 	NsNotAllowed {
@@ -360,6 +384,18 @@ pub enum Error {
 		name: String,
 	},
 
+	/// The requested job does not exist
+	#[error("The job '{name}' does not exist")]
+	JbNotFound {
+		name: String,
+	},
+
+	/// The requested sink does not exist
+	#[error("The sink '{name}' does not exist")]
+	SkNotFound {
+		name: String,
+	},
+
 	/// The requested config does not exist
 	#[error("The config for {name} does not exist")]
 	CgNotFound {
@@ -432,6 +468,27 @@ pub enum Error {
 		db: String,
 	},
 
+	/// The requested root role does not exist
+	#[error("The root role '{name}' does not exist")]
+	RoleRootNotFound {
+		name: String,
+	},
+
+	/// The requested namespace role does not exist
+	#[error("The role '{name}' does not exist in the namespace '{ns}'")]
+	RoleNsNotFound {
+		name: String,
+		ns: String,
+	},
+
+	/// The requested database role does not exist
+	#[error("The role '{name}' does not exist in the database '{db}'")]
+	RoleDbNotFound {
+		name: String,
+		ns: String,
+		db: String,
+	},
+
 	/// Unable to perform the realtime query
 	#[error("Unable to perform the realtime query")]
 	RealtimeDisabled,
@@ -765,6 +822,10 @@ pub enum Error {
 	#[error("A value can't be highlighted: {0}")]
 	HighlightError(String),
 
+	/// Represents an error when parsing a full-text query string
+	#[error("The full-text query is invalid: {0}")]
+	FtQueryError(String),
+
 	/// Represents an underlying error with Bincode serializing / deserializing
 	#[error("Bincode error: {0}")]
 	Bincode(#[from] BincodeError),
@@ -959,6 +1020,25 @@ pub enum Error {
 		name: String,
 	},
 
+	/// The requested job already exists
+	#[error("The job '{name}' already exists")]
+	JbAlreadyExists {
+		name: String,
+	},
+
+	/// The requested sink already exists
+	#[error("The sink '{name}' already exists")]
+	SkAlreadyExists {
+		name: String,
+	},
+
+	/// A sink was defined on a table without a changefeed
+	#[error("The sink '{name}' requires a changefeed on the table '{tb}'")]
+	SkChangefeedRequired {
+		name: String,
+		tb: String,
+	},
+
 	/// The requested config already exists
 	#[error("The config for {name} already exists")]
 	CgAlreadyExists {
@@ -1004,6 +1084,33 @@ pub enum Error {
 		db: String,
 	},
 
+	/// The requested root role already exists
+	#[error("The root role '{name}' already exists")]
+	RoleRootAlreadyExists {
+		name: String,
+	},
+
+	/// The requested namespace role already exists
+	#[error("The role '{name}' already exists in the namespace '{ns}'")]
+	RoleNsAlreadyExists {
+		name: String,
+		ns: String,
+	},
+
+	/// The requested database role already exists
+	#[error("The role '{name}' already exists in the database '{db}'")]
+	RoleDbAlreadyExists {
+		name: String,
+		ns: String,
+		db: String,
+	},
+
+	/// The role name is reserved for a built-in role
+	#[error("The role '{name}' is a built-in role and can not be redefined")]
+	RoleBuiltIn {
+		name: String,
+	},
+
 	/// A database index entry for the specified table is already building
 	#[error("Database index `{name}` is currently building")]
 	IndexAlreadyBuilding {
@@ -1199,6 +1306,16 @@ pub enum Error {
 	#[error("Encountered an issue while processed export config: found {0}, but expected {1}.")]
 	InvalidExportConfig(Value, String),
 
+	#[error("Encountered an issue while processing import config: {0}")]
+	InvalidImportConfig(String),
+
+	/// A row of a CSV or NDJSON import could not be imported
+	#[error("Unable to import row {row}: {message}")]
+	InvalidImportRow {
+		row: usize,
+		message: String,
+	},
+
 	/// Found an unexpected value in a range
 	#[error("Found {found} for bound but expected {expected}.")]
 	InvalidBound {
@@ -1280,6 +1397,14 @@ pub enum Error {
 
 	#[error("File access denied: {0}")]
 	FileAccessDenied(String),
+
+	/// There was a problem creating or restoring a backup
+	#[error("There was a problem with the backup: {0}")]
+	Backup(String),
+
+	/// There was a problem exporting or importing columnar data
+	#[error("There was a problem with the columnar data: {0}")]
+	Columnar(String),
 }
 
 impl From<Error> for String {
@@ -522,9 +522,11 @@ pub async fn asynchronous(
 		"record::refs" => record::refs((stk, ctx, opt, doc)).await,
 		//
 		"search::analyze" => search::analyze((stk, ctx, Some(opt))).await,
+		"search::facets" => search::facets((stk, ctx, Some(opt), doc)).await,
 		"search::score" => search::score((ctx, doc)).await,
 		"search::highlight" => search::highlight((ctx, doc)).await,
 		"search::offsets" => search::offsets((ctx, doc)).await,
+		"search::rrf" => search::rrf((stk, ctx, Some(opt), doc)).await,
 		//
 		"sleep" => sleep::sleep(ctx).await,
 		//
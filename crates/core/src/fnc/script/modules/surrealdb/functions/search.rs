@@ -9,7 +9,9 @@ impl_module_def!(
 	Package,
 	"search",
 	"analyze" => fut Async,
+	"facets" => fut Async,
 	"highlight" => fut Async,
 	"offsets" => fut Async,
+	"rrf" => fut Async,
 	"score" => fut Async
 );
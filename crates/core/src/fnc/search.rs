@@ -6,6 +6,7 @@ use crate::fnc::get_execution_context;
 use crate::idx::ft::analyzer::Analyzer;
 use crate::idx::ft::highlighter::HighlightParams;
 use crate::sql::Value;
+use crate::syn;
 use reblessive::tree::Stk;
 
 pub async fn analyze(
@@ -32,6 +33,43 @@ pub async fn score(
 	Ok(Value::None)
 }
 
+pub async fn rrf(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, Option<&Options>, Option<&CursorDoc>),
+	(match_ref, k): (Value, Option<f64>),
+) -> Result<Value, Error> {
+	if let (Some(opt), Some((exe, _, thg))) = (opt, get_execution_context(ctx, doc)) {
+		let k = k.unwrap_or(60.0);
+		if k.is_nan() || k < 0.0 {
+			return Err(Error::InvalidArguments {
+				name: String::from("search::rrf"),
+				message: String::from("The constant k must be a non-negative number."),
+			});
+		}
+		return exe.rrf(stk, ctx, opt, &match_ref, thg, k).await;
+	}
+	Ok(Value::None)
+}
+
+pub async fn facets(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, Option<&Options>, Option<&CursorDoc>),
+	(match_ref, field, limit): (Value, String, Option<i64>),
+) -> Result<Value, Error> {
+	if let (Some(opt), Some((exe, _, _))) = (opt, get_execution_context(ctx, doc)) {
+		let limit = match limit {
+			Some(l) if l < 0 => {
+				return Err(Error::InvalidArguments {
+					name: String::from("search::facets"),
+					message: String::from("The limit must be a non-negative number."),
+				});
+			}
+			l => l.map(|l| l as usize),
+		};
+		let field = syn::idiom(&field)?;
+		return exe.facets(stk, ctx, opt, &match_ref, field, limit).await;
+	}
+	Ok(Value::None)
+}
+
 pub async fn highlight(
 	(ctx, doc): (&Context, Option<&CursorDoc>),
 	args: (Value, Value, Value, Option<Value>),
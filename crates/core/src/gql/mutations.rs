@@ -0,0 +1,414 @@
+use std::sync::Arc;
+
+use crate::dbs::Session;
+use crate::gql::ext::TryAsExt;
+use crate::gql::schema::kind_to_input_type;
+use crate::kvs::{Datastore, Transaction};
+use crate::sql::statements::{
+	CreateStatement, DefineFieldStatement, DefineTableStatement, DeleteStatement, RelateStatement,
+	UpdateStatement, UpsertStatement,
+};
+use crate::sql::{self, Data, Fields, Idiom, Kind, Output, Part, TableType, Thing};
+use crate::sql::{Query, Value as SqlValue};
+use async_graphql::dynamic::indexmap::IndexMap;
+use async_graphql::dynamic::FieldFuture;
+use async_graphql::dynamic::InputValue;
+use async_graphql::dynamic::TypeRef;
+use async_graphql::dynamic::{Field, FieldValue, Type};
+use async_graphql::dynamic::{InputObject, Object};
+use async_graphql::Name;
+use async_graphql::Value as GqlValue;
+
+use super::error::{resolver_error, GqlError};
+use super::ext::IntoExt;
+use super::schema::gql_to_sql_kind;
+use crate::gql::error::internal_error;
+use crate::gql::utils::{execute_stmt, field_val_erase_owned, ErasedRecord, GQLTx, GqlValueUtils};
+
+/// The kind of mutation which is being generated for a table
+#[derive(Clone, Copy)]
+enum MutationKind {
+	Create,
+	Update,
+	Upsert,
+	Relate,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn process_tb_mutations(
+	tbs: Arc<[DefineTableStatement]>,
+	mut mutation: Object,
+	types: &mut Vec<Type>,
+	tx: &Transaction,
+	ns: &str,
+	db: &str,
+	session: &Session,
+	datastore: &Arc<Datastore>,
+) -> Result<Object, GqlError> {
+	for tb in tbs.iter() {
+		trace!("Adding mutations for table: {}", tb.name);
+		let tb_name = tb.name.to_string();
+		let is_relation = matches!(tb.kind, TableType::Relation(_));
+
+		let fds = tx.all_tb_fields(ns, db, &tb.name.0, None).await?;
+		// Only top level fields with a defined type can be written through GraphQL
+		let fds: Arc<[DefineFieldStatement]> = fds
+			.iter()
+			.filter(|fd| fd.kind.is_some() && writable_field_name(fd, is_relation).is_some())
+			.filter(|fd| !matches!(fd.kind, Some(Kind::References(_, _))))
+			.cloned()
+			.collect();
+
+		let create_input_name = format!("_create_{tb_name}");
+		let mut create_input = InputObject::new(&create_input_name)
+			.description(format!("Generated from `{}` the data used to create a record", tb.name));
+		let update_input_name = format!("_update_{tb_name}");
+		let mut update_input = InputObject::new(&update_input_name)
+			.description(format!("Generated from `{}` the data used to update a record", tb.name));
+
+		for fd in fds.iter() {
+			let Some(fd_name) = writable_field_name(fd, is_relation) else {
+				continue;
+			};
+			let Some(ref kind) = fd.kind else {
+				continue;
+			};
+			let fd_type = kind_to_input_type(kind.clone(), types)?;
+			// Fields which are computed or defaulted by the database are never required
+			let create_type = match fd.default.is_some() || fd.value.is_some() {
+				true => unwrap_non_null(fd_type.clone()),
+				false => fd_type.clone(),
+			};
+			create_input = create_input.field(InputValue::new(fd_name, create_type));
+			if !fd.readonly {
+				update_input =
+					update_input.field(InputValue::new(fd_name, unwrap_non_null(fd_type)));
+			}
+		}
+
+		let has_create_fields = !fds.is_empty();
+		let has_update_fields = fds.iter().any(|fd| !fd.readonly);
+
+		let create_required = fds.iter().any(|fd| {
+			fd.default.is_none()
+				&& fd.value.is_none()
+				&& !matches!(fd.kind, Some(Kind::Option(_)) | Some(Kind::Any))
+		});
+		let create_data_ty = match create_required {
+			true => TypeRef::named_nn(&create_input_name),
+			false => TypeRef::named(&create_input_name),
+		};
+		// Tables without any writable fields don't take any data
+		let data_arg =
+			|| has_create_fields.then(|| InputValue::new("data", create_data_ty.clone()));
+
+		if is_relation {
+			mutation = mutation.field(with_argument(
+				mutation_field(
+					format!("relate_{tb_name}"),
+					TypeRef::named(&tb_name),
+					MutationKind::Relate,
+					tb_name.clone(),
+					fds.clone(),
+					session,
+					datastore,
+				)
+				.description(format!(
+					"Generated from table `{}`\nallows relating two records through this table",
+					tb.name
+				))
+				.argument(InputValue::new("in", TypeRef::named_nn(TypeRef::ID)))
+				.argument(InputValue::new("out", TypeRef::named_nn(TypeRef::ID)))
+				.argument(InputValue::new("id", TypeRef::named(TypeRef::ID))),
+				data_arg(),
+			));
+		} else {
+			mutation = mutation.field(with_argument(
+				mutation_field(
+					format!("create_{tb_name}"),
+					TypeRef::named(&tb_name),
+					MutationKind::Create,
+					tb_name.clone(),
+					fds.clone(),
+					session,
+					datastore,
+				)
+				.description(format!(
+					"Generated from table `{}`\nallows creating a single record",
+					tb.name
+				))
+				.argument(InputValue::new("id", TypeRef::named(TypeRef::ID))),
+				data_arg(),
+			));
+
+			mutation = mutation.field(with_argument(
+				mutation_field(
+					format!("upsert_{tb_name}"),
+					TypeRef::named(&tb_name),
+					MutationKind::Upsert,
+					tb_name.clone(),
+					fds.clone(),
+					session,
+					datastore,
+				)
+				.description(format!(
+					"Generated from table `{}`\nallows creating or replacing a single record by ID",
+					tb.name
+				))
+				.argument(InputValue::new("id", TypeRef::named_nn(TypeRef::ID))),
+				data_arg(),
+			));
+		}
+
+		if has_update_fields {
+			mutation = mutation.field(
+				mutation_field(
+					format!("update_{tb_name}"),
+					TypeRef::named(&tb_name),
+					MutationKind::Update,
+					tb_name.clone(),
+					fds.clone(),
+					session,
+					datastore,
+				)
+				.description(format!(
+					"Generated from table `{}`\nallows merging data into a single record by ID",
+					tb.name
+				))
+				.argument(InputValue::new("id", TypeRef::named_nn(TypeRef::ID)))
+				.argument(InputValue::new("data", TypeRef::named_nn(&update_input_name))),
+			);
+		}
+
+		let sess1 = session.to_owned();
+		let kvs1 = datastore.to_owned();
+		let delete_tb_name = tb_name.clone();
+		mutation = mutation.field(
+			Field::new(format!("delete_{tb_name}"), TypeRef::named(TypeRef::ID), move |ctx| {
+				let tb_name = delete_tb_name.clone();
+				let sess1 = sess1.clone();
+				let kvs1 = kvs1.clone();
+				FieldFuture::new(async move {
+					let args = ctx.args.as_index_map();
+					let thing = thing_from_args(args, "id", Some(&tb_name))?
+						.ok_or_else(|| internal_error("Schema validation failed: No id found"))?;
+
+					// DELETE foo:1 RETURN BEFORE
+					let stmt = DeleteStatement {
+						what: vec![SqlValue::Thing(thing)].into(),
+						output: Some(Output::Before),
+						..Default::default()
+					};
+
+					let res = execute_stmt(&kvs1, &sess1, stmt).await?;
+
+					let deleted = match res {
+						SqlValue::Array(a) => a.0.into_iter().next(),
+						v => {
+							return Err(
+								internal_error(format!("expected array, found: {v:?}")).into()
+							)
+						}
+					};
+
+					match deleted.map(|v| v.pick(&[Part::from("id")])) {
+						Some(SqlValue::Thing(t)) => {
+							Ok(Some(FieldValue::value(GqlValue::String(t.to_string()))))
+						}
+						_ => Ok(None),
+					}
+				})
+			})
+			.description(format!(
+				"Generated from table `{}`\nallows deleting a single record by ID",
+				tb.name
+			))
+			.argument(InputValue::new("id", TypeRef::named_nn(TypeRef::ID))),
+		);
+
+		if has_create_fields {
+			types.push(Type::InputObject(create_input));
+		}
+		if has_update_fields {
+			types.push(Type::InputObject(update_input));
+		}
+	}
+
+	Ok(mutation)
+}
+
+fn mutation_field(
+	name: String,
+	ty: TypeRef,
+	kind: MutationKind,
+	tb_name: String,
+	fds: Arc<[DefineFieldStatement]>,
+	session: &Session,
+	datastore: &Arc<Datastore>,
+) -> Field {
+	let sess1 = session.to_owned();
+	let kvs1 = datastore.to_owned();
+	Field::new(name, ty, move |ctx| {
+		let tb_name = tb_name.clone();
+		let fds = fds.clone();
+		let sess1 = sess1.clone();
+		let kvs1 = kvs1.clone();
+		FieldFuture::new(async move {
+			let args = ctx.args.as_index_map();
+			trace!("received mutation with args: {args:?}");
+
+			let data = match args.get("data") {
+				Some(GqlValue::Object(o)) => Some(data_from_input(o, &fds)?),
+				Some(GqlValue::Null) | None => None,
+				Some(v) => {
+					error!("Found data {v}, which should be object and should have been rejected by async graphql.");
+					return Err("Value in data doesn't fit schema".into());
+				}
+			};
+
+			let thing = thing_from_args(args, "id", Some(&tb_name))?;
+			let what = match thing {
+				Some(t) => SqlValue::Thing(t),
+				None => SqlValue::Table(tb_name.as_str().intox()),
+			};
+
+			// RETURN VALUE id
+			let output = Some(Output::Fields(Fields(
+				vec![sql::Field::Single {
+					expr: SqlValue::Idiom(Idiom::from("id")),
+					alias: None,
+				}],
+				// this means the `value` keyword
+				true,
+			)));
+
+			let ast: Query = match kind {
+				MutationKind::Create => CreateStatement {
+					what: vec![what].into(),
+					data: data.map(Data::ContentExpression),
+					output,
+					..Default::default()
+				}
+				.into(),
+				MutationKind::Upsert => UpsertStatement {
+					what: vec![what].into(),
+					data: data.map(Data::ContentExpression),
+					output,
+					..Default::default()
+				}
+				.into(),
+				MutationKind::Update => UpdateStatement {
+					what: vec![what].into(),
+					data: data.map(Data::MergeExpression),
+					output,
+					..Default::default()
+				}
+				.into(),
+				MutationKind::Relate => {
+					let from = thing_from_args(args, "in", None)?
+						.ok_or_else(|| internal_error("Schema validation failed: No in found"))?;
+					let with = thing_from_args(args, "out", None)?
+						.ok_or_else(|| internal_error("Schema validation failed: No out found"))?;
+					RelateStatement {
+						kind: what,
+						from: SqlValue::Thing(from),
+						with: SqlValue::Thing(with),
+						data: data.map(Data::ContentExpression),
+						output,
+						..Default::default()
+					}
+					.into()
+				}
+			};
+
+			trace!("generated mutation ast: {ast:?}");
+
+			let res = execute_stmt(&kvs1, &sess1, ast).await?;
+
+			let rid = match res {
+				SqlValue::Array(a) => a.0.into_iter().next(),
+				v => {
+					error!("Found top level value, in result which should be array: {v:?}");
+					return Err("Internal Error".into());
+				}
+			};
+
+			match rid {
+				Some(v) => match v.try_as_thing() {
+					Ok(t) => {
+						// The write has been committed, so the record is read in a new transaction
+						let gtx = GQLTx::new(&kvs1, &sess1).await?;
+						let erased: ErasedRecord = (gtx, t);
+						Ok(Some(field_val_erase_owned(erased)))
+					}
+					Err(v) => Err(internal_error(format!("expected thing, found: {v:?}")).into()),
+				},
+				None => Ok(None),
+			}
+		})
+	})
+}
+
+/// Returns the name of a field if it can be written through a mutation input
+fn writable_field_name(fd: &DefineFieldStatement, is_relation: bool) -> Option<&str> {
+	let [Part::Field(name)] = fd.name.0.as_slice() else {
+		return None;
+	};
+	match name.as_str() {
+		"id" => None,
+		"in" | "out" if is_relation => None,
+		name => Some(name),
+	}
+}
+
+fn with_argument(field: Field, arg: Option<InputValue>) -> Field {
+	match arg {
+		Some(arg) => field.argument(arg),
+		None => field,
+	}
+}
+
+fn unwrap_non_null(ty: TypeRef) -> TypeRef {
+	match ty {
+		TypeRef::NonNull(t) => *t,
+		_ => ty,
+	}
+}
+
+fn thing_from_args(
+	args: &IndexMap<Name, GqlValue>,
+	arg: &str,
+	tb_name: Option<&str>,
+) -> Result<Option<Thing>, GqlError> {
+	let Some(id) = args.get(arg).and_then(GqlValueUtils::as_string) else {
+		return Ok(None);
+	};
+	let parsed: Result<Thing, _> = id.clone().try_into();
+	let thing = match (parsed, tb_name) {
+		(Ok(t), Some(tb)) if tb != t.tb => {
+			return Err(resolver_error(format!("record `{t}` does not belong to table `{tb}`")))
+		}
+		(Ok(t), _) => t,
+		(Err(_), Some(tb)) => Thing::from((tb, id.as_str())),
+		(Err(_), None) => return Err(resolver_error(format!("invalid id: {id}"))),
+	};
+	Ok(Some(thing))
+}
+
+fn data_from_input(
+	input: &IndexMap<Name, GqlValue>,
+	fds: &[DefineFieldStatement],
+) -> Result<SqlValue, GqlError> {
+	let mut out = sql::Object::default();
+	for (k, v) in input.iter() {
+		let Some(fd) = fds.iter().find(|fd| fd.name.to_string() == k.as_str()) else {
+			return Err(resolver_error(format!("Field `{k}` not found")));
+		};
+		let val = match v {
+			GqlValue::Null => SqlValue::None,
+			v => gql_to_sql_kind(v, fd.kind.clone().unwrap_or_default())?,
+		};
+		out.insert(k.to_string(), val);
+	}
+	Ok(out.into())
+}
@@ -3,6 +3,8 @@ use std::sync::Arc;
 
 use crate::dbs::Session;
 use crate::gql::functions::process_fns;
+use crate::gql::mutations::process_tb_mutations;
+use crate::gql::subscriptions::{live_action, process_tb_subscriptions};
 use crate::gql::tables::process_tbs;
 use crate::kvs::Datastore;
 use crate::sql;
@@ -14,6 +16,7 @@ use async_graphql::dynamic::Interface;
 use async_graphql::dynamic::InterfaceField;
 use async_graphql::dynamic::Object;
 use async_graphql::dynamic::Schema;
+use async_graphql::dynamic::Subscription;
 use async_graphql::dynamic::{Enum, Type, Union};
 use async_graphql::dynamic::{Scalar, TypeRef};
 use async_graphql::Name;
@@ -89,13 +92,43 @@ pub async fn generate_schema(
 	}
 
 	let mut query = Object::new("Query");
+	let mut mutation = None;
+	let mut subscription = None;
 	let mut types: Vec<Type> = Vec::new();
 
 	trace!(ns, db, ?tbs, ?fns, "generating schema");
 
 	match tbs {
 		Some(tbs) if !tbs.is_empty() => {
-			query = process_tbs(tbs, query, &mut types, &tx, ns, db, session, datastore).await?;
+			query = process_tbs(tbs.clone(), query, &mut types, &tx, ns, db, session, datastore)
+				.await?;
+			mutation = Some(
+				process_tb_mutations(
+					tbs.clone(),
+					Object::new("Mutation"),
+					&mut types,
+					&tx,
+					ns,
+					db,
+					session,
+					datastore,
+				)
+				.await?,
+			);
+			subscription = Some(
+				process_tb_subscriptions(
+					tbs,
+					Subscription::new("Subscription"),
+					&mut types,
+					&tx,
+					ns,
+					db,
+					session,
+					datastore,
+				)
+				.await?,
+			);
+			types.push(Type::Enum(live_action()));
 		}
 		_ => {}
 	}
@@ -106,7 +139,18 @@ pub async fn generate_schema(
 
 	trace!("current Query object for schema: {:?}", query);
 
-	let mut schema = Schema::build("Query", None, None).register(query);
+	trace!("current Mutation object for schema: {:?}", mutation);
+	trace!("current Subscription object for schema: {:?}", subscription);
+
+	let mut schema = match (mutation, subscription) {
+		(Some(mutation), Some(subscription)) => {
+			Schema::build("Query", Some("Mutation"), Some("Subscription"))
+				.register(query)
+				.register(mutation)
+				.register(subscription)
+		}
+		_ => Schema::build("Query", None, None).register(query),
+	};
 	for ty in types {
 		trace!("adding type: {ty:?}");
 		schema = schema.register(ty);
@@ -335,6 +379,34 @@ pub fn kind_to_type(kind: Kind, types: &mut Vec<Type>) -> Result<TypeRef, GqlErr
 	Ok(out)
 }
 
+pub fn kind_to_input_type(kind: Kind, types: &mut Vec<Type>) -> Result<TypeRef, GqlError> {
+	let (optional, match_kind) = match kind {
+		Kind::Option(op_ty) => (true, *op_ty),
+		_ => (false, kind),
+	};
+	let out_ty = match match_kind {
+		// Records are referenced by their id in input values
+		Kind::Record(_) => TypeRef::named(TypeRef::ID),
+		Kind::Option(t) => unwrap_type(kind_to_input_type(*t, types)?),
+		Kind::Array(k, _) => TypeRef::List(Box::new(kind_to_input_type(*k, types)?)),
+		// Unions can not be used as input types, only enums of string literals can
+		Kind::Either(ks) if ks.iter().all(|k| matches!(k, Kind::Literal(Literal::String(_)))) => {
+			unwrap_type(kind_to_type(Kind::Either(ks), types)?)
+		}
+		Kind::Either(_) => TypeRef::named("any"),
+		Kind::References(_, _) => {
+			return Err(schema_error("Kind::References can not be used as an input"))
+		}
+		k => unwrap_type(kind_to_type(k, types)?),
+	};
+
+	let out = match optional {
+		true => out_ty,
+		false => TypeRef::NonNull(Box::new(out_ty)),
+	};
+	Ok(out)
+}
+
 pub fn unwrap_type(ty: TypeRef) -> TypeRef {
 	match ty {
 		TypeRef::NonNull(t) => unwrap_type(*t),
@@ -615,6 +687,9 @@ pub fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError>
 					Err(resolver_error("binary input for Either is not yet supported"))
 				}
 				GqlValue::Enum(n) => {
+					if ks.contains(&Kind::Literal(sql::kind::Literal::String(n.as_str().into()))) {
+						return Ok(SqlValue::Strand(n.as_str().into()));
+					}
 					either_try_kind!(ks, &GqlValue::String(n.to_string()), Kind::String);
 					Err(type_error(kind, val))
 				}
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::dbs::{Action, Notification, Session};
+use crate::gql::tables::{cond_from_filter, filter_name_from_table};
+use crate::kvs::{Datastore, Transaction};
+use crate::sql::statements::{DefineTableStatement, LiveStatement};
+use crate::sql::{Fields, Table, Thing, Value as SqlValue};
+use async_channel::{Receiver, Sender};
+use async_graphql::dynamic::FieldFuture;
+use async_graphql::dynamic::TypeRef;
+use async_graphql::dynamic::{Enum, Field, FieldValue, InputValue, Object, Type};
+use async_graphql::dynamic::{Subscription, SubscriptionField, SubscriptionFieldFuture};
+use async_graphql::Name;
+use async_graphql::Value as GqlValue;
+use futures::stream;
+#[cfg(not(target_family = "wasm"))]
+use tokio::spawn;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+#[cfg(target_family = "wasm")]
+use wasm_bindgen_futures::spawn_local as spawn;
+
+use super::error::{resolver_error, GqlError};
+use crate::gql::error::internal_error;
+use crate::gql::utils::{execute_stmt, field_val_erase_owned, GQLTx};
+
+/// The number of notifications which can be buffered for a single subscription
+const SUBSCRIPTION_CHANNEL_SIZE: usize = 100;
+
+/// The name of the enum describing the action of a live event
+const LIVE_ACTION_NAME: &str = "_live_action";
+
+/// Routes live query notifications to the GraphQL subscriptions which started
+/// the corresponding LIVE queries. The server notification loop passes each
+/// notification it can not route to an RPC connection on to this registry.
+#[derive(Clone, Default, Debug)]
+pub struct Subscriptions(Arc<RwLock<HashMap<Uuid, Sender<Notification>>>>);
+
+impl Subscriptions {
+	/// Delivers a notification to the subscription which owns its live query.
+	/// Returns `false` if no GraphQL subscription is registered for the live query.
+	///
+	/// A subscription which falls too far behind is closed with an error,
+	/// rather than silently missing notifications.
+	pub async fn notify(&self, notification: Notification) -> bool {
+		let id = notification.id.0;
+		let sender = self.0.read().await.get(&id).cloned();
+		match sender {
+			Some(sender) => {
+				if sender.try_send(notification).is_err() {
+					warn!("Closing a lagging GraphQL subscription for live query {id}");
+					sender.close();
+					self.remove(&id).await;
+				}
+				true
+			}
+			None => false,
+		}
+	}
+
+	async fn register(&self, id: Uuid) -> Receiver<Notification> {
+		let (send, recv) = async_channel::bounded(SUBSCRIPTION_CHANNEL_SIZE);
+		self.0.write().await.insert(id, send);
+		recv
+	}
+
+	async fn remove(&self, id: &Uuid) {
+		self.0.write().await.remove(id);
+	}
+}
+
+/// Kills the live query of a subscription once its stream is dropped
+struct LiveGuard {
+	id: Uuid,
+	subscriptions: Subscriptions,
+	datastore: Arc<Datastore>,
+}
+
+impl Drop for LiveGuard {
+	fn drop(&mut self) {
+		let id = self.id;
+		let subscriptions = self.subscriptions.clone();
+		let datastore = self.datastore.clone();
+		spawn(async move {
+			subscriptions.remove(&id).await;
+			if let Err(e) = datastore.delete_queries(vec![id]).await {
+				error!("Error killing the live query of a GraphQL subscription: {e}");
+			}
+		});
+	}
+}
+
+/// The record and action of a received live query notification
+type LiveEvent = (GQLTx, Action, Thing);
+
+fn live_name_from_table(tb_name: impl std::fmt::Display) -> String {
+	format!("_live_{tb_name}")
+}
+
+pub fn live_action() -> Enum {
+	Enum::new(LIVE_ACTION_NAME)
+		.description("The action which caused a live event")
+		.item("CREATE")
+		.item("UPDATE")
+		.item("DELETE")
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn process_tb_subscriptions(
+	tbs: Arc<[DefineTableStatement]>,
+	mut subscription: Subscription,
+	types: &mut Vec<Type>,
+	tx: &Transaction,
+	ns: &str,
+	db: &str,
+	session: &Session,
+	datastore: &Arc<Datastore>,
+) -> Result<Subscription, GqlError> {
+	for tb in tbs.iter() {
+		trace!("Adding subscription for table: {}", tb.name);
+		let tb_name = tb.name.to_string();
+		let live_name = live_name_from_table(&tb_name);
+
+		let live_ty = Object::new(&live_name)
+			.description(format!("Generated from `{}` a change to a record in the table", tb.name))
+			.field(Field::new("action", TypeRef::named_nn(LIVE_ACTION_NAME), |ctx| {
+				FieldFuture::new(async move {
+					let (_, action, _) = ctx
+						.parent_value
+						.downcast_ref::<LiveEvent>()
+						.ok_or_else(|| internal_error("failed to downcast"))?;
+					Ok(Some(FieldValue::value(GqlValue::Enum(Name::new(action.to_string())))))
+				})
+			}))
+			.field(Field::new("id", TypeRef::named_nn(TypeRef::ID), |ctx| {
+				FieldFuture::new(async move {
+					let (_, _, rid) = ctx
+						.parent_value
+						.downcast_ref::<LiveEvent>()
+						.ok_or_else(|| internal_error("failed to downcast"))?;
+					Ok(Some(FieldValue::value(GqlValue::String(rid.to_string()))))
+				})
+			}))
+			.field(
+				Field::new("record", TypeRef::named(&tb_name), |ctx| {
+					FieldFuture::new(async move {
+						let (gtx, action, rid) = ctx
+							.parent_value
+							.downcast_ref::<LiveEvent>()
+							.ok_or_else(|| internal_error("failed to downcast"))?;
+						if let Action::Delete = action {
+							return Ok(None);
+						}
+						match gtx.get_record_field(rid.clone(), "id").await? {
+							SqlValue::Thing(t) => Ok(Some(field_val_erase_owned((gtx.clone(), t)))),
+							_ => Ok(None),
+						}
+					})
+				})
+				.description(
+					"The current state of the record, which is null once it has been deleted",
+				),
+			);
+		types.push(Type::Object(live_ty));
+
+		let fds = tx.all_tb_fields(ns, db, &tb.name.0, None).await?;
+		let sess1 = session.to_owned();
+		let kvs1 = datastore.to_owned();
+		let live_tb_name = tb_name.clone();
+		subscription = subscription.field(
+			SubscriptionField::new(
+				tb_name.clone(),
+				TypeRef::named_nn(&live_name),
+				move |ctx| {
+					let tb_name = live_tb_name.clone();
+					let fds = fds.clone();
+					// Live queries require a realtime session
+					let sess1 = sess1.clone().with_rt(true);
+					let kvs1 = kvs1.clone();
+					SubscriptionFieldFuture::new(async move {
+						let subscriptions = ctx.data::<Subscriptions>().map_err(|_| {
+							resolver_error("Subscriptions are only supported through the graphql-ws protocol")
+						})?;
+
+						let args = ctx.args.as_index_map();
+						trace!("received subscription with args: {args:?}");
+
+						let cond = match args.get("filter") {
+							Some(GqlValue::Object(o)) => Some(cond_from_filter(o, &fds)?),
+							Some(GqlValue::Null) | None => None,
+							Some(f) => {
+								error!("Found filter {f}, which should be object and should have been rejected by async graphql.");
+								return Err("Value in cond doesn't fit schema".into());
+							}
+						};
+
+						// LIVE SELECT * FROM ...
+						let mut stmt = LiveStatement::new_from_what_expr(
+							Fields::all(),
+							SqlValue::Table(Table::from(tb_name.as_str())),
+						);
+						stmt.cond = cond;
+						let id = stmt.id.0;
+
+						trace!("generated live query ast: {stmt:?}");
+
+						// Register the subscription before the live query exists,
+						// so that no notification is missed
+						let recv = subscriptions.register(id).await;
+						let guard = LiveGuard {
+							id,
+							subscriptions: subscriptions.clone(),
+							datastore: kvs1.clone(),
+						};
+
+						execute_stmt(&kvs1, &sess1, stmt).await?;
+
+						Ok(stream::unfold(Some((recv, guard)), move |state| {
+							let kvs1 = kvs1.clone();
+							let sess1 = sess1.clone();
+							async move {
+								let (recv, guard) = state?;
+								// The channel is only closed when the subscription lags
+								let Ok(notification) = recv.recv().await else {
+									let err = resolver_error(
+										"The subscription fell too far behind and was closed",
+									);
+									return Some((Err(err.into()), None));
+								};
+								let event = match notification.record {
+									SqlValue::Thing(rid) => match GQLTx::new(&kvs1, &sess1).await {
+										Ok(gtx) => {
+											let event: LiveEvent = (gtx, notification.action, rid);
+											Ok(FieldValue::owned_any(event))
+										}
+										Err(e) => Err(e.into()),
+									},
+									v => Err(internal_error(format!(
+										"expected thing, found: {v:?}"
+									))
+									.into()),
+								};
+								Some((event, Some((recv, guard))))
+							}
+						}))
+					})
+				},
+			)
+			.description(format!(
+				"Generated from table `{}`\nallows subscribing to changes of records in a table",
+				tb.name
+			))
+			.argument(InputValue::new("filter", TypeRef::named(filter_name_from_table(&tb_name)))),
+		);
+	}
+
+	Ok(subscription)
+}
@@ -60,7 +60,7 @@ macro_rules! id_input {
 	};
 }
 
-fn filter_name_from_table(tb_name: impl Display) -> String {
+pub fn filter_name_from_table(tb_name: impl Display) -> String {
 	format!("_filter_{tb_name}")
 }
 
@@ -499,7 +499,7 @@ fn filter_from_type(
 	Ok(filter)
 }
 
-fn cond_from_filter(
+pub fn cond_from_filter(
 	filter: &IndexMap<Name, GqlValue>,
 	fds: &[DefineFieldStatement],
 ) -> Result<Cond, GqlError> {
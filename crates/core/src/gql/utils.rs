@@ -12,6 +12,7 @@ use crate::sql;
 use crate::sql::part::Part;
 use crate::sql::FlowResultExt;
 use crate::sql::Function;
+use crate::sql::Query;
 use crate::sql::Statement;
 use crate::sql::{Thing, Value as SqlValue};
 
@@ -19,7 +20,7 @@ use async_graphql::dynamic::FieldValue;
 use async_graphql::{dynamic::indexmap::IndexMap, Name, Value as GqlValue};
 use reblessive::TreeStack;
 
-use super::error::GqlError;
+use super::error::{internal_error, GqlError};
 
 pub(crate) trait GqlValueUtils {
 	fn as_i64(&self) -> Option<i64>;
@@ -132,6 +133,21 @@ impl GQLTx {
 	}
 }
 
+/// Runs a single statement in its own transaction, through the same executor
+/// which processes SurrealQL queries, so that writes are committed, permissions
+/// are checked, and live query notifications are sent as usual.
+pub async fn execute_stmt(
+	kvs: &Arc<Datastore>,
+	sess: &Session,
+	stmt: impl Into<Query>,
+) -> Result<SqlValue, GqlError> {
+	let mut res = kvs.process(stmt.into(), sess, None).await?;
+	match res.pop() {
+		Some(res) => Ok(res.result?),
+		None => Err(internal_error("no response was returned for the statement")),
+	}
+}
+
 pub type ErasedRecord = (GQLTx, Thing);
 
 pub fn field_val_erase_owned(val: ErasedRecord) -> FieldValue<'static> {
@@ -2,7 +2,7 @@ use crate::sql::statements::{DefineAccessStatement, DefineUserStatement};
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 
-use super::{is_allowed, Action, Actor, Error, Level, Resource, Role};
+use super::{is_allowed, Action, Actor, Error, Grant, Level, Resource, Role};
 
 /// Specifies the current authentication for the datastore execution context.
 #[revisioned(revision = 1)]
@@ -93,6 +93,11 @@ impl Auth {
 		is_allowed(&self.actor, &action, res, None)
 	}
 
+	/// Returns the grants of any custom roles of the current actor
+	pub fn grants(&self) -> &[Grant] {
+		self.actor.grants()
+	}
+
 	/// Checks if the current actor has a given role
 	pub fn has_role(&self, role: Role) -> bool {
 		self.actor.has_role(role)
@@ -0,0 +1,83 @@
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+
+use cedar_policy::RestrictedExpression;
+
+use super::{Action, ResourceKind};
+use crate::sql::Ident;
+
+/// A privilege granted by a custom role, allowing an action on a kind of resource.
+/// A grant to edit a resource kind implies a grant to view the same resource kind.
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Grant {
+	pub action: Action,
+	pub kind: ResourceKind,
+	/// Restricts a grant on tables to a single table
+	pub table: Option<Ident>,
+}
+
+impl Grant {
+	pub fn new(action: Action, kind: ResourceKind) -> Self {
+		Self {
+			action,
+			kind,
+			table: None,
+		}
+	}
+
+	pub fn on_table(action: Action, table: Ident) -> Self {
+		Self {
+			action,
+			kind: ResourceKind::Table,
+			table: Some(table),
+		}
+	}
+}
+
+impl std::fmt::Display for Grant {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let action = match self.action {
+			Action::View => "VIEW",
+			Action::Edit => "EDIT",
+		};
+		write!(f, "{action} ON ")?;
+		match self.kind {
+			ResourceKind::Namespace => write!(f, "NAMESPACE")?,
+			ResourceKind::Database => write!(f, "DATABASE")?,
+			ResourceKind::Table => write!(f, "TABLE")?,
+			ResourceKind::Function => write!(f, "FUNCTION")?,
+			ResourceKind::Analyzer => write!(f, "ANALYZER")?,
+			ResourceKind::Parameter => write!(f, "PARAM")?,
+			ResourceKind::Model => write!(f, "MODEL")?,
+			ResourceKind::Event => write!(f, "EVENT")?,
+			ResourceKind::Field => write!(f, "FIELD")?,
+			ResourceKind::Index => write!(f, "INDEX")?,
+			ResourceKind::Access => write!(f, "ACCESS")?,
+			ResourceKind::Api => write!(f, "API")?,
+			ResourceKind::Actor => write!(f, "USER")?,
+			ref kind => write!(f, "{}", kind.to_string().to_uppercase())?,
+		}
+		if let Some(ref table) = self.table {
+			write!(f, " {table}")?;
+		}
+		Ok(())
+	}
+}
+
+impl std::convert::From<&Grant> for RestrictedExpression {
+	fn from(grant: &Grant) -> Self {
+		RestrictedExpression::new_record([
+			("action".to_string(), RestrictedExpression::new_string(grant.action.to_string())),
+			("type".to_string(), (&grant.kind).into()),
+			(
+				"id".to_string(),
+				RestrictedExpression::new_string(
+					grant.table.as_ref().map(|t| t.to_raw()).unwrap_or_default(),
+				),
+			),
+		])
+	}
+}
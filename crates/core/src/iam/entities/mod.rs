@@ -1,9 +1,11 @@
 mod action;
+mod grants;
 mod resources;
 mod roles;
 mod schema;
 
 pub use self::action::*;
+pub use self::grants::*;
 pub use self::resources::*;
 pub use self::roles::*;
 pub use self::schema::*;
@@ -7,19 +7,22 @@ use cedar_policy::{Entity, EntityId, EntityTypeName, EntityUid, RestrictedExpres
 use serde::{Deserialize, Serialize};
 
 use super::{Level, Resource, ResourceKind};
-use crate::iam::{Error, Role};
+use crate::iam::{Error, Grant, Role};
 use crate::sql::statements::{DefineAccessStatement, DefineUserStatement};
 
 //
 // User
 //
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct Actor {
 	res: Resource,
 	roles: Vec<Role>,
+	/// The grants of any custom roles assigned to this actor
+	#[revision(start = 2)]
+	grants: Vec<Grant>,
 }
 
 impl Default for Actor {
@@ -27,6 +30,7 @@ impl Default for Actor {
 		Self {
 			res: ResourceKind::Actor.on_level(Level::No),
 			roles: Vec::new(),
+			grants: Vec::new(),
 		}
 	}
 }
@@ -53,9 +57,21 @@ impl Actor {
 		Self {
 			res: Resource::new(id, super::ResourceKind::Actor, level),
 			roles,
+			grants: Vec::new(),
 		}
 	}
 
+	/// Sets the grants of the custom roles assigned to the actor.
+	pub fn with_grants(mut self, grants: Vec<Grant>) -> Self {
+		self.grants = grants;
+		self
+	}
+
+	/// Returns the grants of the custom roles assigned to the actor.
+	pub fn grants(&self) -> &[Grant] {
+		&self.grants
+	}
+
 	/// Checks if the actor has the given role.
 	pub fn has_role(&self, role: Role) -> bool {
 		self.roles.contains(&role)
@@ -80,8 +96,10 @@ impl Actor {
 	pub fn cedar_attrs(&self) -> HashMap<String, RestrictedExpression> {
 		[
 			("type", self.kind().into()),
+			("id", RestrictedExpression::new_string(self.id().to_owned())),
 			("level", self.level().into()),
 			("roles", RestrictedExpression::new_set(self.roles.iter().map(|r| r.into()))),
+			("grants", RestrictedExpression::new_set(self.grants.iter().map(|g| g.into()))),
 		]
 		.into_iter()
 		.map(|(x, v)| (x.into(), v))
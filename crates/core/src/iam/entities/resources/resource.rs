@@ -137,10 +137,14 @@ impl Resource {
 
 	// Cedar policy helpers
 	pub fn cedar_attrs(&self) -> HashMap<String, RestrictedExpression> {
-		[("type", self.kind().into()), ("level", self.level().into())]
-			.into_iter()
-			.map(|(x, v)| (x.into(), v))
-			.collect()
+		[
+			("type", self.kind().into()),
+			("id", RestrictedExpression::new_string(self.id().to_owned())),
+			("level", self.level().into()),
+		]
+		.into_iter()
+		.map(|(x, v)| (x.into(), v))
+		.collect()
 	}
 
 	pub fn cedar_parents(&self) -> HashSet<EntityUid> {
@@ -11,9 +11,19 @@ pub static DEFAULT_CEDAR_SCHEMA: LazyLock<serde_json::Value> = LazyLock::new(||
 						"type": "Record",
 						"attributes": {
 							"type": { "type": "String", "required": true },
+							"id": { "type": "String", "required": true },
 							"level" : { "type": "Entity", "name": "Level", "required": true },
 						}
 					},
+					// Represents a privilege granted by a custom role
+					"Grant": {
+						"type": "Record",
+						"attributes": {
+							"action": { "type": "String", "required": true },
+							"type": { "type": "String", "required": true },
+							"id": { "type": "String", "required": true },
+						}
+					},
 				},
 				"entityTypes": {
 					// Represents the Root, Namespace, Database and Record levels
@@ -55,8 +65,10 @@ pub static DEFAULT_CEDAR_SCHEMA: LazyLock<serde_json::Value> = LazyLock::new(||
 							"type": "Record",
 							"attributes": {
 								"type": { "type": "String", "required": true },
+								"id": { "type": "String", "required": true },
 								"level" : { "type": "Entity", "name": "Level", "required": true },
 								"roles": { "type": "Set", "element": { "type": "Entity", "name": "Role" }, "required": true},
+								"grants": { "type": "Set", "element": { "type": "Grant" }, "required": true},
 							},
 						},
 						"memberOfTypes": ["Level"],
@@ -36,5 +36,34 @@ pub static POLICY_SET: LazyLock<PolicySet> = LazyLock::new(|| {
         principal.roles.contains(Role::"Owner") &&
         resource.level in principal.level
     };
+
+    // Custom roles can view the resources they were granted on the same level hierarchy or below
+    permit(
+        principal,
+        action == Action::"View",
+        resource
+    ) when {
+        resource.level in principal.level &&
+        (
+            principal.grants.contains({ "action": "View", "type": resource.type, "id": "" }) ||
+            principal.grants.contains({ "action": "View", "type": resource.type, "id": resource.id }) ||
+            principal.grants.contains({ "action": "Edit", "type": resource.type, "id": "" }) ||
+            principal.grants.contains({ "action": "Edit", "type": resource.type, "id": resource.id })
+        )
+    };
+
+    // Custom roles can edit the non-IAM resources they were granted on the same level hierarchy or below
+    permit(
+        principal,
+        action == Action::"Edit",
+        resource
+    ) when {
+        resource.level in principal.level &&
+        !["Access", "Actor"].contains(resource.type) &&
+        (
+            principal.grants.contains({ "action": "Edit", "type": resource.type, "id": "" }) ||
+            principal.grants.contains({ "action": "Edit", "type": resource.type, "id": resource.id })
+        )
+    };
 "#).unwrap()
 });
@@ -2,8 +2,8 @@ use super::access::{
 	authenticate_generic, authenticate_record, create_refresh_token_record,
 	revoke_refresh_token_record,
 };
-use super::verify::{verify_db_creds, verify_ns_creds, verify_root_creds};
-use super::{Actor, Level, Role};
+use super::verify::{system_user_actor, verify_db_creds, verify_ns_creds, verify_root_creds};
+use super::{Actor, Level};
 use crate::cnf::{INSECURE_FORWARD_ACCESS_ERRORS, SERVER_NAME};
 use crate::dbs::capabilities::ExperimentalTarget;
 use crate::dbs::Session;
@@ -353,7 +353,15 @@ pub async fn db_user(
 			session.ns = Some(ns.to_owned());
 			session.db = Some(db.to_owned());
 			session.exp = expiration(u.duration.session)?;
-			session.au = Arc::new((&u, Level::Database(ns.to_owned(), db.to_owned())).try_into()?);
+			session.au = Arc::new(Auth::new(
+				system_user_actor(
+					kvs,
+					&u.name,
+					u.roles.iter().map(|r| r.as_str()),
+					Level::Database(ns.to_owned(), db.to_owned()),
+				)
+				.await?,
+			));
 			// Check the authentication token
 			match enc {
 				// The auth token was created successfully
@@ -436,7 +444,15 @@ pub async fn ns_user(
 			session.tk = Some((&val).into());
 			session.ns = Some(ns.to_owned());
 			session.exp = expiration(u.duration.session)?;
-			session.au = Arc::new((&u, Level::Namespace(ns.to_owned())).try_into()?);
+			session.au = Arc::new(Auth::new(
+				system_user_actor(
+					kvs,
+					&u.name,
+					u.roles.iter().map(|r| r.as_str()),
+					Level::Namespace(ns.to_owned()),
+				)
+				.await?,
+			));
 			// Check the authentication token
 			match enc {
 				// The auth token was created successfully
@@ -484,7 +500,10 @@ pub async fn root_user(
 			// Set the authentication on the session
 			session.tk = Some(val.into());
 			session.exp = expiration(u.duration.session)?;
-			session.au = Arc::new((&u, Level::Root).try_into()?);
+			session.au = Arc::new(Auth::new(
+				system_user_actor(kvs, &u.name, u.roles.iter().map(|r| r.as_str()), Level::Root)
+					.await?,
+			));
 			// Check the authentication token
 			match enc {
 				// The auth token was created successfully
@@ -682,16 +701,16 @@ pub async fn signin_bearer(
 	session.exp = expiration(av.duration.session)?;
 	match &gr.subject {
 		access::Subject::User(user) => {
-			session.au = Arc::new(Auth::new(Actor::new(
-				user.to_string(),
-				roles.iter().map(Role::try_from).collect::<Result<_, _>>()?,
-				match (ns, db) {
-					(Some(ns), Some(db)) => Level::Database(ns, db),
-					(Some(ns), None) => Level::Namespace(ns),
-					(None, None) => Level::Root,
-					(None, Some(_)) => return Err(Error::NsEmpty),
-				},
-			)));
+			let level = match (ns, db) {
+				(Some(ns), Some(db)) => Level::Database(ns, db),
+				(Some(ns), None) => Level::Namespace(ns),
+				(None, None) => Level::Root,
+				(None, Some(_)) => return Err(Error::NsEmpty),
+			};
+			session.au = Arc::new(Auth::new(
+				system_user_actor(kvs, &user.to_string(), roles.iter().map(|r| r.as_str()), level)
+					.await?,
+			));
 		}
 		access::Subject::Record(rid) => {
 			session.au = Arc::new(Auth::new(Actor::new(
@@ -4149,17 +4168,24 @@ dn/RsYEONbwQSjIfMPkvxF+8HQ==
 				hash: "$argon2id$v=19$m=16,t=2,p=1$VUlHTHVOYjc5d0I1dGE3OQ$sVtmRNH+Xtiijk0uXL2+4w"
 					.to_string(),
 				code: "dummy".to_string(),
-				roles: vec!["nonexistent".into()],
+				roles: vec!["custom".into()],
 				duration: UserDuration::default(),
 				comment: None,
 				if_not_exists: false,
 				overwrite: false,
 			};
 
-			// Use pre-parsed definition, which bypasses the existent role check during parsing.
+			// Define the user with a custom role, which is then removed.
+			let role = format!("DEFINE ROLE custom ON {}", level.level);
+			ds.execute(&role, &sess, None).await.unwrap().remove(0).result.unwrap();
 			ds.process(Statement::Define(DefineStatement::User(user)).into(), &sess, None)
 				.await
+				.unwrap()
+				.remove(0)
+				.result
 				.unwrap();
+			let role = format!("REMOVE ROLE custom ON {}", level.level);
+			ds.execute(&role, &sess, None).await.unwrap().remove(0).result.unwrap();
 
 			let mut sess = Session {
 				ns: level.ns.map(String::from),
@@ -3,6 +3,7 @@ use crate::err::Error;
 use crate::iam::access::{authenticate_generic, authenticate_record};
 #[cfg(feature = "jwks")]
 use crate::iam::jwks;
+use crate::iam::Error as IamError;
 use crate::iam::{issue::expiration, token::Claims, Actor, Auth, Level, Role};
 use crate::kvs::{Datastore, LockType::*, TransactionType::*};
 use crate::sql::access_type::{AccessType, Jwt, JwtAccessVerify};
@@ -95,8 +96,15 @@ pub async fn basic(
 			Ok(u) => {
 				debug!("Authenticated as database user '{}'", user);
 				session.exp = expiration(u.duration.session)?;
-				session.au =
-					Arc::new((&u, Level::Database(ns.to_owned(), db.to_owned())).try_into()?);
+				session.au = Arc::new(Auth::new(
+					system_user_actor(
+						kvs,
+						&u.name,
+						u.roles.iter().map(|r| r.as_str()),
+						Level::Database(ns.to_owned(), db.to_owned()),
+					)
+					.await?,
+				));
 				Ok(())
 			}
 			Err(err) => Err(err),
@@ -106,7 +114,15 @@ pub async fn basic(
 			Ok(u) => {
 				debug!("Authenticated as namespace user '{}'", user);
 				session.exp = expiration(u.duration.session)?;
-				session.au = Arc::new((&u, Level::Namespace(ns.to_owned())).try_into()?);
+				session.au = Arc::new(Auth::new(
+					system_user_actor(
+						kvs,
+						&u.name,
+						u.roles.iter().map(|r| r.as_str()),
+						Level::Namespace(ns.to_owned()),
+					)
+					.await?,
+				));
 				Ok(())
 			}
 			Err(err) => Err(err),
@@ -116,7 +132,15 @@ pub async fn basic(
 			Ok(u) => {
 				debug!("Authenticated as root user '{}'", user);
 				session.exp = expiration(u.duration.session)?;
-				session.au = Arc::new((&u, Level::Root).try_into()?);
+				session.au = Arc::new(Auth::new(
+					system_user_actor(
+						kvs,
+						&u.name,
+						u.roles.iter().map(|r| r.as_str()),
+						Level::Root,
+					)
+					.await?,
+				));
 				Ok(())
 			}
 			Err(err) => Err(err),
@@ -260,18 +284,18 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 						sess.or.clone_from(&session.or);
 						authenticate_generic(kvs, &sess, au).await?;
 					}
-					// Parse the roles
+					// Parse the roles, granting the viewer role if none are provided
 					let roles = match &token_data.claims.roles {
-						// If no role is provided, grant the viewer role
-						None => vec![Role::Viewer],
-						// If roles are provided, parse them
-						Some(roles) => roles
-							.iter()
-							.map(|r| -> Result<Role, Error> {
-								Role::from_str(r.as_str()).map_err(Error::IamError)
-							})
-							.collect::<Result<Vec<_>, _>>()?,
+						None => vec![Role::Viewer.to_string()],
+						Some(roles) => roles.clone(),
 					};
+					let actor = system_user_actor(
+						kvs,
+						&de.name,
+						roles.iter().map(String::as_str),
+						Level::Database(ns.to_string(), db.to_string()),
+					)
+					.await?;
 					// Log the success
 					debug!("Authenticated to database `{}` with access method `{}`", db, ac);
 					// Set the session
@@ -280,11 +304,7 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 					session.db = Some(db.to_owned());
 					session.ac = Some(ac.to_owned());
 					session.exp = expiration(de.duration.session)?;
-					session.au = Arc::new(Auth::new(Actor::new(
-						de.name.to_string(),
-						roles,
-						Level::Database(ns.to_string(), db.to_string()),
-					)));
+					session.au = Arc::new(Auth::new(actor));
 				}
 				// If the access type is Record, this is record access
 				// Record access without an "id" claim is only possible if there is an AUTHENTICATE clause
@@ -364,11 +384,15 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 			session.ns = Some(ns.to_owned());
 			session.db = Some(db.to_owned());
 			session.exp = expiration(de.duration.session)?;
-			session.au = Arc::new(Auth::new(Actor::new(
-				id.to_string(),
-				de.roles.iter().map(Role::try_from).collect::<Result<_, _>>()?,
-				Level::Database(ns.to_string(), db.to_string()),
-			)));
+			session.au = Arc::new(Auth::new(
+				system_user_actor(
+					kvs,
+					id,
+					de.roles.iter().map(|r| r.as_str()),
+					Level::Database(ns.to_string(), db.to_string()),
+				)
+				.await?,
+			));
 			Ok(())
 		}
 		// Check if this is namespace access
@@ -413,18 +437,18 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 				sess.or.clone_from(&session.or);
 				authenticate_generic(kvs, &sess, au).await?;
 			}
-			// Parse the roles
+			// Parse the roles, granting the viewer role if none are provided
 			let roles = match &token_data.claims.roles {
-				// If no role is provided, grant the viewer role
-				None => vec![Role::Viewer],
-				// If roles are provided, parse them
-				Some(roles) => roles
-					.iter()
-					.map(|r| -> Result<Role, Error> {
-						Role::from_str(r.as_str()).map_err(Error::IamError)
-					})
-					.collect::<Result<Vec<_>, _>>()?,
+				None => vec![Role::Viewer.to_string()],
+				Some(roles) => roles.clone(),
 			};
+			let actor = system_user_actor(
+				kvs,
+				&de.name,
+				roles.iter().map(String::as_str),
+				Level::Namespace(ns.to_string()),
+			)
+			.await?;
 			// Log the success
 			debug!("Authenticated to namespace `{}` with access method `{}`", ns, ac);
 			// Set the session
@@ -432,11 +456,7 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 			session.ns = Some(ns.to_owned());
 			session.ac = Some(ac.to_owned());
 			session.exp = expiration(de.duration.session)?;
-			session.au = Arc::new(Auth::new(Actor::new(
-				de.name.to_string(),
-				roles,
-				Level::Namespace(ns.to_string()),
-			)));
+			session.au = Arc::new(Auth::new(actor));
 			Ok(())
 		}
 		// Check if this is namespace authentication with user credentials
@@ -466,11 +486,15 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 			session.tk = Some(value);
 			session.ns = Some(ns.to_owned());
 			session.exp = expiration(de.duration.session)?;
-			session.au = Arc::new(Auth::new(Actor::new(
-				id.to_string(),
-				de.roles.iter().map(Role::try_from).collect::<Result<_, _>>()?,
-				Level::Namespace(ns.to_string()),
-			)));
+			session.au = Arc::new(Auth::new(
+				system_user_actor(
+					kvs,
+					id,
+					de.roles.iter().map(|r| r.as_str()),
+					Level::Namespace(ns.to_string()),
+				)
+				.await?,
+			));
 			Ok(())
 		}
 		// Check if this is root access
@@ -514,25 +538,21 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 				sess.or.clone_from(&session.or);
 				authenticate_generic(kvs, &sess, au).await?;
 			}
-			// Parse the roles
+			// Parse the roles, granting the viewer role if none are provided
 			let roles = match &token_data.claims.roles {
-				// If no role is provided, grant the viewer role
-				None => vec![Role::Viewer],
-				// If roles are provided, parse them
-				Some(roles) => roles
-					.iter()
-					.map(|r| -> Result<Role, Error> {
-						Role::from_str(r.as_str()).map_err(Error::IamError)
-					})
-					.collect::<Result<Vec<_>, _>>()?,
+				None => vec![Role::Viewer.to_string()],
+				Some(roles) => roles.clone(),
 			};
+			let actor =
+				system_user_actor(kvs, &de.name, roles.iter().map(String::as_str), Level::Root)
+					.await?;
 			// Log the success
 			debug!("Authenticated to root with access method `{}`", ac);
 			// Set the session
 			session.tk = Some(value);
 			session.ac = Some(ac.to_owned());
 			session.exp = expiration(de.duration.session)?;
-			session.au = Arc::new(Auth::new(Actor::new(de.name.to_string(), roles, Level::Root)));
+			session.au = Arc::new(Auth::new(actor));
 			Ok(())
 		}
 		// Check if this is root authentication with user credentials
@@ -560,11 +580,10 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 			// Set the session
 			session.tk = Some(value);
 			session.exp = expiration(de.duration.session)?;
-			session.au = Arc::new(Auth::new(Actor::new(
-				id.to_string(),
-				de.roles.iter().map(Role::try_from).collect::<Result<_, _>>()?,
-				Level::Root,
-			)));
+			session.au = Arc::new(Auth::new(
+				system_user_actor(kvs, id, de.roles.iter().map(|r| r.as_str()), Level::Root)
+					.await?,
+			));
 			Ok(())
 		}
 		// There was an auth error
@@ -641,6 +660,49 @@ pub async fn verify_db_creds(
 	Ok(user)
 }
 
+/// Creates the actor for an authenticated system user, resolving the
+/// grants of any custom roles which are defined on the user's level.
+pub(super) async fn system_user_actor<'a>(
+	kvs: &Datastore,
+	id: &str,
+	roles: impl IntoIterator<Item = &'a str>,
+	level: Level,
+) -> Result<Actor, Error> {
+	let mut builtin = Vec::new();
+	let mut custom = Vec::new();
+	for role in roles {
+		match Role::from_str(role) {
+			Ok(role) => builtin.push(role),
+			Err(_) => custom.push(role),
+		}
+	}
+	let mut grants = Vec::new();
+	if !custom.is_empty() {
+		// Create a new readonly transaction
+		let tx = kvs.transaction(Read, Optimistic).await?;
+		for role in custom {
+			// Fetch the role from the same level as the user
+			let rl = match &level {
+				Level::Root => tx.get_root_role(role).await,
+				Level::Namespace(ns) => tx.get_ns_role(ns, role).await,
+				Level::Database(ns, db) => tx.get_db_role(ns, db, role).await,
+				_ => Err(Error::InvalidAuth),
+			};
+			match rl {
+				Ok(rl) => grants.extend(rl.grants.iter().cloned()),
+				Err(e) => {
+					debug!("Error retrieving role `{role}` for authentication: {e}");
+					tx.cancel().await?;
+					return Err(Error::IamError(IamError::InvalidRole(role.to_owned())));
+				}
+			}
+		}
+		// Ensure that the transaction is cancelled
+		tx.cancel().await?;
+	}
+	Ok(Actor::new(id.to_owned(), builtin, level).with_grants(grants))
+}
+
 fn verify_pass(pass: &str, hash: &str) -> Result<(), Error> {
 	// Compute the hash and verify the password
 	let hash = PasswordHash::new(hash).unwrap();
@@ -864,17 +926,24 @@ mod tests {
 				hash: "$argon2id$v=19$m=16,t=2,p=1$VUlHTHVOYjc5d0I1dGE3OQ$sVtmRNH+Xtiijk0uXL2+4w"
 					.to_string(),
 				code: "dummy".to_string(),
-				roles: vec!["nonexistent".into()],
+				roles: vec!["custom".into()],
 				duration: UserDuration::default(),
 				comment: None,
 				if_not_exists: false,
 				overwrite: false,
 			};
 
-			// Use pre-parsed definition, which bypasses the existent role check during parsing.
+			// Define the user with a custom role, which is then removed.
+			let role = format!("DEFINE ROLE custom ON {}", level.level);
+			ds.execute(&role, &sess, None).await.unwrap().remove(0).result.unwrap();
 			ds.process(Statement::Define(DefineStatement::User(user)).into(), &sess, None)
 				.await
+				.unwrap()
+				.remove(0)
+				.result
 				.unwrap();
+			let role = format!("REMOVE ROLE custom ON {}", level.level);
+			ds.execute(&role, &sess, None).await.unwrap().remove(0).result.unwrap();
 
 			let mut sess = Session {
 				ns: level.ns.map(String::from),
@@ -893,6 +962,117 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_basic_custom_role() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let sess = Session::owner().with_ns("test").with_db("test");
+		let sql = "
+			DEFINE TABLE person;
+			DEFINE FIELD hidden ON person PERMISSIONS NONE;
+			DEFINE TABLE secret;
+			CREATE person:tobie, secret:one SET hidden = true;
+			DEFINE ROLE indexer ON DATABASE GRANT EDIT ON INDEX GRANT VIEW ON TABLE person;
+			DEFINE USER user ON DATABASE PASSWORD 'pass' ROLES indexer;
+		";
+		for res in ds.execute(sql, &sess, None).await.unwrap() {
+			res.result.unwrap();
+		}
+
+		let mut sess = Session {
+			ns: Some("test".to_string()),
+			db: Some("test".to_string()),
+			..Default::default()
+		};
+		basic(&ds, &mut sess, "user", "pass", Some("test"), Some("test")).await.unwrap();
+		assert_eq!(sess.au.grants().len(), 2);
+		assert!(!sess.au.has_viewer_role());
+
+		let sql = "
+			DEFINE INDEX name ON person FIELDS name;
+			REBUILD INDEX name ON person;
+			SELECT * FROM person;
+			SELECT * FROM secret;
+			DEFINE TABLE other;
+			UPDATE person:tobie SET name = 'Tobie';
+		";
+		let mut res = ds.execute(sql, &sess, None).await.unwrap();
+		assert_eq!(res.len(), 6);
+		// The role grants editing indexes
+		res.remove(0).result.unwrap();
+		res.remove(0).result.unwrap();
+		// The role grants viewing the records of a single table, but not its hidden fields
+		let tmp = res.remove(0).result.unwrap();
+		assert_eq!(tmp, syn::value("[{ id: person:tobie }]").unwrap());
+		let tmp = res.remove(0).result.unwrap();
+		assert_eq!(tmp, syn::value("[]").unwrap());
+		// The role does not grant editing tables
+		let tmp = res.remove(0).result;
+		assert!(matches!(tmp, Err(Error::IamError(IamError::NotAllowed { .. }))), "{tmp:?}");
+		// The role does not grant editing records
+		let tmp = res.remove(0).result.unwrap();
+		assert_eq!(tmp, syn::value("[]").unwrap());
+	}
+
+	#[tokio::test]
+	async fn test_basic_custom_role_escalation() {
+		use crate::iam::{Action, Error as IamError, Grant, ResourceKind};
+		use crate::sql::statements::{define::DefineStatement, DefineRoleStatement};
+		use crate::sql::{Base, Statement};
+
+		let ds = Datastore::new("memory").await.unwrap();
+		let sess = Session::owner().with_ns("test").with_db("test");
+		// Users and access methods can not be granted to a role
+		for sql in [
+			"DEFINE ROLE admin ON DATABASE GRANT EDIT ON USER",
+			"DEFINE ROLE admin ON DATABASE GRANT EDIT ON ACCESS",
+		] {
+			let res = ds.execute(sql, &sess, None).await;
+			assert!(res.is_err(), "Unexpected successful grant: {res:?}");
+		}
+		// A role which was stored with such grants does not allow editing users or roles
+		let role = DefineRoleStatement {
+			name: "admin".into(),
+			base: Base::Db,
+			grants: vec![
+				Grant::new(Action::Edit, ResourceKind::Actor),
+				Grant::new(Action::Edit, ResourceKind::Access),
+				Grant::new(Action::Edit, ResourceKind::Table),
+			],
+			..Default::default()
+		};
+		ds.process(Statement::Define(DefineStatement::Role(role)).into(), &sess, None)
+			.await
+			.unwrap()
+			.remove(0)
+			.result
+			.unwrap();
+		let sql = "DEFINE USER user ON DATABASE PASSWORD 'pass' ROLES admin";
+		ds.execute(sql, &sess, None).await.unwrap().remove(0).result.unwrap();
+
+		let mut sess = Session {
+			ns: Some("test".to_string()),
+			db: Some("test".to_string()),
+			..Default::default()
+		};
+		basic(&ds, &mut sess, "user", "pass", Some("test"), Some("test")).await.unwrap();
+
+		let sql = "
+			DEFINE USER other ON DATABASE PASSWORD 'pass' ROLES OWNER;
+			DEFINE ROLE other ON DATABASE GRANT EDIT ON DATABASE;
+			DEFINE ACCESS other ON DATABASE TYPE JWT ALGORITHM HS512 KEY 'secret';
+			DEFINE TABLE other;
+		";
+		let mut res = ds.execute(sql, &sess, None).await.unwrap();
+		assert_eq!(res.len(), 4);
+		// The role holder can not create an owner, or a role with other grants
+		for _ in 0..3 {
+			let tmp = res.remove(0).result;
+			assert!(matches!(tmp, Err(Error::IamError(IamError::NotAllowed { .. }))), "{tmp:?}");
+		}
+		// The other grants of the role still apply
+		res.remove(0).result.unwrap();
+	}
+
 	#[tokio::test]
 	async fn test_token() {
 		#[derive(Debug)]
@@ -1032,6 +1212,44 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_token_undefined_role() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let sess = Session::owner().with_ns("test").with_db("test");
+		let sql = "
+			DEFINE ACCESS token ON DATABASE TYPE JWT ALGORITHM HS512 KEY 'secret';
+			DEFINE ROLE custom ON NAMESPACE GRANT VIEW ON TABLE person;
+		";
+		for res in ds.execute(sql, &sess, None).await.unwrap() {
+			res.result.unwrap();
+		}
+
+		// Neither an undefined role, nor a role defined on another level, is accepted
+		for role in ["undefined", "custom"] {
+			let claims = Claims {
+				iss: Some("surrealdb-test".to_string()),
+				iat: Some(Utc::now().timestamp()),
+				nbf: Some(Utc::now().timestamp()),
+				exp: Some((Utc::now() + Duration::hours(1)).timestamp()),
+				ac: Some("token".to_string()),
+				ns: Some("test".to_string()),
+				db: Some("test".to_string()),
+				roles: Some(vec!["viewer".to_string(), role.to_string()]),
+				..Claims::default()
+			};
+			let key = EncodingKey::from_secret("secret".as_ref());
+			let enc = encode(&HEADER, &claims, &key).unwrap();
+
+			let mut sess = Session::default();
+			let res = token(&ds, &mut sess, &enc).await;
+			assert!(
+				matches!(&res, Err(Error::IamError(IamError::InvalidRole(r))) if r == role),
+				"{res:?}"
+			);
+			assert!(sess.au.is_anon());
+		}
+	}
+
 	#[tokio::test]
 	async fn test_token_record() {
 		#[derive(Debug)]
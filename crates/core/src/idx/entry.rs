@@ -0,0 +1,69 @@
+use crate::err::Error;
+use crate::kvs::Val;
+use crate::sql::paths::ID;
+use crate::sql::statements::DefineIndexStatement;
+use crate::sql::{Idiom, Part, Thing, Value};
+
+/// The value stored against the keys of standard and unique indexes.
+///
+/// By default, an index entry only stores the record id.
+/// When the index has an `INCLUDE` clause, the entry stores a partial document
+/// composed of the record id, the indexed fields and the included fields,
+/// so that queries only using these fields can be answered from the index.
+pub(crate) enum IndexEntry {
+	RecordId(Thing),
+	Document(Thing, Value),
+}
+
+impl IndexEntry {
+	/// Builds the entry for the given record.
+	/// `vals` contains the values of the index columns, followed by the included values.
+	pub(crate) fn new(ix: &DefineIndexStatement, rid: &Thing, vals: &[Value]) -> Self {
+		let Some(include) = &ix.include else {
+			return Self::RecordId(rid.clone());
+		};
+		let mut doc = Value::Object(Default::default());
+		for (i, v) in ix.cols.iter().chain(include.iter()).zip(vals) {
+			if Self::is_storable(i) {
+				doc.put(i, v.clone());
+			}
+		}
+		doc.put(&*ID, Value::Thing(rid.clone()));
+		Self::Document(rid.clone(), doc)
+	}
+
+	/// Decodes an entry, using the format matching the index definition.
+	pub(crate) fn decode(ix: &DefineIndexStatement, val: &[u8]) -> Result<Self, Error> {
+		Self::decode_value(ix.include.is_some(), val)
+	}
+
+	pub(crate) fn decode_value(with_document: bool, val: &[u8]) -> Result<Self, Error> {
+		if !with_document {
+			return Ok(Self::RecordId(revision::from_slice(val)?));
+		}
+		let doc: Value = revision::from_slice(val)?;
+		match doc.rid() {
+			Value::Thing(rid) => Ok(Self::Document(rid, doc)),
+			_ => Err(Error::CorruptedIndex("The index entry does not contain a record id")),
+		}
+	}
+
+	pub(crate) fn encode(&self) -> Result<Val, Error> {
+		Ok(match self {
+			Self::RecordId(rid) => revision::to_vec(rid)?,
+			Self::Document(_, doc) => revision::to_vec(doc)?,
+		})
+	}
+
+	pub(crate) fn into_rid(self) -> Thing {
+		match self {
+			Self::RecordId(rid) | Self::Document(rid, _) => rid,
+		}
+	}
+
+	/// Only plain field paths can be stored in the partial document.
+	/// Computed columns (expressions, flattened arrays, ...) are left out.
+	pub(crate) fn is_storable(i: &Idiom) -> bool {
+		!i.is_empty() && i.iter().all(|p| matches!(p, Part::Field(_)))
+	}
+}
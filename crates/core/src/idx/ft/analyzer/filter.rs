@@ -1,5 +1,7 @@
 use crate::err::Error;
 use crate::idx::ft::analyzer::mapper::Mapper;
+use crate::idx::ft::analyzer::stopwords::stopwords;
+use crate::idx::ft::analyzer::synonym::Synonyms;
 use crate::idx::ft::analyzer::tokenizer::Tokens;
 use crate::idx::ft::offsets::Position;
 use crate::idx::trees::store::IndexStores;
@@ -7,6 +9,7 @@ use crate::sql::filter::Filter as SqlFilter;
 use crate::sql::language::Language;
 use deunicode::deunicode;
 use rust_stemmers::{Algorithm, Stemmer};
+use std::collections::HashSet;
 
 #[derive(Clone, Copy)]
 pub(super) enum FilteringStage {
@@ -21,6 +24,9 @@ pub(super) enum Filter {
 	Lowercase,
 	Uppercase,
 	Mapper(Mapper),
+	Length(u16, u16),
+	Stopwords(HashSet<&'static str>),
+	Synonym(Synonyms),
 }
 
 impl Filter {
@@ -55,6 +61,9 @@ impl Filter {
 			}
 			SqlFilter::Uppercase => Filter::Uppercase,
 			SqlFilter::Mapper(path) => Filter::Mapper(ixs.mappers().get(path)?),
+			SqlFilter::Length(min, max) => Filter::Length(*min, *max),
+			SqlFilter::Stopwords(l) => Filter::Stopwords(stopwords(l).iter().copied().collect()),
+			SqlFilter::Synonym(path) => Filter::Synonym(ixs.mappers().get_synonyms(path)?),
 		};
 		Ok(f)
 	}
@@ -76,7 +85,8 @@ impl Filter {
 
 	fn is_stage(&self, stage: FilteringStage) -> bool {
 		if let FilteringStage::Querying = stage {
-			!matches!(self, Filter::EdgeNgram(_, _) | Filter::Ngram(_, _))
+			// Synonyms are expanded when indexing, the query terms are kept as they are
+			!matches!(self, Filter::EdgeNgram(_, _) | Filter::Ngram(_, _) | Filter::Synonym(_))
 		} else {
 			true
 		}
@@ -106,6 +116,9 @@ impl Filter {
 			Filter::Stemmer(s) => Self::stem(s, c),
 			Filter::Uppercase => Self::uppercase(c),
 			Filter::Mapper(m) => m.map(c),
+			Filter::Length(min, max) => Self::length(c, *min, *max),
+			Filter::Stopwords(s) => Self::stopword(s, c),
+			Filter::Synonym(s) => s.expand(c),
 		}
 	}
 
@@ -140,6 +153,25 @@ impl Filter {
 		Self::check_term(c, s.stem(&c.to_lowercase()).into())
 	}
 
+	#[inline]
+	fn length(c: &str, min: u16, max: u16) -> FilterResult {
+		let l = c.chars().count();
+		if l < min as usize || l > max as usize {
+			FilterResult::Ignore
+		} else {
+			FilterResult::Term(Term::Unchanged)
+		}
+	}
+
+	#[inline]
+	fn stopword(s: &HashSet<&'static str>, c: &str) -> FilterResult {
+		if s.contains(c.to_lowercase().as_str()) {
+			FilterResult::Ignore
+		} else {
+			FilterResult::Term(Term::Unchanged)
+		}
+	}
+
 	#[inline]
 	fn ngram(c: &str, min: u16, max: u16) -> FilterResult {
 		let min = min as usize;
@@ -900,4 +932,30 @@ mod tests {
 		)
 		.await;
 	}
+
+	#[tokio::test]
+	async fn test_length() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS blank,class FILTERS length(2,4);",
+			"A cat is sleeping on the sofa.",
+			&["cat", "is", "on", "the", "sofa"],
+		)
+		.await;
+	}
+
+	#[tokio::test]
+	async fn test_stopwords() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS blank,class FILTERS stopwords(english);",
+			"The cat is sleeping on the sofa",
+			&["cat", "sleeping", "sofa"],
+		)
+		.await;
+		test_analyzer(
+			"ANALYZER test TOKENIZERS blank,class FILTERS lowercase,stopwords(french);",
+			"Le chat dort sur le canapé",
+			&["chat", "dort", "canapé"],
+		)
+		.await;
+	}
 }
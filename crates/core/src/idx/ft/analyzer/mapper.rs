@@ -24,7 +24,10 @@ impl Mapper {
 	pub(in crate::idx) async fn new(path: &Path) -> Result<Self, Error> {
 		let mut terms = Tree::new();
 		let path = is_path_allowed(path)?;
-		Self::iterate_file(&mut terms, &path).await?;
+		for_each_line(&path, |line, line_number| {
+			Self::add_line_tree(&mut terms, line, line_number)
+		})
+		.await?;
 		Ok(Self {
 			terms: Arc::new(terms),
 		})
@@ -57,38 +60,6 @@ impl Mapper {
 		Ok(())
 	}
 
-	#[cfg(not(target_family = "wasm"))]
-	async fn iterate_file(
-		terms: &mut Tree<VariableSizeKey, String>,
-		path: &Path,
-	) -> Result<(), Error> {
-		let file = File::open(path).await?;
-		let reader = BufReader::new(file);
-		let mut lines = reader.lines();
-		let mut line_number = 0;
-		while let Some(line) = lines.next_line().await? {
-			Self::add_line_tree(terms, line, line_number)?;
-			line_number += 1;
-		}
-		Ok(())
-	}
-
-	#[cfg(target_family = "wasm")]
-	async fn iterate_file(
-		terms: &mut Tree<VariableSizeKey, String>,
-		path: &Path,
-	) -> Result<(), Error> {
-		let file = File::open(path)?;
-		let reader = BufReader::new(file);
-		let mut line_number = 0;
-		for line_result in reader.lines() {
-			let line = line_result?;
-			Self::add_line_tree(terms, line, line_number)?;
-			line_number += 1;
-		}
-		Ok(())
-	}
-
 	pub(super) fn map(&self, token: &str) -> FilterResult {
 		if let Ok(key) = VariableSizeKey::from_str(token) {
 			if let Some((lemme, _, _)) = self.terms.get(&key, 0) {
@@ -98,3 +69,37 @@ impl Mapper {
 		FilterResult::Term(Term::Unchanged)
 	}
 }
+
+/// Calls the function for every line of the file, with the line number.
+#[cfg(not(target_family = "wasm"))]
+pub(super) async fn for_each_line<F>(path: &Path, mut f: F) -> Result<(), Error>
+where
+	F: FnMut(String, usize) -> Result<(), Error>,
+{
+	let file = File::open(path).await?;
+	let reader = BufReader::new(file);
+	let mut lines = reader.lines();
+	let mut line_number = 0;
+	while let Some(line) = lines.next_line().await? {
+		f(line, line_number)?;
+		line_number += 1;
+	}
+	Ok(())
+}
+
+/// Calls the function for every line of the file, with the line number.
+#[cfg(target_family = "wasm")]
+pub(super) async fn for_each_line<F>(path: &Path, mut f: F) -> Result<(), Error>
+where
+	F: FnMut(String, usize) -> Result<(), Error>,
+{
+	let file = File::open(path)?;
+	let reader = BufReader::new(file);
+	let mut line_number = 0;
+	for line_result in reader.lines() {
+		let line = line_result?;
+		f(line, line_number)?;
+		line_number += 1;
+	}
+	Ok(())
+}
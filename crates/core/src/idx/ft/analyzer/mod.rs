@@ -6,6 +6,7 @@ use crate::idx::ft::analyzer::tokenizer::{Tokenizer, Tokens};
 use crate::idx::ft::doclength::DocLength;
 use crate::idx::ft::offsets::{Offset, OffsetRecords};
 use crate::idx::ft::postings::TermFrequency;
+use crate::idx::ft::query::{DocTokens, FtQuery, FuzzyTerm, QueryNode, QueryTerm};
 use crate::idx::ft::terms::{TermId, TermLen, Terms};
 use crate::idx::trees::store::IndexStores;
 use crate::sql::statements::DefineAnalyzerStatement;
@@ -19,6 +20,8 @@ use std::sync::Arc;
 
 mod filter;
 pub(in crate::idx) mod mapper;
+mod stopwords;
+pub(in crate::idx) mod synonym;
 mod tokenizer;
 
 #[derive(Clone)]
@@ -29,27 +32,6 @@ pub(crate) struct Analyzer {
 
 pub(in crate::idx) type TermsList = Vec<Option<(TermId, TermLen)>>;
 
-pub(in crate::idx) struct TermsSet {
-	set: HashSet<TermId>,
-	has_unknown_terms: bool,
-}
-
-impl TermsSet {
-	/// If the query TermsSet contains terms that are unknown in the index
-	/// of if there is no terms in the set then
-	/// we are sure that it does not match any document
-	pub(in crate::idx) fn is_matchable(&self) -> bool {
-		!(self.has_unknown_terms || self.set.is_empty())
-	}
-
-	pub(in crate::idx) fn is_subset(&self, other: &TermsSet) -> bool {
-		if self.has_unknown_terms {
-			return false;
-		}
-		self.set.is_subset(&other.set)
-	}
-}
-
 impl Analyzer {
 	pub(crate) fn new(ixs: &IndexStores, az: Arc<DefineAnalyzerStatement>) -> Result<Self, Error> {
 		Ok(Self {
@@ -58,71 +40,161 @@ impl Analyzer {
 		})
 	}
 
-	pub(super) async fn extract_querying_terms(
+	/// Parses the query string and analyzes its terms.
+	/// When the fuzziness is not zero, the terms outside phrases and prefixes
+	/// also match the terms of the index within this edit distance.
+	pub(super) async fn extract_query(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		t: &Terms,
+		query_string: String,
+		fuzziness: u8,
+	) -> Result<FtQuery, Error> {
+		let node = QueryNode::parse(&query_string);
+		self.resolve_query(stk, ctx, opt, t, node, fuzziness).await
+	}
+
+	async fn resolve_query(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		t: &Terms,
+		node: QueryNode,
+		fuzziness: u8,
+	) -> Result<FtQuery, Error> {
+		let q = match node {
+			QueryNode::Words(w) if fuzziness > 0 => {
+				let terms = self.resolve_terms(stk, ctx, opt, t, w, true).await?;
+				let tx = ctx.tx();
+				let mut fuzzy_terms = Vec::with_capacity(terms.len());
+				for qt in terms {
+					let ids = t.get_terms_fuzzy(&tx, &qt.term, fuzziness).await?;
+					fuzzy_terms.push(FuzzyTerm {
+						term: qt.term,
+						ids,
+					});
+				}
+				FtQuery::Fuzzy(fuzzy_terms, fuzziness)
+			}
+			QueryNode::Words(w) => {
+				FtQuery::Terms(self.resolve_terms(stk, ctx, opt, t, w, true).await?)
+			}
+			QueryNode::Phrase(p) => {
+				FtQuery::Phrase(self.resolve_terms(stk, ctx, opt, t, p, false).await?)
+			}
+			QueryNode::Prefix(p) => {
+				let mut terms = self.resolve_terms(stk, ctx, opt, t, p, false).await?;
+				// The wildcard applies to the last term
+				let Some(last) = terms.pop() else {
+					return Ok(FtQuery::Terms(terms));
+				};
+				let ids = t.get_terms_by_prefix(&ctx.tx(), &last.term).await?;
+				let prefix = FtQuery::Prefix(last.term, ids);
+				if terms.is_empty() {
+					prefix
+				} else {
+					FtQuery::And(vec![FtQuery::Terms(terms), prefix])
+				}
+			}
+			QueryNode::Near(a, b, n) => {
+				let a = stk.run(|stk| self.resolve_query(stk, ctx, opt, t, *a, fuzziness)).await?;
+				let b = stk.run(|stk| self.resolve_query(stk, ctx, opt, t, *b, fuzziness)).await?;
+				FtQuery::Near(Box::new(a), Box::new(b), n)
+			}
+			QueryNode::And(nodes) => {
+				FtQuery::And(self.resolve_queries(stk, ctx, opt, t, nodes, fuzziness).await?)
+			}
+			QueryNode::Or(nodes) => {
+				FtQuery::Or(self.resolve_queries(stk, ctx, opt, t, nodes, fuzziness).await?)
+			}
+			QueryNode::Not(n) => {
+				let n = stk.run(|stk| self.resolve_query(stk, ctx, opt, t, *n, fuzziness)).await?;
+				FtQuery::Not(Box::new(n))
+			}
+			QueryNode::Boost(n, boost) => {
+				let n = stk.run(|stk| self.resolve_query(stk, ctx, opt, t, *n, fuzziness)).await?;
+				FtQuery::Boost(Box::new(n), boost)
+			}
+		};
+		Ok(q)
+	}
+
+	async fn resolve_queries(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		t: &Terms,
+		nodes: Vec<QueryNode>,
+		fuzziness: u8,
+	) -> Result<Vec<FtQuery>, Error> {
+		let mut res = Vec::with_capacity(nodes.len());
+		for n in nodes {
+			res.push(stk.run(|stk| self.resolve_query(stk, ctx, opt, t, n, fuzziness)).await?);
+		}
+		Ok(res)
+	}
+
+	/// Analyzes a text of the query, and resolves the ids of its terms.
+	async fn resolve_terms(
 		&self,
 		stk: &mut Stk,
 		ctx: &Context,
 		opt: &Options,
 		t: &Terms,
 		content: String,
-	) -> Result<(TermsList, TermsSet), Error> {
+		dedup: bool,
+	) -> Result<Vec<QueryTerm>, Error> {
 		let tokens = self.generate_tokens(stk, ctx, opt, FilteringStage::Querying, content).await?;
-		// We extract the term ids
-		let mut list = Vec::with_capacity(tokens.list().len());
+		let mut terms = Vec::with_capacity(tokens.list().len());
 		let mut unique_tokens = HashSet::new();
-		let mut set = HashSet::new();
 		let tx = ctx.tx();
-		let mut has_unknown_terms = false;
 		for token in tokens.list() {
+			let term = tokens.get_token_string(token)?;
 			// Tokens can contains duplicated, not need to evaluate them again
-			if unique_tokens.insert(token) {
-				// Is the term known in the index?
-				let opt_term_id = t.get_term_id(&tx, tokens.get_token_string(token)?).await?;
-				list.push(opt_term_id.map(|tid| (tid, token.get_char_len())));
-				if let Some(term_id) = opt_term_id {
-					set.insert(term_id);
-				} else {
-					has_unknown_terms = true;
-				}
+			if dedup && !unique_tokens.insert(term) {
+				continue;
 			}
+			// Is the term known in the index?
+			let id = t.get_term_id(&tx, term).await?.map(|tid| (tid, token.get_char_len()));
+			terms.push(QueryTerm {
+				term: term.to_string(),
+				id,
+			});
 		}
 		drop(tx);
-		Ok((
-			list,
-			TermsSet {
-				set,
-				has_unknown_terms,
-			},
-		))
+		Ok(terms)
 	}
 
-	pub(in crate::idx) async fn extract_indexing_terms(
+	/// Extracts the terms of a document, with their positions.
+	/// Tokens generated from the same original term (e.g. ngrams) share the same position.
+	pub(in crate::idx) async fn extract_document_tokens(
 		&self,
 		stk: &mut Stk,
 		ctx: &Context,
 		opt: &Options,
-		t: &Terms,
 		content: Value,
-	) -> Result<TermsSet, Error> {
+	) -> Result<DocTokens, Error> {
 		let mut tv = Vec::new();
 		self.analyze_value(stk, ctx, opt, content, FilteringStage::Indexing, &mut tv).await?;
-		let mut set = HashSet::new();
-		let mut has_unknown_terms = false;
-		let tx = ctx.tx();
-		for tokens in tv {
+		let mut doc = DocTokens::default();
+		for (i, tokens) in tv.iter().enumerate() {
+			let i = i as u32;
+			let mut pos = 0;
+			let mut last_start = None;
 			for token in tokens.list() {
-				if let Some(term_id) = t.get_term_id(&tx, tokens.get_token_string(token)?).await? {
-					set.insert(term_id);
-				} else {
-					has_unknown_terms = true;
+				let start = token.new_offset(i).start;
+				if last_start.is_some_and(|s| s != start) {
+					pos += 1;
 				}
+				last_start = Some(start);
+				doc.push(tokens.get_token_string(token)?, i, pos);
 			}
 		}
-		drop(tx);
-		Ok(TermsSet {
-			set,
-			has_unknown_terms,
-		})
+		Ok(doc)
 	}
 
 	/// This method is used for indexing.
@@ -134,25 +206,27 @@ impl Analyzer {
 		opt: &Options,
 		terms: &mut Terms,
 		field_content: Vec<Value>,
+		weights: Option<&[u32]>,
 	) -> Result<(DocLength, Vec<(TermId, TermFrequency)>), Error> {
 		let mut dl = 0;
 		// Let's first collect all the inputs, and collect the tokens.
 		// We need to store them because everything after is zero-copy
 		let mut inputs = vec![];
-		self.analyze_content(stk, ctx, opt, field_content, FilteringStage::Indexing, &mut inputs)
+		let inputs_weights = self
+			.analyze_weighted_content(stk, ctx, opt, field_content, weights, &mut inputs)
 			.await?;
 		// We then collect every unique terms and count the frequency
 		let mut tf: HashMap<&str, TermFrequency> = HashMap::new();
-		for tks in &inputs {
+		for (tks, w) in inputs.iter().zip(inputs_weights) {
 			for tk in tks.list() {
-				dl += 1;
+				dl += w;
 				let s = tks.get_token_string(tk)?;
 				match tf.entry(s) {
 					Entry::Vacant(e) => {
-						e.insert(1);
+						e.insert(w);
 					}
 					Entry::Occupied(mut e) => {
-						e.insert(*e.get() + 1);
+						e.insert(*e.get() + w);
 					}
 				}
 			}
@@ -176,24 +250,30 @@ impl Analyzer {
 		opt: &Options,
 		terms: &mut Terms,
 		content: Vec<Value>,
+		weights: Option<&[u32]>,
 	) -> Result<(DocLength, Vec<(TermId, TermFrequency)>, Vec<(TermId, OffsetRecords)>), Error> {
 		let mut dl = 0;
 		// Let's first collect all the inputs, and collect the tokens.
 		// We need to store them because everything after is zero-copy
 		let mut inputs = Vec::with_capacity(content.len());
-		self.analyze_content(stk, ctx, opt, content, FilteringStage::Indexing, &mut inputs).await?;
+		let inputs_weights =
+			self.analyze_weighted_content(stk, ctx, opt, content, weights, &mut inputs).await?;
 		// We then collect every unique terms and count the frequency and extract the offsets
-		let mut tfos: HashMap<&str, Vec<Offset>> = HashMap::new();
-		for (i, tks) in inputs.iter().enumerate() {
+		let mut tfos: HashMap<&str, (TermFrequency, Vec<Offset>)> = HashMap::new();
+		for (i, (tks, w)) in inputs.iter().zip(inputs_weights).enumerate() {
 			for tk in tks.list() {
-				dl += 1;
+				dl += w;
 				let s = tks.get_token_string(tk)?;
 				let o = tk.new_offset(i as u32);
 				match tfos.entry(s) {
 					Entry::Vacant(e) => {
-						e.insert(vec![o]);
+						e.insert((w, vec![o]));
+					}
+					Entry::Occupied(mut e) => {
+						let (f, os) = e.get_mut();
+						*f += w;
+						os.push(o);
 					}
-					Entry::Occupied(mut e) => e.get_mut().push(o),
 				}
 			}
 		}
@@ -202,29 +282,33 @@ impl Analyzer {
 		let mut tfid = Vec::with_capacity(tfos.len());
 		let mut osid = Vec::with_capacity(tfos.len());
 		let tx = ctx.tx();
-		for (t, o) in tfos {
+		for (t, (f, o)) in tfos {
 			let id = terms.resolve_term_id(&tx, t).await?;
-			tfid.push((id, o.len() as TermFrequency));
+			tfid.push((id, f));
 			osid.push((id, OffsetRecords(o)));
 		}
 		drop(tx);
 		Ok((dl, tfid, osid))
 	}
 
-	/// Was marked recursive
-	async fn analyze_content(
+	/// Analyzes the content of each field for indexing,
+	/// and returns the weight of each of the resulting inputs.
+	async fn analyze_weighted_content(
 		&self,
 		stk: &mut Stk,
 		ctx: &Context,
 		opt: &Options,
 		content: Vec<Value>,
-		stage: FilteringStage,
+		weights: Option<&[u32]>,
 		tks: &mut Vec<Tokens>,
-	) -> Result<(), Error> {
-		for v in content {
-			self.analyze_value(stk, ctx, opt, v, stage, tks).await?;
+	) -> Result<Vec<TermFrequency>, Error> {
+		let mut inputs_weights = Vec::with_capacity(content.len());
+		for (i, v) in content.into_iter().enumerate() {
+			self.analyze_value(stk, ctx, opt, v, FilteringStage::Indexing, tks).await?;
+			let w = weights.and_then(|w| w.get(i)).copied().unwrap_or(1) as TermFrequency;
+			inputs_weights.resize(tks.len(), w);
 		}
-		Ok(())
+		Ok(inputs_weights)
 	}
 
 	/// Was marked recursive
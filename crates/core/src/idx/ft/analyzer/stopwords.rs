@@ -0,0 +1,519 @@
+use crate::sql::language::Language;
+
+/// Returns the built-in list of stopwords for the language.
+/// The stopwords are lowercase.
+pub(super) fn stopwords(l: &Language) -> &'static [&'static str] {
+	match l {
+		Language::Arabic => ARABIC,
+		Language::Danish => DANISH,
+		Language::Dutch => DUTCH,
+		Language::English => ENGLISH,
+		Language::Finnish => FINNISH,
+		Language::French => FRENCH,
+		Language::German => GERMAN,
+		Language::Greek => GREEK,
+		Language::Hungarian => HUNGARIAN,
+		Language::Italian => ITALIAN,
+		Language::Norwegian => NORWEGIAN,
+		Language::Portuguese => PORTUGUESE,
+		Language::Romanian => ROMANIAN,
+		Language::Russian => RUSSIAN,
+		Language::Spanish => SPANISH,
+		Language::Swedish => SWEDISH,
+		Language::Tamil => TAMIL,
+		Language::Turkish => TURKISH,
+	}
+}
+
+const ARABIC: &[&str] = &[
+	"إلى",
+	"إلا",
+	"إن",
+	"إذا",
+	"أن",
+	"أو",
+	"أي",
+	"أما",
+	"أنا",
+	"أنت",
+	"أنه",
+	"أيضا",
+	"الذي",
+	"الذين",
+	"التي",
+	"اللذين",
+	"بعد",
+	"بعض",
+	"بين",
+	"ثم",
+	"حتى",
+	"حيث",
+	"ذلك",
+	"عن",
+	"على",
+	"عند",
+	"غير",
+	"فإن",
+	"في",
+	"فيه",
+	"فيها",
+	"قبل",
+	"قد",
+	"كان",
+	"كانت",
+	"كل",
+	"كما",
+	"لا",
+	"لقد",
+	"لم",
+	"لن",
+	"له",
+	"لها",
+	"ما",
+	"مع",
+	"مما",
+	"من",
+	"منذ",
+	"منه",
+	"نحن",
+	"هذا",
+	"هذه",
+	"هم",
+	"هناك",
+	"هو",
+	"هي",
+	"و",
+	"وقد",
+	"ولا",
+	"وهو",
+	"وهي",
+	"يكون",
+];
+
+const DANISH: &[&str] = &[
+	"ad", "af", "alle", "alt", "anden", "at", "blev", "blive", "bliver", "da", "de", "dem", "den",
+	"denne", "der", "deres", "det", "dette", "dig", "din", "disse", "dog", "du", "efter", "eller",
+	"en", "end", "er", "et", "for", "fra", "ham", "han", "hans", "har", "havde", "have", "hende",
+	"hendes", "her", "hos", "hun", "hvad", "hvis", "hvor", "i", "ikke", "ind", "jeg", "jer", "jo",
+	"kunne", "man", "mange", "med", "meget", "men", "mig", "min", "mine", "mit", "mod", "ned",
+	"noget", "nogle", "nu", "når", "og", "også", "om", "op", "os", "over", "på", "selv", "sig",
+	"sin", "sine", "sit", "skal", "skulle", "som", "sådan", "thi", "til", "ud", "under", "var",
+	"vi", "vil", "ville", "vor", "være", "været",
+];
+
+const DUTCH: &[&str] = &[
+	"aan", "al", "alles", "als", "altijd", "andere", "ben", "bij", "daar", "dan", "dat", "de",
+	"der", "deze", "die", "dit", "doch", "doen", "door", "dus", "een", "eens", "en", "er", "ge",
+	"geen", "geweest", "haar", "had", "heb", "hebben", "heeft", "hem", "het", "hier", "hij", "hoe",
+	"hun", "iemand", "iets", "ik", "in", "is", "ja", "je", "kan", "kon", "kunnen", "maar", "me",
+	"meer", "men", "met", "mij", "mijn", "moet", "na", "naar", "niet", "niets", "nog", "nu", "of",
+	"om", "omdat", "onder", "ons", "ook", "op", "over", "reeds", "te", "tegen", "toch", "toen",
+	"tot", "u", "uit", "uw", "van", "veel", "voor", "want", "waren", "was", "wat", "werd", "wezen",
+	"wie", "wil", "worden", "wordt", "zal", "ze", "zelf", "zich", "zij", "zijn", "zo", "zonder",
+	"zou",
+];
+
+const ENGLISH: &[&str] = &[
+	"a",
+	"about",
+	"above",
+	"after",
+	"again",
+	"against",
+	"all",
+	"am",
+	"an",
+	"and",
+	"any",
+	"are",
+	"as",
+	"at",
+	"be",
+	"because",
+	"been",
+	"before",
+	"being",
+	"below",
+	"between",
+	"both",
+	"but",
+	"by",
+	"can",
+	"could",
+	"did",
+	"do",
+	"does",
+	"doing",
+	"down",
+	"during",
+	"each",
+	"few",
+	"for",
+	"from",
+	"further",
+	"had",
+	"has",
+	"have",
+	"having",
+	"he",
+	"her",
+	"here",
+	"hers",
+	"herself",
+	"him",
+	"himself",
+	"his",
+	"how",
+	"i",
+	"if",
+	"in",
+	"into",
+	"is",
+	"it",
+	"its",
+	"itself",
+	"me",
+	"more",
+	"most",
+	"my",
+	"myself",
+	"no",
+	"nor",
+	"not",
+	"of",
+	"off",
+	"on",
+	"once",
+	"only",
+	"or",
+	"other",
+	"ought",
+	"our",
+	"ours",
+	"ourselves",
+	"out",
+	"over",
+	"own",
+	"same",
+	"she",
+	"should",
+	"so",
+	"some",
+	"such",
+	"than",
+	"that",
+	"the",
+	"their",
+	"theirs",
+	"them",
+	"themselves",
+	"then",
+	"there",
+	"these",
+	"they",
+	"this",
+	"those",
+	"through",
+	"to",
+	"too",
+	"under",
+	"until",
+	"up",
+	"very",
+	"was",
+	"we",
+	"were",
+	"what",
+	"when",
+	"where",
+	"which",
+	"while",
+	"who",
+	"whom",
+	"why",
+	"with",
+	"would",
+	"you",
+	"your",
+	"yours",
+	"yourself",
+	"yourselves",
+];
+
+const FINNISH: &[&str] = &[
+	"ei", "eivät", "emme", "en", "et", "ette", "että", "he", "heidän", "hän", "hänen", "ja", "jos",
+	"joka", "jotka", "kanssa", "koska", "kuin", "kun", "me", "meidän", "minä", "minun", "mikä",
+	"mitä", "mutta", "mukaan", "ne", "niin", "nyt", "olen", "olet", "olemme", "olette", "oli",
+	"olivat", "olla", "on", "ovat", "se", "sen", "sinä", "sinun", "siis", "sitä", "tai", "te",
+	"teidän", "tämä", "tämän", "vaan", "vai", "vaikka", "voi", "yli",
+];
+
+const FRENCH: &[&str] = &[
+	"au", "aux", "avec", "ce", "ces", "cette", "dans", "de", "des", "du", "elle", "elles", "en",
+	"est", "et", "eu", "il", "ils", "je", "la", "le", "les", "leur", "leurs", "lui", "ma", "mais",
+	"me", "même", "mes", "moi", "mon", "ne", "nos", "notre", "nous", "on", "ou", "où", "par",
+	"pas", "pour", "qu", "que", "qui", "sa", "se", "ses", "son", "sont", "sur", "ta", "te", "tes",
+	"toi", "ton", "tu", "un", "une", "vos", "votre", "vous", "c", "d", "j", "l", "m", "n", "s",
+	"t", "y", "été", "était", "étaient", "être", "avait", "avaient", "ont", "suis", "es", "sommes",
+	"êtes", "ai", "as", "avons", "avez",
+];
+
+const GERMAN: &[&str] = &[
+	"aber", "alle", "allem", "allen", "aller", "alles", "als", "also", "am", "an", "ander",
+	"andere", "anderen", "auch", "auf", "aus", "bei", "bin", "bis", "bist", "da", "damit", "dann",
+	"das", "dass", "dein", "deine", "dem", "den", "der", "des", "dich", "die", "dies", "diese",
+	"dieser", "dieses", "dir", "doch", "dort", "du", "durch", "ein", "eine", "einem", "einen",
+	"einer", "eines", "er", "es", "euch", "euer", "für", "hab", "habe", "haben", "hat", "hatte",
+	"hier", "hin", "ich", "ihm", "ihn", "ihr", "ihre", "im", "in", "ist", "ja", "jede", "jeder",
+	"kann", "kein", "keine", "man", "mein", "meine", "mich", "mir", "mit", "muss", "nach", "nicht",
+	"nichts", "noch", "nun", "nur", "ob", "oder", "ohne", "sehr", "sein", "seine", "sich", "sie",
+	"sind", "so", "um", "und", "uns", "unser", "unter", "viel", "vom", "von", "vor", "war",
+	"waren", "was", "weil", "wenn", "wer", "wie", "wir", "wird", "wo", "zu", "zum", "zur", "über",
+];
+
+const GREEK: &[&str] = &[
+	"αλλά",
+	"αν",
+	"από",
+	"για",
+	"δε",
+	"δεν",
+	"εγώ",
+	"εδώ",
+	"είναι",
+	"εκεί",
+	"εμείς",
+	"εσύ",
+	"η",
+	"θα",
+	"και",
+	"κι",
+	"με",
+	"μη",
+	"μην",
+	"μια",
+	"να",
+	"ο",
+	"οι",
+	"όπως",
+	"ότι",
+	"που",
+	"πως",
+	"σε",
+	"στη",
+	"στην",
+	"στο",
+	"στον",
+	"στα",
+	"στις",
+	"στους",
+	"τα",
+	"την",
+	"της",
+	"τι",
+	"τις",
+	"το",
+	"τον",
+	"του",
+	"τους",
+	"των",
+	"ως",
+];
+
+const HUNGARIAN: &[&str] = &[
+	"a", "az", "azt", "azok", "de", "egy", "el", "ez", "ezt", "ezek", "fel", "hanem", "hogy", "is",
+	"igen", "ki", "le", "meg", "mert", "mi", "mint", "már", "nem", "sem", "vagy", "van", "volt",
+	"voltak", "én", "és", "ő", "ők", "pedig", "csak", "még", "ha", "ahogy", "akkor", "amely",
+	"amelyek", "amikor", "után", "között",
+];
+
+const ITALIAN: &[&str] = &[
+	"a", "ad", "al", "alla", "alle", "agli", "ai", "anche", "che", "chi", "ci", "come", "con",
+	"contro", "da", "dal", "dalla", "dei", "del", "della", "delle", "dello", "degli", "di", "e",
+	"ed", "era", "erano", "gli", "ha", "hanno", "ho", "i", "il", "in", "io", "la", "le", "lei",
+	"li", "lo", "loro", "lui", "ma", "me", "mi", "mia", "mio", "ne", "nei", "nel", "nella", "noi",
+	"non", "o", "per", "perché", "più", "quale", "quando", "quella", "quello", "questa", "questo",
+	"se", "si", "sia", "siamo", "sono", "su", "sua", "sul", "sulla", "suo", "ti", "tra", "tu",
+	"tua", "tuo", "un", "una", "uno", "vi", "voi", "è",
+];
+
+const NORWEGIAN: &[&str] = &[
+	"alle", "at", "av", "bare", "blir", "da", "de", "deg", "dei", "dem", "den", "denne", "der",
+	"det", "dette", "di", "din", "du", "eg", "ein", "eit", "eller", "en", "er", "et", "etter",
+	"for", "fra", "før", "ha", "hadde", "han", "hans", "har", "hen", "her", "hun", "hva", "hvis",
+	"hvor", "i", "ikke", "inn", "jeg", "kan", "kunne", "man", "mange", "med", "meg", "men", "mot",
+	"min", "mitt", "ned", "noe", "noen", "nå", "når", "og", "også", "om", "opp", "oss", "over",
+	"på", "seg", "selv", "sin", "sitt", "skal", "skulle", "som", "så", "til", "ut", "var", "vi",
+	"vil", "ville", "være", "vært",
+];
+
+const PORTUGUESE: &[&str] = &[
+	"a", "ao", "aos", "as", "até", "com", "como", "da", "das", "de", "dela", "dele", "do", "dos",
+	"e", "ela", "elas", "ele", "eles", "em", "entre", "era", "essa", "esse", "esta", "este", "eu",
+	"foi", "há", "isso", "isto", "já", "lhe", "mais", "mas", "me", "mesmo", "meu", "minha", "na",
+	"nas", "no", "nos", "nós", "não", "num", "numa", "o", "os", "ou", "para", "pela", "pelo",
+	"por", "qual", "quando", "que", "quem", "se", "sem", "ser", "seu", "sua", "são", "só",
+	"também", "te", "tem", "um", "uma", "você", "à", "às", "é",
+];
+
+const ROMANIAN: &[&str] = &[
+	"a", "acea", "aceasta", "această", "acest", "acesta", "al", "ale", "am", "ca", "care", "ce",
+	"cu", "de", "din", "după", "este", "eu", "fi", "fost", "i", "ii", "la", "le", "lui", "mai",
+	"mult", "ne", "nici", "noi", "nu", "o", "pe", "pentru", "prin", "sa", "se", "sau", "sunt",
+	"să", "și", "un", "una", "unei", "unui", "voi", "în", "încă",
+];
+
+const RUSSIAN: &[&str] = &[
+	"а",
+	"без",
+	"более",
+	"бы",
+	"был",
+	"была",
+	"были",
+	"было",
+	"быть",
+	"в",
+	"вам",
+	"вас",
+	"весь",
+	"во",
+	"вот",
+	"все",
+	"всё",
+	"вы",
+	"где",
+	"да",
+	"даже",
+	"для",
+	"до",
+	"его",
+	"ее",
+	"её",
+	"если",
+	"есть",
+	"еще",
+	"ещё",
+	"же",
+	"за",
+	"здесь",
+	"и",
+	"из",
+	"или",
+	"им",
+	"их",
+	"к",
+	"как",
+	"когда",
+	"кто",
+	"ли",
+	"либо",
+	"мне",
+	"может",
+	"мы",
+	"на",
+	"над",
+	"надо",
+	"наш",
+	"не",
+	"него",
+	"нее",
+	"нет",
+	"ни",
+	"них",
+	"но",
+	"ну",
+	"о",
+	"об",
+	"однако",
+	"он",
+	"она",
+	"они",
+	"оно",
+	"от",
+	"очень",
+	"по",
+	"под",
+	"при",
+	"с",
+	"со",
+	"так",
+	"также",
+	"такой",
+	"там",
+	"те",
+	"тем",
+	"то",
+	"того",
+	"тоже",
+	"той",
+	"только",
+	"том",
+	"ты",
+	"у",
+	"уже",
+	"хотя",
+	"чего",
+	"чей",
+	"чем",
+	"что",
+	"чтобы",
+	"эта",
+	"эти",
+	"это",
+	"я",
+];
+
+const SPANISH: &[&str] = &[
+	"a", "al", "algo", "algunos", "ante", "antes", "como", "con", "contra", "cual", "cuando", "de",
+	"del", "desde", "donde", "durante", "e", "el", "ella", "ellas", "ellos", "en", "entre", "era",
+	"es", "esa", "ese", "eso", "esta", "estaba", "estar", "este", "esto", "estos", "fue", "ha",
+	"hay", "la", "las", "le", "les", "lo", "los", "me", "mi", "mis", "muy", "más", "nada", "ni",
+	"no", "nos", "nosotros", "o", "os", "otra", "otro", "para", "pero", "poco", "por", "porque",
+	"que", "quien", "se", "sea", "ser", "si", "sin", "sobre", "son", "su", "sus", "también", "te",
+	"tiene", "todo", "todos", "tu", "tus", "un", "una", "uno", "unos", "y", "ya", "yo", "él",
+];
+
+const SWEDISH: &[&str] = &[
+	"alla", "allt", "att", "av", "blev", "bli", "blir", "de", "dem", "den", "denna", "deras",
+	"dess", "det", "detta", "dig", "din", "ditt", "du", "där", "då", "efter", "ej", "eller", "en",
+	"er", "ett", "från", "för", "ha", "hade", "han", "hans", "har", "henne", "hennes", "hon",
+	"honom", "hur", "här", "i", "icke", "ingen", "inom", "inte", "jag", "ju", "kan", "kunde",
+	"man", "med", "mellan", "men", "mig", "min", "mina", "mitt", "mot", "mycket", "ni", "nu",
+	"när", "någon", "något", "några", "och", "om", "oss", "på", "samma", "sedan", "sig", "sin",
+	"sitt", "ska", "skulle", "som", "så", "till", "under", "upp", "ut", "utan", "vad", "var",
+	"vara", "varit", "vi", "vid", "vilken", "vår", "än", "är", "över",
+];
+
+const TAMIL: &[&str] = &[
+	"அது",
+	"அந்த",
+	"அவர்",
+	"அவர்கள்",
+	"அல்லது",
+	"ஆகும்",
+	"இது",
+	"இந்த",
+	"இருந்து",
+	"உள்ள",
+	"உள்ளது",
+	"என்று",
+	"என்ற",
+	"என்பது",
+	"ஒரு",
+	"கொண்டு",
+	"நான்",
+	"பல",
+	"போது",
+	"மற்றும்",
+	"மேலும்",
+	"வரை",
+];
+
+const TURKISH: &[&str] = &[
+	"acaba", "ama", "ancak", "bazı", "belki", "ben", "beni", "benim", "bir", "biri", "birkaç",
+	"biz", "bu", "bunu", "çok", "çünkü", "da", "daha", "de", "defa", "diye", "en", "gibi", "hem",
+	"hep", "her", "hiç", "için", "ile", "ise", "kez", "ki", "kim", "mı", "mi", "mu", "mü", "nasıl",
+	"ne", "neden", "nerede", "niçin", "o", "sen", "siz", "şey", "şu", "tüm", "ve", "veya", "ya",
+	"yani",
+];
@@ -0,0 +1,97 @@
+use crate::err::Error;
+use crate::iam::file::is_path_allowed;
+use crate::idx::ft::analyzer::filter::{FilterResult, Term};
+use crate::idx::ft::analyzer::mapper::for_each_line;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Expands a term with its synonyms.
+/// Every line of the file is a group of equivalent terms separated by commas.
+/// A synonym is a single term, multi-word synonyms are rejected.
+#[derive(Clone, Default)]
+pub(in crate::idx) struct Synonyms {
+	terms: Arc<HashMap<String, Arc<[String]>>>,
+}
+
+impl Synonyms {
+	pub(in crate::idx) async fn new(path: &Path) -> Result<Self, Error> {
+		let mut terms = HashMap::new();
+		let path = is_path_allowed(path)?;
+		for_each_line(&path, |line, line_number| Self::add_line(&mut terms, line, line_number))
+			.await?;
+		Ok(Self {
+			terms: Arc::new(terms),
+		})
+	}
+
+	fn add_line(
+		terms: &mut HashMap<String, Arc<[String]>>,
+		line: String,
+		line_number: usize,
+	) -> Result<(), Error> {
+		// Empty lines and comments are skipped
+		let line = line.trim();
+		if line.is_empty() || line.starts_with('#') {
+			return Ok(());
+		}
+		let group: Vec<String> = line
+			.split(',')
+			.map(|t| t.trim())
+			.filter(|t| !t.is_empty())
+			.map(|t| t.to_string())
+			.collect();
+		if group.len() < 2 {
+			return Err(Error::AnalyzerError(format!(
+				"Expected at least two terms separated by a comma line {line_number}: {line}"
+			)));
+		}
+		if let Some(t) = group.iter().find(|t| t.contains(char::is_whitespace)) {
+			return Err(Error::AnalyzerError(format!(
+				"Multi-word synonyms are not supported, found '{t}' line {line_number}: {line}"
+			)));
+		}
+		let group: Arc<[String]> = group.into();
+		for t in group.iter() {
+			terms.insert(t.clone(), group.clone());
+		}
+		Ok(())
+	}
+
+	pub(super) fn expand(&self, token: &str) -> FilterResult {
+		if let Some(group) = self.terms.get(token) {
+			let terms = group
+				.iter()
+				.map(|t| {
+					if t == token {
+						Term::Unchanged
+					} else {
+						Term::NewTerm(t.clone(), 0)
+					}
+				})
+				.collect();
+			return FilterResult::Terms(terms);
+		}
+		FilterResult::Term(Term::Unchanged)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::idx::ft::analyzer::synonym::Synonyms;
+	use std::collections::HashMap;
+
+	#[test]
+	fn test_add_line() {
+		let mut terms = HashMap::new();
+		Synonyms::add_line(&mut terms, "car, automobile ,vehicle".to_string(), 0).unwrap();
+		assert_eq!(terms.len(), 3);
+		assert_eq!(terms["automobile"].as_ref(), ["car", "automobile", "vehicle"]);
+		Synonyms::add_line(&mut terms, "car".to_string(), 1).unwrap_err();
+		let err = Synonyms::add_line(&mut terms, "car,motor vehicle".to_string(), 2).unwrap_err();
+		assert_eq!(
+			err.to_string(),
+			"A value can't be analyzed: Multi-word synonyms are not supported, found 'motor vehicle' line 2: car,motor vehicle"
+		);
+	}
+}
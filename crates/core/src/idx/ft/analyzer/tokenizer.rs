@@ -4,6 +4,7 @@ use crate::idx::ft::analyzer::filter::{Filter, FilterResult, Term};
 use crate::idx::ft::offsets::{Offset, Position};
 use crate::sql::tokenizer::Tokenizer as SqlTokenizer;
 use crate::sql::Value;
+use unicode_segmentation::UnicodeSegmentation;
 
 pub(in crate::idx) struct Tokens {
 	/// The input string
@@ -216,16 +217,48 @@ impl Tokenizer {
 	}
 
 	pub(super) fn tokenize(t: &[SqlTokenizer], i: String) -> Tokens {
-		let mut w = Tokenizer::new(t);
-		let mut last_char_pos = 0;
-		let mut last_byte_pos = 0;
-		let mut current_char_pos = 0;
-		let mut current_byte_pos = 0;
+		let mut tks = Vec::new();
+		if t.contains(&SqlTokenizer::Unicode) {
+			// The words are segmented first, the other tokenizers are then applied on each word
+			let others: Vec<SqlTokenizer> =
+				t.iter().filter(|t| !matches!(t, SqlTokenizer::Unicode)).cloned().collect();
+			let mut char_pos = 0;
+			let mut byte_pos = 0;
+			for (byte_idx, word) in i.unicode_word_indices() {
+				char_pos += i[byte_pos..byte_idx].chars().count() as Position;
+				byte_pos = byte_idx;
+				let word_chars = word.chars().count() as Position;
+				if others.is_empty() {
+					tks.push(Token::Ref {
+						chars: (char_pos, char_pos, char_pos + word_chars),
+						bytes: (byte_pos as Position, (byte_pos + word.len()) as Position),
+						len: word_chars,
+					});
+				} else {
+					Tokenizer::new(&others).split(word, char_pos, byte_pos as Position, &mut tks);
+				}
+				char_pos += word_chars;
+				byte_pos += word.len();
+			}
+		} else {
+			Tokenizer::new(t).split(&i, 0, 0, &mut tks);
+		}
+		Tokens {
+			i,
+			t: tks,
+		}
+	}
+
+	/// Splits the text, starting at the given char and byte positions of the input.
+	fn split(&mut self, i: &str, char_pos: Position, byte_pos: Position, t: &mut Vec<Token>) {
+		let mut last_char_pos = char_pos;
+		let mut last_byte_pos = byte_pos;
+		let mut current_char_pos = char_pos;
+		let mut current_byte_pos = byte_pos;
 		let mut previous_character_role = CharacterRole::PartOfCurrentToken;
-		let mut t = Vec::new();
 		for c in i.chars() {
 			let char_len = c.len_utf8() as Position;
-			let cr = w.character_role(c);
+			let cr = self.character_role(c);
 			// if the new character is not part of the current token,
 			if !matches!(cr, CharacterRole::PartOfCurrentToken)
 				|| matches!(previous_character_role, CharacterRole::IsolatedToken)
@@ -259,10 +292,6 @@ impl Tokenizer {
 				len: current_char_pos - last_char_pos,
 			});
 		}
-		Tokens {
-			i,
-			t,
-		}
 	}
 }
 
@@ -334,6 +363,8 @@ impl Splitter {
 			SqlTokenizer::Camel => self.camel_role(cl),
 			SqlTokenizer::Class => self.class_role(cl),
 			SqlTokenizer::Punct => self.punct_role(cl),
+			// The words are segmented before the splitters are applied
+			SqlTokenizer::Unicode => CharacterRole::PartOfCurrentToken,
 		}
 	}
 
@@ -470,4 +501,31 @@ static LANGUAGE: &str = "Rust";"#,
 		)
 		.await;
 	}
+
+	#[tokio::test]
+	async fn test_tokenize_unicode() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS unicode",
+			"Don't panic, it's 3.14 and the e-mail is here!",
+			&["Don't", "panic", "it's", "3.14", "and", "the", "e", "mail", "is", "here"],
+		)
+		.await;
+		// Ideographs and kana are tokenized as unigrams
+		test_analyzer(
+			"ANALYZER test TOKENIZERS unicode",
+			"東京都に住む",
+			&["東", "京", "都", "に", "住", "む"],
+		)
+		.await;
+	}
+
+	#[tokio::test]
+	async fn test_tokenize_unicode_camel() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS unicode,camel",
+			"helloWorld, SurrealDB 2.0",
+			&["hello", "World", "Surreal", "DB", "2.0"],
+		)
+		.await;
+	}
 }
@@ -0,0 +1,133 @@
+use fst::Automaton;
+
+/// The maximum edit distance supported by fuzzy matching.
+pub(crate) const MAX_FUZZINESS: u8 = 2;
+
+/// An automaton accepting the terms within a given Levenshtein distance of a term.
+/// The distance is computed on characters, the bytes of multi-byte characters are buffered
+/// until the character is complete.
+pub(in crate::idx) struct Levenshtein {
+	term: Vec<char>,
+	distance: u32,
+}
+
+#[derive(Clone)]
+pub(in crate::idx) struct LevenshteinState {
+	/// The edit distances between the consumed input and each prefix of the term
+	row: Vec<u32>,
+	/// The bytes of an incomplete UTF-8 character
+	pending: Vec<u8>,
+}
+
+impl Levenshtein {
+	pub(in crate::idx) fn new(term: &str, distance: u8) -> Self {
+		Self {
+			term: term.chars().collect(),
+			distance: distance as u32,
+		}
+	}
+
+	/// Checks if the given text is within the distance of the term.
+	pub(super) fn is_within(&self, text: &str) -> bool {
+		let mut state = self.start();
+		for b in text.as_bytes() {
+			if !self.can_match(&state) {
+				return false;
+			}
+			state = self.accept(&state, *b);
+		}
+		self.is_match(&state)
+	}
+
+	fn next_row(&self, row: &[u32], c: char) -> Vec<u32> {
+		let mut next = Vec::with_capacity(row.len());
+		next.push(row[0] + 1);
+		for (i, tc) in self.term.iter().enumerate() {
+			let substitution = row[i] + u32::from(*tc != c);
+			let insertion = row[i + 1] + 1;
+			let deletion = next[i] + 1;
+			next.push(substitution.min(insertion).min(deletion));
+		}
+		next
+	}
+}
+
+impl Automaton for Levenshtein {
+	/// `None` is the dead state
+	type State = Option<LevenshteinState>;
+
+	fn start(&self) -> Self::State {
+		Some(LevenshteinState {
+			row: (0..=self.term.len() as u32).collect(),
+			pending: vec![],
+		})
+	}
+
+	fn is_match(&self, state: &Self::State) -> bool {
+		state.as_ref().is_some_and(|s| {
+			s.pending.is_empty() && s.row.last().is_some_and(|d| *d <= self.distance)
+		})
+	}
+
+	fn can_match(&self, state: &Self::State) -> bool {
+		state.as_ref().is_some_and(|s| s.row.iter().min().is_some_and(|d| *d <= self.distance))
+	}
+
+	fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+		let state = state.as_ref()?;
+		let mut pending = state.pending.clone();
+		pending.push(byte);
+		let c = match std::str::from_utf8(&pending) {
+			Ok(s) => s.chars().next()?,
+			// The character is not complete yet
+			Err(e) if e.error_len().is_none() => {
+				return Some(LevenshteinState {
+					row: state.row.clone(),
+					pending,
+				});
+			}
+			Err(_) => return None,
+		};
+		Some(LevenshteinState {
+			row: self.next_row(&state.row, c),
+			pending: vec![],
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::idx::ft::fuzzy::Levenshtein;
+
+	#[test]
+	fn test_levenshtein() {
+		let l = Levenshtein::new("hello", 1);
+		for t in ["hello", "hallo", "hell", "helloo", "ello", "jello"] {
+			assert!(l.is_within(t), "{t}");
+		}
+		for t in ["", "help", "world", "hellooo", "olleh"] {
+			assert!(!l.is_within(t), "{t}");
+		}
+		let l = Levenshtein::new("hello", 2);
+		assert!(l.is_within("help"));
+		assert!(l.is_within("hellooo"));
+		assert!(!l.is_within("world"));
+		let l = Levenshtein::new("hello", 0);
+		assert!(l.is_within("hello"));
+		assert!(!l.is_within("hallo"));
+	}
+
+	#[test]
+	fn test_levenshtein_multi_bytes() {
+		// 'é' is one character encoded on two bytes
+		let l = Levenshtein::new("café", 1);
+		assert!(l.is_within("cafe"));
+		assert!(l.is_within("cafés"));
+		assert!(l.is_within("caf"));
+		assert!(!l.is_within("cofe"));
+		let l = Levenshtein::new("東京都", 1);
+		assert!(l.is_within("東京"));
+		assert!(l.is_within("東京府"));
+		assert!(!l.is_within("大阪"));
+	}
+}
@@ -1,8 +1,10 @@
 pub(crate) mod analyzer;
 mod doclength;
+pub(crate) mod fuzzy;
 pub(crate) mod highlighter;
 mod offsets;
 mod postings;
+pub(super) mod query;
 pub(super) mod scorer;
 pub(super) mod termdocs;
 pub(crate) mod terms;
@@ -11,12 +13,13 @@ use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::err::Error;
 use crate::idx::docids::{DocId, DocIds};
-use crate::idx::ft::analyzer::{Analyzer, TermsList, TermsSet};
+use crate::idx::ft::analyzer::{Analyzer, TermsList};
 use crate::idx::ft::doclength::DocLengths;
 use crate::idx::ft::highlighter::{HighlightParams, Highlighter, Offseter};
 use crate::idx::ft::offsets::Offsets;
 use crate::idx::ft::postings::Postings;
-use crate::idx::ft::scorer::BM25Scorer;
+use crate::idx::ft::query::{FtQuery, QueryHits};
+use crate::idx::ft::scorer::{FtScorer, ScoringModel};
 use crate::idx::ft::termdocs::{TermDocs, TermsDocs};
 use crate::idx::ft::terms::{TermId, TermLen, Terms};
 use crate::idx::trees::btree::BStatistics;
@@ -33,7 +36,7 @@ use revision::revisioned;
 use roaring::treemap::IntoIter;
 use roaring::RoaringTreemap;
 use serde::{Deserialize, Serialize};
-use std::ops::BitAnd;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -44,7 +47,9 @@ pub(crate) struct FtIndex {
 	state_key: Key,
 	index_key_base: IndexKeyBase,
 	state: State,
-	bm25: Option<Bm25Params>,
+	scoring: Option<ScoringModel>,
+	/// The weight of each field (BM25F)
+	weights: Option<Vec<u32>>,
 	highlighting: bool,
 	doc_ids: Arc<RwLock<DocIds>>,
 	doc_lengths: Arc<RwLock<DocLengths>>,
@@ -148,23 +153,38 @@ impl FtIndex {
 		));
 		let term_docs = TermDocs::new(index_key_base.clone());
 		let offsets = Offsets::new(index_key_base.clone());
-		let mut bm25 = None;
-		if let Scoring::Bm {
-			k1,
-			b,
-		} = p.sc
-		{
-			bm25 = Some(Bm25Params {
+		let (scoring, weights) = match &p.sc {
+			Scoring::Bm {
 				k1,
 				b,
-			});
-		}
+			} => (
+				Some(ScoringModel::Bm25(Bm25Params {
+					k1: *k1,
+					b: *b,
+				})),
+				None,
+			),
+			Scoring::Bm25f {
+				k1,
+				b,
+				weights,
+			} => (
+				Some(ScoringModel::Bm25(Bm25Params {
+					k1: *k1,
+					b: *b,
+				})),
+				Some(weights.clone()),
+			),
+			Scoring::Tfidf => (Some(ScoringModel::Tfidf), None),
+			_ => (None, None),
+		};
 		let analyzer = Analyzer::new(ixs, az)?;
 		Ok(Self {
 			state,
 			state_key,
 			index_key_base,
-			bm25,
+			scoring,
+			weights,
 			highlighting: p.hl,
 			analyzer,
 			doc_ids,
@@ -180,10 +200,6 @@ impl FtIndex {
 		self.doc_ids.clone()
 	}
 
-	pub(super) fn terms(&self) -> Arc<RwLock<Terms>> {
-		self.terms.clone()
-	}
-
 	pub(super) fn analyzer(&self) -> Analyzer {
 		self.analyzer.clone()
 	}
@@ -260,13 +276,27 @@ impl FtIndex {
 		let (doc_length, terms_and_frequencies, offsets) = if self.highlighting {
 			let (dl, tf, ofs) = self
 				.analyzer
-				.extract_terms_with_frequencies_with_offsets(stk, ctx, opt, &mut t, content)
+				.extract_terms_with_frequencies_with_offsets(
+					stk,
+					ctx,
+					opt,
+					&mut t,
+					content,
+					self.weights.as_deref(),
+				)
 				.await?;
 			(dl, tf, Some(ofs))
 		} else {
 			let (dl, tf) = self
 				.analyzer
-				.extract_terms_with_frequencies(stk, ctx, opt, &mut t, content)
+				.extract_terms_with_frequencies(
+					stk,
+					ctx,
+					opt,
+					&mut t,
+					content,
+					self.weights.as_deref(),
+				)
 				.await?;
 			(dl, tf, None)
 		};
@@ -350,19 +380,34 @@ impl FtIndex {
 		Ok(())
 	}
 
-	pub(super) async fn extract_querying_terms(
+	pub(super) async fn parse_query(
 		&self,
 		stk: &mut Stk,
 		ctx: &Context,
 		opt: &Options,
 		query_string: String,
-	) -> Result<(TermsList, TermsSet), Error> {
+		fuzziness: u8,
+	) -> Result<FtQuery, Error> {
 		let t = self.terms.read().await;
-		let res = self.analyzer.extract_querying_terms(stk, ctx, opt, &t, query_string).await?;
+		let res = self.analyzer.extract_query(stk, ctx, opt, &t, query_string, fuzziness).await?;
 		drop(t);
 		Ok(res)
 	}
 
+	/// Collects the documents containing the terms of the query, and evaluates the query.
+	pub(super) async fn get_query_hits(
+		&self,
+		tx: &Transaction,
+		query: &FtQuery,
+	) -> Result<QueryHits, Error> {
+		let mut docs = HashMap::new();
+		for term_id in query.term_ids() {
+			let d = self.term_docs.get_docs(tx, term_id).await?;
+			docs.insert(term_id, d.unwrap_or_default());
+		}
+		Ok(query.hits(&docs))
+	}
+
 	pub(super) async fn get_terms_docs(
 		&self,
 		tx: &Transaction,
@@ -384,39 +429,27 @@ impl FtIndex {
 		Ok(terms_docs)
 	}
 
-	pub(super) fn new_hits_iterator(
-		&self,
-		terms_docs: TermsDocs,
-	) -> Result<Option<HitsIterator>, Error> {
-		let mut hits: Option<RoaringTreemap> = None;
-		for opt_term_docs in terms_docs.iter() {
-			if let Some((_, term_docs)) = opt_term_docs {
-				if let Some(h) = hits {
-					hits = Some(h.bitand(term_docs));
-				} else {
-					hits = Some(term_docs.clone());
-				}
-			} else {
-				return Ok(None);
-			}
-		}
-		if let Some(hits) = hits {
-			if !hits.is_empty() {
-				return Ok(Some(HitsIterator::new(self.doc_ids.clone(), hits)));
-			}
+	pub(super) fn new_hits_iterator(&self, hits: RoaringTreemap) -> Option<HitsIterator> {
+		if hits.is_empty() {
+			return None;
 		}
-		Ok(None)
+		Some(HitsIterator::new(self.doc_ids.clone(), hits))
 	}
 
-	pub(super) fn new_scorer(&self, terms_docs: TermsDocs) -> Result<Option<BM25Scorer>, Error> {
-		if let Some(bm25) = &self.bm25 {
-			return Ok(Some(BM25Scorer::new(
+	pub(super) fn new_scorer(
+		&self,
+		terms_docs: TermsDocs,
+		boosts: HashMap<TermId, f32>,
+	) -> Result<Option<FtScorer>, Error> {
+		if let Some(model) = &self.scoring {
+			return Ok(Some(FtScorer::new(
 				self.postings.clone(),
 				terms_docs,
+				boosts,
 				self.doc_lengths.clone(),
 				self.state.total_docs_lengths,
 				self.state.doc_count,
-				bm25.clone(),
+				model.clone(),
 			)));
 		}
 		Ok(None)
@@ -532,7 +565,7 @@ impl HitsIterator {
 mod tests {
 	use crate::ctx::{Context, MutableContext};
 	use crate::dbs::Options;
-	use crate::idx::ft::scorer::{BM25Scorer, Score};
+	use crate::idx::ft::scorer::{FtScorer, Score};
 	use crate::idx::ft::{FtIndex, HitsIterator};
 	use crate::idx::IndexKeyBase;
 	use crate::kvs::{Datastore, LockType::*, TransactionType};
@@ -548,7 +581,7 @@ mod tests {
 	async fn check_hits(
 		ctx: &Context,
 		hits: Option<HitsIterator>,
-		scr: BM25Scorer,
+		scr: FtScorer,
 		e: Vec<(&Thing, Option<Score>)>,
 	) {
 		let tx = ctx.tx();
@@ -573,14 +606,13 @@ mod tests {
 		opt: &Options,
 		fti: &FtIndex,
 		qs: &str,
-	) -> (Option<HitsIterator>, BM25Scorer) {
-		let (term_list, _) =
-			fti.extract_querying_terms(stk, ctx, opt, qs.to_string()).await.unwrap();
+	) -> (Option<HitsIterator>, FtScorer) {
+		let query = fti.parse_query(stk, ctx, opt, qs.to_string(), 0).await.unwrap();
 		let tx = ctx.tx();
-		let td = Arc::new(fti.get_terms_docs(&tx, &term_list).await.unwrap());
-		let scr = fti.new_scorer(td.clone()).unwrap().unwrap();
-		let hits = fti.new_hits_iterator(td).unwrap();
-		(hits, scr)
+		let td = Arc::new(fti.get_terms_docs(&tx, &query.terms_list()).await.unwrap());
+		let scr = fti.new_scorer(td, query.boosts()).unwrap().unwrap();
+		let hits = fti.get_query_hits(&tx, &query).await.unwrap();
+		(fti.new_hits_iterator(hits.docs), scr)
 	}
 
 	pub(super) async fn tx_fti(
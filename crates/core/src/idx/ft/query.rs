@@ -0,0 +1,780 @@
+use crate::err::Error;
+use crate::idx::ft::analyzer::TermsList;
+use crate::idx::ft::fuzzy::Levenshtein;
+use crate::idx::ft::terms::{TermId, TermLen};
+use roaring::RoaringTreemap;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+/// The syntax tree of a full-text query string, before the analysis of its terms.
+///
+/// - Terms separated by spaces must all match (implicit `AND`).
+/// - `"an exact phrase"` matches consecutive terms.
+/// - `a NEAR/3 b` matches when at most 3 terms separate `a` and `b`.
+/// - `AND`, `OR` and `NOT` combine expressions, and parentheses group them.
+/// - `term*` matches any term starting with `term`.
+/// - `term^2` multiplies the score of a term, a phrase, a prefix or a group by 2.
+///
+/// A query string which is not a valid expression (e.g. `fox)` or `fox OR`)
+/// is not rejected, its terms are simply all required to match.
+#[derive(Debug, PartialEq)]
+pub(super) enum QueryNode {
+	Words(String),
+	Phrase(String),
+	Prefix(String),
+	Near(Box<QueryNode>, Box<QueryNode>, u32),
+	And(Vec<QueryNode>),
+	Or(Vec<QueryNode>),
+	Not(Box<QueryNode>),
+	Boost(Box<QueryNode>, f32),
+}
+
+#[derive(Debug, PartialEq)]
+enum Lexeme {
+	Word(String),
+	Phrase(String),
+	Prefix(String),
+	And,
+	Or,
+	Not,
+	Near(u32),
+	Boost(f32),
+	Open,
+	Close,
+}
+
+impl Display for Lexeme {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Word(w) => write!(f, "'{w}'"),
+			Self::Phrase(p) => write!(f, "'\"{p}\"'"),
+			Self::Prefix(p) => write!(f, "'{p}*'"),
+			Self::And => f.write_str("AND"),
+			Self::Or => f.write_str("OR"),
+			Self::Not => f.write_str("NOT"),
+			Self::Near(n) => write!(f, "NEAR/{n}"),
+			Self::Boost(b) => write!(f, "'^{b}'"),
+			Self::Open => f.write_str("'('"),
+			Self::Close => f.write_str("')'"),
+		}
+	}
+}
+
+type Lexemes = Peekable<IntoIter<Lexeme>>;
+
+impl QueryNode {
+	pub(super) fn parse(qs: &str) -> Self {
+		Self::try_parse(qs).unwrap_or_else(|_| Self::Words(qs.to_owned()))
+	}
+
+	fn try_parse(qs: &str) -> Result<Self, Error> {
+		let mut lexemes = Self::lex(qs).into_iter().peekable();
+		// An empty query is a valid query, it just does not match anything
+		if lexemes.peek().is_none() {
+			return Ok(Self::Words(String::new()));
+		}
+		let node = Self::parse_or(&mut lexemes)?;
+		if let Some(l) = lexemes.next() {
+			return Err(Error::FtQueryError(format!("Unexpected {l}")));
+		}
+		Ok(node)
+	}
+
+	fn lex(qs: &str) -> Vec<Lexeme> {
+		let mut res = Vec::new();
+		let mut chars = qs.chars().peekable();
+		while let Some(c) = chars.next() {
+			match c {
+				c if c.is_whitespace() => {}
+				'(' => res.push(Lexeme::Open),
+				')' => res.push(Lexeme::Close),
+				'"' => res.push(Lexeme::Phrase(chars.by_ref().take_while(|c| *c != '"').collect())),
+				c => {
+					let mut word = String::from(c);
+					while let Some(&c) = chars.peek() {
+						if c.is_whitespace() || matches!(c, '(' | ')' | '"') {
+							break;
+						}
+						word.push(c);
+						chars.next();
+					}
+					// A boost is a suffix of a word, a phrase or a group
+					let boost = word
+						.rsplit_once('^')
+						.and_then(|(w, b)| Some((w.len(), b.parse::<f32>().ok()?)))
+						.filter(|(_, b)| b.is_finite() && *b > 0.0);
+					if let Some((len, b)) = boost {
+						word.truncate(len);
+						if !word.is_empty() {
+							res.push(Self::word_lexeme(word));
+						}
+						res.push(Lexeme::Boost(b));
+					} else {
+						res.push(Self::word_lexeme(word));
+					}
+				}
+			}
+		}
+		res
+	}
+
+	fn word_lexeme(word: String) -> Lexeme {
+		match word.as_str() {
+			"AND" => Lexeme::And,
+			"OR" => Lexeme::Or,
+			"NOT" => Lexeme::Not,
+			w => {
+				if let Some(n) = w.strip_prefix("NEAR/").and_then(|n| n.parse().ok()) {
+					Lexeme::Near(n)
+				} else if w.len() > 1 && w.ends_with('*') {
+					Lexeme::Prefix(w[..w.len() - 1].to_string())
+				} else {
+					Lexeme::Word(word)
+				}
+			}
+		}
+	}
+
+	fn parse_or(lexemes: &mut Lexemes) -> Result<Self, Error> {
+		let mut nodes = vec![Self::parse_and(lexemes)?];
+		while lexemes.next_if_eq(&Lexeme::Or).is_some() {
+			nodes.push(Self::parse_and(lexemes)?);
+		}
+		Ok(if nodes.len() == 1 {
+			nodes.remove(0)
+		} else {
+			Self::Or(nodes)
+		})
+	}
+
+	fn parse_and(lexemes: &mut Lexemes) -> Result<Self, Error> {
+		let mut nodes: Vec<Self> = Vec::new();
+		loop {
+			let node = match lexemes.peek() {
+				Some(Lexeme::And) if !nodes.is_empty() => {
+					lexemes.next();
+					Self::parse_not(lexemes)?
+				}
+				Some(
+					Lexeme::Word(_)
+					| Lexeme::Phrase(_)
+					| Lexeme::Prefix(_)
+					| Lexeme::Not
+					| Lexeme::Open,
+				) => Self::parse_not(lexemes)?,
+				_ => break,
+			};
+			// Consecutive words are analyzed together, as a single text
+			if let Self::Words(w) = &node {
+				if let Some(Self::Words(prev)) = nodes.last_mut() {
+					prev.push(' ');
+					prev.push_str(w);
+					continue;
+				}
+			}
+			nodes.push(node);
+		}
+		match nodes.len() {
+			0 => Err(Self::unexpected(lexemes.next())),
+			1 => Ok(nodes.remove(0)),
+			_ => Ok(Self::And(nodes)),
+		}
+	}
+
+	fn parse_not(lexemes: &mut Lexemes) -> Result<Self, Error> {
+		if lexemes.next_if_eq(&Lexeme::Not).is_some() {
+			return Ok(Self::Not(Box::new(Self::parse_not(lexemes)?)));
+		}
+		Self::parse_near(lexemes)
+	}
+
+	fn parse_near(lexemes: &mut Lexemes) -> Result<Self, Error> {
+		let mut node = Self::parse_boost(lexemes)?;
+		while let Some(Lexeme::Near(n)) = lexemes.peek() {
+			let n = *n;
+			lexemes.next();
+			let right = Self::parse_boost(lexemes)?;
+			if !node.is_near_operand() || !right.is_near_operand() {
+				return Err(Error::FtQueryError(format!(
+					"NEAR/{n} expects a term, a prefix or a phrase on each side"
+				)));
+			}
+			node = Self::Near(Box::new(node), Box::new(right), n);
+		}
+		Ok(node)
+	}
+
+	fn parse_boost(lexemes: &mut Lexemes) -> Result<Self, Error> {
+		let node = Self::parse_primary(lexemes)?;
+		if let Some(Lexeme::Boost(b)) = lexemes.peek() {
+			let b = *b;
+			lexemes.next();
+			return Ok(Self::Boost(Box::new(node), b));
+		}
+		Ok(node)
+	}
+
+	fn parse_primary(lexemes: &mut Lexemes) -> Result<Self, Error> {
+		match lexemes.next() {
+			Some(Lexeme::Word(w)) => Ok(Self::Words(w)),
+			Some(Lexeme::Phrase(p)) => Ok(Self::Phrase(p)),
+			Some(Lexeme::Prefix(p)) => Ok(Self::Prefix(p)),
+			Some(Lexeme::Open) => {
+				let node = Self::parse_or(lexemes)?;
+				if lexemes.next_if_eq(&Lexeme::Close).is_none() {
+					return Err(Error::FtQueryError("Missing closing parenthesis".to_string()));
+				}
+				Ok(node)
+			}
+			l => Err(Self::unexpected(l)),
+		}
+	}
+
+	fn unexpected(l: Option<Lexeme>) -> Error {
+		match l {
+			Some(l) => Error::FtQueryError(format!("Unexpected {l}")),
+			None => Error::FtQueryError("Unexpected end of query".to_string()),
+		}
+	}
+
+	fn is_near_operand(&self) -> bool {
+		matches!(self, Self::Words(_) | Self::Phrase(_) | Self::Prefix(_))
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(in crate::idx) struct QueryTerm {
+	pub(super) term: String,
+	/// The id of the term, if the term is known by the index
+	pub(super) id: Option<(TermId, TermLen)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(in crate::idx) struct FuzzyTerm {
+	pub(super) term: String,
+	/// The terms of the index within the edit distance
+	pub(super) ids: Vec<(TermId, TermLen)>,
+}
+
+/// A full-text query with its analyzed terms
+#[derive(Clone, Debug, PartialEq)]
+pub(in crate::idx) enum FtQuery {
+	/// Every term must match
+	Terms(Vec<QueryTerm>),
+	/// The terms must be consecutive
+	Phrase(Vec<QueryTerm>),
+	/// Every term must match, allowing the given number of edits
+	Fuzzy(Vec<FuzzyTerm>, u8),
+	/// Any term starting with the prefix, with the terms of the index starting with it
+	Prefix(String, Vec<(TermId, TermLen)>),
+	/// Both sides are separated by at most N terms
+	Near(Box<FtQuery>, Box<FtQuery>, u32),
+	And(Vec<FtQuery>),
+	Or(Vec<FtQuery>),
+	/// Excludes the documents matching the query.
+	/// It only applies within an `AND`, as a negation alone does not match anything.
+	Not(Box<FtQuery>),
+	/// Multiplies the score of the terms of the query
+	Boost(Box<FtQuery>, f32),
+}
+
+/// The documents matching a query.
+pub(in crate::idx) struct QueryHits {
+	pub(in crate::idx) docs: RoaringTreemap,
+	/// If false, the documents are candidates that still need to be checked
+	/// against their content (phrases and proximity).
+	pub(in crate::idx) exact: bool,
+}
+
+/// The positions of the terms of a document.
+/// A position is the index of the value (when the field contains several values)
+/// and the position of the term within this value.
+#[derive(Default)]
+pub(in crate::idx) struct DocTokens(HashMap<String, Vec<(u32, u32)>>);
+
+impl DocTokens {
+	pub(super) fn push(&mut self, term: &str, idx: u32, pos: u32) {
+		if let Some(positions) = self.0.get_mut(term) {
+			positions.push((idx, pos));
+		} else {
+			self.0.insert(term.to_string(), vec![(idx, pos)]);
+		}
+	}
+
+	fn positions(&self, term: &str) -> &[(u32, u32)] {
+		self.0.get(term).map(|p| p.as_slice()).unwrap_or_default()
+	}
+
+	fn fuzzy_positions(&self, term: &str, distance: u8) -> Vec<(u32, u32)> {
+		let aut = Levenshtein::new(term, distance);
+		let mut res: Vec<(u32, u32)> = self
+			.0
+			.iter()
+			.filter(|(t, _)| aut.is_within(t))
+			.flat_map(|(_, p)| p.iter().copied())
+			.collect();
+		res.sort_unstable();
+		res
+	}
+
+	fn prefix_positions(&self, prefix: &str) -> Vec<(u32, u32)> {
+		let mut res: Vec<(u32, u32)> = self
+			.0
+			.iter()
+			.filter(|(t, _)| t.starts_with(prefix))
+			.flat_map(|(_, p)| p.iter().copied())
+			.collect();
+		res.sort_unstable();
+		res
+	}
+}
+
+impl FtQuery {
+	/// The terms of the query that are contributing to the match, used for scoring and highlighting.
+	pub(in crate::idx) fn terms_list(&self) -> TermsList {
+		let mut set = HashSet::new();
+		let mut list = Vec::new();
+		self.collect_terms(false, &mut |id| {
+			if set.insert(id.0) {
+				list.push(Some(id));
+			}
+		});
+		list
+	}
+
+	/// The boost of the terms of the query, when a term is boosted several times the highest boost is kept.
+	pub(in crate::idx) fn boosts(&self) -> HashMap<TermId, f32> {
+		let mut boosts = HashMap::new();
+		self.collect_boosts(1.0, &mut boosts);
+		boosts
+	}
+
+	fn collect_boosts(&self, boost: f32, boosts: &mut HashMap<TermId, f32>) {
+		match self {
+			Self::Boost(n, b) => n.collect_boosts(boost * b, boosts),
+			Self::Near(a, b, _) => {
+				a.collect_boosts(boost, boosts);
+				b.collect_boosts(boost, boosts);
+			}
+			Self::And(nodes) | Self::Or(nodes) => {
+				nodes.iter().for_each(|n| n.collect_boosts(boost, boosts))
+			}
+			Self::Not(_) => {}
+			_ => self.collect_terms(false, &mut |(id, _)| {
+				let b = boosts.entry(id).or_insert(boost);
+				*b = b.max(boost);
+			}),
+		}
+	}
+
+	/// Every term id referenced by the query, including the excluded ones.
+	pub(in crate::idx) fn term_ids(&self) -> HashSet<TermId> {
+		let mut ids = HashSet::new();
+		self.collect_terms(true, &mut |(id, _)| {
+			ids.insert(id);
+		});
+		ids
+	}
+
+	fn collect_terms<F: FnMut((TermId, TermLen))>(&self, excluded: bool, f: &mut F) {
+		match self {
+			Self::Terms(terms) | Self::Phrase(terms) => {
+				terms.iter().filter_map(|t| t.id).for_each(f);
+			}
+			Self::Fuzzy(terms, _) => terms.iter().flat_map(|t| t.ids.iter().copied()).for_each(f),
+			Self::Prefix(_, ids) => ids.iter().copied().for_each(f),
+			Self::Near(a, b, _) => {
+				a.collect_terms(excluded, f);
+				b.collect_terms(excluded, f);
+			}
+			Self::And(nodes) | Self::Or(nodes) => {
+				nodes.iter().for_each(|n| n.collect_terms(excluded, f))
+			}
+			Self::Not(n) => {
+				if excluded {
+					n.collect_terms(excluded, f);
+				}
+			}
+			Self::Boost(n, _) => n.collect_terms(excluded, f),
+		}
+	}
+
+	/// Computes the documents matching the query, given the documents of each term.
+	pub(in crate::idx) fn hits(&self, docs: &HashMap<TermId, RoaringTreemap>) -> QueryHits {
+		let (docs, exact) = self.compute_hits(docs);
+		QueryHits {
+			docs,
+			exact,
+		}
+	}
+
+	fn compute_hits(&self, docs: &HashMap<TermId, RoaringTreemap>) -> (RoaringTreemap, bool) {
+		match self {
+			Self::Terms(terms) | Self::Phrase(terms) => {
+				let mut hits: Option<RoaringTreemap> = None;
+				for t in terms {
+					let d = t.id.and_then(|(id, _)| docs.get(&id));
+					let Some(d) = d else {
+						// A term unknown by the index can't match
+						return (RoaringTreemap::new(), true);
+					};
+					hits = Some(match hits {
+						Some(h) => h & d,
+						None => d.clone(),
+					});
+				}
+				// The order of the terms of a phrase has to be checked on the content
+				let exact = matches!(self, Self::Terms(_)) || terms.len() < 2;
+				(hits.unwrap_or_default(), exact)
+			}
+			Self::Fuzzy(terms, _) => {
+				let mut hits: Option<RoaringTreemap> = None;
+				for t in terms {
+					let d = Self::union_hits(&t.ids, docs);
+					hits = Some(match hits {
+						Some(h) => h & d,
+						None => d,
+					});
+				}
+				(hits.unwrap_or_default(), true)
+			}
+			Self::Prefix(_, ids) => (Self::union_hits(ids, docs), true),
+			Self::Near(a, b, _) => {
+				let (a, _) = a.compute_hits(docs);
+				let (b, _) = b.compute_hits(docs);
+				(a & b, false)
+			}
+			Self::And(nodes) => {
+				let mut hits: Option<RoaringTreemap> = None;
+				let mut exact = true;
+				for n in nodes.iter().filter(|n| !matches!(n, Self::Not(_))) {
+					let (d, e) = n.compute_hits(docs);
+					exact &= e;
+					hits = Some(match hits {
+						Some(h) => h & d,
+						None => d,
+					});
+				}
+				let Some(mut hits) = hits else {
+					return (RoaringTreemap::new(), true);
+				};
+				for n in nodes {
+					if let Self::Not(n) = n {
+						let (d, e) = n.compute_hits(docs);
+						// Excluding inexact candidates would exclude valid documents
+						if e {
+							hits -= d;
+						} else {
+							exact = false;
+						}
+					}
+				}
+				(hits, exact)
+			}
+			Self::Or(nodes) => {
+				let mut hits = RoaringTreemap::new();
+				let mut exact = true;
+				for n in nodes.iter().filter(|n| !matches!(n, Self::Not(_))) {
+					let (d, e) = n.compute_hits(docs);
+					exact &= e;
+					hits |= d;
+				}
+				(hits, exact)
+			}
+			Self::Not(_) => (RoaringTreemap::new(), true),
+			Self::Boost(n, _) => n.compute_hits(docs),
+		}
+	}
+
+	/// The documents containing any of the terms.
+	fn union_hits(
+		ids: &[(TermId, TermLen)],
+		docs: &HashMap<TermId, RoaringTreemap>,
+	) -> RoaringTreemap {
+		let mut hits = RoaringTreemap::new();
+		for d in ids.iter().filter_map(|(id, _)| docs.get(id)) {
+			hits |= d;
+		}
+		hits
+	}
+
+	/// Checks if the terms of a document are matching the query.
+	pub(in crate::idx) fn matches(&self, doc: &DocTokens) -> bool {
+		match self {
+			Self::Terms(terms) => {
+				!terms.is_empty() && terms.iter().all(|t| !doc.positions(&t.term).is_empty())
+			}
+			Self::Fuzzy(terms, distance) => {
+				!terms.is_empty()
+					&& terms.iter().all(|t| !doc.fuzzy_positions(&t.term, *distance).is_empty())
+			}
+			Self::Phrase(_) | Self::Prefix(_, _) => !self.spans(doc).is_empty(),
+			Self::Near(a, b, n) => {
+				let b = b.spans(doc);
+				a.spans(doc).iter().any(|a| b.iter().any(|b| Self::distance(a, b) <= *n))
+			}
+			Self::And(nodes) => {
+				let mut positive = false;
+				for n in nodes {
+					if let Self::Not(n) = n {
+						if n.matches(doc) {
+							return false;
+						}
+					} else if n.matches(doc) {
+						positive = true;
+					} else {
+						return false;
+					}
+				}
+				positive
+			}
+			Self::Or(nodes) => nodes.iter().any(|n| !matches!(n, Self::Not(_)) && n.matches(doc)),
+			Self::Not(_) => false,
+			Self::Boost(n, _) => n.matches(doc),
+		}
+	}
+
+	/// Returns the spans (value index, first position, last position) where a term,
+	/// a prefix or a phrase occurs in the document.
+	fn spans(&self, doc: &DocTokens) -> Vec<(u32, u32, u32)> {
+		match self {
+			Self::Terms(terms) | Self::Phrase(terms) => Self::consecutive_spans(
+				terms.iter().map(|t| doc.positions(&t.term).to_vec()).collect(),
+			),
+			Self::Fuzzy(terms, distance) => Self::consecutive_spans(
+				terms.iter().map(|t| doc.fuzzy_positions(&t.term, *distance)).collect(),
+			),
+			Self::Prefix(prefix, _) => {
+				doc.prefix_positions(prefix).into_iter().map(|(idx, pos)| (idx, pos, pos)).collect()
+			}
+			_ => vec![],
+		}
+	}
+
+	/// The spans where each term immediately follows the previous one.
+	/// The positions of each term must be sorted.
+	fn consecutive_spans(positions: Vec<Vec<(u32, u32)>>) -> Vec<(u32, u32, u32)> {
+		let Some(first) = positions.first() else {
+			return vec![];
+		};
+		let len = positions.len() as u32;
+		first
+			.iter()
+			.filter(|(idx, pos)| {
+				positions
+					.iter()
+					.enumerate()
+					.skip(1)
+					.all(|(i, p)| p.binary_search(&(*idx, pos + i as u32)).is_ok())
+			})
+			.map(|(idx, pos)| (*idx, *pos, pos + len - 1))
+			.collect()
+	}
+
+	/// The number of terms between two spans
+	fn distance(a: &(u32, u32, u32), b: &(u32, u32, u32)) -> u32 {
+		if a.0 != b.0 {
+			return u32::MAX;
+		}
+		if a.2 < b.1 {
+			b.1 - a.2 - 1
+		} else if b.2 < a.1 {
+			a.1 - b.2 - 1
+		} else {
+			0
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::idx::ft::query::{DocTokens, FtQuery, FuzzyTerm, QueryNode, QueryTerm};
+	use roaring::RoaringTreemap;
+	use std::collections::HashMap;
+
+	fn words(w: &str) -> QueryNode {
+		QueryNode::Words(w.to_string())
+	}
+
+	#[test]
+	fn test_parse() {
+		assert_eq!(QueryNode::parse("hello world"), words("hello world"));
+		assert_eq!(QueryNode::parse(""), words(""));
+		assert_eq!(
+			QueryNode::parse("\"brown fox\" jumped"),
+			QueryNode::And(vec![QueryNode::Phrase("brown fox".to_string()), words("jumped")])
+		);
+		assert_eq!(
+			QueryNode::parse("quick fox OR lazy NOT dog"),
+			QueryNode::Or(vec![
+				words("quick fox"),
+				QueryNode::And(vec![words("lazy"), QueryNode::Not(Box::new(words("dog")))])
+			])
+		);
+		assert_eq!(
+			QueryNode::parse("(quick OR fast) AND fox*"),
+			QueryNode::And(vec![
+				QueryNode::Or(vec![words("quick"), words("fast")]),
+				QueryNode::Prefix("fox".to_string())
+			])
+		);
+		assert_eq!(
+			QueryNode::parse("fox NEAR/2 \"lazy dog\""),
+			QueryNode::Near(
+				Box::new(words("fox")),
+				Box::new(QueryNode::Phrase("lazy dog".to_string())),
+				2
+			)
+		);
+		assert_eq!(
+			QueryNode::parse("quick fox^2 \"lazy dog\"^1.5 (cat OR dog)^3"),
+			QueryNode::And(vec![
+				words("quick"),
+				QueryNode::Boost(Box::new(words("fox")), 2.0),
+				QueryNode::Boost(Box::new(QueryNode::Phrase("lazy dog".to_string())), 1.5),
+				QueryNode::Boost(Box::new(QueryNode::Or(vec![words("cat"), words("dog")])), 3.0),
+			])
+		);
+		assert_eq!(QueryNode::parse("e^x"), words("e^x"));
+		for (qs, err) in [
+			("(fox", "Missing closing parenthesis"),
+			("^2", "Unexpected '^2'"),
+			("fox)", "Unexpected ')'"),
+			("fox OR", "Unexpected end of query"),
+			("AND fox", "Unexpected AND"),
+			("(fox OR dog) NEAR/2 cat", "NEAR/2 expects a term, a prefix or a phrase on each side"),
+		] {
+			assert_eq!(
+				QueryNode::try_parse(qs).unwrap_err().to_string(),
+				format!("The full-text query is invalid: {err}"),
+				"{qs}"
+			);
+			// An invalid query falls back to matching all of its terms
+			assert_eq!(QueryNode::parse(qs), words(qs), "{qs}");
+		}
+	}
+
+	fn term(t: &str, id: u64) -> QueryTerm {
+		QueryTerm {
+			term: t.to_string(),
+			id: Some((id, t.len() as u32)),
+		}
+	}
+
+	fn doc(text: &str) -> DocTokens {
+		let mut doc = DocTokens::default();
+		for (pos, t) in text.split(' ').enumerate() {
+			doc.push(t, 0, pos as u32);
+		}
+		doc
+	}
+
+	#[test]
+	fn test_matches() {
+		let doc = doc("the quick brown fox jumped over the lazy dog");
+		let phrase = |a, b| FtQuery::Phrase(vec![term(a, 0), term(b, 1)]);
+		assert!(phrase("brown", "fox").matches(&doc));
+		assert!(!phrase("fox", "brown").matches(&doc));
+		let near = |n| {
+			FtQuery::Near(
+				Box::new(FtQuery::Terms(vec![term("quick", 0)])),
+				Box::new(phrase("lazy", "dog")),
+				n,
+			)
+		};
+		assert!(!near(4).matches(&doc));
+		assert!(near(5).matches(&doc));
+		let prefix = |p: &str| FtQuery::Prefix(p.to_string(), vec![]);
+		assert!(prefix("jum").matches(&doc));
+		assert!(!prefix("cat").matches(&doc));
+		let not = |q| FtQuery::Not(Box::new(q));
+		assert!(FtQuery::And(vec![prefix("jum"), not(prefix("cat"))]).matches(&doc));
+		assert!(!FtQuery::And(vec![prefix("jum"), not(prefix("do"))]).matches(&doc));
+		assert!(!FtQuery::And(vec![not(prefix("cat"))]).matches(&doc));
+		assert!(FtQuery::Or(vec![prefix("cat"), prefix("do")]).matches(&doc));
+		let fuzzy = |terms: &[&str], d| {
+			let terms = terms
+				.iter()
+				.map(|t| FuzzyTerm {
+					term: t.to_string(),
+					ids: vec![],
+				})
+				.collect();
+			FtQuery::Fuzzy(terms, d)
+		};
+		assert!(fuzzy(&["qick", "borwn"], 2).matches(&doc));
+		assert!(!fuzzy(&["qick", "borwn"], 1).matches(&doc));
+		assert!(!fuzzy(&["cat"], 1).matches(&doc));
+		let near =
+			FtQuery::Near(Box::new(fuzzy(&["brwn", "fix"], 1)), Box::new(fuzzy(&["jumpd"], 1)), 0);
+		assert!(near.matches(&doc));
+	}
+
+	#[test]
+	fn test_hits() {
+		let docs = HashMap::from([
+			(0, RoaringTreemap::from_iter([1, 2, 3])),
+			(1, RoaringTreemap::from_iter([2, 3, 4])),
+			(2, RoaringTreemap::from_iter([3])),
+		]);
+		let terms = FtQuery::Terms(vec![term("a", 0), term("b", 1)]);
+		let hits = terms.hits(&docs);
+		assert_eq!(hits.docs, RoaringTreemap::from_iter([2, 3]));
+		assert!(hits.exact);
+		let phrase = FtQuery::Phrase(vec![term("a", 0), term("b", 1)]);
+		let hits = phrase.hits(&docs);
+		assert_eq!(hits.docs, RoaringTreemap::from_iter([2, 3]));
+		assert!(!hits.exact);
+		let not =
+			FtQuery::And(vec![terms, FtQuery::Not(Box::new(FtQuery::Terms(vec![term("c", 2)])))]);
+		let hits = not.hits(&docs);
+		assert_eq!(hits.docs, RoaringTreemap::from_iter([2]));
+		assert!(hits.exact);
+		let not =
+			FtQuery::And(vec![FtQuery::Terms(vec![term("a", 0)]), FtQuery::Not(Box::new(phrase))]);
+		let hits = not.hits(&docs);
+		assert_eq!(hits.docs, RoaringTreemap::from_iter([1, 2, 3]));
+		assert!(!hits.exact);
+		let or = FtQuery::Or(vec![
+			FtQuery::Terms(vec![term("c", 2)]),
+			FtQuery::Prefix("x".to_string(), vec![(1, 2)]),
+		]);
+		let hits = or.hits(&docs);
+		assert_eq!(hits.docs, RoaringTreemap::from_iter([2, 3, 4]));
+		assert!(hits.exact);
+		let fuzzy = FtQuery::Fuzzy(
+			vec![
+				FuzzyTerm {
+					term: "a".to_string(),
+					ids: vec![(0, 1), (2, 1)],
+				},
+				FuzzyTerm {
+					term: "b".to_string(),
+					ids: vec![(1, 1)],
+				},
+			],
+			1,
+		);
+		let hits = fuzzy.hits(&docs);
+		assert_eq!(hits.docs, RoaringTreemap::from_iter([2, 3]));
+		assert!(hits.exact);
+		assert_eq!(fuzzy.terms_list(), vec![Some((0, 1)), Some((2, 1)), Some((1, 1))]);
+		let boost = FtQuery::Boost(Box::new(FtQuery::Terms(vec![term("a", 0)])), 2.0);
+		let hits = boost.hits(&docs);
+		assert_eq!(hits.docs, RoaringTreemap::from_iter([1, 2, 3]));
+	}
+
+	#[test]
+	fn test_boosts() {
+		let boost = |q, b| FtQuery::Boost(Box::new(q), b);
+		let query = FtQuery::And(vec![
+			FtQuery::Terms(vec![term("a", 0)]),
+			boost(FtQuery::Terms(vec![term("b", 1)]), 2.0),
+			boost(FtQuery::Or(vec![boost(FtQuery::Terms(vec![term("c", 2)]), 3.0)]), 2.0),
+			FtQuery::Not(Box::new(boost(FtQuery::Terms(vec![term("d", 3)]), 2.0))),
+		]);
+		assert_eq!(query.boosts(), HashMap::from([(0, 1.0), (1, 2.0), (2, 6.0)]));
+	}
+}
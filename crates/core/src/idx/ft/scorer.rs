@@ -3,38 +3,52 @@ use crate::idx::docids::DocId;
 use crate::idx::ft::doclength::{DocLength, DocLengths};
 use crate::idx::ft::postings::{Postings, TermFrequency};
 use crate::idx::ft::termdocs::TermsDocs;
+use crate::idx::ft::terms::TermId;
 use crate::idx::ft::Bm25Params;
 use crate::kvs::Transaction;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 pub(super) type Score = f32;
 
-pub(crate) struct BM25Scorer {
+/// The scoring model of a full-text index.
+#[derive(Clone)]
+pub(super) enum ScoringModel {
+	/// BM25, and BM25F as the field weights are applied to the frequencies when indexing
+	Bm25(Bm25Params),
+	Tfidf,
+}
+
+pub(crate) struct FtScorer {
 	postings: Arc<RwLock<Postings>>,
 	terms_docs: TermsDocs,
+	/// The boost of the terms of the query (1.0 if not present)
+	boosts: HashMap<TermId, f32>,
 	doc_lengths: Arc<RwLock<DocLengths>>,
 	average_doc_length: f32,
 	doc_count: f32,
-	bm25: Bm25Params,
+	model: ScoringModel,
 }
 
-impl BM25Scorer {
+impl FtScorer {
 	pub(super) fn new(
 		postings: Arc<RwLock<Postings>>,
 		terms_docs: TermsDocs,
+		boosts: HashMap<TermId, f32>,
 		doc_lengths: Arc<RwLock<DocLengths>>,
 		total_docs_length: u128,
 		doc_count: u64,
-		bm25: Bm25Params,
+		model: ScoringModel,
 	) -> Self {
 		Self {
 			postings,
 			terms_docs,
+			boosts,
 			doc_lengths,
 			average_doc_length: (total_docs_length as f32) / (doc_count as f32),
 			doc_count: doc_count as f32,
-			bm25,
+			model,
 		}
 	}
 
@@ -45,10 +59,22 @@ impl BM25Scorer {
 		term_doc_count: DocLength,
 		term_frequency: TermFrequency,
 	) -> Result<Score, Error> {
-		let dl = self.doc_lengths.read().await;
-		let doc_length = dl.get_doc_length(tx, doc_id).await?.unwrap_or(0);
-		drop(dl);
-		Ok(self.compute_bm25_score(term_frequency as f32, term_doc_count as f32, doc_length as f32))
+		match &self.model {
+			ScoringModel::Bm25(bm25) => {
+				let dl = self.doc_lengths.read().await;
+				let doc_length = dl.get_doc_length(tx, doc_id).await?.unwrap_or(0);
+				drop(dl);
+				Ok(self.compute_bm25_score(
+					bm25,
+					term_frequency as f32,
+					term_doc_count as f32,
+					doc_length as f32,
+				))
+			}
+			ScoringModel::Tfidf => {
+				Ok(self.compute_tfidf_score(term_frequency as f32, term_doc_count as f32))
+			}
+		}
 	}
 
 	pub(crate) async fn score(
@@ -62,7 +88,8 @@ impl BM25Scorer {
 			if docs.contains(doc_id) {
 				let tf = p.get_term_frequency(tx, *term_id, doc_id).await?;
 				if let Some(term_freq) = tf {
-					sc += self.term_score(tx, doc_id, docs.len(), term_freq).await?;
+					let boost = self.boosts.get(term_id).copied().unwrap_or(1.0);
+					sc += boost * self.term_score(tx, doc_id, docs.len(), term_freq).await?;
 				}
 			}
 		}
@@ -72,7 +99,13 @@ impl BM25Scorer {
 
 	// https://en.wikipedia.org/wiki/Okapi_BM25
 	// Including the lower-bounding term frequency normalization (2011 CIKM)
-	fn compute_bm25_score(&self, term_freq: f32, term_doc_count: f32, doc_length: f32) -> f32 {
+	fn compute_bm25_score(
+		&self,
+		bm25: &Bm25Params,
+		term_freq: f32,
+		term_doc_count: f32,
+		doc_length: f32,
+	) -> f32 {
 		// (n(qi) + 0.5)
 		let denominator = term_doc_count + 0.5;
 		// (N - n(qi) + 0.5)
@@ -83,10 +116,18 @@ impl BM25Scorer {
 		}
 		let tf_prim = 1.0 + term_freq.ln();
 		// idf * (k1 + 1)
-		let numerator = idf * (self.bm25.k1 + 1.0) * tf_prim;
+		let numerator = idf * (bm25.k1 + 1.0) * tf_prim;
 		// 1 - b + b * (|D| / avgDL)
-		let denominator = 1.0 - self.bm25.b + self.bm25.b * (doc_length / self.average_doc_length);
+		let denominator = 1.0 - bm25.b + bm25.b * (doc_length / self.average_doc_length);
 		// numerator / (k1 * denominator + 1)
-		numerator / (self.bm25.k1 * denominator + 1.0)
+		numerator / (bm25.k1 * denominator + 1.0)
+	}
+
+	// https://en.wikipedia.org/wiki/Tf%E2%80%93idf
+	// Using a logarithmic term frequency and a smoothed inverse document frequency
+	fn compute_tfidf_score(&self, term_freq: f32, term_doc_count: f32) -> f32 {
+		let tf = 1.0 + term_freq.ln();
+		let idf = ((self.doc_count + 1.0) / (term_doc_count + 1.0)).ln() + 1.0;
+		tf * idf
 	}
 }
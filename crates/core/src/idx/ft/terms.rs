@@ -1,4 +1,5 @@
 use crate::err::Error;
+use crate::idx::ft::fuzzy::Levenshtein;
 use crate::idx::trees::bkeys::FstKeys;
 use crate::idx::trees::btree::{BState, BState1, BState1skip, BStatistics, BTree, BTreeStore};
 use crate::idx::trees::store::TreeNodeProvider;
@@ -95,6 +96,38 @@ impl Terms {
 		self.btree.search(tx, &self.store, &term.into()).await
 	}
 
+	/// Returns the terms starting with the given prefix, with their length in characters.
+	pub(super) async fn get_terms_by_prefix(
+		&self,
+		tx: &Transaction,
+		prefix: &str,
+	) -> Result<Vec<(TermId, TermLen)>, Error> {
+		let terms = self.btree.search_by_prefix(tx, &self.store, &prefix.into()).await?;
+		let mut res = Vec::with_capacity(terms.len());
+		for (term, term_id) in terms {
+			let term = String::from_utf8(term)?;
+			res.push((term_id, term.chars().count() as TermLen));
+		}
+		Ok(res)
+	}
+
+	/// Returns the terms within the given edit distance of the term, with their length in characters.
+	pub(super) async fn get_terms_fuzzy(
+		&self,
+		tx: &Transaction,
+		term: &str,
+		distance: u8,
+	) -> Result<Vec<(TermId, TermLen)>, Error> {
+		let aut = Levenshtein::new(term, distance);
+		let terms = self.btree.search_matching(tx, &self.store, &aut).await?;
+		let mut res = Vec::with_capacity(terms.len());
+		for (term, term_id) in terms {
+			let term = String::from_utf8(term)?;
+			res.push((term_id, term.chars().count() as TermLen));
+		}
+		Ok(res)
+	}
+
 	pub(super) async fn remove_term_id(
 		&mut self,
 		tx: &Transaction,
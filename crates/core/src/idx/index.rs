@@ -3,12 +3,14 @@
 use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::err::Error;
+use crate::idx::entry::IndexEntry;
 use crate::idx::ft::FtIndex;
+use crate::idx::trees::geohash::GeohashIndex;
 use crate::idx::trees::mtree::MTreeIndex;
 use crate::idx::IndexKeyBase;
 use crate::key;
 use crate::kvs::TransactionType;
-use crate::sql::index::{HnswParams, MTreeParams, SearchParams};
+use crate::sql::index::{GeohashParams, HnswParams, MTreeParams, SearchParams};
 use crate::sql::statements::DefineIndexStatement;
 use crate::sql::{Array, Index, Part, Thing, Value};
 use reblessive::tree::Stk;
@@ -51,6 +53,7 @@ impl<'a> IndexOperation<'a> {
 			Index::Search(p) => self.index_full_text(stk, p).await,
 			Index::MTree(p) => self.index_mtree(stk, p).await,
 			Index::Hnsw(p) => self.index_hnsw(p).await,
+			Index::Geohash(p) => self.index_geohash(p).await,
 		}
 	}
 
@@ -70,10 +73,11 @@ impl<'a> IndexOperation<'a> {
 		let mut txn = tx.lock().await;
 		// Delete the old index data
 		if let Some(o) = self.o.take() {
+			let entry = IndexEntry::new(self.ix, self.rid, &o).encode()?;
 			let i = Indexable::new(o, self.ix);
 			for o in i {
 				let key = self.get_unique_index_key(&o)?;
-				match txn.delc(key, Some(revision::to_vec(self.rid)?)).await {
+				match txn.delc(key, Some(entry.clone())).await {
 					Err(Error::TxConditionNotMet) => Ok(()),
 					Err(e) => Err(e),
 					Ok(v) => Ok(v),
@@ -82,14 +86,15 @@ impl<'a> IndexOperation<'a> {
 		}
 		// Create the new index data
 		if let Some(n) = self.n.take() {
+			let entry = IndexEntry::new(self.ix, self.rid, &n).encode()?;
 			let i = Indexable::new(n, self.ix);
 			for n in i {
 				if !n.is_all_none_or_null() {
 					let key = self.get_unique_index_key(&n)?;
-					if txn.putc(key, revision::to_vec(self.rid)?, None).await.is_err() {
+					if txn.putc(key, entry.clone(), None).await.is_err() {
 						let key = self.get_unique_index_key(&n)?;
 						let val = txn.get(key, None).await?.unwrap();
-						let rid: Thing = revision::from_slice(&val)?;
+						let rid = IndexEntry::decode(self.ix, &val)?.into_rid();
 						return self.err_index_exists(rid, n);
 					}
 				}
@@ -104,10 +109,11 @@ impl<'a> IndexOperation<'a> {
 		let mut txn = tx.lock().await;
 		// Delete the old index data
 		if let Some(o) = self.o.take() {
+			let entry = IndexEntry::new(self.ix, self.rid, &o).encode()?;
 			let i = Indexable::new(o, self.ix);
 			for o in i {
 				let key = self.get_non_unique_index_key(&o)?;
-				match txn.delc(key, Some(revision::to_vec(self.rid)?)).await {
+				match txn.delc(key, Some(entry.clone())).await {
 					Err(Error::TxConditionNotMet) => Ok(()),
 					Err(e) => Err(e),
 					Ok(v) => Ok(v),
@@ -116,13 +122,14 @@ impl<'a> IndexOperation<'a> {
 		}
 		// Create the new index data
 		if let Some(n) = self.n.take() {
+			let entry = IndexEntry::new(self.ix, self.rid, &n).encode()?;
 			let i = Indexable::new(n, self.ix);
 			for n in i {
 				let key = self.get_non_unique_index_key(&n)?;
-				if txn.putc(key, revision::to_vec(self.rid)?, None).await.is_err() {
+				if txn.putc(key, entry.clone(), None).await.is_err() {
 					let key = self.get_non_unique_index_key(&n)?;
 					let val = txn.get(key, None).await?.unwrap();
-					let rid: Thing = revision::from_slice(&val)?;
+					let rid = IndexEntry::decode(self.ix, &val)?.into_rid();
 					return self.err_index_exists(rid, n);
 				}
 			}
@@ -185,6 +192,32 @@ impl<'a> IndexOperation<'a> {
 		if let Some(n) = self.n.take() {
 			hnsw.index_document(&txn, &self.rid.id, &n).await?;
 		}
+		// Train the quantizer once the index contains enough vectors
+		if hnsw.schedule_training() {
+			// The training runs in the background, outside of this transaction
+			#[cfg(not(target_family = "wasm"))]
+			if let Some(ib) = self.ctx.get_index_builder() {
+				ib.train_hnsw(self.ctx, self.opt.clone(), self.ix.clone(), p.clone());
+				return Ok(());
+			}
+			hnsw.train(&txn).await?;
+		}
+		Ok(())
+	}
+
+	async fn index_geohash(&mut self, p: &GeohashParams) -> Result<(), Error> {
+		let txn = self.ctx.tx();
+		let (ns, db) = self.opt.ns_db()?;
+		let ikb = IndexKeyBase::new(ns, db, self.ix)?;
+		let gh = GeohashIndex::new(ikb, p);
+		// Delete the old index data
+		if let Some(o) = self.o.take() {
+			gh.remove_document(&txn, &self.rid.id, &o).await?;
+		}
+		// Create the new index data
+		if let Some(n) = self.n.take() {
+			gh.index_document(&txn, &self.rid.id, &n).await?;
+		}
 		Ok(())
 	}
 }
@@ -1,4 +1,5 @@
 pub mod docids;
+pub(crate) mod entry;
 pub(crate) mod ft;
 pub(crate) mod index;
 pub mod planner;
@@ -21,10 +22,13 @@ use crate::key::index::bp::Bp;
 use crate::key::index::bs::Bs;
 use crate::key::index::bt::Bt;
 use crate::key::index::bu::Bu;
+use crate::key::index::gh;
+use crate::key::index::gh::Gh;
 use crate::key::index::hd::Hd;
 use crate::key::index::he::He;
 use crate::key::index::hi::Hi;
 use crate::key::index::hl::Hl;
+use crate::key::index::hq::Hq;
 use crate::key::index::hs::Hs;
 use crate::key::index::hv::Hv;
 use crate::key::index::vm::Vm;
@@ -34,6 +38,7 @@ use crate::sql::{Id, Thing};
 use revision::Revisioned;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::ops::Range;
 use std::sync::Arc;
 
 #[derive(Debug, Clone, Default)]
@@ -184,6 +189,28 @@ impl IndexKeyBase {
 		.encode()
 	}
 
+	fn new_gh_key(&self, cell: &str, id: &Id) -> Result<Key, Error> {
+		Gh::new(
+			self.inner.ns.as_str(),
+			self.inner.db.as_str(),
+			self.inner.tb.as_str(),
+			self.inner.ix.as_str(),
+			cell,
+			id,
+		)
+		.encode()
+	}
+
+	fn new_gh_prefix_range(&self, cell: &str) -> Result<Range<Key>, Error> {
+		let (ns, db, tb, ix) = (&self.inner.ns, &self.inner.db, &self.inner.tb, &self.inner.ix);
+		Ok(gh::prefix(ns, db, tb, ix, cell)?..gh::suffix(ns, db, tb, ix, cell)?)
+	}
+
+	fn new_gh_cell_range(&self, cell: &str) -> Result<Range<Key>, Error> {
+		let (ns, db, tb, ix) = (&self.inner.ns, &self.inner.db, &self.inner.tb, &self.inner.ix);
+		Ok(gh::cell_prefix(ns, db, tb, ix, cell)?..gh::cell_suffix(ns, db, tb, ix, cell)?)
+	}
+
 	fn new_hd_key(&self, doc_id: Option<DocId>) -> Result<Key, Error> {
 		Hd::new(
 			self.inner.ns.as_str(),
@@ -240,6 +267,16 @@ impl IndexKeyBase {
 		.encode()
 	}
 
+	fn new_hq_key(&self) -> Result<Key, Error> {
+		Hq::new(
+			self.inner.ns.as_str(),
+			self.inner.db.as_str(),
+			self.inner.tb.as_str(),
+			self.inner.ix.as_str(),
+		)
+		.encode()
+	}
+
 	fn new_hs_key(&self) -> Result<Key, Error> {
 		Hs::new(
 			self.inner.ns.as_str(),
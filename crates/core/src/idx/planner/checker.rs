@@ -30,12 +30,16 @@ impl<'a> HnswConditionChecker<'a> {
 	}
 
 	pub(in crate::idx) fn new_cond(ctx: &'a Context, opt: &'a Options, cond: Arc<Cond>) -> Self {
-		Self::HnswCondition(HnswCondChecker {
-			ctx,
-			opt,
-			cond,
-			cache: Default::default(),
-		})
+		if Cond(Value::Bool(true)).ne(cond.as_ref()) {
+			Self::HnswCondition(HnswCondChecker {
+				ctx,
+				opt,
+				cond,
+				cache: Default::default(),
+			})
+		} else {
+			Self::new()
+		}
 	}
 
 	pub(in crate::idx) async fn check_truthy(
@@ -51,12 +55,6 @@ impl<'a> HnswConditionChecker<'a> {
 		}
 	}
 
-	pub(in crate::idx) fn expire(&mut self, doc_id: u64) {
-		if let Self::HnswCondition(c) = self {
-			c.expire(doc_id)
-		}
-	}
-
 	pub(in crate::idx) fn expires(&mut self, doc_ids: Ids64) {
 		if let Self::HnswCondition(c) = self {
 			c.expires(doc_ids)
@@ -1,17 +1,16 @@
 use crate::ctx::Context;
-use crate::dbs::Options;
-use crate::doc::CursorDoc;
+use crate::dbs::{Options, Statement, Workable};
+use crate::doc::{CursorDoc, Document};
 use crate::err::Error;
-use crate::idx::docids::DocIds;
-use crate::idx::ft::analyzer::{Analyzer, TermsList, TermsSet};
+use crate::idx::docids::{DocId, DocIds};
+use crate::idx::ft::analyzer::{Analyzer, TermsList};
 use crate::idx::ft::highlighter::HighlightParams;
-use crate::idx::ft::scorer::BM25Scorer;
-use crate::idx::ft::termdocs::TermsDocs;
-use crate::idx::ft::terms::Terms;
+use crate::idx::ft::query::{FtQuery, QueryHits};
+use crate::idx::ft::scorer::FtScorer;
 use crate::idx::ft::{FtIndex, MatchRef};
 use crate::idx::planner::checker::{HnswConditionChecker, MTreeConditionChecker};
 use crate::idx::planner::iterators::{
-	IndexEqualThingIterator, IndexJoinThingIterator, IndexRangeThingIterator,
+	GeohashThingIterator, IndexEqualThingIterator, IndexJoinThingIterator, IndexRangeThingIterator,
 	IndexUnionThingIterator, IteratorRange, IteratorRecord, IteratorRef, KnnIterator,
 	KnnIteratorResult, MatchesThingIterator, MultipleIterators, ThingIterator,
 	UniqueEqualThingIterator, UniqueJoinThingIterator, UniqueRangeThingIterator,
@@ -25,23 +24,25 @@ use crate::idx::planner::knn::{KnnBruteForceResult, KnnPriorityList};
 use crate::idx::planner::plan::IndexOperator::Matches;
 use crate::idx::planner::plan::{IndexOperator, IndexOption, RangeValue};
 use crate::idx::planner::tree::{IdiomPosition, IndexReference};
-use crate::idx::planner::IterationStage;
+use crate::idx::planner::{IterationStage, RecordStrategy};
+use crate::idx::trees::geohash::GeohashIndex;
 use crate::idx::trees::mtree::MTreeIndex;
 use crate::idx::trees::store::hnsw::SharedHnswIndex;
 use crate::idx::IndexKeyBase;
 use crate::kvs::TransactionType;
-use crate::sql::index::{Distance, Index};
-use crate::sql::statements::DefineIndexStatement;
+use crate::sql::index::{Distance, GeohashParams, Index};
+use crate::sql::statements::{DefineIndexStatement, SelectStatement};
 use crate::sql::{
-	Array, Cond, Expression, FlowResultExt as _, Idiom, Number, Object, Table, Thing, Value,
+	Array, Cond, Expression, FlowResultExt as _, Geometry, Idiom, Number, Object, Table, Thing,
+	Value, Values,
 };
 use num_traits::{FromPrimitive, ToPrimitive};
 use reblessive::tree::Stk;
 use rust_decimal::Decimal;
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{OnceCell, RwLock};
 
 pub(super) type KnnBruteForceEntry = (KnnPriorityList, Idiom, Arc<Vec<Number>>, Distance);
 
@@ -79,6 +80,8 @@ pub(super) struct InnerQueryExecutor {
 	mt_entries: HashMap<Arc<Expression>, MtEntry>,
 	hnsw_entries: HashMap<Arc<Expression>, HnswEntry>,
 	knn_bruteforce_entries: HashMap<Arc<Expression>, KnnBruteForceEntry>,
+	/// The WHERE condition of the statement
+	cond: Option<Cond>,
 }
 
 impl From<InnerQueryExecutor> for QueryExecutor {
@@ -118,6 +121,7 @@ impl InnerQueryExecutor {
 		knns: KnnExpressions,
 		kbtes: KnnBruteForceExpressions,
 		knn_condition: Option<Cond>,
+		cond: Option<Cond>,
 	) -> Result<Self, Error> {
 		let mut mr_entries = HashMap::default();
 		let mut exp_entries = HashMap::default();
@@ -155,7 +159,7 @@ impl InnerQueryExecutor {
 						}
 					};
 					if let Some(e) = ft_entry {
-						if let Matches(_, Some(mr)) = e.0.index_option.op() {
+						if let Matches(_, Some(mr), _) = e.0.index_option.op() {
 							if mr_entries.insert(*mr, e.clone()).is_some() {
 								return Err(Error::DuplicatedMatchRef {
 									mr: *mr,
@@ -247,6 +251,7 @@ impl InnerQueryExecutor {
 			mt_entries,
 			hnsw_entries,
 			knn_bruteforce_entries,
+			cond,
 		})
 	}
 
@@ -305,7 +310,13 @@ impl QueryExecutor {
 	/// Returns `true` if the expression is matching the current iterator.
 	pub(crate) fn is_iterator_expression(&self, ir: IteratorRef, exp: &Expression) -> bool {
 		match self.0.it_entries.get(ir) {
-			Some(IteratorEntry::Single(Some(e), ..)) => exp.eq(e.as_ref()),
+			Some(IteratorEntry::Single(Some(e), ..)) => {
+				// The documents returned by an inexact full-text search still have to be checked
+				if self.0.exp_entries.get(e.as_ref()).is_some_and(|ft| !ft.0.hits.exact) {
+					return false;
+				}
+				exp.eq(e.as_ref())
+			}
 			Some(IteratorEntry::Range(es, ..)) => es.contains(exp),
 			_ => false,
 		}
@@ -359,9 +370,40 @@ impl QueryExecutor {
 			} => self.new_search_index_iterator(irf, io.clone()).await,
 			Index::MTree(_) => Ok(self.new_mtree_index_knn_iterator(irf)),
 			Index::Hnsw(_) => Ok(self.new_hnsw_index_ann_iterator(irf)),
+			Index::Geohash(ref p) => self.new_geohash_index_iterator(opt, irf, ixr, p, io),
 		}
 	}
 
+	fn new_geohash_index_iterator(
+		&self,
+		opt: &Options,
+		irf: IteratorRef,
+		ixr: &IndexReference,
+		p: &GeohashParams,
+		io: &IndexOption,
+	) -> Result<Option<ThingIterator>, Error> {
+		let rect = match io.op() {
+			IndexOperator::Geo(_, v) => match v.as_ref() {
+				Value::Geometry(g) => GeohashIndex::geometry_rect(g),
+				_ => None,
+			},
+			IndexOperator::GeoDistance(_, origin, d) => match (origin.as_ref(), d.as_ref()) {
+				(Value::Geometry(Geometry::Point(o)), Value::Number(d)) => {
+					Some(GeohashIndex::distance_rect(o, d.to_float()))
+				}
+				_ => None,
+			},
+			_ => None,
+		};
+		let Some(rect) = rect else {
+			return Ok(None);
+		};
+		let (ns, db) = opt.ns_db()?;
+		let gh = GeohashIndex::new(IndexKeyBase::new(ns, db, ixr)?, p);
+		let it = GeohashThingIterator::new(irf, &ixr.what, gh.query_ranges(&rect)?);
+		Ok(Some(ThingIterator::Geohash(it)))
+	}
+
 	async fn new_index_iterator(
 		&self,
 		opt: &Options,
@@ -418,6 +460,9 @@ impl QueryExecutor {
 					)?))
 				}
 			}
+			IndexOperator::Range(prefix, from, to, order) => {
+				Self::new_compound_range_iterator(ir, opt, ix, prefix, from, to, *order)?
+			}
 			_ => None,
 		})
 	}
@@ -823,6 +868,47 @@ impl QueryExecutor {
 		Ok(ThingIterator::Multiples(Box::new(MultipleIterators::new(iterators))))
 	}
 
+	fn new_compound_range_iterator(
+		ir: IteratorRef,
+		opt: &Options,
+		ix: &DefineIndexStatement,
+		prefix: &Value,
+		from: &RangeValue,
+		to: &RangeValue,
+		order: Option<bool>,
+	) -> Result<Option<ThingIterator>, Error> {
+		let (ns, db) = opt.ns_db()?;
+		let prefixes = Self::get_equal_variants_from_value(prefix);
+		let ranges = Self::get_ranges_variants(from, to)
+			.unwrap_or_else(|| vec![IteratorRange::new_ref(ValueType::None, from, to)]);
+		let mut iterators = VecDeque::with_capacity(prefixes.len() * ranges.len());
+		for prefix in &prefixes {
+			for range in &ranges {
+				let it = if order == Some(true) {
+					#[cfg(any(feature = "kv-rocksdb", feature = "kv-tikv"))]
+					{
+						ThingIterator::IndexRangeReverse(
+							IndexRangeReverseThingIterator::compound_range(
+								ir, ns, db, ix, prefix, range,
+							)?,
+						)
+					}
+					#[cfg(not(any(feature = "kv-rocksdb", feature = "kv-tikv")))]
+					return Ok(None);
+				} else {
+					ThingIterator::IndexRange(IndexRangeThingIterator::compound_range(
+						ir, ns, db, ix, prefix, range,
+					)?)
+				};
+				iterators.push_back(it);
+			}
+		}
+		if iterators.len() == 1 {
+			return Ok(iterators.pop_front());
+		}
+		Ok(Some(ThingIterator::Multiples(Box::new(MultipleIterators::new(iterators)))))
+	}
+
 	async fn new_unique_index_iterator(
 		&self,
 		opt: &Options,
@@ -877,6 +963,10 @@ impl QueryExecutor {
 					)?))
 				}
 			}
+			IndexOperator::Range(prefix, from, to, order) => {
+				// The compound unique index is iterated as a standard index
+				Self::new_compound_range_iterator(irf, opt, ixr, prefix, from, to, *order)?
+			}
 			_ => None,
 		})
 	}
@@ -904,11 +994,10 @@ impl QueryExecutor {
 		io: IndexOption,
 	) -> Result<Option<ThingIterator>, Error> {
 		if let Some(IteratorEntry::Single(Some(exp), ..)) = self.0.it_entries.get(ir) {
-			if let Matches(_, _) = io.op() {
+			if let Matches(..) = io.op() {
 				if let Some(fti) = self.0.ft_map.get(io.ix_ref()) {
 					if let Some(fte) = self.0.exp_entries.get(exp) {
-						let it =
-							MatchesThingIterator::new(ir, fti, fte.0.terms_docs.clone()).await?;
+						let it = MatchesThingIterator::new(ir, fti, fte.0.hits.docs.clone());
 						return Ok(Some(ThingIterator::Matches(it)));
 					}
 				}
@@ -966,7 +1055,14 @@ impl QueryExecutor {
 		if let Some(ft) = self.0.exp_entries.get(exp) {
 			let ix = ft.0.index_option.ix_ref();
 			if self.0.table.eq(&ix.what.0) {
-				return self.matches_with_doc_id(ctx, thg, ft).await;
+				if !self.matches_with_doc_id(ctx, thg, ft).await? {
+					return Ok(false);
+				}
+				// The positions of the terms are not indexed,
+				// so they are checked against the value of the record
+				if ft.0.hits.exact {
+					return Ok(true);
+				}
 			}
 			return self.matches_with_value(stk, ctx, opt, ft, l, r).await;
 		}
@@ -990,22 +1086,7 @@ impl QueryExecutor {
 		let doc_id = di.get_doc_id(&tx, doc_key).await?;
 		drop(di);
 		if let Some(doc_id) = doc_id {
-			let term_goals = ft.0.terms_docs.len();
-			// If there is no terms, it can't be a match
-			if term_goals == 0 {
-				return Ok(false);
-			}
-			for opt_td in ft.0.terms_docs.iter() {
-				if let Some((_, docs)) = opt_td {
-					if !docs.contains(doc_id) {
-						return Ok(false);
-					}
-				} else {
-					// If one of the term is missing, it can't be a match
-					return Ok(false);
-				}
-			}
-			return Ok(true);
+			return Ok(ft.0.hits.docs.contains(doc_id));
 		}
 		Ok(false)
 	}
@@ -1019,22 +1100,14 @@ impl QueryExecutor {
 		l: Value,
 		r: Value,
 	) -> Result<bool, Error> {
-		// If the query terms contains terms that are unknown in the index
-		// of if there are no terms in the query
-		// we are sure that it does not match any document
-		if !ft.0.query_terms_set.is_matchable() {
-			return Ok(false);
-		}
 		let v = match ft.0.index_option.id_pos() {
-			IdiomPosition::Left => r,
-			IdiomPosition::Right => l,
+			IdiomPosition::Left => l,
+			IdiomPosition::Right => r,
 			IdiomPosition::None => return Ok(false),
 		};
-		let terms = ft.0.terms.read().await;
-		// Extract the terms set from the record
-		let t = ft.0.analyzer.extract_indexing_terms(stk, ctx, opt, &terms, v).await?;
-		drop(terms);
-		Ok(ft.0.query_terms_set.is_subset(&t))
+		// Extract the terms, with their positions, from the record
+		let doc = ft.0.analyzer.extract_document_tokens(stk, ctx, opt, v).await?;
+		Ok(ft.0.query.matches(&doc))
 	}
 
 	fn get_ft_entry(&self, match_ref: &Value) -> Option<&FtEntry> {
@@ -1117,8 +1190,73 @@ impl QueryExecutor {
 		}
 		Ok(Value::None)
 	}
+
+	/// Counts the values of a field amongst the records matching the full-text query
+	/// of the given match reference. At most `limit` values are returned.
+	pub(crate) async fn facets(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		match_ref: &Value,
+		field: Idiom,
+		limit: Option<usize>,
+	) -> Result<Value, Error> {
+		let Some(e) = self.get_ft_entry(match_ref) else {
+			return Ok(Value::None);
+		};
+		let facets = e.facets(stk, ctx, opt, self.0.cond.as_ref(), field).await?;
+		let limit = limit.unwrap_or(facets.len());
+		let res: Vec<Value> = facets
+			.iter()
+			.take(limit)
+			.map(|(v, c)| {
+				let mut o = Object::default();
+				o.insert("value".to_string(), v.clone());
+				o.insert("count".to_string(), Value::from(*c));
+				Value::from(o)
+			})
+			.collect();
+		Ok(Value::from(res))
+	}
+
+	/// Computes the reciprocal rank fusion score of a record.
+	/// It combines the rank of the record in the full-text results of the given match reference,
+	/// with its rank in each of the KNN results of the query.
+	pub(crate) async fn rrf(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		match_ref: &Value,
+		rid: &Thing,
+		k: f64,
+	) -> Result<Value, Error> {
+		let Some(e) = self.get_ft_entry(match_ref) else {
+			return Ok(Value::None);
+		};
+		let mut score = 0.0;
+		if let Some(rank) = e.rank(stk, ctx, opt, self.0.cond.as_ref(), rid).await? {
+			score += 1.0 / (k + rank as f64);
+		}
+		let knn_ranks = self
+			.0
+			.mt_entries
+			.values()
+			.map(|e| &e.ranks)
+			.chain(self.0.hnsw_entries.values().map(|e| &e.ranks));
+		for ranks in knn_ranks {
+			if let Some(rank) = ranks.get(rid) {
+				score += 1.0 / (k + *rank as f64);
+			}
+		}
+		Ok(Value::from(score))
+	}
 }
 
+/// The values of a field with their number of occurrences
+type Facets = Arc<[(Value, usize)]>;
+
 #[derive(Clone)]
 struct FtEntry(Arc<Inner>);
 
@@ -1126,11 +1264,13 @@ struct Inner {
 	index_option: IndexOption,
 	doc_ids: Arc<RwLock<DocIds>>,
 	analyzer: Analyzer,
-	query_terms_set: TermsSet,
+	query: FtQuery,
 	query_terms_list: TermsList,
-	terms: Arc<RwLock<Terms>>,
-	terms_docs: TermsDocs,
-	scorer: Option<BM25Scorer>,
+	hits: QueryHits,
+	scorer: Option<FtScorer>,
+	ranks: OnceCell<HashMap<DocId, usize>>,
+	/// The value counts of the fields requested by `search::facets`
+	facets: RwLock<HashMap<Idiom, Facets>>,
 }
 
 impl FtEntry {
@@ -1141,31 +1281,174 @@ impl FtEntry {
 		ft: &FtIndex,
 		io: IndexOption,
 	) -> Result<Option<Self>, Error> {
-		if let Matches(qs, _) = io.op() {
-			let (terms_list, terms_set) =
-				ft.extract_querying_terms(stk, ctx, opt, qs.to_owned()).await?;
+		if let Matches(qs, _, fuzziness) = io.op() {
+			let fuzziness = fuzziness.unwrap_or(0);
+			let query = ft.parse_query(stk, ctx, opt, qs.to_owned(), fuzziness).await?;
+			let terms_list = query.terms_list();
+			let boosts = query.boosts();
 			let tx = ctx.tx();
 			let terms_docs = Arc::new(ft.get_terms_docs(&tx, &terms_list).await?);
+			let hits = ft.get_query_hits(&tx, &query).await?;
 			drop(tx);
 			Ok(Some(Self(Arc::new(Inner {
 				index_option: io,
 				doc_ids: ft.doc_ids(),
 				analyzer: ft.analyzer(),
-				query_terms_set: terms_set,
+				query,
 				query_terms_list: terms_list,
-				scorer: ft.new_scorer(terms_docs.clone())?,
-				terms: ft.terms(),
-				terms_docs,
+				hits,
+				scorer: ft.new_scorer(terms_docs, boosts)?,
+				ranks: OnceCell::new(),
+				facets: Default::default(),
 			}))))
 		} else {
 			Ok(None)
 		}
 	}
+
+	/// Returns the rank (starting at 1) of the record amongst the matching documents,
+	/// ordered by descending score.
+	async fn rank(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		cond: Option<&Cond>,
+		rid: &Thing,
+	) -> Result<Option<usize>, Error> {
+		let ranks = self.0.ranks.get_or_try_init(|| self.build_ranks(stk, ctx, opt, cond)).await?;
+		let tx = ctx.tx();
+		let key = revision::to_vec(rid)?;
+		let di = self.0.doc_ids.read().await;
+		let doc_id = di.get_doc_id(&tx, key).await?;
+		drop(di);
+		Ok(doc_id.and_then(|doc_id| ranks.get(&doc_id).copied()))
+	}
+
+	/// Returns the number of matching records for each value of the field,
+	/// ordered by descending count. The counts are computed once per query and field.
+	/// Only the records which are visible to the user, and which match the condition
+	/// of the query, are counted, with the fields which the user is permitted to view.
+	async fn facets(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		cond: Option<&Cond>,
+		field: Idiom,
+	) -> Result<Facets, Error> {
+		if let Some(f) = self.0.facets.read().await.get(&field) {
+			return Ok(f.clone());
+		}
+		let stm = self.select_statement(cond);
+		let stm = Statement::from(&stm);
+		#[allow(clippy::mutable_key_type)]
+		let mut counts: BTreeMap<Value, usize> = BTreeMap::new();
+		for doc_id in &self.0.hits.docs {
+			let Some(doc) = self.visible_record(stk, ctx, opt, &stm, doc_id).await? else {
+				continue;
+			};
+			match field.compute(stk, ctx, opt, Some(&doc)).await.catch_return()? {
+				Value::None | Value::Null => {}
+				// Each value of an array is counted
+				Value::Array(a) => {
+					for v in a.0.into_iter().collect::<BTreeSet<_>>() {
+						*counts.entry(v).or_default() += 1;
+					}
+				}
+				v => *counts.entry(v).or_default() += 1,
+			}
+		}
+		let mut facets: Vec<(Value, usize)> = counts.into_iter().collect();
+		facets.sort_by(|(_, a), (_, b)| b.cmp(a));
+		let facets: Facets = facets.into();
+		self.0.facets.write().await.insert(field, facets.clone());
+		Ok(facets)
+	}
+
+	/// Ranks the matching documents by descending score. Only the records which are
+	/// visible to the user, and which match the condition of the query, are ranked.
+	async fn build_ranks(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		cond: Option<&Cond>,
+	) -> Result<HashMap<DocId, usize>, Error> {
+		let stm = self.select_statement(cond);
+		let stm = Statement::from(&stm);
+		let mut scored = Vec::new();
+		for doc_id in &self.0.hits.docs {
+			if self.visible_record(stk, ctx, opt, &stm, doc_id).await?.is_none() {
+				continue;
+			}
+			let score = match &self.0.scorer {
+				Some(scorer) => scorer.score(&ctx.tx(), doc_id).await?.unwrap_or(0.0),
+				None => 0.0,
+			};
+			scored.push((doc_id, score));
+		}
+		scored.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+		Ok(scored.into_iter().enumerate().map(|(pos, (doc_id, _))| (doc_id, pos + 1)).collect())
+	}
+
+	/// A statement selecting the records of the indexed table with the condition of the query
+	fn select_statement(&self, cond: Option<&Cond>) -> SelectStatement {
+		let tb = &self.0.index_option.ix_ref().what.0;
+		SelectStatement {
+			what: Values(vec![Value::Table(Table(tb.to_owned()))]),
+			cond: cond.cloned(),
+			..Default::default()
+		}
+	}
+
+	/// Fetches a matching record, if it is visible to the user and matches the condition
+	/// of the statement, which also discards inexact hits. The permissions are checked
+	/// directly, without processing the statement for the record.
+	async fn visible_record(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		stm: &Statement<'_>,
+		doc_id: DocId,
+	) -> Result<Option<CursorDoc>, Error> {
+		let (ns, db) = opt.ns_db()?;
+		let tx = ctx.tx();
+		let di = self.0.doc_ids.read().await;
+		let doc_key = di.get_doc_key(&tx, doc_id).await?;
+		drop(di);
+		let Some(doc_key) = doc_key else {
+			return Ok(None);
+		};
+		let rid: Thing = revision::from_slice(&doc_key)?;
+		let record = tx.get_record(ns, db, &rid.tb, &rid.id, None).await?;
+		drop(tx);
+		if record.is_none() {
+			return Ok(None);
+		}
+		let mut doc = Document::new(
+			Some(rid.into()),
+			None,
+			None,
+			record,
+			Workable::Normal,
+			false,
+			RecordStrategy::KeysAndValues,
+		);
+		Ok(doc.check_visible(stk, ctx, opt, stm).await?.cloned())
+	}
+}
+
+/// The ranks (starting at 1) of the records in the results of a KNN search
+fn knn_ranks(res: &VecDeque<KnnIteratorResult>) -> HashMap<Arc<Thing>, usize> {
+	res.iter().enumerate().map(|(pos, (thg, _, _))| (thg.clone(), pos + 1)).collect()
 }
 
 #[derive(Clone)]
 pub(super) struct MtEntry {
 	res: VecDeque<KnnIteratorResult>,
+	ranks: HashMap<Arc<Thing>, usize>,
 }
 
 impl MtEntry {
@@ -1185,6 +1468,7 @@ impl MtEntry {
 		};
 		let res = mt.knn_search(stk, ctx, o, k as usize, cond_checker).await?;
 		Ok(Self {
+			ranks: knn_ranks(&res),
 			res,
 		})
 	}
@@ -1193,6 +1477,7 @@ impl MtEntry {
 #[derive(Clone)]
 pub(super) struct HnswEntry {
 	res: VecDeque<KnnIteratorResult>,
+	ranks: HashMap<Arc<Thing>, usize>,
 }
 
 impl HnswEntry {
@@ -1218,6 +1503,7 @@ impl HnswEntry {
 			.knn_search(&ctx.tx(), stk, v, n as usize, ef as usize, cond_checker)
 			.await?;
 		Ok(Self {
+			ranks: knn_ranks(&res),
 			res,
 		})
 	}
@@ -2,19 +2,21 @@ use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::err::Error;
 use crate::idx::docids::DocId;
-use crate::idx::ft::termdocs::TermsDocs;
+use crate::idx::entry::IndexEntry;
 use crate::idx::ft::{FtIndex, HitsIterator};
 use crate::idx::planner::plan::RangeValue;
 use crate::idx::planner::tree::IndexReference;
+use crate::key::index::gh::Gh;
 use crate::key::index::Index;
 use crate::kvs::{Key, Val};
-use crate::kvs::{KeyEncode, Transaction};
+use crate::kvs::{KeyDecode, KeyEncode, Transaction};
 use crate::sql::statements::DefineIndexStatement;
-use crate::sql::{Array, Ident, Number, Thing, Value};
+use crate::sql::{Array, Id, Ident, Number, Thing, Value};
 use radix_trie::Trie;
+use roaring::RoaringTreemap;
 use rust_decimal::Decimal;
 use std::borrow::Cow;
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -121,6 +123,7 @@ pub(crate) enum ThingIterator {
 	UniqueJoin(Box<UniqueJoinThingIterator>),
 	Matches(MatchesThingIterator),
 	Knn(KnnIterator),
+	Geohash(GeohashThingIterator),
 	Multiples(Box<MultipleIterators>),
 }
 
@@ -144,6 +147,7 @@ impl ThingIterator {
 			Self::UniqueUnion(i) => i.next_batch(ctx, txn, size).await,
 			Self::Matches(i) => i.next_batch(ctx, txn, size).await,
 			Self::Knn(i) => i.next_batch(ctx, size).await,
+			Self::Geohash(i) => i.next_batch(ctx, txn, size).await,
 			Self::IndexJoin(i) => Box::pin(i.next_batch(ctx, txn, size)).await,
 			Self::UniqueJoin(i) => Box::pin(i.next_batch(ctx, txn, size)).await,
 			Self::Multiples(i) => Box::pin(i.next_batch(ctx, txn, size)).await,
@@ -169,6 +173,7 @@ impl ThingIterator {
 			Self::UniqueUnion(i) => i.next_count(ctx, txn, size).await,
 			Self::Matches(i) => i.next_count(ctx, txn, size).await,
 			Self::Knn(i) => i.next_count(ctx, size).await,
+			Self::Geohash(i) => i.next_count(ctx, txn, size).await,
 			Self::IndexJoin(i) => Box::pin(i.next_count(ctx, txn, size)).await,
 			Self::UniqueJoin(i) => Box::pin(i.next_count(ctx, txn, size)).await,
 			Self::Multiples(i) => Box::pin(i.next_count(ctx, txn, size)).await,
@@ -181,6 +186,8 @@ pub(crate) enum IndexItemRecord {
 	Key(Arc<Thing>, IteratorRecord),
 	/// We have collected the key and the value
 	KeyValue(Arc<Thing>, Arc<Value>, IteratorRecord),
+	/// We have collected the key and the partial document stored in the index entry
+	KeyIndexed(Arc<Thing>, Arc<Value>, IteratorRecord),
 }
 
 impl IndexItemRecord {
@@ -195,16 +202,36 @@ impl IndexItemRecord {
 	fn new_key(t: Thing, ir: IteratorRecord) -> Self {
 		Self::Key(Arc::new(t), ir)
 	}
+
+	/// Decodes the value of a standard or unique index entry.
+	/// `include` tells if the index entries contain a partial document (`INCLUDE` clause).
+	fn new_entry(include: bool, val: &[u8], ir: IteratorRecord) -> Result<Self, Error> {
+		Ok(match IndexEntry::decode_value(include, val)? {
+			IndexEntry::RecordId(t) => Self::Key(Arc::new(t), ir),
+			IndexEntry::Document(t, doc) => Self::KeyIndexed(Arc::new(t), Arc::new(doc), ir),
+		})
+	}
+
 	fn thing(&self) -> &Thing {
 		match self {
 			Self::Key(t, _) => t,
 			Self::KeyValue(t, _, _) => t,
+			Self::KeyIndexed(t, _, _) => t,
+		}
+	}
+
+	/// The partial document stored in the index entry is only
+	/// used when the query is covered by the index.
+	pub(crate) fn into_covered(self) -> Self {
+		match self {
+			Self::KeyIndexed(t, v, ir) => Self::KeyValue(t, v, ir),
+			r => r,
 		}
 	}
 
 	pub(crate) fn consume(self) -> (Arc<Thing>, Option<Arc<Value>>, IteratorRecord) {
 		match self {
-			Self::Key(t, ir) => (t, None, ir),
+			Self::Key(t, ir) | Self::KeyIndexed(t, _, ir) => (t, None, ir),
 			Self::KeyValue(t, v, ir) => (t, Some(v), ir),
 		}
 	}
@@ -212,6 +239,7 @@ impl IndexItemRecord {
 
 pub(crate) struct IndexEqualThingIterator {
 	irf: IteratorRef,
+	include: bool,
 	beg: Vec<u8>,
 	end: Vec<u8>,
 }
@@ -227,6 +255,7 @@ impl IndexEqualThingIterator {
 		let (beg, end) = Self::get_beg_end(ns, db, ix, a)?;
 		Ok(Self {
 			irf,
+			include: ix.include.is_some(),
 			beg,
 			end,
 		})
@@ -271,6 +300,7 @@ impl IndexEqualThingIterator {
 	async fn next_scan_batch<B: IteratorBatch>(
 		tx: &Transaction,
 		irf: IteratorRef,
+		include: bool,
 		beg: &mut Vec<u8>,
 		end: &[u8],
 		limit: u32,
@@ -278,7 +308,7 @@ impl IndexEqualThingIterator {
 		let res = Self::next_scan(tx, beg, end, limit).await?;
 		let mut records = B::with_capacity(res.len());
 		res.into_iter().try_for_each(|(_, val)| -> Result<(), Error> {
-			records.add(IndexItemRecord::new_key(revision::from_slice(&val)?, irf.into()));
+			records.add(IndexItemRecord::new_entry(include, &val, irf.into())?);
 			Ok(())
 		})?;
 		Ok(records)
@@ -289,7 +319,7 @@ impl IndexEqualThingIterator {
 		tx: &Transaction,
 		limit: u32,
 	) -> Result<B, Error> {
-		Self::next_scan_batch(tx, self.irf, &mut self.beg, &self.end, limit).await
+		Self::next_scan_batch(tx, self.irf, self.include, &mut self.beg, &self.end, limit).await
 	}
 
 	async fn next_count(&mut self, tx: &Transaction, limit: u32) -> Result<usize, Error> {
@@ -443,6 +473,26 @@ impl ValueType {
 		}
 	}
 
+	/// The lowest value of the type, if the type is known
+	fn min_value(&self) -> Option<Value> {
+		match self {
+			Self::None => None,
+			Self::NumberInt => Some(Number::Int(i64::MIN).into()),
+			Self::NumberFloat => Some(Number::Float(f64::MIN).into()),
+			Self::NumberDecimal => Some(Number::Decimal(Decimal::MIN).into()),
+		}
+	}
+
+	/// The highest value of the type, if the type is known
+	fn max_value(&self) -> Option<Value> {
+		match self {
+			Self::None => None,
+			Self::NumberInt => Some(Number::Int(i64::MAX).into()),
+			Self::NumberFloat => Some(Number::Float(f64::MAX).into()),
+			Self::NumberDecimal => Some(Number::Decimal(Decimal::MAX).into()),
+		}
+	}
+
 	fn prefix_end(
 		&self,
 		ns: &str,
@@ -479,6 +529,7 @@ impl ValueType {
 
 pub(crate) struct IndexRangeThingIterator {
 	irf: IteratorRef,
+	include: bool,
 	r: RangeScan,
 }
 
@@ -492,6 +543,7 @@ impl IndexRangeThingIterator {
 	) -> Result<Self, Error> {
 		Ok(Self {
 			irf,
+			include: ix.include.is_some(),
 			r: Self::range_scan(ns, db, ix, range)?,
 		})
 	}
@@ -506,6 +558,23 @@ impl IndexRangeThingIterator {
 		Self::new(irf, ns, db, ix, &range)
 	}
 
+	/// Iterates over a compound index, on the records matching the values
+	/// of the leading columns (prefix) and the range on the following column.
+	pub(super) fn compound_range(
+		irf: IteratorRef,
+		ns: &str,
+		db: &str,
+		ix: &DefineIndexStatement,
+		prefix: &Array,
+		range: &IteratorRange<'_>,
+	) -> Result<Self, Error> {
+		Ok(Self {
+			irf,
+			include: ix.include.is_some(),
+			r: Self::compound_range_scan(ns, db, ix, prefix, range)?,
+		})
+	}
+
 	fn range_scan(
 		ns: &str,
 		db: &str,
@@ -517,6 +586,61 @@ impl IndexRangeThingIterator {
 		Ok(RangeScan::new(beg, range.from.inclusive, end, range.to.inclusive))
 	}
 
+	fn compound_range_scan(
+		ns: &str,
+		db: &str,
+		ix: &DefineIndexStatement,
+		prefix: &Array,
+		range: &IteratorRange<'_>,
+	) -> Result<RangeScan, Error> {
+		let beg = Self::compute_compound_beg(ns, db, ix, prefix, &range.from, range.value_type)?;
+		let end = Self::compute_compound_end(ns, db, ix, prefix, &range.to, range.value_type)?;
+		// The composite boundaries never match an existing key: the range is [beg, end)
+		Ok(RangeScan::new(beg, true, end, false))
+	}
+
+	fn compute_compound_beg(
+		ns: &str,
+		db: &str,
+		ix: &DefineIndexStatement,
+		prefix: &Array,
+		from: &RangeValue,
+		value_type: ValueType,
+	) -> Result<Vec<u8>, Error> {
+		let mut fd = prefix.clone();
+		if from.value == Value::None {
+			fd.0.extend(value_type.min_value());
+			return Index::prefix_ids_composite_beg(ns, db, &ix.what, &ix.name, &fd);
+		}
+		fd.0.push(from.value.to_owned());
+		if from.inclusive {
+			Index::prefix_ids_composite_beg(ns, db, &ix.what, &ix.name, &fd)
+		} else {
+			Index::prefix_ids_composite_end(ns, db, &ix.what, &ix.name, &fd)
+		}
+	}
+
+	fn compute_compound_end(
+		ns: &str,
+		db: &str,
+		ix: &DefineIndexStatement,
+		prefix: &Array,
+		to: &RangeValue,
+		value_type: ValueType,
+	) -> Result<Vec<u8>, Error> {
+		let mut fd = prefix.clone();
+		if to.value == Value::None {
+			fd.0.extend(value_type.max_value());
+			return Index::prefix_ids_composite_end(ns, db, &ix.what, &ix.name, &fd);
+		}
+		fd.0.push(to.value.to_owned());
+		if to.inclusive {
+			Index::prefix_ids_composite_end(ns, db, &ix.what, &ix.name, &fd)
+		} else {
+			Index::prefix_ids_composite_beg(ns, db, &ix.what, &ix.name, &fd)
+		}
+	}
+
 	fn compute_beg(
 		ns: &str,
 		db: &str,
@@ -582,7 +706,7 @@ impl IndexRangeThingIterator {
 		let mut records = B::with_capacity(res.len());
 		res.into_iter().filter(|(k, _)| self.r.matches(k)).try_for_each(
 			|(_, v)| -> Result<(), Error> {
-				records.add(IndexItemRecord::new_key(revision::from_slice(&v)?, self.irf.into()));
+				records.add(IndexItemRecord::new_entry(self.include, &v, self.irf.into())?);
 				Ok(())
 			},
 		)?;
@@ -599,6 +723,7 @@ impl IndexRangeThingIterator {
 #[cfg(any(feature = "kv-rocksdb", feature = "kv-tikv"))]
 pub(crate) struct IndexRangeReverseThingIterator {
 	irf: IteratorRef,
+	include: bool,
 	r: ReverseRangeScan,
 }
 
@@ -613,10 +738,28 @@ impl IndexRangeReverseThingIterator {
 	) -> Result<Self, Error> {
 		Ok(Self {
 			irf,
+			include: ix.include.is_some(),
 			r: ReverseRangeScan::new(IndexRangeThingIterator::range_scan(ns, db, ix, range)?),
 		})
 	}
 
+	pub(super) fn compound_range(
+		irf: IteratorRef,
+		ns: &str,
+		db: &str,
+		ix: &DefineIndexStatement,
+		prefix: &Array,
+		range: &IteratorRange<'_>,
+	) -> Result<Self, Error> {
+		Ok(Self {
+			irf,
+			include: ix.include.is_some(),
+			r: ReverseRangeScan::new(IndexRangeThingIterator::compound_range_scan(
+				ns, db, ix, prefix, range,
+			)?),
+		})
+	}
+
 	pub(super) fn full_range(
 		irf: IteratorRef,
 		ns: &str,
@@ -637,7 +780,7 @@ impl IndexRangeReverseThingIterator {
 		self.r.r.end_excl_match_checked = true;
 		if let Some(v) = tx.get(&self.r.r.end, None).await? {
 			*limit -= 1;
-			Ok(Some(IndexItemRecord::new_key(revision::from_slice(&v)?, self.irf.into())))
+			Ok(Some(IndexItemRecord::new_entry(self.include, &v, self.irf.into())?))
 		} else {
 			Ok(None)
 		}
@@ -689,7 +832,7 @@ impl IndexRangeReverseThingIterator {
 		// Feed the result
 		res.into_iter().filter(|(k, _)| self.r.r.matches(k)).try_for_each(
 			|(_, v)| -> Result<(), Error> {
-				records.add(IndexItemRecord::new_key(revision::from_slice(&v)?, self.irf.into()));
+				records.add(IndexItemRecord::new_entry(self.include, &v, self.irf.into())?);
 				Ok(())
 			},
 		)?;
@@ -735,6 +878,7 @@ impl IndexRangeReverseThingIterator {
 
 pub(crate) struct IndexUnionThingIterator {
 	irf: IteratorRef,
+	include: bool,
 	values: VecDeque<(Vec<u8>, Vec<u8>)>,
 	current: Option<(Vec<u8>, Vec<u8>)>,
 }
@@ -757,6 +901,7 @@ impl IndexUnionThingIterator {
 		let current = values.pop_front();
 		Ok(Self {
 			irf,
+			include: ix.include.is_some(),
 			values,
 			current,
 		})
@@ -772,9 +917,15 @@ impl IndexUnionThingIterator {
 			if ctx.is_done(true) {
 				break;
 			}
-			let records: B =
-				IndexEqualThingIterator::next_scan_batch(tx, self.irf, &mut r.0, &r.1, limit)
-					.await?;
+			let records: B = IndexEqualThingIterator::next_scan_batch(
+				tx,
+				self.irf,
+				self.include,
+				&mut r.0,
+				&r.1,
+				limit,
+			)
+			.await?;
 			if !records.is_empty() {
 				return Ok(records);
 			}
@@ -980,6 +1131,7 @@ impl IndexJoinThingIterator {
 
 pub(crate) struct UniqueEqualThingIterator {
 	irf: IteratorRef,
+	include: bool,
 	key: Option<Key>,
 }
 
@@ -994,6 +1146,7 @@ impl UniqueEqualThingIterator {
 		let key = Index::new(ns, db, &ix.what, &ix.name, a, None).encode()?;
 		Ok(Self {
 			irf,
+			include: ix.include.is_some(),
 			key: Some(key),
 		})
 	}
@@ -1001,8 +1154,7 @@ impl UniqueEqualThingIterator {
 	async fn next_batch<B: IteratorBatch>(&mut self, tx: &Transaction) -> Result<B, Error> {
 		if let Some(key) = self.key.take() {
 			if let Some(val) = tx.get(key, None).await? {
-				let rid: Thing = revision::from_slice(&val)?;
-				let record = IndexItemRecord::new_key(rid, self.irf.into());
+				let record = IndexItemRecord::new_entry(self.include, &val, self.irf.into())?;
 				return Ok(B::from_one(record));
 			}
 		}
@@ -1033,6 +1185,7 @@ fn full_iterator_range<'a>() -> IteratorRange<'a> {
 
 pub(crate) struct UniqueRangeThingIterator {
 	irf: IteratorRef,
+	include: bool,
 	r: RangeScan,
 	done: bool,
 }
@@ -1059,6 +1212,7 @@ impl UniqueRangeThingIterator {
 		let r = Self::range_scan(ns, db, ix, r)?;
 		Ok(Self {
 			irf,
+			include: ix.include.is_some(),
 			r,
 			done: false,
 		})
@@ -1120,15 +1274,13 @@ impl UniqueRangeThingIterator {
 				return Ok(records);
 			}
 			if self.r.matches(&k) {
-				let rid: Thing = revision::from_slice(&v)?;
-				records.add(IndexItemRecord::new_key(rid, self.irf.into()));
+				records.add(IndexItemRecord::new_entry(self.include, &v, self.irf.into())?);
 			}
 		}
 
 		if self.r.matches_end() {
 			if let Some(v) = tx.get(&self.r.end, None).await? {
-				let rid: Thing = revision::from_slice(&v)?;
-				records.add(IndexItemRecord::new_key(rid, self.irf.into()));
+				records.add(IndexItemRecord::new_entry(self.include, &v, self.irf.into())?);
 			}
 		}
 		self.done = true;
@@ -1163,6 +1315,7 @@ impl UniqueRangeThingIterator {
 #[cfg(any(feature = "kv-rocksdb", feature = "kv-tikv"))]
 pub(crate) struct UniqueRangeReverseThingIterator {
 	irf: IteratorRef,
+	include: bool,
 	r: ReverseRangeScan,
 	done: bool,
 }
@@ -1179,6 +1332,7 @@ impl UniqueRangeReverseThingIterator {
 		let r = ReverseRangeScan::new(UniqueRangeThingIterator::range_scan(ns, db, ix, &r)?);
 		Ok(Self {
 			irf,
+			include: ix.include.is_some(),
 			r,
 			done: false,
 		})
@@ -1198,8 +1352,7 @@ impl UniqueRangeReverseThingIterator {
 			self.r.end_incl = false;
 			// tx.scanr is end exclusive, so we have to manually collect the value using a get
 			if let Some(v) = tx.get(&self.r.r.end, None).await? {
-				let rid: Thing = revision::from_slice(&v)?;
-				let record = IndexItemRecord::new_key(rid, self.irf.into());
+				let record = IndexItemRecord::new_entry(self.include, &v, self.irf.into())?;
 				limit -= 1;
 				if limit == 0 {
 					return Ok(B::from_one(record));
@@ -1230,8 +1383,7 @@ impl UniqueRangeReverseThingIterator {
 			records.add(record);
 		}
 		for (_, v) in res {
-			let rid: Thing = revision::from_slice(&v)?;
-			records.add(IndexItemRecord::new_key(rid, self.irf.into()));
+			records.add(IndexItemRecord::new_entry(self.include, &v, self.irf.into())?);
 		}
 		Ok(records)
 	}
@@ -1274,6 +1426,7 @@ impl UniqueRangeReverseThingIterator {
 
 pub(crate) struct UniqueUnionThingIterator {
 	irf: IteratorRef,
+	include: bool,
 	keys: VecDeque<Key>,
 }
 
@@ -1293,6 +1446,7 @@ impl UniqueUnionThingIterator {
 		}
 		Ok(Self {
 			irf,
+			include: ix.include.is_some(),
 			keys,
 		})
 	}
@@ -1312,8 +1466,7 @@ impl UniqueUnionThingIterator {
 			}
 			if let Some(val) = tx.get(key, None).await? {
 				count += 1;
-				let rid: Thing = revision::from_slice(&val)?;
-				results.add(IndexItemRecord::new_key(rid, self.irf.into()));
+				results.add(IndexItemRecord::new_entry(self.include, &val, self.irf.into())?);
 				if results.len() >= limit {
 					break;
 				}
@@ -1393,22 +1546,18 @@ pub(crate) struct MatchesThingIterator {
 }
 
 impl MatchesThingIterator {
-	pub(super) async fn new(
-		irf: IteratorRef,
-		fti: &FtIndex,
-		terms_docs: TermsDocs,
-	) -> Result<Self, Error> {
-		let hits = fti.new_hits_iterator(terms_docs)?;
+	pub(super) fn new(irf: IteratorRef, fti: &FtIndex, docs: RoaringTreemap) -> Self {
+		let hits = fti.new_hits_iterator(docs);
 		let hits_left = if let Some(h) = &hits {
 			h.len()
 		} else {
 			0
 		};
-		Ok(Self {
+		Self {
 			irf,
 			hits,
 			hits_left,
-		})
+		}
 	}
 
 	async fn next_batch<B: IteratorBatch>(
@@ -1513,6 +1662,80 @@ impl KnnIterator {
 	}
 }
 
+pub(crate) struct GeohashThingIterator {
+	irf: IteratorRef,
+	tb: String,
+	ranges: VecDeque<Range<Key>>,
+	/// A record is stored in every cell covering its geometry, it is only returned once
+	seen: HashSet<Id>,
+}
+
+impl GeohashThingIterator {
+	pub(super) fn new(irf: IteratorRef, tb: &str, ranges: VecDeque<Range<Key>>) -> Self {
+		Self {
+			irf,
+			tb: tb.to_owned(),
+			ranges,
+			seen: Default::default(),
+		}
+	}
+
+	async fn next_ids(
+		&mut self,
+		ctx: &Context,
+		tx: &Transaction,
+		limit: u32,
+	) -> Result<Vec<Id>, Error> {
+		let limit = limit as usize;
+		let mut ids = Vec::with_capacity(limit);
+		while limit > ids.len() && !ctx.is_done(true) {
+			let Some(range) = self.ranges.front_mut() else {
+				break;
+			};
+			let scan_limit = limit - ids.len();
+			let keys = tx.keys(range.clone(), scan_limit as u32, None).await?;
+			if keys.len() < scan_limit {
+				self.ranges.pop_front();
+			} else if let Some(key) = keys.last() {
+				let mut key = key.clone();
+				key.push(0x00);
+				range.start = key;
+			}
+			for key in keys {
+				let id = Gh::decode(&key)?.id.into_owned();
+				if self.seen.insert(id.clone()) {
+					ids.push(id);
+				}
+			}
+		}
+		Ok(ids)
+	}
+
+	async fn next_batch<B: IteratorBatch>(
+		&mut self,
+		ctx: &Context,
+		tx: &Transaction,
+		limit: u32,
+	) -> Result<B, Error> {
+		let ids = self.next_ids(ctx, tx, limit).await?;
+		let mut records = B::with_capacity(ids.len());
+		for id in ids {
+			let thing = Thing::from((self.tb.clone(), id));
+			records.add(IndexItemRecord::new_key(thing, self.irf.into()));
+		}
+		Ok(records)
+	}
+
+	async fn next_count(
+		&mut self,
+		ctx: &Context,
+		tx: &Transaction,
+		limit: u32,
+	) -> Result<usize, Error> {
+		Ok(self.next_ids(ctx, tx, limit).await?.len())
+	}
+}
+
 pub(crate) struct MultipleIterators {
 	iterators: VecDeque<ThingIterator>,
 	current: Option<ThingIterator>,
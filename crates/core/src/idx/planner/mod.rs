@@ -9,13 +9,19 @@ pub(in crate::idx) mod tree;
 use crate::ctx::Context;
 use crate::dbs::{Iterable, Iterator, Options, Statement};
 use crate::err::Error;
+use crate::idx::entry::IndexEntry;
 use crate::idx::planner::executor::{InnerQueryExecutor, IteratorEntry, QueryExecutor};
 use crate::idx::planner::iterators::IteratorRef;
 use crate::idx::planner::knn::KnnBruteForceResults;
 use crate::idx::planner::plan::{Plan, PlanBuilder, PlanBuilderParameters};
 use crate::idx::planner::tree::Tree;
+use crate::sql::paths::ID;
+use crate::sql::statements::DefineIndexStatement;
 use crate::sql::with::With;
-use crate::sql::{order::Ordering, Cond, Fields, Groups, Table};
+use crate::sql::{
+	order::Ordering, Cond, Expression, Field, Fields, Groups, Idiom, Permission, Subquery, Table,
+	Value,
+};
 use reblessive::tree::Stk;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
@@ -43,6 +49,8 @@ pub(crate) enum RecordStrategy {
 	Count,
 	KeysOnly,
 	KeysAndValues,
+	/// The values are read from the entries of a covering index (`INCLUDE` clause)
+	Covering,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -99,6 +107,10 @@ impl<'a> StatementContext<'a> {
 		if !self.is_perm {
 			return Ok(GrantedPermission::Full);
 		}
+		// Custom roles can grant access to all records in a table
+		if self.opt.is_table_granted(self.stm.into(), tb)? {
+			return Ok(GrantedPermission::Full);
+		}
 		// Get the table for this planner
 		match self.ctx.tx().get_tb(self.ns, self.db, tb).await {
 			Ok(table) => {
@@ -217,6 +229,113 @@ impl<'a> StatementContext<'a> {
 		}
 		ScanDirection::Forward
 	}
+
+	/// Checks if the entries of the given index (`INCLUDE` clause) contain every value
+	/// required by the statement, so the records don't need to be fetched from the table.
+	pub(crate) async fn is_covering_index(
+		&self,
+		ix: &DefineIndexStatement,
+		granted_permission: GrantedPermission,
+	) -> Result<bool, Error> {
+		let Some(include) = &ix.include else {
+			return Ok(false);
+		};
+		let Statement::Select(stm) = self.stm else {
+			return Ok(false);
+		};
+		if stm.omit.is_some() || stm.split.is_some() || stm.fetch.is_some() || stm.version.is_some()
+		{
+			return Ok(false);
+		}
+		// The permissions would be evaluated against the partial document
+		if !matches!(granted_permission, GrantedPermission::Full) {
+			return Ok(false);
+		}
+		if self.is_perm {
+			let fields = self.ctx.tx().all_tb_fields(self.ns, self.db, &ix.what, None).await?;
+			if fields.iter().any(|fd| !matches!(fd.permissions.select, Permission::Full)) {
+				return Ok(false);
+			}
+		}
+		let covered = CoveredIdioms(
+			ix.cols.iter().chain(include.iter()).filter(|i| IndexEntry::is_storable(i)).collect(),
+		);
+		let Some(fields) = self.fields else {
+			return Ok(false);
+		};
+		for f in fields.iter() {
+			match f {
+				Field::All
+				| Field::Window {
+					..
+				} => return Ok(false),
+				Field::Single {
+					expr,
+					..
+				} => {
+					if !covered.value(expr) {
+						return Ok(false);
+					}
+				}
+			}
+		}
+		if let Some(cond) = self.cond {
+			if !covered.value(&cond.0) {
+				return Ok(false);
+			}
+		}
+		if let Some(groups) = self.group {
+			if !groups.iter().all(|g| covered.idiom(&g.0)) {
+				return Ok(false);
+			}
+		}
+		if let Some(Ordering::Order(o)) = self.order {
+			if !o.iter().all(|o| covered.idiom(&o.value)) {
+				return Ok(false);
+			}
+		}
+		Ok(true)
+	}
+}
+
+/// The parameters which are set from the document being processed
+const DOCUMENT_PARAMS: [&str; 7] = ["this", "parent", "value", "before", "after", "input", "event"];
+
+/// The idioms available in the partial documents stored by a covering index
+struct CoveredIdioms<'a>(Vec<&'a Idiom>);
+
+impl CoveredIdioms<'_> {
+	fn idiom(&self, i: &Idiom) -> bool {
+		i.starts_with(ID.as_ref()) || self.0.iter().any(|c| i.starts_with(c))
+	}
+
+	/// Checks that every idiom used by the value is available.
+	/// Any value which may depend on the rest of the document is rejected.
+	fn value(&self, v: &Value) -> bool {
+		match v {
+			Value::Idiom(i) => self.idiom(i),
+			Value::Param(p) => !DOCUMENT_PARAMS.contains(&p.0 .0.as_str()),
+			Value::Array(a) => a.iter().all(|v| self.value(v)),
+			Value::Object(o) => o.values().all(|v| self.value(v)),
+			Value::Subquery(s) => match s.as_ref() {
+				Subquery::Value(v) => self.value(v),
+				_ => false,
+			},
+			Value::Expression(e) => match e.as_ref() {
+				Expression::Unary {
+					v,
+					..
+				} => self.value(v),
+				Expression::Binary {
+					l,
+					r,
+					..
+				} => self.value(l) && self.value(r),
+			},
+			Value::Function(f) => f.is_pure() && f.args().iter().all(|v| self.value(v)),
+			v => v.is_static(),
+		}
+	}
 }
 
 pub(crate) struct QueryPlanner {
@@ -288,12 +407,14 @@ impl QueryPlanner {
 			tree.knn_expressions,
 			tree.knn_brute_force_expressions,
 			tree.knn_condition,
+			ctx.cond.cloned(),
 		)
 		.await?;
 		let p = PlanBuilderParameters {
 			root: tree.root,
 			gp,
 			compound_indexes: tree.index_map.compound_indexes,
+			compound_ranges: tree.index_map.compound_ranges,
 			order_limit: tree.index_map.order_limit,
 			with_indexes: tree.with_indexes,
 			all_and: tree.all_and,
@@ -306,7 +427,7 @@ impl QueryPlanner {
 				if io.require_distinct() {
 					self.requires_distinct = true;
 				}
-				let is_order = exp.is_none();
+				let is_order = io.is_order();
 				let ir = exe.add_iterator(IteratorEntry::Single(exp, io));
 				self.add(t.clone(), Some(ir), exe, it, rs);
 				if is_order {
@@ -1,9 +1,10 @@
 use crate::err::Error;
 use crate::idx::ft::MatchRef;
 use crate::idx::planner::tree::{
-	CompoundIndexes, GroupRef, IdiomCol, IdiomPosition, IndexReference, Node,
+	CompoundIndexes, CompoundRanges, GroupRef, IdiomCol, IdiomPosition, IndexReference, Node,
 };
 use crate::idx::planner::{GrantedPermission, RecordStrategy, ScanDirection, StatementContext};
+use crate::sql::order::{OrderList, Ordering};
 use crate::sql::with::With;
 use crate::sql::{Array, Expression, Idiom, Number, Object};
 use crate::sql::{Operator, Value};
@@ -28,6 +29,7 @@ pub(super) struct PlanBuilderParameters {
 	pub(super) root: Option<Node>,
 	pub(super) gp: GrantedPermission,
 	pub(super) compound_indexes: CompoundIndexes,
+	pub(super) compound_ranges: CompoundRanges,
 	pub(super) order_limit: Option<IndexOption>,
 	pub(super) with_indexes: Option<Vec<IndexReference>>,
 	pub(super) all_and: bool,
@@ -62,16 +64,19 @@ impl PlanBuilder {
 
 		// If all boolean operators are AND, we can use the single index plan
 		if p.all_and {
-			// We try first the largest compound indexed
-			let mut compound_index = None;
+			// We try first the compound index satisfying the order, then the largest one
+			let mut compound_index: Option<(IdiomCol, IndexOption)> = None;
 			for (ixr, vals) in p.compound_indexes {
-				if let Some((cols, io)) = b.check_compound_index(ixr, vals) {
-					if let Some((c, _)) = &compound_index {
-						if cols <= *c {
+				let ranges = p.compound_ranges.get(&ixr);
+				if let Some((cols, io)) =
+					b.check_compound_index(ctx, ixr, vals, ranges, p.reverse_scan)
+				{
+					if let Some((c, current)) = &compound_index {
+						if (io.is_order(), cols) <= (current.is_order(), *c) {
 							continue;
 						}
 					}
-					if cols > 1 {
+					if cols > 1 || io.is_order() {
 						compound_index = Some((cols, io));
 					}
 				}
@@ -79,6 +84,8 @@ impl PlanBuilder {
 			if let Some((_, io)) = compound_index {
 				// Evaluate if we can use keys only
 				let record_strategy = ctx.check_record_strategy(true, p.gp)?;
+				let record_strategy =
+					Self::covering_strategy(ctx, record_strategy, io.ix_ref(), p.gp).await?;
 				// Return the plan
 				return Ok(Plan::SingleIndex(None, io, record_strategy));
 			}
@@ -88,6 +95,8 @@ impl PlanBuilder {
 				if let Some((ir, rq)) = group.take_first_range() {
 					// Evaluate the record strategy
 					let record_strategy = ctx.check_record_strategy(true, p.gp)?;
+					let record_strategy =
+						Self::covering_strategy(ctx, record_strategy, &ir, p.gp).await?;
 					// Return the plan
 					return Ok(Plan::SingleIndexRange(ir, rq, record_strategy));
 				}
@@ -96,7 +105,9 @@ impl PlanBuilder {
 			// Otherwise, we try to find the most interesting (todo: TBD) single index option
 			if let Some((e, i)) = b.non_range_indexes.pop() {
 				// Evaluate the record strategy
-				let record_strategy = ctx.check_record_strategy(true, p.gp)?;
+				let record_strategy = ctx.check_record_strategy(!i.is_approximate(), p.gp)?;
+				let record_strategy =
+					Self::covering_strategy(ctx, record_strategy, i.ix_ref(), p.gp).await?;
 				// Return the plan
 				return Ok(Plan::SingleIndex(Some(e), i, record_strategy));
 			}
@@ -104,6 +115,8 @@ impl PlanBuilder {
 			if let Some(o) = p.order_limit {
 				// Evaluate the record strategy
 				let record_strategy = ctx.check_record_strategy(true, p.gp)?;
+				let record_strategy =
+					Self::covering_strategy(ctx, record_strategy, o.ix_ref(), p.gp).await?;
 				// Check it is compatible with the reverse scan capability
 				if Self::check_order_scan(p.reverse_scan, o.op()) {
 					// Return the plan
@@ -122,7 +135,8 @@ impl PlanBuilder {
 				}
 			}
 			// Evaluate the record strategy
-			let record_strategy = ctx.check_record_strategy(true, p.gp)?;
+			let exact = !b.non_range_indexes.iter().any(|(_, io)| io.is_approximate());
+			let record_strategy = ctx.check_record_strategy(exact, p.gp)?;
 			// Return the plan
 			return Ok(Plan::MultiIndex(b.non_range_indexes, ranges, record_strategy));
 		}
@@ -143,6 +157,21 @@ impl PlanBuilder {
 		Ok(Plan::TableIterator(reason, rs, sc))
 	}
 
+	/// If the values have to be fetched, checks if they can be read from the index entries instead
+	async fn covering_strategy(
+		ctx: &StatementContext<'_>,
+		rs: RecordStrategy,
+		ixr: &IndexReference,
+		granted_permission: GrantedPermission,
+	) -> Result<RecordStrategy, Error> {
+		if matches!(rs, RecordStrategy::KeysAndValues)
+			&& ctx.is_covering_index(ixr, granted_permission).await?
+		{
+			return Ok(RecordStrategy::Covering);
+		}
+		Ok(rs)
+	}
+
 	/// Check if we have an explicit list of index that we should use
 	fn filter_index_option(&self, io: Option<&IndexOption>) -> Option<IndexOption> {
 		if let Some(io) = io {
@@ -169,10 +198,14 @@ impl PlanBuilder {
 	}
 
 	/// Check if a compound index can be used.
+	/// Returns the number of columns covered by the conditions, and the index option.
 	fn check_compound_index(
 		&self,
+		ctx: &StatementContext<'_>,
 		ixr: IndexReference,
 		columns: Vec<Vec<Arc<Value>>>,
+		ranges: Option<&Vec<(IdiomCol, Operator, Arc<Value>)>>,
+		reverse_scan: bool,
 	) -> Option<(IdiomCol, IndexOption)> {
 		// Check the index can be used
 		if !self.allowed_index(&ixr) {
@@ -192,9 +225,40 @@ impl PlanBuilder {
 		if cont == 0 {
 			return None;
 		}
-		let combinations = Self::cartesian_product(&columns);
+		let combinations = Self::cartesian_product(&columns[..cont]);
 		if combinations.len() == 1 {
 			let val: Vec<Value> = combinations[0].iter().map(|v| v.as_ref().clone()).collect();
+			// The column following the equality prefix may be constrained by a range,
+			// and the remaining columns may satisfy the ORDER BY clause
+			if cont < ixr.cols.len() {
+				let (from, to) = Self::compound_range(cont, ranges);
+				let has_range = !from.value.is_none() || !to.value.is_none();
+				// Numbers are scanned as one range per number type, the order is then lost
+				let order = if val.iter().any(Value::is_number)
+					|| from.value.is_number()
+					|| to.value.is_number()
+				{
+					None
+				} else {
+					Self::check_compound_order(ctx, &ixr, cont, reverse_scan)
+				};
+				if has_range || order.is_some() {
+					return Some((
+						cont + has_range as usize,
+						IndexOption::new(
+							ixr,
+							None,
+							IdiomPosition::None,
+							IndexOperator::Range(
+								Arc::new(Value::Array(Array(val))),
+								from,
+								to,
+								order,
+							),
+						),
+					));
+				}
+			}
 			return Some((
 				cont,
 				IndexOption::new(
@@ -223,6 +287,60 @@ impl PlanBuilder {
 		))
 	}
 
+	/// Aggregate the range conditions applying to the given column.
+	fn compound_range(
+		col: IdiomCol,
+		ranges: Option<&Vec<(IdiomCol, Operator, Arc<Value>)>>,
+	) -> (RangeValue, RangeValue) {
+		let mut from = RangeValue::default();
+		let mut to = RangeValue::default();
+		for (_, op, val) in ranges.into_iter().flatten().filter(|(c, _, _)| *c == col) {
+			match op {
+				Operator::LessThan => to.set_to(val),
+				Operator::LessThanOrEqual => to.set_to_inclusive(val),
+				Operator::MoreThan => from.set_from(val),
+				Operator::MoreThanOrEqual => from.set_from_inclusive(val),
+				_ => {}
+			}
+		}
+		(from, to)
+	}
+
+	/// Check if scanning the index from the given column satisfies the ORDER BY clause.
+	/// The columns of the equality prefix are constant, they can be skipped.
+	/// Returns `Some(true)` if the index should be scanned backward.
+	fn check_compound_order(
+		ctx: &StatementContext<'_>,
+		ixr: &IndexReference,
+		cont: IdiomCol,
+		reverse_scan: bool,
+	) -> Option<bool> {
+		let Some(Ordering::Order(OrderList(orders))) = ctx.order else {
+			return None;
+		};
+		let mut col = cont;
+		let mut reverse = None;
+		for o in orders {
+			if o.collate || o.numeric {
+				return None;
+			}
+			if ixr.cols[..cont].contains(&o.value) {
+				continue;
+			}
+			if ixr.cols.get(col) != Some(&o.value) {
+				return None;
+			}
+			if *reverse.get_or_insert(!o.direction) == o.direction {
+				return None;
+			}
+			col += 1;
+		}
+		if reverse == Some(true) && !reverse_scan {
+			return None;
+		}
+		reverse
+	}
+
 	fn cartesian_product(values: &[Vec<Arc<Value>>]) -> Vec<Vec<Arc<Value>>> {
 		values.iter().fold(vec![vec![]], |acc, v| {
 			acc.iter()
@@ -319,11 +437,18 @@ pub(super) enum IndexOperator {
 	Union(Arc<Value>),
 	Join(Vec<IndexOption>),
 	RangePart(Operator, Arc<Value>),
-	Matches(String, Option<MatchRef>),
+	Matches(String, Option<MatchRef>, Option<u8>),
 	Knn(Arc<Vec<Number>>, u32),
 	Ann(Arc<Vec<Number>>, u32, u32),
 	/// false = ascending, true = descending
 	Order(bool),
+	/// Compound index scan: the values of the leading columns,
+	/// the range on the following column, and the direction if the scan satisfies the order
+	Range(Arc<Value>, RangeValue, RangeValue, Option<bool>),
+	/// Geospatial predicate (INSIDE, CONTAINS or INTERSECTS) with the indexed field on the left
+	Geo(Operator, Arc<Value>),
+	/// `geo::distance(field, origin)` less than (or equal to) the given distance
+	GeoDistance(Operator, Arc<Value>, Arc<Value>),
 }
 
 impl IndexOption {
@@ -345,6 +470,16 @@ impl IndexOption {
 		matches!(self.op.as_ref(), IndexOperator::Union(_))
 	}
 
+	/// True if the index scan returns the records in the order requested by the statement
+	pub(super) fn is_order(&self) -> bool {
+		matches!(self.op.as_ref(), IndexOperator::Order(_) | IndexOperator::Range(.., Some(_)))
+	}
+
+	/// True if the index returns candidates which still have to be checked against the condition
+	pub(super) fn is_approximate(&self) -> bool {
+		matches!(self.op.as_ref(), IndexOperator::Geo(..) | IndexOperator::GeoDistance(..))
+	}
+
 	pub(super) fn ix_ref(&self) -> &IndexReference {
 		&self.ixr
 	}
@@ -391,8 +526,8 @@ impl IndexOption {
 				let joins = Value::from(joins);
 				e.insert("joins", joins);
 			}
-			IndexOperator::Matches(qs, a) => {
-				e.insert("operator", Value::from(Operator::Matches(*a).to_string()));
+			IndexOperator::Matches(qs, a, f) => {
+				e.insert("operator", Value::from(Operator::Matches(*a, *f).to_string()));
 				e.insert("value", Value::from(qs.to_owned()));
 			}
 			IndexOperator::RangePart(op, v) => {
@@ -411,6 +546,15 @@ impl IndexOption {
 				e.insert("operator", op);
 				e.insert("value", val);
 			}
+			IndexOperator::Geo(op, v) => {
+				e.insert("operator", Value::from(op.to_string()));
+				e.insert("value", v.as_ref().to_owned());
+			}
+			IndexOperator::GeoDistance(op, origin, d) => {
+				e.insert("operator", Value::from(format!("geo::distance {op}")));
+				e.insert("origin", origin.as_ref().to_owned());
+				e.insert("value", d.as_ref().to_owned());
+			}
 			IndexOperator::Order(reverse) => {
 				e.insert(
 					"operator",
@@ -421,6 +565,19 @@ impl IndexOption {
 					}),
 				);
 			}
+			IndexOperator::Range(prefix, from, to, order) => {
+				e.insert("prefix", Self::reduce_array(prefix));
+				e.insert("from", Value::from(from));
+				e.insert("to", Value::from(to));
+				e.insert(
+					"direction",
+					Value::from(if *order == Some(true) {
+						"backward"
+					} else {
+						"forward"
+					}),
+				);
+			}
 		};
 		Value::from(e)
 	}
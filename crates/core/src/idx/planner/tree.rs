@@ -6,14 +6,15 @@ use crate::idx::planner::executor::{
 use crate::idx::planner::plan::{IndexOperator, IndexOption};
 use crate::idx::planner::rewriter::KnnConditionRewriter;
 use crate::idx::planner::StatementContext;
+use crate::idx::trees::geohash::GeohashIndex;
 use crate::kvs::Transaction;
 use crate::sql::index::Index;
 use crate::sql::statements::{DefineFieldStatement, DefineIndexStatement};
 use crate::sql::FlowResultExt as _;
 use crate::sql::{
 	order::{OrderList, Ordering},
-	Array, Cond, Expression, Idiom, Kind, Number, Operator, Order, Part, Subquery, Table, Value,
-	With,
+	Array, Cond, Expression, Function, Geometry, Idiom, Kind, Number, Operator, Order, Part,
+	Subquery, Table, Value, With,
 };
 use reblessive::tree::Stk;
 use std::collections::HashMap;
@@ -181,6 +182,13 @@ impl<'a> TreeBuilder<'a> {
 		match v {
 			Value::Expression(e) => self.eval_expression(stk, group, e).await,
 			Value::Idiom(i) => self.eval_idiom(stk, group, i).await,
+			Value::Function(_) => {
+				self.leaf_nodes_count += 1;
+				if let Some(n) = self.resolve_indexed_expression(v).await? {
+					return Ok(n);
+				}
+				Ok(Node::Computable)
+			}
 			Value::Strand(_)
 			| Value::Number(_)
 			| Value::Bool(_)
@@ -192,8 +200,7 @@ impl<'a> TreeBuilder<'a> {
 			| Value::Datetime(_)
 			| Value::Param(_)
 			| Value::Null
-			| Value::None
-			| Value::Function(_) => {
+			| Value::None => {
 				self.leaf_nodes_count += 1;
 				Ok(Node::Computable)
 			}
@@ -273,6 +280,21 @@ impl<'a> TreeBuilder<'a> {
 		Ok(n)
 	}
 
+	/// Check if the value matches an expression used as an index column
+	async fn resolve_indexed_expression(&mut self, v: &Value) -> Result<Option<Node>, Error> {
+		let tx = self.ctx.ctx.tx();
+		self.lazy_load_schema_resolver(&tx, self.table).await?;
+		let Some(schema) = self.schemas.get(self.table).cloned() else {
+			return Ok(None);
+		};
+		let i = Idiom(vec![Part::Start(v.clone())]);
+		let irs = self.resolve_indexes(self.table, &i, &schema);
+		if irs.is_empty() {
+			return Ok(None);
+		}
+		Ok(Some(Node::IndexedField(Arc::new(i), irs)))
+	}
+
 	fn resolve_indexes(&mut self, t: &Table, i: &Idiom, schema: &SchemaCache) -> LocalIndexRefs {
 		// Did we already resolve this idiom?
 		if let Some(m) = self.idioms_indexes.get(t) {
@@ -282,6 +304,9 @@ impl<'a> TreeBuilder<'a> {
 		}
 		let mut irs = Vec::new();
 		for (idx, ix) in schema.indexes.iter().enumerate() {
+			if !self.is_index_applicable(t, ix) {
+				continue;
+			}
 			if let Some(idiom_index) = ix.cols.iter().position(|p| p.eq(i)) {
 				let ixr = schema.new_reference(idx);
 				// Check if the WITH clause allow the index to be used
@@ -306,6 +331,52 @@ impl<'a> TreeBuilder<'a> {
 		irs
 	}
 
+	/// A partial index only contains the records matching its condition.
+	/// It can be used if every part of this condition is also required by the statement.
+	fn is_index_applicable(&self, t: &Table, ix: &DefineIndexStatement) -> bool {
+		let Some(ix_cond) = &ix.cond else {
+			return true;
+		};
+		// The statement condition only applies to the table being queried
+		if t != self.table {
+			return false;
+		}
+		let Some(cond) = self.ctx.cond else {
+			return false;
+		};
+		let mut conjuncts = Vec::new();
+		Self::collect_conjuncts(&cond.0, &mut conjuncts);
+		let mut required = Vec::new();
+		Self::collect_conjuncts(&ix_cond.0, &mut required);
+		required.iter().all(|r| conjuncts.contains(r))
+	}
+
+	/// Collect the expressions joined by top-level AND operators
+	fn collect_conjuncts<'b>(v: &'b Value, conjuncts: &mut Vec<&'b Value>) {
+		match v {
+			Value::Expression(e) => {
+				if let Expression::Binary {
+					l,
+					o: Operator::And,
+					r,
+				} = e.as_ref()
+				{
+					Self::collect_conjuncts(l, conjuncts);
+					Self::collect_conjuncts(r, conjuncts);
+					return;
+				}
+			}
+			Value::Subquery(s) => {
+				if let Subquery::Value(v) = s.as_ref() {
+					Self::collect_conjuncts(v, conjuncts);
+					return;
+				}
+			}
+			_ => {}
+		}
+		conjuncts.push(v);
+	}
+
 	async fn resolve_record_field(
 		&mut self,
 		tx: &Transaction,
@@ -373,6 +444,9 @@ impl<'a> TreeBuilder<'a> {
 				if let Some(re) = self.resolved_expressions.get(e).cloned() {
 					return Ok(re.into());
 				}
+				if let Some(re) = self.eval_geo_distance(stk, group, e, l, o, r).await? {
+					return Ok(re.into());
+				}
 				self.check_boolean_operator(group, o);
 				let left = stk.run(|stk| self.eval_value(stk, group, l)).await?;
 				let right = stk.run(|stk| self.eval_value(stk, group, r)).await?;
@@ -425,6 +499,88 @@ impl<'a> TreeBuilder<'a> {
 		}
 	}
 
+	/// Resolves `geo::distance(field, origin) < distance` when the field has a geohash index
+	#[allow(clippy::too_many_arguments)]
+	async fn eval_geo_distance(
+		&mut self,
+		stk: &mut Stk,
+		group: GroupRef,
+		e: &Expression,
+		l: &Value,
+		o: &Operator,
+		r: &Value,
+	) -> Result<Option<ResolvedExpression>, Error> {
+		let (f, d, p) = match (l, r) {
+			(Value::Function(f), d) => (f, d, IdiomPosition::Left),
+			(d, Value::Function(f)) => (f, d, IdiomPosition::Right),
+			_ => return Ok(None),
+		};
+		let op = p.transform(o);
+		if !matches!(op, Operator::LessThan | Operator::LessThanOrEqual) {
+			return Ok(None);
+		}
+		let Function::Normal(name, args) = f.as_ref() else {
+			return Ok(None);
+		};
+		if name != "geo::distance" || args.len() != 2 {
+			return Ok(None);
+		}
+		for (field, origin) in [(&args[0], &args[1]), (&args[1], &args[0])] {
+			let Value::Idiom(i) = field else {
+				continue;
+			};
+			let Node::IndexedField(id, irs) = self.resolve_idiom(i).await? else {
+				continue;
+			};
+			let Some((ixr, _)) =
+				irs.iter().find(|(ixr, col)| *col == 0 && matches!(ixr.index, Index::Geohash(_)))
+			else {
+				continue;
+			};
+			let (Some(origin), Some(d)) = (
+				stk.run(|stk| origin.compute(stk, self.ctx.ctx, self.ctx.opt, None))
+					.await
+					.catch_return()
+					.ok(),
+				stk.run(|stk| d.compute(stk, self.ctx.ctx, self.ctx.opt, None))
+					.await
+					.catch_return()
+					.ok(),
+			) else {
+				return Ok(None);
+			};
+			if !matches!(origin, Value::Geometry(Geometry::Point(_)))
+				|| !matches!(&d, Value::Number(n) if n.to_float() >= 0.0)
+			{
+				return Ok(None);
+			}
+			let exp = Arc::new(e.clone());
+			let d = Arc::new(d);
+			let op = IndexOperator::GeoDistance(op, Arc::new(origin), d.clone());
+			let io = IndexOption::new(ixr.clone(), Some(id.clone()), p, op);
+			self.index_map.options.push((exp.clone(), io.clone()));
+			self.leaf_nodes_count += 2;
+			self.check_boolean_operator(group, o);
+			self.check_leaf_node_with_index(Some(&io));
+			let field = Arc::new(Node::IndexedField(id, irs));
+			let d = Arc::new(Node::Computed(d));
+			let (left, right) = match p {
+				IdiomPosition::Left => (field, d),
+				_ => (d, field),
+			};
+			let re = ResolvedExpression {
+				group,
+				exp: exp.clone(),
+				io: Some(io),
+				left,
+				right,
+			};
+			self.resolved_expressions.insert(exp, re.clone());
+			return Ok(Some(re));
+		}
+		Ok(None)
+	}
+
 	fn check_boolean_operator(&mut self, gr: GroupRef, op: &Operator) {
 		match op {
 			Operator::Neg | Operator::Or => {
@@ -506,6 +662,7 @@ impl<'a> TreeBuilder<'a> {
 				} if *col == 0 => Self::eval_matches_operator(op, n),
 				Index::MTree(_) if *col == 0 => self.eval_mtree_knn(e, op, n)?,
 				Index::Hnsw(_) if *col == 0 => self.eval_hnsw_knn(e, op, n)?,
+				Index::Geohash(_) if *col == 0 => Self::eval_geo_operator(op, n, p),
 				_ => None,
 			};
 			if res.is_none() {
@@ -531,13 +688,33 @@ impl<'a> TreeBuilder<'a> {
 
 	fn eval_matches_operator(op: &Operator, n: &Node) -> Option<IndexOperator> {
 		if let Some(v) = n.is_computed() {
-			if let Operator::Matches(mr) = op {
-				return Some(IndexOperator::Matches(v.to_raw_string(), *mr));
+			if let Operator::Matches(mr, fz) = op {
+				return Some(IndexOperator::Matches(v.to_raw_string(), *mr, *fz));
 			}
 		}
 		None
 	}
 
+	fn eval_geo_operator(op: &Operator, n: &Node, p: IdiomPosition) -> Option<IndexOperator> {
+		let v = n.is_computed()?;
+		let Value::Geometry(g) = v.as_ref() else {
+			return None;
+		};
+		// Without bounding box (empty collection) every geometry may match
+		GeohashIndex::geometry_rect(g)?;
+		// The operator is expressed with the field on the left
+		let op =
+			match (op, p) {
+				(Operator::Inside, IdiomPosition::Left)
+				| (Operator::Contain, IdiomPosition::Right) => Operator::Inside,
+				(Operator::Contain, IdiomPosition::Left)
+				| (Operator::Inside, IdiomPosition::Right) => Operator::Contain,
+				(Operator::Intersects, _) => Operator::Intersects,
+				_ => return None,
+			};
+		Some(IndexOperator::Geo(op, v))
+	}
+
 	fn eval_mtree_knn(
 		&mut self,
 		exp: &Arc<Expression>,
@@ -636,9 +813,11 @@ impl<'a> TreeBuilder<'a> {
 					v,
 					p,
 				) => {
+					let op = p.transform(op);
 					if col == 0 {
-						return Some(IndexOperator::RangePart(p.transform(op), v));
+						return Some(IndexOperator::RangePart(op, v));
 					}
+					self.index_map.check_compound_range(ixr, col, op, &v);
 				}
 				_ => {}
 			}
@@ -656,6 +835,7 @@ impl<'a> TreeBuilder<'a> {
 }
 
 pub(super) type CompoundIndexes = HashMap<IndexReference, Vec<Vec<Arc<Value>>>>;
+pub(super) type CompoundRanges = HashMap<IndexReference, Vec<(IdiomCol, Operator, Arc<Value>)>>;
 
 /// For each expression a possible index option
 #[derive(Default)]
@@ -663,6 +843,8 @@ pub(super) struct IndexesMap {
 	pub(super) options: Vec<(Arc<Expression>, IndexOption)>,
 	/// For each index, tells if the columns are requested
 	pub(super) compound_indexes: CompoundIndexes,
+	/// For each index, the range conditions on the columns after the first one
+	pub(super) compound_ranges: CompoundRanges,
 	pub(super) order_limit: Option<IndexOption>,
 }
 
@@ -675,6 +857,16 @@ impl IndexesMap {
 		}
 	}
 
+	pub(crate) fn check_compound_range(
+		&mut self,
+		ixr: &IndexReference,
+		col: usize,
+		op: Operator,
+		val: &Arc<Value>,
+	) {
+		self.compound_ranges.entry(ixr.clone()).or_default().push((col, op, val.clone()));
+	}
+
 	pub(crate) fn check_compound_array(&mut self, ixr: &IndexReference, col: usize, a: &Array) {
 		for v in a.iter() {
 			self.check_compound(ixr, col, &Arc::new(v.clone()))
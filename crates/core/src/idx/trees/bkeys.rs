@@ -1,7 +1,7 @@
 use crate::err::Error;
 use crate::idx::trees::btree::Payload;
 use crate::kvs::Key;
-use fst::{IntoStreamer, Map, MapBuilder, Streamer};
+use fst::{Automaton, IntoStreamer, Map, MapBuilder, Streamer};
 use radix_trie::{SubTrie, Trie, TrieCommon};
 use serde::ser;
 use std::collections::VecDeque;
@@ -19,6 +19,7 @@ pub trait BKeys: Default + Debug + Display + Sized {
 	// The size of the Node should be small, therefore one instance of
 	// BKeys would never be store a large volume of keys.
 	fn collect_with_prefix(&self, prefix_key: &Key) -> Result<VecDeque<(Key, Payload)>, Error>;
+	fn collect_matching<A: Automaton>(&self, aut: &A) -> Result<VecDeque<(Key, Payload)>, Error>;
 	fn insert(&mut self, key: Key, payload: Payload) -> Option<Payload>;
 	fn append(&mut self, keys: Self);
 	fn remove(&mut self, key: &Key) -> Option<Payload>;
@@ -32,6 +33,19 @@ pub trait BKeys: Default + Debug + Display + Sized {
 	fn compile(&mut self) {}
 }
 
+/// Feeds the automaton with the given bytes.
+/// Returns `None` as soon as the automaton can't match anymore.
+pub(super) fn run_automaton<A: Automaton>(aut: &A, bytes: &[u8]) -> Option<A::State> {
+	let mut state = aut.start();
+	for b in bytes {
+		if !aut.can_match(&state) {
+			return None;
+		}
+		state = aut.accept(&state, *b);
+	}
+	aut.can_match(&state).then_some(state)
+}
+
 #[non_exhaustive]
 pub struct SplitKeys<BK>
 where
@@ -102,8 +116,35 @@ impl BKeys for FstKeys {
 		}
 	}
 
-	fn collect_with_prefix(&self, _prefix_key: &Key) -> Result<VecDeque<(Key, Payload)>, Error> {
-		Err(fail!("BKeys/FSTKeys::collect_with_prefix"))
+	fn collect_with_prefix(&self, prefix_key: &Key) -> Result<VecDeque<(Key, Payload)>, Error> {
+		match &self.i {
+			Inner::Map(m) => {
+				let mut r = VecDeque::new();
+				let mut s = m.range().ge(prefix_key).into_stream();
+				while let Some((key, payload)) = s.next() {
+					if !key.starts_with(prefix_key) {
+						break;
+					}
+					r.push_back((key.to_vec(), payload));
+				}
+				Ok(r)
+			}
+			Inner::Trie(t) => t.collect_with_prefix(prefix_key),
+		}
+	}
+
+	fn collect_matching<A: Automaton>(&self, aut: &A) -> Result<VecDeque<(Key, Payload)>, Error> {
+		match &self.i {
+			Inner::Map(m) => {
+				let mut r = VecDeque::new();
+				let mut s = m.search(aut).into_stream();
+				while let Some((key, payload)) = s.next() {
+					r.push_back((key.to_vec(), payload));
+				}
+				Ok(r)
+			}
+			Inner::Trie(t) => t.collect_matching(aut),
+		}
 	}
 
 	fn insert(&mut self, key: Key, payload: Payload) -> Option<Payload> {
@@ -349,6 +390,16 @@ impl BKeys for TrieKeys {
 		Ok(r)
 	}
 
+	fn collect_matching<A: Automaton>(&self, aut: &A) -> Result<VecDeque<(Key, Payload)>, Error> {
+		let mut r = VecDeque::new();
+		for (k, p) in self.keys.iter() {
+			if run_automaton(aut, k).is_some_and(|s| aut.is_match(&s)) {
+				r.push_back((k.clone(), *p));
+			}
+		}
+		Ok(r)
+	}
+
 	fn insert(&mut self, key: Key, payload: Payload) -> Option<Payload> {
 		self.keys.insert(key, payload)
 	}
@@ -1,11 +1,12 @@
 use crate::err::Error;
-use crate::idx::trees::bkeys::BKeys;
+use crate::idx::trees::bkeys::{run_automaton, BKeys};
 use crate::idx::trees::store::{NodeId, StoreGeneration, StoredNode, TreeNode, TreeStore};
 use crate::idx::VersionedStore;
 use crate::kvs::{Key, Transaction, Val};
 use crate::sql::{Object, Value};
 #[cfg(debug_assertions)]
 use ahash::HashSet;
+use fst::Automaton;
 use revision::{revisioned, Revisioned};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
@@ -327,6 +328,74 @@ where
 		Ok(None)
 	}
 
+	/// Collects every key starting with the given prefix.
+	pub(in crate::idx) async fn search_by_prefix(
+		&self,
+		tx: &Transaction,
+		store: &BTreeStore<BK>,
+		prefix: &Key,
+	) -> Result<Vec<(Key, Payload)>, Error> {
+		let mut res = Vec::new();
+		let mut node_queue = VecDeque::new();
+		if let Some(node_id) = self.state.root {
+			node_queue.push_back(node_id);
+		}
+		while let Some(node_id) = node_queue.pop_front() {
+			let current = store.get_node(tx, node_id).await?;
+			let keys = current.n.keys();
+			res.extend(keys.collect_with_prefix(prefix)?);
+			if let BTreeNode::Internal(_, children) = &current.n {
+				// A child holds the keys between its two surrounding keys.
+				// We only visit the children whose range may contain the prefix.
+				for (idx, child_id) in children.iter().enumerate() {
+					let lower = idx.checked_sub(1).and_then(|i| keys.get_key(i));
+					let upper = keys.get_key(idx);
+					let after_lower = lower.is_none_or(|k| k.starts_with(prefix) || k.lt(prefix));
+					let before_upper = upper.is_none_or(|k| k.ge(prefix));
+					if after_lower && before_upper {
+						node_queue.push_back(*child_id);
+					}
+				}
+			}
+		}
+		Ok(res)
+	}
+
+	/// Collects every key accepted by the automaton.
+	/// A child node is skipped when the automaton rejects the prefix shared by all its keys.
+	pub(in crate::idx) async fn search_matching<A: Automaton>(
+		&self,
+		tx: &Transaction,
+		store: &BTreeStore<BK>,
+		aut: &A,
+	) -> Result<Vec<(Key, Payload)>, Error> {
+		let mut res = Vec::new();
+		let mut node_queue = VecDeque::new();
+		if let Some(node_id) = self.state.root {
+			node_queue.push_back(node_id);
+		}
+		while let Some(node_id) = node_queue.pop_front() {
+			let current = store.get_node(tx, node_id).await?;
+			let keys = current.n.keys();
+			res.extend(keys.collect_matching(aut)?);
+			if let BTreeNode::Internal(_, children) = &current.n {
+				for (idx, child_id) in children.iter().enumerate() {
+					let lower = idx.checked_sub(1).and_then(|i| keys.get_key(i));
+					let upper = keys.get_key(idx);
+					if let (Some(lower), Some(upper)) = (lower, upper) {
+						let len =
+							lower.iter().zip(upper.iter()).take_while(|(l, u)| l == u).count();
+						if run_automaton(aut, &lower[..len]).is_none() {
+							continue;
+						}
+					}
+					node_queue.push_back(*child_id);
+				}
+			}
+		}
+		Ok(res)
+	}
+
 	pub async fn insert(
 		&mut self,
 		tx: &Transaction,
@@ -995,6 +1064,7 @@ where
 #[cfg(test)]
 mod tests {
 	use crate::err::Error;
+	use crate::idx::ft::fuzzy::Levenshtein;
 	use crate::idx::trees::bkeys::{BKeys, FstKeys, TrieKeys};
 	use crate::idx::trees::btree::{
 		BState, BStatistics, BStoredNode, BTree, BTreeNode, BTreeStore, Payload,
@@ -1200,6 +1270,70 @@ mod tests {
 		}
 	}
 
+	#[test(tokio::test)]
+	async fn test_btree_fst_search_by_prefix() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let mut t = BTree::new(BState::new(3));
+
+		let mut samples: Vec<usize> = (0..200).collect();
+		let mut rng = thread_rng();
+		samples.shuffle(&mut rng);
+
+		{
+			let (tx, st) = new_operation_fst(&ds, &t, TransactionType::Write, 20).await;
+			insertions_test(tx, st, &mut t, 200, |i| get_key_value(samples[i])).await;
+		}
+
+		let (tx, st) = new_operation_fst(&ds, &t, TransactionType::Read, 20).await;
+		for (prefix, expected) in [("1", 111), ("19", 11), ("199", 1), ("5", 11), ("200", 0)] {
+			let res = t.search_by_prefix(&tx, &st, &prefix.into()).await.unwrap();
+			assert_eq!(res.len(), expected, "{prefix}");
+			for (key, payload) in res {
+				let (_, p) =
+					get_key_value(String::from_utf8(key.clone()).unwrap().parse().unwrap());
+				assert!(key.starts_with(prefix.as_bytes()));
+				assert_eq!(payload, p);
+			}
+		}
+		tx.cancel().await.unwrap();
+	}
+
+	#[test(tokio::test)]
+	async fn test_btree_fst_search_matching() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let mut t = BTree::new(BState::new(3));
+
+		let mut samples: Vec<usize> = (0..200).collect();
+		let mut rng = thread_rng();
+		samples.shuffle(&mut rng);
+
+		{
+			let (tx, st) = new_operation_fst(&ds, &t, TransactionType::Write, 20).await;
+			insertions_test(tx, st, &mut t, 200, |i| get_key_value(samples[i])).await;
+		}
+
+		let (tx, st) = new_operation_fst(&ds, &t, TransactionType::Read, 20).await;
+		for (term, distance) in [("150", 1), ("150", 2), ("7", 1), ("1999", 1), ("abc", 1)] {
+			let aut = Levenshtein::new(term, distance);
+			let mut res: Vec<Key> = t
+				.search_matching(&tx, &st, &aut)
+				.await
+				.unwrap()
+				.into_iter()
+				.map(|(k, _)| k)
+				.collect();
+			res.sort();
+			let mut expected: Vec<Key> = (0..200)
+				.map(|i| i.to_string())
+				.filter(|k| strsim::levenshtein(k, term) <= distance as usize)
+				.map(|k| k.into())
+				.collect();
+			expected.sort();
+			assert_eq!(res, expected, "{term} {distance}");
+		}
+		tx.cancel().await.unwrap();
+	}
+
 	#[test(tokio::test)]
 	async fn test_btree_trie_small_order_random_insertions() {
 		let ds = Datastore::new("memory").await.unwrap();
@@ -0,0 +1,261 @@
+use crate::err::Error;
+use crate::fnc::util::geo::encode;
+use crate::idx::IndexKeyBase;
+use crate::kvs::{Key, Transaction};
+use crate::sql::index::GeohashParams;
+use crate::sql::{Geometry, Id, Value};
+use geo::{coord, BoundingRect, Point, Rect};
+use std::collections::{BTreeSet, VecDeque};
+use std::ops::Range;
+
+/// The maximum number of cells covering the bounding box of a geometry.
+/// When more cells would be required, coarser cells are used.
+const MAX_CELLS: u64 = 32;
+
+/// The mean radius of the Earth in meters, as used by `geo::distance`
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// A geospatial index storing every geometry in the geohash cells covering its bounding box.
+/// Small geometries are stored in cells of the configured precision, larger geometries in coarser
+/// cells. Geometries without a bounding box (empty collections) are stored in the root cell.
+pub(crate) struct GeohashIndex {
+	ikb: IndexKeyBase,
+	precision: u8,
+}
+
+impl GeohashIndex {
+	pub(crate) fn new(ikb: IndexKeyBase, p: &GeohashParams) -> Self {
+		Self {
+			ikb,
+			precision: p.precision,
+		}
+	}
+
+	pub(crate) async fn index_document(
+		&self,
+		tx: &Transaction,
+		id: &Id,
+		content: &[Value],
+	) -> Result<(), Error> {
+		for cell in self.document_cells(content) {
+			let key = self.ikb.new_gh_key(&cell, id)?;
+			tx.set(key, vec![], None).await?;
+		}
+		Ok(())
+	}
+
+	pub(crate) async fn remove_document(
+		&self,
+		tx: &Transaction,
+		id: &Id,
+		content: &[Value],
+	) -> Result<(), Error> {
+		for cell in self.document_cells(content) {
+			let key = self.ikb.new_gh_key(&cell, id)?;
+			tx.del(key).await?;
+		}
+		Ok(())
+	}
+
+	fn document_cells(&self, content: &[Value]) -> BTreeSet<String> {
+		let mut cells = BTreeSet::new();
+		for v in content {
+			self.collect_cells(v, &mut cells);
+		}
+		cells
+	}
+
+	fn collect_cells(&self, v: &Value, cells: &mut BTreeSet<String>) {
+		match v {
+			Value::Geometry(g) => match Self::geometry_rect(g) {
+				Some(r) => cells.extend(cover(&r, self.precision)),
+				None => {
+					cells.insert(String::new());
+				}
+			},
+			Value::Array(a) => {
+				for v in a.iter() {
+					self.collect_cells(v, cells);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	/// Returns the key ranges containing every geometry whose bounding box may intersect the given
+	/// rectangle: the cells within the cells covering the rectangle, and the cells containing them.
+	pub(crate) fn query_ranges(&self, r: &Rect) -> Result<VecDeque<Range<Key>>, Error> {
+		let cells = cover(r, self.precision);
+		let mut parents = BTreeSet::new();
+		let mut ranges = VecDeque::with_capacity(cells.len());
+		for cell in &cells {
+			ranges.push_back(self.ikb.new_gh_prefix_range(cell)?);
+			for len in 0..cell.len() {
+				parents.insert(&cell[..len]);
+			}
+		}
+		for cell in parents {
+			ranges.push_back(self.ikb.new_gh_cell_range(cell)?);
+		}
+		Ok(ranges)
+	}
+
+	pub(crate) fn geometry_rect(g: &Geometry) -> Option<Rect> {
+		geo::Geometry::from(g.clone()).bounding_rect()
+	}
+
+	/// Returns a rectangle containing every point within the given distance (in meters)
+	/// of the origin, on the sphere used by `geo::distance`.
+	pub(crate) fn distance_rect(origin: &Point, distance: f64) -> Rect {
+		let d = distance / EARTH_RADIUS;
+		let lat = origin.y().to_radians();
+		let min_lat = lat - d;
+		let max_lat = lat + d;
+		let (min_lon, max_lon) = if min_lat > -std::f64::consts::FRAC_PI_2
+			&& max_lat < std::f64::consts::FRAC_PI_2
+			&& d.sin() < lat.cos()
+		{
+			let delta = (d.sin() / lat.cos()).asin().to_degrees();
+			let (min_lon, max_lon) = (origin.x() - delta, origin.x() + delta);
+			if min_lon < -180.0 || max_lon > 180.0 {
+				// The circle crosses the antimeridian
+				(-180.0, 180.0)
+			} else {
+				(min_lon, max_lon)
+			}
+		} else {
+			// The circle contains a pole
+			(-180.0, 180.0)
+		};
+		Rect::new(
+			coord! { x: min_lon, y: min_lat.to_degrees().max(-90.0) },
+			coord! { x: max_lon, y: max_lat.to_degrees().min(90.0) },
+		)
+	}
+}
+
+/// Returns the geohashes of the cells covering the rectangle, using the longest geohashes
+/// (up to the given precision) for which no more than `MAX_CELLS` cells are required.
+fn cover(r: &Rect, precision: u8) -> Vec<String> {
+	let mut precision = precision.max(1) as u32;
+	loop {
+		let grid = Grid::new(precision, r);
+		if precision == 1 || grid.len() <= MAX_CELLS {
+			return grid.cells();
+		}
+		precision -= 1;
+	}
+}
+
+/// The cells of a given geohash length intersecting a rectangle
+struct Grid {
+	precision: u32,
+	lon_cells: u64,
+	lat_cells: u64,
+	lon: (u64, u64),
+	lat: (u64, u64),
+}
+
+impl Grid {
+	fn new(precision: u32, r: &Rect) -> Self {
+		// The bits of a geohash alternate between longitude and latitude, starting with longitude
+		let lon_cells = 1 << (precision * 5).div_ceil(2);
+		let lat_cells = 1 << (precision * 5 / 2);
+		Self {
+			precision,
+			lon_cells,
+			lat_cells,
+			lon: (
+				Self::index(r.min().x, -180.0, 360.0, lon_cells),
+				Self::index(r.max().x, -180.0, 360.0, lon_cells),
+			),
+			lat: (
+				Self::index(r.min().y, -90.0, 180.0, lat_cells),
+				Self::index(r.max().y, -90.0, 180.0, lat_cells),
+			),
+		}
+	}
+
+	fn index(v: f64, min: f64, size: f64, cells: u64) -> u64 {
+		let i = ((v - min) / size * cells as f64).floor();
+		if i.is_nan() || i < 0.0 {
+			0
+		} else {
+			(i as u64).min(cells - 1)
+		}
+	}
+
+	fn len(&self) -> u64 {
+		(self.lon.1 - self.lon.0 + 1) * (self.lat.1 - self.lat.0 + 1)
+	}
+
+	fn cells(&self) -> Vec<String> {
+		let width = 360.0 / self.lon_cells as f64;
+		let height = 180.0 / self.lat_cells as f64;
+		let mut cells = Vec::with_capacity(self.len() as usize);
+		for i in self.lon.0..=self.lon.1 {
+			for j in self.lat.0..=self.lat.1 {
+				// The center of the cell is encoded to get its geohash
+				let x = -180.0 + (i as f64 + 0.5) * width;
+				let y = -90.0 + (j as f64 + 0.5) * height;
+				cells.push(encode(Point::new(x, y), self.precision as usize).0);
+			}
+		}
+		cells
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::fnc::util::geo::encode;
+	use crate::idx::trees::geohash::{cover, GeohashIndex, MAX_CELLS};
+	use geo::{coord, Point, Rect};
+
+	#[test]
+	fn test_cover_point() {
+		let p = Point::new(-0.118092, 51.509865);
+		let r = Rect::new(p.0, p.0);
+		assert_eq!(cover(&r, 8), vec![encode(p, 8).0]);
+		assert_eq!(cover(&r, 3), vec!["gcp".to_string()]);
+	}
+
+	#[test]
+	fn test_cover_rect() {
+		// A small rectangle in London
+		let r = Rect::new(coord! { x: -0.13, y: 51.50 }, coord! { x: -0.11, y: 51.52 });
+		let cells = cover(&r, 8);
+		assert!(cells.len() as u64 <= MAX_CELLS);
+		let len = cells[0].len();
+		assert!(len < 8);
+		for c in &cells {
+			assert_eq!(c.len(), len);
+			assert!(c.starts_with("gc"), "{c}");
+		}
+		// Every corner is within a cell
+		for p in [r.min(), r.max(), coord! { x: -0.13, y: 51.52 }, coord! { x: -0.11, y: 51.50 }] {
+			let h = encode(Point(p), len).0;
+			assert!(cells.contains(&h), "{h}");
+		}
+		// The whole world
+		let r = Rect::new(coord! { x: -180.0, y: -90.0 }, coord! { x: 180.0, y: 90.0 });
+		assert_eq!(cover(&r, 8).len(), 32);
+	}
+
+	#[test]
+	fn test_distance_rect() {
+		let p = Point::new(-0.118092, 51.509865);
+		let r = GeohashIndex::distance_rect(&p, 1000.0);
+		// One kilometre is about 0.009 degrees of latitude
+		assert!((r.max().y - p.y() - 0.009).abs() < 0.0001);
+		assert!((p.y() - r.min().y - 0.009).abs() < 0.0001);
+		// The longitude span is wider at this latitude
+		assert!(r.max().x - p.x() > 0.014);
+		assert!(p.x() - r.min().x > 0.014);
+		// Near the pole every longitude is included
+		let r = GeohashIndex::distance_rect(&Point::new(10.0, 89.99), 10000.0);
+		assert_eq!((r.min().x, r.max().x, r.max().y), (-180.0, 180.0, 90.0));
+		// Across the antimeridian every longitude is included
+		let r = GeohashIndex::distance_rect(&Point::new(179.999, 0.0), 1000.0);
+		assert_eq!((r.min().x, r.max().x), (-180.0, 180.0));
+	}
+}
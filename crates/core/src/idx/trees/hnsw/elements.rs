@@ -1,25 +1,37 @@
 use crate::err::Error;
 use crate::idx::trees::hnsw::ElementId;
+use crate::idx::trees::knn::DoublePriorityQueue;
+use crate::idx::trees::quantizer::{QuantizedVector, Quantizer};
 use crate::idx::trees::vector::{SerializedVector, SharedVector, Vector};
 use crate::idx::{IndexKeyBase, VersionedStore};
 use crate::kvs::Transaction;
-use crate::sql::index::Distance;
+use crate::sql::index::{Distance, HnswParams, VectorType};
 use dashmap::DashMap;
 
+/// The representation of an element kept in memory.
+enum CachedVector {
+	Full(SharedVector),
+	Quantized(QuantizedVector),
+}
+
 pub(super) struct HnswElements {
 	ikb: IndexKeyBase,
-	elements: DashMap<ElementId, SharedVector>,
+	elements: DashMap<ElementId, CachedVector>,
 	next_element_id: ElementId,
 	dist: Distance,
+	vector_type: VectorType,
+	quantizer: Option<Quantizer>,
 }
 
 impl HnswElements {
-	pub(super) fn new(ikb: IndexKeyBase, dist: Distance) -> Self {
+	pub(super) fn new(ikb: IndexKeyBase, p: &HnswParams) -> Self {
 		Self {
 			ikb,
 			elements: Default::default(),
 			next_element_id: 0,
-			dist,
+			dist: p.distance.clone(),
+			vector_type: p.vector_type,
+			quantizer: p.quantization.map(Quantizer::new),
 		}
 	}
 
@@ -46,6 +58,33 @@ impl HnswElements {
 		self.elements.contains_key(e_id)
 	}
 
+	pub(super) fn codebook_id(&self) -> Option<u64> {
+		self.quantizer.as_ref().and_then(|q| q.codebook_id())
+	}
+
+	/// Ensures the codebook matches the one referenced by the state of the index.
+	pub(super) async fn check_codebook(
+		&mut self,
+		tx: &Transaction,
+		id: Option<u64>,
+	) -> Result<(), Error> {
+		if let Some(q) = &mut self.quantizer {
+			if q.codebook_id() != id {
+				let codebook = match id {
+					Some(_) => match tx.get(self.ikb.new_hq_key()?, None).await? {
+						Some(val) => Some(VersionedStore::try_from(val)?),
+						None => None,
+					},
+					None => None,
+				};
+				q.set_codebook(codebook);
+				// The cached elements may have been encoded with another codebook
+				self.elements.clear();
+			}
+		}
+		Ok(())
+	}
+
 	pub(super) async fn insert(
 		&mut self,
 		tx: &Transaction,
@@ -56,34 +95,85 @@ impl HnswElements {
 		let key = self.ikb.new_he_key(id)?;
 		let val = VersionedStore::try_into(ser_vec)?;
 		tx.set(key, val, None).await?;
-		let pt: SharedVector = vec.into();
-		self.elements.insert(id, pt.clone());
-		Ok(pt)
+		Ok(self.cache(id, vec))
 	}
 
-	pub(super) async fn get_vector(
-		&self,
-		tx: &Transaction,
-		e_id: &ElementId,
-	) -> Result<Option<SharedVector>, Error> {
-		if let Some(r) = self.elements.get(e_id) {
-			return Ok(Some(r.value().clone()));
+	/// Returns true if the quantizer has to be trained before vectors can be encoded.
+	pub(super) fn requires_training(&self) -> bool {
+		self.quantizer.as_ref().is_some_and(|q| q.requires_training(self.next_element_id as usize))
+	}
+
+	/// Trains the product quantizer with the vectors currently stored in the index.
+	/// Does nothing if the quantizer does not need to be trained.
+	pub(super) async fn train(&mut self, tx: &Transaction) -> Result<(), Error> {
+		if !self.quantizer.as_ref().is_some_and(|q| q.is_untrained()) {
+			return Ok(());
+		}
+		let mut samples = Vec::with_capacity(self.next_element_id as usize);
+		for e_id in 0..self.next_element_id {
+			if let Some(v) = self.load(tx, &e_id).await? {
+				samples.push(v);
+			}
+		}
+		if let Some(codebook) = self.quantizer.as_mut().and_then(|q| q.train(&samples)) {
+			let val: Vec<u8> = VersionedStore::try_into(codebook)?;
+			tx.set(self.ikb.new_hq_key()?, val, None).await?;
+		}
+		// The elements cached in full precision will be reloaded and encoded
+		self.elements.clear();
+		Ok(())
+	}
+
+	/// Caches the element, and returns the vector used to navigate the graph.
+	/// This is the decoded quantized vector when the index is quantized.
+	fn cache(&self, e_id: ElementId, vec: Vector) -> SharedVector {
+		if let Some(q) = self.quantizer.as_ref().and_then(|q| q.encode(&vec)) {
+			let pt = q.decode(self.vector_type).into();
+			self.elements.insert(e_id, CachedVector::Quantized(q));
+			pt
+		} else {
+			let pt: SharedVector = vec.into();
+			self.elements.insert(e_id, CachedVector::Full(pt.clone()));
+			pt
 		}
+	}
+
+	async fn load(&self, tx: &Transaction, e_id: &ElementId) -> Result<Option<Vector>, Error> {
 		let key = self.ikb.new_he_key(*e_id)?;
 		match tx.get(key, None).await? {
 			None => Ok(None),
 			Some(val) => {
 				let vec: SerializedVector = VersionedStore::try_from(val)?;
-				let vec = Vector::from(vec);
-				let vec: SharedVector = vec.into();
-				self.elements.insert(*e_id, vec.clone());
-				Ok(Some(vec))
+				Ok(Some(Vector::from(vec)))
 			}
 		}
 	}
 
-	pub(super) fn distance(&self, a: &SharedVector, b: &SharedVector) -> f64 {
-		self.dist.calculate(a, b)
+	/// Returns the vector used to navigate the graph.
+	pub(super) async fn get_vector(
+		&self,
+		tx: &Transaction,
+		e_id: &ElementId,
+	) -> Result<Option<SharedVector>, Error> {
+		if let Some(r) = self.elements.get(e_id) {
+			return Ok(Some(match r.value() {
+				CachedVector::Full(v) => v.clone(),
+				CachedVector::Quantized(q) => q.decode(self.vector_type).into(),
+			}));
+		}
+		Ok(self.load(tx, e_id).await?.map(|v| self.cache(*e_id, v)))
+	}
+
+	/// Returns the vector in full precision, as it has been indexed.
+	pub(super) async fn get_exact_vector(
+		&self,
+		tx: &Transaction,
+		e_id: &ElementId,
+	) -> Result<Option<SharedVector>, Error> {
+		if self.quantizer.is_none() {
+			return self.get_vector(tx, e_id).await;
+		}
+		Ok(self.load(tx, e_id).await?.map(|v| v.into()))
 	}
 
 	pub(super) async fn get_distance(
@@ -92,9 +182,38 @@ impl HnswElements {
 		q: &SharedVector,
 		e_id: &ElementId,
 	) -> Result<Option<f64>, Error> {
+		if let Some(r) = self.elements.get(e_id) {
+			return Ok(Some(match r.value() {
+				CachedVector::Full(v) => self.dist.calculate(v, q),
+				CachedVector::Quantized(v) => self.dist.calculate(&v.decode(self.vector_type), q),
+			}));
+		}
 		Ok(self.get_vector(tx, e_id).await?.map(|r| self.dist.calculate(&r, q)))
 	}
 
+	/// Returns the `k` nearest candidates.
+	/// When the index is quantized, the candidates are re-ranked using their exact distance.
+	pub(super) async fn rerank(
+		&self,
+		tx: &Transaction,
+		q: &SharedVector,
+		candidates: DoublePriorityQueue,
+		k: usize,
+	) -> Result<Vec<(f64, ElementId)>, Error> {
+		if self.quantizer.is_none() {
+			return Ok(candidates.to_vec_limit(k));
+		}
+		let mut res = Vec::with_capacity(candidates.len());
+		for (_, e_id) in candidates.to_vec() {
+			if let Some(v) = self.load(tx, &e_id).await? {
+				res.push((self.dist.calculate(&v, q), e_id));
+			}
+		}
+		res.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+		res.truncate(k);
+		Ok(res)
+	}
+
 	pub(super) async fn remove(&mut self, tx: &Transaction, e_id: ElementId) -> Result<(), Error> {
 		self.elements.remove(&e_id);
 		let key = self.ikb.new_he_key(e_id)?;
@@ -95,6 +95,42 @@ impl HnswFlavor {
 			HnswFlavor::Hset(h) => h.insert(tx, q_pt).await,
 		}
 	}
+	pub(super) fn requires_training(&self) -> bool {
+		match self {
+			HnswFlavor::H5_9(h) => h.requires_training(),
+			HnswFlavor::H5_17(h) => h.requires_training(),
+			HnswFlavor::H5_25(h) => h.requires_training(),
+			HnswFlavor::H5set(h) => h.requires_training(),
+			HnswFlavor::H9_17(h) => h.requires_training(),
+			HnswFlavor::H9_25(h) => h.requires_training(),
+			HnswFlavor::H9set(h) => h.requires_training(),
+			HnswFlavor::H13_25(h) => h.requires_training(),
+			HnswFlavor::H13set(h) => h.requires_training(),
+			HnswFlavor::H17set(h) => h.requires_training(),
+			HnswFlavor::H21set(h) => h.requires_training(),
+			HnswFlavor::H25set(h) => h.requires_training(),
+			HnswFlavor::H29set(h) => h.requires_training(),
+			HnswFlavor::Hset(h) => h.requires_training(),
+		}
+	}
+	pub(super) async fn train(&mut self, tx: &Transaction) -> Result<(), Error> {
+		match self {
+			HnswFlavor::H5_9(h) => h.train(tx).await,
+			HnswFlavor::H5_17(h) => h.train(tx).await,
+			HnswFlavor::H5_25(h) => h.train(tx).await,
+			HnswFlavor::H5set(h) => h.train(tx).await,
+			HnswFlavor::H9_17(h) => h.train(tx).await,
+			HnswFlavor::H9_25(h) => h.train(tx).await,
+			HnswFlavor::H9set(h) => h.train(tx).await,
+			HnswFlavor::H13_25(h) => h.train(tx).await,
+			HnswFlavor::H13set(h) => h.train(tx).await,
+			HnswFlavor::H17set(h) => h.train(tx).await,
+			HnswFlavor::H21set(h) => h.train(tx).await,
+			HnswFlavor::H25set(h) => h.train(tx).await,
+			HnswFlavor::H29set(h) => h.train(tx).await,
+			HnswFlavor::Hset(h) => h.train(tx).await,
+		}
+	}
 	pub(super) async fn remove(
 		&mut self,
 		tx: &Transaction,
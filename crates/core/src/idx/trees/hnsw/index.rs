@@ -22,6 +22,8 @@ pub struct HnswIndex {
 	hnsw: HnswFlavor,
 	docs: HnswDocs,
 	vec_docs: VecDocs,
+	/// Whether the training of the quantizer has been scheduled
+	training: bool,
 }
 
 pub(super) struct HnswCheckedSearchContext<'a> {
@@ -83,6 +85,7 @@ impl HnswIndex {
 			hnsw: HnswFlavor::new(ikb.clone(), p)?,
 			docs: HnswDocs::new(tx, tb, ikb.clone()).await?,
 			vec_docs: VecDocs::new(ikb),
+			training: false,
 		})
 	}
 
@@ -129,6 +132,23 @@ impl HnswIndex {
 		Ok(())
 	}
 
+	/// Returns true, only once, when the quantizer has to be trained.
+	/// The training is expected to happen outside the transaction that inserted the vectors.
+	pub(crate) fn schedule_training(&mut self) -> bool {
+		if self.training || !self.hnsw.requires_training() {
+			return false;
+		}
+		self.training = true;
+		true
+	}
+
+	/// Trains the quantizer with the vectors currently stored in the index.
+	pub(crate) async fn train(&mut self, tx: &Transaction) -> Result<(), Error> {
+		// If the training fails, it can be scheduled again
+		self.training = false;
+		self.hnsw.train(tx).await
+	}
+
 	// Ensure the layers are up-to-date
 	pub async fn check_state(&mut self, tx: &Transaction) -> Result<(), Error> {
 		self.hnsw.check_state(tx).await
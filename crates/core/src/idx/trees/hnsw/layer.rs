@@ -105,7 +105,6 @@ where
 		tx: &Transaction,
 		stk: &mut Stk,
 		search: &HnswCheckedSearchContext<'_>,
-		ep_pt: &SharedVector,
 		ep_dist: f64,
 		ep_id: ElementId,
 		chk: &mut HnswConditionChecker<'_>,
@@ -113,7 +112,7 @@ where
 		let visited = HashSet::from_iter([ep_id]);
 		let candidates = DoublePriorityQueue::from(ep_dist, ep_id);
 		let mut w = DoublePriorityQueue::default();
-		Self::add_if_truthy(tx, stk, search, &mut w, ep_pt, ep_dist, ep_id, chk).await?;
+		Self::add_if_truthy(tx, stk, search, &mut w, ep_dist, ep_id, chk).await?;
 		self.search_checked(tx, stk, search, candidates, visited, w, chk).await
 	}
 
@@ -171,8 +170,7 @@ where
 					if !visited.insert(e_id) {
 						continue;
 					}
-					if let Some(e_pt) = elements.get_vector(tx, &e_id).await? {
-						let e_dist = elements.distance(&e_pt, q);
+					if let Some(e_dist) = elements.get_distance(tx, q, &e_id).await? {
 						if e_dist < fq_dist || w.len() < ef {
 							candidates.push(e_dist, e_id);
 							w.push(e_dist, e_id);
@@ -206,7 +204,10 @@ where
 		let elements = search.elements();
 
 		while let Some((dist, doc)) = candidates.pop_first() {
-			if dist > f_dist {
+			// `w` only collects the elements matching the condition.
+			// The traversal goes on through non-matching elements until `w` is full,
+			// otherwise a selective condition would return less than `ef` results.
+			if dist > f_dist && w.len() >= ef {
 				break;
 			}
 			if let Some(neighbourhood) = self.graph.get_edges(&doc) {
@@ -215,14 +216,11 @@ where
 					if !visited.insert(e_id) {
 						continue;
 					}
-					if let Some(e_pt) = elements.get_vector(tx, &e_id).await? {
-						let e_dist = elements.distance(&e_pt, pt);
+					if let Some(e_dist) = elements.get_distance(tx, pt, &e_id).await? {
 						if e_dist < f_dist || w.len() < ef {
 							candidates.push(e_dist, e_id);
-							if Self::add_if_truthy(
-								tx, stk, search, &mut w, &e_pt, e_dist, e_id, chk,
-							)
-							.await?
+							if Self::add_if_truthy(tx, stk, search, &mut w, e_dist, e_id, chk)
+								.await?
 							{
 								f_dist = w.peek_last_dist().unwrap(); // w can't be empty
 							}
@@ -240,17 +238,24 @@ where
 		stk: &mut Stk,
 		search: &HnswCheckedSearchContext<'_>,
 		w: &mut DoublePriorityQueue,
-		e_pt: &SharedVector,
 		e_dist: f64,
 		e_id: ElementId,
 		chk: &mut HnswConditionChecker<'_>,
 	) -> Result<bool, Error> {
-		if let Some(docs) = search.vec_docs().get_docs(tx, e_pt).await? {
+		let Some(e_pt) = search.elements().get_exact_vector(tx, &e_id).await? else {
+			return Ok(false);
+		};
+		if let Some(docs) = search.vec_docs().get_docs(tx, &e_pt).await? {
 			if chk.check_truthy(tx, stk, search.docs(), docs).await? {
 				w.push(e_dist, e_id);
 				if w.len() > search.ef() {
 					if let Some((_, id)) = w.pop_last() {
-						chk.expire(id);
+						// The checker caches documents, not elements
+						if let Some(pt) = search.elements().get_exact_vector(tx, &id).await? {
+							if let Some(docs) = search.vec_docs().get_docs(tx, &pt).await? {
+								chk.expires(docs);
+							}
+						}
 					}
 				}
 				return Ok(true);
@@ -311,8 +316,7 @@ where
 		let mut w = DoublePriorityQueue::default();
 		if let Some(e_pt) = elements.get_vector(tx, &e_id).await? {
 			for n_id in neighbors.iter() {
-				if let Some(n_pt) = elements.get_vector(tx, n_id).await? {
-					let dist = elements.distance(&e_pt, &n_pt);
+				if let Some(dist) = elements.get_distance(tx, &e_pt, n_id).await? {
 					w.push(dist, *n_id);
 				}
 			}
@@ -37,18 +37,21 @@ impl HnswSearch {
 		Self {
 			pt,
 			k,
-			ef,
+			// The search list must be able to hold the `k` results
+			ef: ef.max(k),
 		}
 	}
 }
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Default, Serialize, Deserialize)]
 pub(super) struct HnswState {
 	enter_point: Option<ElementId>,
 	next_element_id: ElementId,
 	layer0: LayerState,
 	layers: Vec<LayerState>,
+	#[revision(start = 2)]
+	codebook: Option<u64>,
 }
 
 impl VersionedStore for HnswState {}
@@ -89,7 +92,7 @@ where
 			ml: p.ml.to_float(),
 			layer0: HnswLayer::new(ikb.clone(), 0, m0),
 			layers: Vec::default(),
-			elements: HnswElements::new(ikb.clone(), p.distance.clone()),
+			elements: HnswElements::new(ikb.clone(), p),
 			rng: SmallRng::from_entropy(),
 			heuristic: p.into(),
 			ikb,
@@ -124,6 +127,8 @@ where
 		for _ in self.layers.len()..st.layers.len() {
 			self.layers.pop();
 		}
+		// Check the codebook of the quantizer
+		self.elements.check_codebook(tx, st.codebook).await?;
 		// Set the enter_point
 		self.elements.set_next_element_id(st.next_element_id);
 		self.state = st;
@@ -284,6 +289,23 @@ where
 		Ok(res)
 	}
 
+	fn requires_training(&self) -> bool {
+		self.elements.requires_training()
+	}
+
+	async fn train(&mut self, tx: &Transaction) -> Result<(), Error> {
+		// The quantizer may already have been trained by another node
+		self.check_state(tx).await?;
+		if self.state.codebook.is_none() {
+			self.elements.train(tx).await?;
+			if self.elements.codebook_id().is_some() {
+				self.state.codebook = self.elements.codebook_id();
+				self.save_state(tx).await?;
+			}
+		}
+		Ok(())
+	}
+
 	async fn remove(&mut self, tx: &Transaction, e_id: ElementId) -> Result<bool, Error> {
 		let mut removed = false;
 
@@ -344,7 +366,7 @@ where
 				.layer0
 				.search_single(tx, &self.elements, &search.pt, ep_dist, ep_id, search.ef)
 				.await?;
-			Ok(w.to_vec_limit(search.k))
+			self.elements.rerank(tx, &search.pt, w, search.k).await
 		} else {
 			Ok(vec![])
 		}
@@ -360,20 +382,18 @@ where
 		chk: &mut HnswConditionChecker<'_>,
 	) -> Result<Vec<(f64, ElementId)>, Error> {
 		if let Some((ep_dist, ep_id)) = self.search_ep(tx, &search.pt).await? {
-			if let Some(ep_pt) = self.elements.get_vector(tx, &ep_id).await? {
-				let search_ctx = HnswCheckedSearchContext::new(
-					&self.elements,
-					hnsw_docs,
-					vec_docs,
-					&search.pt,
-					search.ef,
-				);
-				let w = self
-					.layer0
-					.search_single_checked(tx, stk, &search_ctx, &ep_pt, ep_dist, ep_id, chk)
-					.await?;
-				return Ok(w.to_vec_limit(search.k));
-			}
+			let search_ctx = HnswCheckedSearchContext::new(
+				&self.elements,
+				hnsw_docs,
+				vec_docs,
+				&search.pt,
+				search.ef,
+			);
+			let w = self
+				.layer0
+				.search_single_checked(tx, stk, &search_ctx, ep_dist, ep_id, chk)
+				.await?;
+			return self.elements.rerank(tx, &search.pt, w, search.k).await;
 		}
 		Ok(vec![])
 	}
@@ -411,7 +431,7 @@ where
 		tx: &Transaction,
 		e_id: &ElementId,
 	) -> Result<Option<SharedVector>, Error> {
-		self.elements.get_vector(tx, e_id).await
+		self.elements.get_exact_vector(tx, e_id).await
 	}
 	#[cfg(test)]
 	fn check_hnsw_properties(&self, expected_count: usize) {
@@ -446,7 +466,7 @@ mod tests {
 	use crate::idx::IndexKeyBase;
 	use crate::kvs::LockType::Optimistic;
 	use crate::kvs::{Datastore, Transaction, TransactionType};
-	use crate::sql::index::{Distance, HnswParams, VectorType};
+	use crate::sql::index::{Distance, HnswParams, VectorQuantization, VectorType};
 	use crate::sql::{Id, Value};
 	use ahash::{HashMap, HashSet};
 	use ndarray::Array1;
@@ -569,6 +589,7 @@ mod tests {
 			efc as u16,
 			extend_candidates,
 			keep_pruned_connections,
+			None,
 		)
 	}
 
@@ -793,6 +814,35 @@ mod tests {
 		Ok(())
 	}
 
+	#[test(tokio::test(flavor = "multi_thread"))]
+	async fn tests_hnsw_index_quantized() -> Result<(), Error> {
+		let mut futures = Vec::new();
+		for q in
+			[VectorQuantization::Scalar, VectorQuantization::Binary, VectorQuantization::Product(5)]
+		{
+			for vt in [
+				VectorType::F64,
+				VectorType::F32,
+				VectorType::I64,
+				VectorType::I32,
+				VectorType::I16,
+			] {
+				for unique in [true, false] {
+					let mut p = new_params(5, vt, Distance::Euclidean, 8, 150, false, false);
+					p.quantization = Some(q);
+					let f = tokio::spawn(async move {
+						test_hnsw_index(30, unique, p).await;
+					});
+					futures.push(f);
+				}
+			}
+		}
+		for f in futures {
+			f.await.expect("Task error");
+		}
+		Ok(())
+	}
+
 	#[test(tokio::test(flavor = "multi_thread"))]
 	async fn test_simple_hnsw() {
 		let collection = TestCollection::Unique(vec![
@@ -853,6 +903,14 @@ mod tests {
 			h.index_document(&tx, &Id::Number(*doc_id as i64), &content).await?;
 		}
 		tx.commit().await?;
+		// The quantizer is trained outside of the transaction which inserted the vectors
+		if h.schedule_training() {
+			let ctx = new_ctx(&ds, TransactionType::Write).await;
+			let tx = ctx.tx();
+			h.train(&tx).await?;
+			tx.commit().await?;
+			assert!(!h.schedule_training());
+		}
 
 		let h = Arc::new(h);
 
@@ -950,6 +1008,51 @@ mod tests {
 		.await
 	}
 
+	#[test(tokio::test(flavor = "multi_thread"))]
+	async fn test_recall_euclidean_scalar_quantization() -> Result<(), Error> {
+		let mut p = new_params(20, VectorType::F32, Distance::Euclidean, 8, 100, false, false);
+		p.quantization = Some(VectorQuantization::Scalar);
+		test_recall(
+			"hnsw-random-9000-20-euclidean.gz",
+			1000,
+			"hnsw-random-5000-20-euclidean.gz",
+			300,
+			p,
+			&[(10, 0.98), (40, 1.0)],
+		)
+		.await
+	}
+
+	#[test(tokio::test(flavor = "multi_thread"))]
+	async fn test_recall_euclidean_binary_quantization() -> Result<(), Error> {
+		let mut p = new_params(20, VectorType::F32, Distance::Euclidean, 8, 100, false, false);
+		p.quantization = Some(VectorQuantization::Binary);
+		test_recall(
+			"hnsw-random-9000-20-euclidean.gz",
+			1000,
+			"hnsw-random-5000-20-euclidean.gz",
+			300,
+			p,
+			&[(10, 0.8), (40, 0.95)],
+		)
+		.await
+	}
+
+	#[test(tokio::test(flavor = "multi_thread"))]
+	async fn test_recall_euclidean_product_quantization() -> Result<(), Error> {
+		let mut p = new_params(20, VectorType::F32, Distance::Euclidean, 8, 100, false, false);
+		p.quantization = Some(VectorQuantization::Product(10));
+		test_recall(
+			"hnsw-random-9000-20-euclidean.gz",
+			1500,
+			"hnsw-random-5000-20-euclidean.gz",
+			300,
+			p,
+			&[(10, 0.85), (40, 0.98)],
+		)
+		.await
+	}
+
 	impl TestCollection {
 		fn knn(&self, pt: &SharedVector, dist: Distance, n: usize) -> KnnResult {
 			let mut b = KnnResultBuilder::new(n);
@@ -1,9 +1,11 @@
 pub mod bkeys;
 pub mod btree;
 pub mod dynamicset;
+pub(crate) mod geohash;
 mod graph;
 pub mod hnsw;
 pub(in crate::idx) mod knn;
 pub mod mtree;
+mod quantizer;
 pub mod store;
 pub mod vector;
@@ -0,0 +1,346 @@
+use crate::fnc::util::math::ToFloat;
+use crate::idx::trees::vector::Vector;
+use crate::idx::VersionedStore;
+use crate::sql::index::{VectorQuantization, VectorType};
+use ndarray::Array1;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The number of centroids per subspace, so that a centroid id fits in a single byte.
+const PQ_CENTROIDS: usize = 256;
+/// The number of vectors required before the codebook of a product quantizer is trained.
+pub(super) const PQ_TRAINING_SIZE: usize = 1024;
+/// The number of k-means iterations used to train the codebook.
+const PQ_ITERATIONS: usize = 8;
+
+/// The codebook of a product quantizer.
+/// For every subspace, it contains the centroids, stored contiguously.
+#[revisioned(revision = 1)]
+#[derive(Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub(super) struct Codebook {
+	id: u64,
+	sub_dim: u32,
+	centroids: Vec<Vec<f32>>,
+}
+
+impl VersionedStore for Codebook {}
+
+impl Codebook {
+	fn train(samples: &[Vec<f64>], subspaces: usize) -> Self {
+		let sub_dim = samples.first().map(|s| s.len() / subspaces).unwrap_or(0);
+		let k = PQ_CENTROIDS.min(samples.len());
+		let mut centroids = Vec::with_capacity(subspaces);
+		for s in 0..subspaces {
+			let range = s * sub_dim..(s + 1) * sub_dim;
+			// The initial centroids are spread over the samples
+			let mut sub: Vec<Vec<f64>> =
+				(0..k).map(|i| samples[i * samples.len() / k][range.clone()].to_vec()).collect();
+			for _ in 0..PQ_ITERATIONS {
+				let mut sums = vec![vec![0.0; sub_dim]; k];
+				let mut counts = vec![0usize; k];
+				for sample in samples {
+					let x = &sample[range.clone()];
+					let c = Self::nearest(sub.iter().map(|c| c.as_slice()), x);
+					counts[c] += 1;
+					sums[c].iter_mut().zip(x).for_each(|(s, x)| *s += x);
+				}
+				// Empty clusters keep their previous centroid
+				for ((c, sum), count) in sub.iter_mut().zip(sums).zip(counts) {
+					if count > 0 {
+						c.iter_mut().zip(sum).for_each(|(c, s)| *c = s / count as f64);
+					}
+				}
+			}
+			centroids.push(sub.into_iter().flatten().map(|x| x as f32).collect());
+		}
+		Self {
+			id: rand::random(),
+			sub_dim: sub_dim as u32,
+			centroids,
+		}
+	}
+
+	fn nearest<'a, T, C>(centroids: C, x: &[f64]) -> usize
+	where
+		T: ToFloat + Copy + 'a,
+		C: Iterator<Item = &'a [T]>,
+	{
+		let mut nearest = (0, f64::MAX);
+		for (i, c) in centroids.enumerate() {
+			let d: f64 = c.iter().zip(x).map(|(c, x)| (c.to_float() - x).powi(2)).sum();
+			if d < nearest.1 {
+				nearest = (i, d);
+			}
+		}
+		nearest.0
+	}
+
+	fn encode(&self, v: &[f64]) -> Box<[u8]> {
+		let sub_dim = self.sub_dim as usize;
+		self.centroids
+			.iter()
+			.zip(v.chunks(sub_dim))
+			.map(|(c, x)| Self::nearest(c.chunks(sub_dim), x) as u8)
+			.collect()
+	}
+
+	fn decode(&self, codes: &[u8]) -> Vec<f64> {
+		let sub_dim = self.sub_dim as usize;
+		let mut v = Vec::with_capacity(codes.len() * sub_dim);
+		for (c, &code) in self.centroids.iter().zip(codes) {
+			let start = code as usize * sub_dim;
+			v.extend(c[start..start + sub_dim].iter().map(|&x| x as f64));
+		}
+		v
+	}
+}
+
+/// The compact representation of a vector.
+/// Decoding it gives an approximation of the original vector.
+#[derive(Debug)]
+pub(super) enum QuantizedVector {
+	/// Every dimension is mapped on 256 levels between the min and the max of the vector
+	Scalar {
+		min: f32,
+		step: f32,
+		codes: Box<[u8]>,
+	},
+	/// Every dimension is stored as a bit telling if the value is above the mean of the vector
+	Binary {
+		dim: u32,
+		low: f32,
+		high: f32,
+		bits: Box<[u64]>,
+	},
+	/// Every subspace is stored as the id of its nearest centroid
+	Product(Arc<Codebook>, Box<[u8]>),
+}
+
+impl QuantizedVector {
+	pub(super) fn decode(&self, t: VectorType) -> Vector {
+		let v = match self {
+			Self::Scalar {
+				min,
+				step,
+				codes,
+			} => codes.iter().map(|&c| (*min + c as f32 * *step) as f64).collect(),
+			Self::Binary {
+				dim,
+				low,
+				high,
+				bits,
+			} => (0..*dim as usize)
+				.map(|i| {
+					if bits[i / 64] & (1 << (i % 64)) != 0 {
+						*high as f64
+					} else {
+						*low as f64
+					}
+				})
+				.collect(),
+			Self::Product(codebook, codes) => codebook.decode(codes),
+		};
+		Self::to_vector(t, v)
+	}
+
+	fn to_vector(t: VectorType, v: Vec<f64>) -> Vector {
+		match t {
+			VectorType::F64 => Vector::F64(Array1::from_vec(v)),
+			VectorType::F32 => Vector::F32(v.into_iter().map(|x| x as f32).collect()),
+			VectorType::I64 => Vector::I64(v.into_iter().map(|x| x.round() as i64).collect()),
+			VectorType::I32 => Vector::I32(v.into_iter().map(|x| x.round() as i32).collect()),
+			VectorType::I16 => Vector::I16(v.into_iter().map(|x| x.round() as i16).collect()),
+		}
+	}
+}
+
+pub(super) struct Quantizer {
+	quantization: VectorQuantization,
+	codebook: Option<Arc<Codebook>>,
+}
+
+impl Quantizer {
+	pub(super) fn new(quantization: VectorQuantization) -> Self {
+		Self {
+			quantization,
+			codebook: None,
+		}
+	}
+
+	/// Returns `None` if the vector can't be quantized yet,
+	/// which is the case for a product quantizer whose codebook is not trained.
+	pub(super) fn encode(&self, v: &Vector) -> Option<QuantizedVector> {
+		let v = Self::to_f64(v);
+		match self.quantization {
+			VectorQuantization::Scalar => {
+				let (min, max) =
+					v.iter().fold((f64::MAX, f64::MIN), |(min, max), &x| (min.min(x), max.max(x)));
+				let step = if max > min {
+					(max - min) / 255.0
+				} else {
+					0.0
+				};
+				let codes = v
+					.iter()
+					.map(|&x| {
+						if step > 0.0 {
+							((x - min) / step).round() as u8
+						} else {
+							0
+						}
+					})
+					.collect();
+				Some(QuantizedVector::Scalar {
+					min: min as f32,
+					step: step as f32,
+					codes,
+				})
+			}
+			VectorQuantization::Binary => {
+				let mean = v.iter().sum::<f64>() / v.len().max(1) as f64;
+				let mut bits = vec![0u64; v.len().div_ceil(64)];
+				let (mut high, mut high_count, mut low, mut low_count) = (0.0, 0, 0.0, 0);
+				for (i, &x) in v.iter().enumerate() {
+					if x > mean {
+						bits[i / 64] |= 1 << (i % 64);
+						high += x;
+						high_count += 1;
+					} else {
+						low += x;
+						low_count += 1;
+					}
+				}
+				// Each bit is decoded as the mean of the values on its side of the vector's mean
+				let side_mean = |sum: f64, count: usize| {
+					if count > 0 {
+						sum / count as f64
+					} else {
+						mean
+					}
+				};
+				Some(QuantizedVector::Binary {
+					dim: v.len() as u32,
+					low: side_mean(low, low_count) as f32,
+					high: side_mean(high, high_count) as f32,
+					bits: bits.into(),
+				})
+			}
+			VectorQuantization::Product(_) => {
+				let codebook = self.codebook.as_ref()?;
+				Some(QuantizedVector::Product(codebook.clone(), codebook.encode(&v)))
+			}
+		}
+	}
+
+	pub(super) fn codebook_id(&self) -> Option<u64> {
+		self.codebook.as_ref().map(|c| c.id)
+	}
+
+	pub(super) fn set_codebook(&mut self, codebook: Option<Codebook>) {
+		self.codebook = codebook.map(Arc::new);
+	}
+
+	/// A product quantizer can't encode vectors until its codebook is trained.
+	pub(super) fn is_untrained(&self) -> bool {
+		matches!(self.quantization, VectorQuantization::Product(_)) && self.codebook.is_none()
+	}
+
+	/// A product quantizer is trained once the index contains enough vectors.
+	pub(super) fn requires_training(&self, count: usize) -> bool {
+		self.is_untrained() && count >= PQ_TRAINING_SIZE
+	}
+
+	/// Trains the codebook of a product quantizer using the given samples.
+	pub(super) fn train(&mut self, samples: &[Vector]) -> Option<&Codebook> {
+		let VectorQuantization::Product(subspaces) = self.quantization else {
+			return None;
+		};
+		if samples.is_empty() {
+			return None;
+		}
+		let samples: Vec<Vec<f64>> = samples.iter().map(Self::to_f64).collect();
+		self.codebook = Some(Arc::new(Codebook::train(&samples, subspaces as usize)));
+		self.codebook.as_deref()
+	}
+
+	fn to_f64(v: &Vector) -> Vec<f64> {
+		match v {
+			Vector::F64(a) => a.to_vec(),
+			Vector::F32(a) => a.iter().map(|x| x.to_float()).collect(),
+			Vector::I64(a) => a.iter().map(|x| x.to_float()).collect(),
+			Vector::I32(a) => a.iter().map(|x| x.to_float()).collect(),
+			Vector::I16(a) => a.iter().map(|x| x.to_float()).collect(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::idx::trees::knn::tests::{get_seed_rnd, new_random_vec, RandomItemGenerator};
+	use crate::idx::trees::quantizer::{Quantizer, PQ_TRAINING_SIZE};
+	use crate::idx::trees::vector::{SharedVector, Vector};
+	use crate::sql::index::{Distance, VectorQuantization, VectorType};
+	use ndarray::Array1;
+
+	fn random_vectors(vt: VectorType, dim: usize, count: usize) -> Vec<SharedVector> {
+		let mut rng = get_seed_rnd();
+		let gen = RandomItemGenerator::new(&Distance::Euclidean, dim);
+		(0..count).map(|_| new_random_vec(&mut rng, vt, dim, &gen)).collect()
+	}
+
+	fn to_f64(v: &Vector) -> Vector {
+		Vector::F64(Quantizer::to_f64(v).into())
+	}
+
+	/// Returns the mean relative error of the decoded vectors
+	fn check_quantizer(q: &Quantizer, vt: VectorType, vectors: &[SharedVector]) -> f64 {
+		let dist = Distance::Euclidean;
+		let mut error = 0.0;
+		for v in vectors {
+			let decoded = q.encode(v).unwrap().decode(vt);
+			assert_eq!(decoded.len(), v.len());
+			let zero = Vector::F64(Array1::zeros(v.len()));
+			let norm = dist.calculate(&to_f64(v), &zero);
+			error += dist.calculate(&to_f64(&decoded), &to_f64(v)) / norm;
+		}
+		error / vectors.len() as f64
+	}
+
+	#[test]
+	fn test_scalar_quantization() {
+		for vt in
+			[VectorType::F64, VectorType::F32, VectorType::I64, VectorType::I32, VectorType::I16]
+		{
+			let vectors = random_vectors(vt, 64, 100);
+			let q = Quantizer::new(VectorQuantization::Scalar);
+			let error = check_quantizer(&q, vt, &vectors);
+			assert!(error < 0.01, "{vt} - {error}");
+		}
+	}
+
+	#[test]
+	fn test_binary_quantization() {
+		let vectors = random_vectors(VectorType::F32, 100, 100);
+		let q = Quantizer::new(VectorQuantization::Binary);
+		let error = check_quantizer(&q, VectorType::F32, &vectors);
+		assert!(error < 0.6, "{error}");
+	}
+
+	#[test]
+	fn test_product_quantization() {
+		let vectors = random_vectors(VectorType::F32, 16, PQ_TRAINING_SIZE);
+		let mut q = Quantizer::new(VectorQuantization::Product(4));
+		assert!(!q.requires_training(PQ_TRAINING_SIZE - 1));
+		assert!(q.requires_training(PQ_TRAINING_SIZE));
+		assert!(q.encode(&vectors[0]).is_none());
+		let samples: Vec<Vector> = vectors.iter().map(|v| v.clone_vector()).collect();
+		let codebook_id = q.train(&samples).map(|c| c.id);
+		assert!(codebook_id.is_some());
+		assert_eq!(q.codebook_id(), codebook_id);
+		assert!(!q.requires_training(PQ_TRAINING_SIZE));
+		let error = check_quantizer(&q, VectorType::F32, &vectors);
+		assert!(error < 0.3, "{error}");
+	}
+}
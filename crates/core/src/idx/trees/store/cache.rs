@@ -66,6 +66,13 @@ impl IndexTreeCaches {
 	pub(crate) fn advance_store_mtree(&self, new_cache: TreeCache<MTreeNode>) {
 		self.mtree_caches.new_cache(new_cache);
 	}
+
+	/// Removes every cached tree, e.g. when the changes of the transaction are rolled back
+	pub(crate) fn clear(&self) {
+		self.btree_fst_caches.0.clear();
+		self.btree_trie_caches.0.clear();
+		self.mtree_caches.0.clear();
+	}
 }
 
 pub(super) struct TreeCaches<N>(Arc<DashMap<Key, Arc<TreeCache<N>>>>)
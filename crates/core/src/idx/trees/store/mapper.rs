@@ -1,78 +1,120 @@
 use crate::err::Error;
 use crate::iam::file::is_path_allowed;
 use crate::idx::ft::analyzer::mapper::Mapper;
+use crate::idx::ft::analyzer::synonym::Synonyms;
 use crate::sql::statements::DefineAnalyzerStatement;
 use crate::sql::Filter;
 use ahash::HashSet;
 use dashmap::DashMap;
 use std::path::Path;
 
+/// The files used by the filters of the analyzers (mappers and synonyms), loaded in memory.
 #[derive(Default)]
-pub(crate) struct Mappers(DashMap<String, Mapper>);
+pub(crate) struct Mappers {
+	mappers: DashMap<String, Mapper>,
+	synonyms: DashMap<String, Synonyms>,
+}
 
 impl Mappers {
-	/// If any mapper is defined, it will be loaded in memory.
+	/// If any mapper or synonym file is defined, it will be loaded in memory.
 	pub(crate) async fn load(&self, az: &DefineAnalyzerStatement) -> Result<(), Error> {
 		if let Some(filters) = &az.filters {
 			for f in filters {
-				if let Filter::Mapper(path) = f {
-					self.insert(path).await?;
+				match f {
+					Filter::Mapper(path) => self.insert_mapper(path).await?,
+					Filter::Synonym(path) => self.insert_synonyms(path).await?,
+					_ => {}
 				}
 			}
 		}
 		Ok(())
 	}
 
-	/// Ensure that if a mapper is defined, that it is also loaded in memory.
-	/// This method does not reload a mapper if it is already in memory.
+	/// Ensure that if a mapper or a synonym file is defined, that it is also loaded in memory.
+	/// This method does not reload a file if it is already in memory.
 	pub(crate) async fn check(&self, az: &DefineAnalyzerStatement) -> Result<(), Error> {
 		if let Some(filters) = &az.filters {
 			for f in filters {
-				if let Filter::Mapper(path) = f {
-					if !self.0.contains_key(path) {
-						self.insert(path).await?;
+				match f {
+					Filter::Mapper(path) if !self.mappers.contains_key(path) => {
+						self.insert_mapper(path).await?
+					}
+					Filter::Synonym(path) if !self.synonyms.contains_key(path) => {
+						self.insert_synonyms(path).await?
 					}
+					_ => {}
 				}
 			}
 		}
 		Ok(())
 	}
 
-	async fn insert(&self, path: &str) -> Result<(), Error> {
+	fn check_path<'a>(path: &'a str, kind: &str) -> Result<&'a Path, Error> {
 		let p = Path::new(path);
 		// Check the path is allowed
 		is_path_allowed(p)?;
 		if !p.exists() || !p.is_file() {
-			return Err(Error::Internal(format!("Invalid mapper path: {p:?}")));
+			return Err(Error::Internal(format!("Invalid {kind} path: {p:?}")));
 		}
+		Ok(p)
+	}
+
+	async fn insert_mapper(&self, path: &str) -> Result<(), Error> {
+		let p = Self::check_path(path, "mapper")?;
 		let mapper = Mapper::new(p).await?;
-		self.0.insert(path.to_string(), mapper);
+		self.mappers.insert(path.to_string(), mapper);
+		Ok(())
+	}
+
+	async fn insert_synonyms(&self, path: &str) -> Result<(), Error> {
+		let p = Self::check_path(path, "synonym")?;
+		let synonyms = Synonyms::new(p).await?;
+		self.synonyms.insert(path.to_string(), synonyms);
 		Ok(())
 	}
 
 	pub(in crate::idx) fn get(&self, path: &str) -> Result<Mapper, Error> {
-		match self.0.get(path) {
+		match self.mappers.get(path) {
 			None => Err(Error::Internal(format!("Mapper not found for {path}"))),
 			Some(e) => Ok(e.value().clone()),
 		}
 	}
 
+	pub(in crate::idx) fn get_synonyms(&self, path: &str) -> Result<Synonyms, Error> {
+		match self.synonyms.get(path) {
+			None => Err(Error::Internal(format!("Synonyms not found for {path}"))),
+			Some(e) => Ok(e.value().clone()),
+		}
+	}
+
 	pub(crate) fn cleanup(&self, azs: &[DefineAnalyzerStatement]) {
-		// Collect every existing mapper
-		let mut keys: HashSet<String> = self.0.iter().map(|e| e.key().to_string()).collect();
+		// Collect every existing mapper and synonym file
+		let mut mappers: HashSet<String> =
+			self.mappers.iter().map(|e| e.key().to_string()).collect();
+		let mut synonyms: HashSet<String> =
+			self.synonyms.iter().map(|e| e.key().to_string()).collect();
 		// Remove keys that still exist in the definitions
 		for az in azs {
 			if let Some(filters) = &az.filters {
 				for f in filters {
-					if let Filter::Mapper(path) = f {
-						keys.remove(path);
+					match f {
+						Filter::Mapper(path) => {
+							mappers.remove(path);
+						}
+						Filter::Synonym(path) => {
+							synonyms.remove(path);
+						}
+						_ => {}
 					}
 				}
 			}
 		}
 		// Any left key can be removed
-		for key in keys {
-			self.0.remove(&key);
+		for key in mappers {
+			self.mappers.remove(&key);
+		}
+		for key in synonyms {
+			self.synonyms.remove(&key);
 		}
 	}
 }
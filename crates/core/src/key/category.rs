@@ -20,12 +20,16 @@ pub enum Category {
 	AccessRoot,
 	/// crate::key::root::access::gr         /*{ac}!gr{gr}
 	AccessGrant,
+	/// crate::key::root::eq                 /!eq{ts}{id}
+	EventQueue,
 	/// crate::key::root::nd                 /!nd{nd}
 	Node,
 	/// crate::key::root::ni                 /!ni
 	NamespaceIdentifier,
 	/// crate::key::root::ns                 /!ns{ns}
 	Namespace,
+	/// crate::key::root::rl                 /!rl{rl}
+	Role,
 	/// crate::key::root::us                 /!us{us}
 	User,
 	///
@@ -55,6 +59,8 @@ pub enum Category {
 	NamespaceAccessRoot,
 	/// crate::key::namespace::access::gr    /*{ns}*{ac}!gr{gr}
 	NamespaceAccessGrant,
+	/// crate::key::namespace::rl            /*{ns}!rl{rl}
+	NamespaceRole,
 	/// crate::key::namespace::us            /*{ns}!us{us}
 	NamespaceUser,
 	///
@@ -76,10 +82,20 @@ pub enum Category {
 	DatabaseFunction,
 	/// crate::key::database::ml             /*{ns}*{db}!ml{ml}{vn}
 	DatabaseModel,
+	/// crate::key::database::jb             /*{ns}*{db}!jb{jb}
+	DatabaseJob,
+	/// crate::key::database::js             /*{ns}*{db}!js{jb}
+	DatabaseJobState,
 	/// crate::key::database::pa             /*{ns}*{db}!pa{pa}
 	DatabaseParameter,
 	/// crate::key::database::tb             /*{ns}*{db}!tb{tb}
 	DatabaseTable,
+	/// crate::key::database::rl             /*{ns}*{db}!rl{rl}
+	DatabaseRole,
+	/// crate::key::database::sk             /*{ns}*{db}!sk{sk}
+	DatabaseSink,
+	/// crate::key::database::so             /*{ns}*{db}!so{sk}
+	DatabaseSinkOffset,
 	/// crate::key::database::ts             /*{ns}*{db}!ts{ts}
 	DatabaseTimestamp,
 	/// crate::key::database::us             /*{ns}*{db}!us{us}
@@ -130,6 +146,8 @@ pub enum Category {
 	IndexBTreeNodeTerms,
 	/// crate::key::index::bu                /*{ns}*{db}*{tb}+{ix}!bu{id}
 	IndexTerms,
+	/// crate::key::index::gh                /*{ns}*{db}*{tb}+{ix}!gh{cell}{id}
+	IndexGeohash,
 	/// crate::key::index::he                /*{ns}*{db}*{tb}+{ix}!he{id}
 	IndexHnswElements,
 	/// crate::key::index::hd                /*{ns}*{db}*{tb}+{ix}!hd{id}
@@ -174,9 +192,11 @@ impl Display for Category {
 			Self::Access => "Access",
 			Self::AccessRoot => "AccessRoot",
 			Self::AccessGrant => "AccessGrant",
+			Self::EventQueue => "EventQueue",
 			Self::Node => "Node",
 			Self::NamespaceIdentifier => "NamespaceIdentifier",
 			Self::Namespace => "Namespace",
+			Self::Role => "Role",
 			Self::User => "User",
 			Self::NodeRoot => "NodeRoot",
 			Self::NodeLiveQuery => "NodeLiveQuery",
@@ -186,6 +206,7 @@ impl Display for Category {
 			Self::NamespaceAccess => "NamespaceAccess",
 			Self::NamespaceAccessRoot => "NamespaceAccessRoot",
 			Self::NamespaceAccessGrant => "NamespaceAccessGrant",
+			Self::NamespaceRole => "NamespaceRole",
 			Self::NamespaceUser => "NamespaceUser",
 			Self::DatabaseRoot => "DatabaseRoot",
 			Self::DatabaseAccess => "DatabaseAccess",
@@ -195,10 +216,15 @@ impl Display for Category {
 			Self::DatabaseAnalyzer => "DatabaseAnalyzer",
 			Self::DatabaseFunction => "DatabaseFunction",
 			Self::DatabaseModel => "DatabaseModel",
+			Self::DatabaseJob => "DatabaseJob",
+			Self::DatabaseJobState => "DatabaseJobState",
 			Self::DatabaseParameter => "DatabaseParameter",
 			Self::DatabaseTable => "DatabaseTable",
 			Self::DatabaseTableIdentifier => "DatabaseTableIdentifier",
 			Self::DatabaseTimestamp => "DatabaseTimestamp",
+			Self::DatabaseRole => "DatabaseRole",
+			Self::DatabaseSink => "DatabaseSink",
+			Self::DatabaseSinkOffset => "DatabaseSinkOffset",
 			Self::DatabaseUser => "DatabaseUser",
 			Self::DatabaseVersionstamp => "DatabaseVersionstamp",
 			Self::DatabaseConfig => "DatabaseConfig",
@@ -220,6 +246,7 @@ impl Display for Category {
 			Self::IndexFullTextState => "IndexFullTextState",
 			Self::IndexBTreeNodeTerms => "IndexBTreeNodeTerms",
 			Self::IndexTerms => "IndexTerms",
+			Self::IndexGeohash => "IndexGeohash",
 			Self::IndexHnswElements => "IndexHnswElements",
 			Self::IndexHnswDocIds => "IndexHnswDocIds",
 			Self::IndexHnswThings => "IndexHnswThings",
@@ -0,0 +1,79 @@
+//! Stores a DEFINE JOB config definition
+use crate::err::Error;
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use crate::kvs::{impl_key, KeyEncode};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Jb<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	_d: u8,
+	_e: u8,
+	pub jb: &'a str,
+}
+impl_key!(Jb<'a>);
+
+pub fn new<'a>(ns: &'a str, db: &'a str, jb: &'a str) -> Jb<'a> {
+	Jb::new(ns, db, jb)
+}
+
+pub fn prefix(ns: &str, db: &str) -> Result<Vec<u8>, Error> {
+	let mut k = super::all::new(ns, db).encode()?;
+	k.extend_from_slice(b"!jb\x00");
+	Ok(k)
+}
+
+pub fn suffix(ns: &str, db: &str) -> Result<Vec<u8>, Error> {
+	let mut k = super::all::new(ns, db).encode()?;
+	k.extend_from_slice(b"!jb\xff");
+	Ok(k)
+}
+
+impl Categorise for Jb<'_> {
+	fn categorise(&self) -> Category {
+		Category::DatabaseJob
+	}
+}
+
+impl<'a> Jb<'a> {
+	pub fn new(ns: &'a str, db: &'a str, jb: &'a str) -> Self {
+		Self {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: b'!',
+			_d: b'j',
+			_e: b'b',
+			jb,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::KeyDecode;
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Jb::new(
+			"testns",
+			"testdb",
+			"testjb",
+		);
+		let enc = Jb::encode(&val).unwrap();
+		assert_eq!(enc, b"/*testns\0*testdb\0!jbtestjb\0");
+
+		let dec = Jb::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -0,0 +1,66 @@
+//! Stores the scheduling state of a DEFINE JOB
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use crate::kvs::impl_key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Js<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	_d: u8,
+	_e: u8,
+	pub jb: &'a str,
+}
+impl_key!(Js<'a>);
+
+pub fn new<'a>(ns: &'a str, db: &'a str, jb: &'a str) -> Js<'a> {
+	Js::new(ns, db, jb)
+}
+
+impl Categorise for Js<'_> {
+	fn categorise(&self) -> Category {
+		Category::DatabaseJobState
+	}
+}
+
+impl<'a> Js<'a> {
+	pub fn new(ns: &'a str, db: &'a str, jb: &'a str) -> Self {
+		Self {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: b'!',
+			_d: b'j',
+			_e: b's',
+			jb,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::{KeyDecode, KeyEncode};
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Js::new(
+			"testns",
+			"testdb",
+			"testjb",
+		);
+		let enc = Js::encode(&val).unwrap();
+		assert_eq!(enc, b"/*testns\0*testdb\0!jstestjb\0");
+
+		let dec = Js::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -5,8 +5,13 @@ pub mod ap;
 pub mod az;
 pub mod cg;
 pub mod fc;
+pub mod jb;
+pub mod js;
 pub mod ml;
 pub mod pa;
+pub mod rl;
+pub mod sk;
+pub mod so;
 pub mod tb;
 pub mod ti;
 pub mod ts;
@@ -0,0 +1,66 @@
+//! Stores the delivery progress of a DEFINE SINK
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use crate::kvs::impl_key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct So<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	_d: u8,
+	_e: u8,
+	pub sk: &'a str,
+}
+impl_key!(So<'a>);
+
+pub fn new<'a>(ns: &'a str, db: &'a str, sk: &'a str) -> So<'a> {
+	So::new(ns, db, sk)
+}
+
+impl Categorise for So<'_> {
+	fn categorise(&self) -> Category {
+		Category::DatabaseSinkOffset
+	}
+}
+
+impl<'a> So<'a> {
+	pub fn new(ns: &'a str, db: &'a str, sk: &'a str) -> Self {
+		Self {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: b'!',
+			_d: b's',
+			_e: b'o',
+			sk,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::{KeyDecode, KeyEncode};
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = So::new(
+			"testns",
+			"testdb",
+			"testsk",
+		);
+		let enc = So::encode(&val).unwrap();
+		assert_eq!(enc, b"/*testns\0*testdb\0!sotestsk\0");
+
+		let dec = So::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -0,0 +1,138 @@
+//! Stores the geohash cells of a geospatial index
+use crate::err::Error;
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use crate::kvs::{impl_key, KeyEncode};
+use crate::sql::Id;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Gh<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+	pub cell: &'a str,
+	pub id: Cow<'a, Id>,
+}
+impl_key!(Gh<'a>);
+
+impl Categorise for Gh<'_> {
+	fn categorise(&self) -> Category {
+		Category::IndexGeohash
+	}
+}
+
+impl<'a> Gh<'a> {
+	pub fn new(
+		ns: &'a str,
+		db: &'a str,
+		tb: &'a str,
+		ix: &'a str,
+		cell: &'a str,
+		id: &'a Id,
+	) -> Self {
+		Self {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: b'*',
+			tb,
+			_d: b'+',
+			ix,
+			_e: b'!',
+			_f: b'g',
+			_g: b'h',
+			cell,
+			id: Cow::Borrowed(id),
+		}
+	}
+}
+
+fn root(ns: &str, db: &str, tb: &str, ix: &str) -> Result<Vec<u8>, Error> {
+	let mut k = crate::key::index::all::new(ns, db, tb, ix).encode()?;
+	k.extend_from_slice(b"!gh");
+	Ok(k)
+}
+
+/// The first key of the entries whose cell starts with the given geohash
+pub fn prefix(ns: &str, db: &str, tb: &str, ix: &str, cell: &str) -> Result<Vec<u8>, Error> {
+	let mut k = root(ns, db, tb, ix)?;
+	k.extend_from_slice(cell.as_bytes());
+	Ok(k)
+}
+
+/// The last key of the entries whose cell starts with the given geohash
+pub fn suffix(ns: &str, db: &str, tb: &str, ix: &str, cell: &str) -> Result<Vec<u8>, Error> {
+	let mut k = prefix(ns, db, tb, ix, cell)?;
+	k.push(0xff);
+	Ok(k)
+}
+
+/// The first key of the entries stored with exactly the given cell
+pub fn cell_prefix(ns: &str, db: &str, tb: &str, ix: &str, cell: &str) -> Result<Vec<u8>, Error> {
+	let mut k = prefix(ns, db, tb, ix, cell)?;
+	k.push(0x00);
+	Ok(k)
+}
+
+/// The last key of the entries stored with exactly the given cell
+pub fn cell_suffix(ns: &str, db: &str, tb: &str, ix: &str, cell: &str) -> Result<Vec<u8>, Error> {
+	let mut k = cell_prefix(ns, db, tb, ix, cell)?;
+	k.push(0xff);
+	Ok(k)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::KeyDecode;
+
+	#[test]
+	fn key() {
+		use super::*;
+		let id = Id::String("testid".to_string());
+		let val = Gh::new("testns", "testdb", "testtb", "testix", "u09tv", &id);
+		let enc = Gh::encode(&val).unwrap();
+		assert_eq!(
+			enc,
+			b"/*testns\0*testdb\0*testtb\0+testix\0!ghu09tv\0\0\0\0\x01testid\0",
+			"{}",
+			String::from_utf8_lossy(&enc)
+		);
+
+		let dec = Gh::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+
+	#[test]
+	fn ranges() {
+		use super::*;
+		let id = Id::String("testid".to_string());
+		let key = Gh::new("testns", "testdb", "testtb", "testix", "u09tv", &id).encode().unwrap();
+		for cell in ["", "u", "u09", "u09tv"] {
+			let beg = prefix("testns", "testdb", "testtb", "testix", cell).unwrap();
+			let end = suffix("testns", "testdb", "testtb", "testix", cell).unwrap();
+			assert!(beg < key && key < end, "{cell}");
+		}
+		let beg = prefix("testns", "testdb", "testtb", "testix", "u09tw").unwrap();
+		assert!(key < beg);
+		let beg = cell_prefix("testns", "testdb", "testtb", "testix", "u09tv").unwrap();
+		let end = cell_suffix("testns", "testdb", "testtb", "testix", "u09tv").unwrap();
+		assert!(beg < key && key < end);
+		let beg = cell_prefix("testns", "testdb", "testtb", "testix", "u09").unwrap();
+		let end = cell_suffix("testns", "testdb", "testtb", "testix", "u09").unwrap();
+		assert!(!(beg < key && key < end));
+	}
+}
@@ -0,0 +1,62 @@
+//! Stores the codebook of a quantized HNSW index
+use crate::kvs::impl_key;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Hq<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+}
+impl_key!(Hq<'a>);
+
+impl<'a> Hq<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str) -> Self {
+		Self {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: b'*',
+			tb,
+			_d: b'+',
+			ix,
+			_e: b'!',
+			_f: b'h',
+			_g: b'q',
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::{KeyDecode, KeyEncode};
+
+	#[test]
+	fn key() {
+		use super::*;
+		let val = Hq::new("testns", "testdb", "testtb", "testix");
+		let enc = Hq::encode(&val).unwrap();
+		assert_eq!(
+			enc,
+			b"/*testns\0*testdb\0*testtb\0+testix\0!hq",
+			"{}",
+			String::from_utf8_lossy(&enc)
+		);
+
+		let dec = Hq::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
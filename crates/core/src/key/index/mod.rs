@@ -11,10 +11,12 @@ pub mod bp;
 pub mod bs;
 pub mod bt;
 pub mod bu;
+pub mod gh;
 pub mod hd;
 pub mod he;
 pub mod hi;
 pub mod hl;
+pub mod hq;
 pub mod hs;
 pub mod hv;
 pub mod ia;
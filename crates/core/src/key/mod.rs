@@ -4,9 +4,11 @@
 ///
 /// crate::key::root::all                /
 /// crate::key::root::ac                 /!ac{ac}
+/// crate::key::root::eq                 /!eq{ts}{id}
 /// crate::key::root::nd                 /!nd{nd}
 /// crate::key::root::ni                 /!ni
 /// crate::key::root::ns                 /!ns{ns}
+/// crate::key::root::rl                 /!rl{rl}
 /// crate::key::root::us                 /!us{us}
 ///
 /// crate::key::node::all                /${nd}
@@ -20,6 +22,7 @@
 /// crate::key::namespace::db            /*{ns}!db{db}
 /// crate::key::namespace::di            /+{ns id}!di
 /// crate::key::namespace::lg            /*{ns}!lg{lg}
+/// crate::key::namespace::rl            /*{ns}!rl{rl}
 /// crate::key::namespace::us            /*{ns}!us{us}
 ///
 /// crate::key::namespace::access::all   /*{ns}&{ac}
@@ -29,8 +32,13 @@
 /// crate::key::database::ac             /*{ns}*{db}!ac{ac}
 /// crate::key::database::az             /*{ns}*{db}!az{az}
 /// crate::key::database::fc             /*{ns}*{db}!fn{fc}
+/// crate::key::database::jb             /*{ns}*{db}!jb{jb}
+/// crate::key::database::js             /*{ns}*{db}!js{jb}
 /// crate::key::database::ml             /*{ns}*{db}!ml{ml}{vn}
 /// crate::key::database::pa             /*{ns}*{db}!pa{pa}
+/// crate::key::database::rl             /*{ns}*{db}!rl{rl}
+/// crate::key::database::sk             /*{ns}*{db}!sk{sk}
+/// crate::key::database::so             /*{ns}*{db}!so{sk}
 /// crate::key::database::tb             /*{ns}*{db}!tb{tb}
 /// crate::key::database::ti             /+{ns id}*{db id}!ti
 /// crate::key::database::ts             /*{ns}*{db}!ts{ts}
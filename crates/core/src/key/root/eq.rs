@@ -0,0 +1,96 @@
+//! Stores a queued asynchronous event awaiting processing
+use crate::err::Error;
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use crate::kvs::{impl_key, KeyEncode};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The Eq key is used to queue asynchronous events for background processing.
+/// Entries are ordered by the time at which they are next due to be processed.
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Eq {
+	__: u8,
+	_a: u8,
+	_b: u8,
+	_c: u8,
+	pub ts: u64,
+	#[serde(with = "uuid::serde::compact")]
+	pub id: Uuid,
+}
+impl_key!(Eq);
+
+pub fn new(ts: u64, id: Uuid) -> Eq {
+	Eq::new(ts, id)
+}
+
+pub fn prefix() -> Vec<u8> {
+	let mut k = crate::key::root::all::kv();
+	k.extend_from_slice(b"!eq\x00");
+	k
+}
+
+/// Returns the key following all events which are due at the given time
+pub fn suffix(ts: u64) -> Result<Vec<u8>, Error> {
+	let mut k = Eq::new(ts, Uuid::max()).encode()?;
+	k.push(0x00);
+	Ok(k)
+}
+
+impl Categorise for Eq {
+	fn categorise(&self) -> Category {
+		Category::EventQueue
+	}
+}
+
+impl Eq {
+	pub fn new(ts: u64, id: Uuid) -> Self {
+		Self {
+			__: b'/',
+			_a: b'!',
+			_b: b'e',
+			_c: b'q',
+			ts,
+			id,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::KeyDecode;
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Eq::new(
+			12345,
+			Uuid::from_bytes([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10]),
+		);
+		let enc = val.encode().unwrap();
+		assert_eq!(
+			enc,
+			b"/!eq\x00\x00\x00\x00\x00\x00\x30\x39\
+			\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\x0e\x0f\x10"
+		);
+		let dec = Eq::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+
+	#[test]
+	fn test_prefix() {
+		let val = super::prefix();
+		assert_eq!(val, b"/!eq\0")
+	}
+
+	#[test]
+	fn test_suffix() {
+		let val = super::suffix(12345).unwrap();
+		assert_eq!(
+			val,
+			b"/!eq\x00\x00\x00\x00\x00\x00\x30\x39\
+			\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\x00"
+		)
+	}
+}
@@ -0,0 +1,77 @@
+//! Stores a DEFINE ROLE ON ROOT config definition
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use crate::kvs::impl_key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Rl<'a> {
+	__: u8,
+	_a: u8,
+	_b: u8,
+	_c: u8,
+	pub role: &'a str,
+}
+impl_key!(Rl<'a>);
+
+pub fn new(role: &str) -> Rl<'_> {
+	Rl::new(role)
+}
+
+pub fn prefix() -> Vec<u8> {
+	let mut k = super::all::kv();
+	k.extend_from_slice(b"!rl\x00");
+	k
+}
+
+pub fn suffix() -> Vec<u8> {
+	let mut k = super::all::kv();
+	k.extend_from_slice(b"!rl\xff");
+	k
+}
+
+impl Categorise for Rl<'_> {
+	fn categorise(&self) -> Category {
+		Category::Role
+	}
+}
+
+impl<'a> Rl<'a> {
+	pub fn new(role: &'a str) -> Self {
+		Self {
+			__: b'/',
+			_a: b'!',
+			_b: b'r',
+			_c: b'l',
+			role,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::kvs::{KeyDecode, KeyEncode};
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Rl::new("testrole");
+		let enc = Rl::encode(&val).unwrap();
+		assert_eq!(enc, b"/!rltestrole\x00");
+		let dec = Rl::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+
+	#[test]
+	fn test_prefix() {
+		let val = super::prefix();
+		assert_eq!(val, b"/!rl\0");
+	}
+
+	#[test]
+	fn test_suffix() {
+		let val = super::suffix();
+		assert_eq!(val, b"/!rl\xff");
+	}
+}
@@ -0,0 +1,973 @@
+//! Binary backups of a whole datastore.
+//!
+//! A full backup is a copy of every key-value pair in the datastore, read within a
+//! single transaction so that it represents one consistent snapshot. An incremental
+//! backup contains the change feed entries which were written since the backup it
+//! builds on, and is restored by replaying those changes. An incremental backup is
+//! refused when a table has no change feed, or when changes since the parent backup
+//! may have been removed by the change feed retention. Each backup is stored in its
+//! own directory, as a set of data files and a manifest which records the checksum
+//! of every data file, and which allows an interrupted backup to be resumed.
+//!
+//! Replayed changes can be limited to a point in time, and can also be read from the
+//! change feeds of another datastore, so that a database can be recovered to the state
+//! it had just before a specific change. The base of a restore is always a full binary
+//! backup, as a SurrealQL export does not record the versionstamps which the replayed
+//! changes follow on from.
+
+use super::{Datastore, Key, KeyDecode as _, LockType::*, Transaction, TransactionType::*, Val};
+use crate::cf::{TableMutation, TableMutations};
+use crate::cnf::EXPORT_BATCH_SIZE;
+use crate::dbs::Session;
+use crate::err::Error;
+use crate::key::change;
+use crate::key::database::ts;
+use crate::sql::paths::{EDGE, IN, OUT};
+use crate::sql::Value;
+use crate::vs::VersionStamp;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use tokio::fs::{self, File};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use uuid::Uuid;
+
+/// The version of the backup format which is written by this build
+const FORMAT: u16 = 1;
+
+/// The bytes which every backup data file starts with
+const MAGIC: &[u8; 4] = b"SDBK";
+
+/// The name of the manifest file within a backup directory
+pub const MANIFEST: &str = "manifest.json";
+
+/// The default maximum size of a single backup data file
+pub const DEFAULT_FILE_SIZE: u64 = 256 * 1024 * 1024;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+	/// The manifest of the backup which an incremental backup builds on
+	pub parent: Option<Manifest>,
+	/// The size after which a new data file is started
+	pub file_size: u64,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Self {
+			parent: None,
+			file_size: DEFAULT_FILE_SIZE,
+		}
+	}
+}
+
+/// The point in time up to which changes are replayed when restoring
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Until {
+	/// Replay the changes up to and including a versionstamp of each database, as
+	/// accepted by `SHOW CHANGES ... SINCE`
+	Versionstamp(u64),
+	/// Replay the changes which were committed before a point in time. Changes are
+	/// matched to a time through the timestamps which are recorded for each database
+	/// on every changefeed tick, so the accuracy depends on the tick interval.
+	Timestamp(DateTime<Utc>),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RestoreConfig {
+	/// The point in time up to which changes are replayed
+	pub until: Option<Until>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+	/// A snapshot of the entire keyspace
+	Full,
+	/// The change feed entries, and changefeed timestamps, written since the parent backup
+	Incremental,
+}
+
+/// The latest versionstamp of a database when a backup was taken
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Versionstamp {
+	pub ns: String,
+	pub db: String,
+	pub vs: u64,
+}
+
+/// A completed data file within a backup
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DataFile {
+	pub name: String,
+	pub size: u64,
+	pub entries: u64,
+	/// The hex encoded BLAKE3 hash of the file contents
+	pub checksum: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+	pub format: u16,
+	pub id: Uuid,
+	pub kind: Kind,
+	/// The backup which an incremental backup builds on
+	pub parent: Option<Uuid>,
+	pub created_at: DateTime<Utc>,
+	/// The versionstamp of each database when the backup was started
+	pub versionstamps: Vec<Versionstamp>,
+	/// The versionstamps from which an incremental backup copies changes
+	pub since: Vec<Versionstamp>,
+	pub files: Vec<DataFile>,
+	/// The hex encoded key of the last entry in the last completed data file
+	pub cursor: Option<String>,
+	/// Whether a full backup was interrupted, and continued from a newer snapshot
+	pub resumed: bool,
+	pub complete: bool,
+}
+
+impl Manifest {
+	/// Reads the manifest of the backup in a directory
+	pub async fn load(dir: &Path) -> Result<Self, Error> {
+		let buf = fs::read(dir.join(MANIFEST)).await?;
+		let manifest: Manifest = serde_json::from_slice(&buf)
+			.map_err(|e| Error::Backup(format!("the manifest could not be read: {e}")))?;
+		if manifest.format != FORMAT {
+			return Err(Error::Backup(format!(
+				"unsupported backup format version {}",
+				manifest.format
+			)));
+		}
+		Ok(manifest)
+	}
+
+	/// Writes the manifest into a directory, replacing any previous manifest atomically
+	async fn save(&self, dir: &Path) -> Result<(), Error> {
+		let buf = serde_json::to_vec_pretty(self)
+			.map_err(|e| Error::Backup(format!("the manifest could not be written: {e}")))?;
+		let tmp = dir.join(format!("{MANIFEST}.tmp"));
+		fs::write(&tmp, buf).await?;
+		fs::rename(&tmp, dir.join(MANIFEST)).await?;
+		Ok(())
+	}
+
+	fn since(&self, ns: &str, db: &str) -> Option<u64> {
+		self.since.iter().find(|v| v.ns == ns && v.db == db).map(|v| v.vs)
+	}
+}
+
+fn data_file_name(index: usize) -> String {
+	format!("data-{index:06}.bin")
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>, Error> {
+	hex::decode(s).map_err(|e| Error::Backup(format!("invalid backup cursor: {e}")))
+}
+
+/// A data file which is currently being written
+struct Open {
+	name: String,
+	out: BufWriter<File>,
+	hasher: blake3::Hasher,
+	size: u64,
+	entries: u64,
+	last: Key,
+}
+
+impl Open {
+	async fn create(dir: &Path, name: String) -> Result<Self, Error> {
+		let file = File::create(dir.join(&name)).await?;
+		let mut open = Self {
+			name,
+			out: BufWriter::new(file),
+			hasher: blake3::Hasher::new(),
+			size: 0,
+			entries: 0,
+			last: Key::new(),
+		};
+		open.write(MAGIC).await?;
+		open.write(&FORMAT.to_be_bytes()).await?;
+		Ok(open)
+	}
+
+	async fn write(&mut self, buf: &[u8]) -> Result<(), Error> {
+		self.out.write_all(buf).await?;
+		self.hasher.update(buf);
+		self.size += buf.len() as u64;
+		Ok(())
+	}
+
+	async fn push(&mut self, key: Key, val: Val) -> Result<(), Error> {
+		self.write(&(key.len() as u32).to_be_bytes()).await?;
+		self.write(&key).await?;
+		self.write(&(val.len() as u32).to_be_bytes()).await?;
+		self.write(&val).await?;
+		self.entries += 1;
+		self.last = key;
+		Ok(())
+	}
+
+	async fn finish(mut self) -> Result<(DataFile, Key), Error> {
+		self.out.flush().await?;
+		self.out.get_mut().sync_all().await?;
+		let file = DataFile {
+			name: self.name,
+			size: self.size,
+			entries: self.entries,
+			checksum: self.hasher.finalize().to_hex().to_string(),
+		};
+		Ok((file, self.last))
+	}
+}
+
+/// Reads the entries of a backup data file in order
+struct Reader {
+	inp: BufReader<File>,
+}
+
+impl Reader {
+	async fn open(path: &Path) -> Result<Self, Error> {
+		let mut inp = BufReader::new(File::open(path).await?);
+		let mut magic = [0u8; 4];
+		inp.read_exact(&mut magic).await?;
+		if &magic != MAGIC || inp.read_u16().await? != FORMAT {
+			return Err(Error::Backup(format!("{} is not a backup data file", path.display())));
+		}
+		Ok(Self {
+			inp,
+		})
+	}
+
+	async fn next(&mut self) -> Result<Option<(Key, Val)>, Error> {
+		if self.inp.fill_buf().await?.is_empty() {
+			return Ok(None);
+		}
+		let mut key = vec![0u8; self.inp.read_u32().await? as usize];
+		self.inp.read_exact(&mut key).await?;
+		let mut val = vec![0u8; self.inp.read_u32().await? as usize];
+		self.inp.read_exact(&mut val).await?;
+		Ok(Some((key, val)))
+	}
+}
+
+/// Computes the checksum of a data file, and checks it against the manifest
+async fn verify(dir: &Path, file: &DataFile) -> Result<(), Error> {
+	let mut inp = File::open(dir.join(&file.name)).await?;
+	let mut hasher = blake3::Hasher::new();
+	let mut buf = vec![0u8; 1024 * 1024];
+	loop {
+		let n = inp.read(&mut buf).await?;
+		if n == 0 {
+			break;
+		}
+		hasher.update(&buf[..n]);
+	}
+	if hasher.finalize().to_hex().as_str() != file.checksum {
+		return Err(Error::Backup(format!(
+			"the checksum of {} in {} does not match the manifest",
+			file.name,
+			dir.display()
+		)));
+	}
+	Ok(())
+}
+
+impl Transaction {
+	/// Fetches the latest versionstamp of every database
+	async fn backup_versionstamps(&self) -> Result<Vec<Versionstamp>, Error> {
+		let mut res = Vec::new();
+		for ns in self.all_ns().await?.iter() {
+			for db in self.all_db(&ns.name).await?.iter() {
+				let key = crate::key::database::vs::new(&ns.name, &db.name);
+				let vs = match self.get(key, None).await? {
+					Some(v) => VersionStamp::from_slice(&v)?.into_u64_lossy(),
+					None => 0,
+				};
+				res.push(Versionstamp {
+					ns: ns.name.to_raw(),
+					db: db.name.to_raw(),
+					vs,
+				});
+			}
+		}
+		Ok(res)
+	}
+
+	/// Checks that the change feeds of a database hold every change which
+	/// was made after a versionstamp, so that the changes can be copied
+	async fn backup_check_changes(&self, ns: &str, db: &str, since: u64) -> Result<(), Error> {
+		// Changes are only recorded for tables with a change feed
+		if self.get_db(ns, db).await?.changefeed.is_none() {
+			let tbs = self.all_tb(ns, db, None).await?;
+			if let Some(tb) = tbs.iter().find(|tb| tb.changefeed.is_none()) {
+				return Err(Error::Backup(format!(
+					"the table {} in {ns}/{db} does not have a change feed, so its changes can not be backed up incrementally",
+					tb.name
+				)));
+			}
+		}
+		// Changes may have been removed by the change feed cleanup
+		let now = Utc::now().timestamp().max(0) as u64;
+		if let Some(vs) = crate::cf::watermark(self, now, ns, db).await? {
+			if vs > next_versionstamp(since)? {
+				return Err(Error::Backup(format!(
+					"changes to {ns}/{db} since the parent backup may have been removed by the change feed retention"
+				)));
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Returns the versionstamp which follows the given versionstamp
+fn next_versionstamp(vs: u64) -> Result<VersionStamp, Error> {
+	VersionStamp::from_u64(vs)
+		.next()
+		.ok_or_else(|| Error::Backup("the versionstamps of the datastore are exhausted".into()))
+}
+
+impl Datastore {
+	/// Writes a binary backup of the entire datastore into a directory.
+	///
+	/// If the directory holds an interrupted backup, then the backup is continued
+	/// after the last completed data file.
+	pub async fn backup(&self, dir: &Path, cfg: Config) -> Result<Manifest, Error> {
+		// Create a new readonly transaction
+		let txn = self.transaction(Read, Optimistic).await?;
+		let res = self.backup_inner(&txn, dir, cfg).await;
+		txn.cancel().await?;
+		res
+	}
+
+	async fn backup_inner(
+		&self,
+		txn: &Transaction,
+		dir: &Path,
+		cfg: Config,
+	) -> Result<Manifest, Error> {
+		fs::create_dir_all(dir).await?;
+		// Check for a previous attempt at this backup
+		let mut manifest = if fs::try_exists(dir.join(MANIFEST)).await? {
+			let manifest = Manifest::load(dir).await?;
+			if manifest.complete {
+				return Err(Error::Backup(format!(
+					"{} already contains a complete backup",
+					dir.display()
+				)));
+			}
+			if manifest.parent != cfg.parent.as_ref().map(|p| p.id) {
+				return Err(Error::Backup(format!(
+					"{} contains an interrupted backup with a different parent",
+					dir.display()
+				)));
+			}
+			info!("Resuming the backup in {}", dir.display());
+			manifest
+		} else {
+			let (kind, since) = match &cfg.parent {
+				Some(parent) if !parent.complete => {
+					return Err(Error::Backup("the parent backup is not complete".to_string()))
+				}
+				Some(parent) => (Kind::Incremental, parent.versionstamps.clone()),
+				None => (Kind::Full, Vec::new()),
+			};
+			Manifest {
+				format: FORMAT,
+				id: Uuid::now_v7(),
+				kind,
+				parent: cfg.parent.as_ref().map(|p| p.id),
+				created_at: Utc::now(),
+				versionstamps: txn.backup_versionstamps().await?,
+				since,
+				files: Vec::new(),
+				cursor: None,
+				resumed: false,
+				complete: false,
+			}
+		};
+		// Remove any data file which was not completed
+		let mut entries = fs::read_dir(dir).await?;
+		while let Some(entry) = entries.next_entry().await? {
+			let name = entry.file_name().to_string_lossy().into_owned();
+			if name.starts_with("data-") && !manifest.files.iter().any(|f| f.name == name) {
+				fs::remove_file(entry.path()).await?;
+			}
+		}
+		// Calculate the key ranges which should be copied, along with the
+		// versionstamps which copied changefeed timestamps should point at
+		let mut ranges: Vec<(Range<Key>, Option<Range<VersionStamp>>)> = match manifest.kind {
+			Kind::Full => vec![(vec![0x00]..vec![0xff], None)],
+			Kind::Incremental => {
+				let mut ranges = Vec::new();
+				for v in manifest.versionstamps.iter() {
+					let since = manifest.since(&v.ns, &v.db).unwrap_or(0);
+					if since >= v.vs {
+						continue;
+					}
+					txn.backup_check_changes(&v.ns, &v.db, since).await?;
+					let beg = next_versionstamp(since)?;
+					let end = next_versionstamp(v.vs)?;
+					ranges.push((
+						ts::prefix(&v.ns, &v.db)?..ts::suffix(&v.ns, &v.db)?,
+						Some(beg..end),
+					));
+					ranges.push((
+						change::prefix_ts(&v.ns, &v.db, beg)?
+							..change::prefix_ts(&v.ns, &v.db, end)?,
+						None,
+					));
+				}
+				ranges.sort_by(|a, b| a.0.start.cmp(&b.0.start));
+				ranges
+			}
+		};
+		// Skip everything up to the last completed data file
+		if let Some(cursor) = &manifest.cursor {
+			let mut after = from_hex(cursor)?;
+			after.push(0x00);
+			ranges.retain(|(r, _)| r.end > after);
+			if let Some((first, _)) = ranges.first_mut() {
+				if first.start < after {
+					first.start = after;
+				}
+			}
+			if manifest.kind == Kind::Full && !manifest.resumed {
+				warn!(
+					"Continuing an interrupted full backup from a newer snapshot of the datastore"
+				);
+				manifest.resumed = true;
+			}
+		}
+		// Copy the ranges into data files
+		let mut open: Option<Open> = None;
+		for (rng, vs) in ranges {
+			let mut next = Some(rng);
+			while let Some(rng) = next {
+				let batch = txn.batch_keys_vals(rng, *EXPORT_BATCH_SIZE, None).await?;
+				next = batch.next;
+				for (k, v) in batch.result {
+					if let Some(vs) = &vs {
+						if !vs.contains(&VersionStamp::from_slice(&v)?) {
+							continue;
+						}
+					}
+					let file = match open.as_mut() {
+						Some(file) => file,
+						None => {
+							let name = data_file_name(manifest.files.len());
+							open.insert(Open::create(dir, name).await?)
+						}
+					};
+					file.push(k, v).await?;
+					// Complete the data file once it is large enough
+					if file.size >= cfg.file_size {
+						if let Some(file) = open.take() {
+							let (file, last) = file.finish().await?;
+							manifest.files.push(file);
+							manifest.cursor = Some(hex::encode(&last));
+							manifest.save(dir).await?;
+						}
+					}
+				}
+			}
+		}
+		if let Some(file) = open.take() {
+			let (file, last) = file.finish().await?;
+			manifest.files.push(file);
+			manifest.cursor = Some(hex::encode(&last));
+		}
+		manifest.complete = true;
+		manifest.save(dir).await?;
+		Ok(manifest)
+	}
+
+	/// Restores a full backup, followed by any incremental backups which build on it,
+	/// into this datastore, which must be empty. Returns the manifest of the last backup.
+	pub async fn restore(&self, dirs: &[PathBuf], cfg: RestoreConfig) -> Result<Manifest, Error> {
+		// Check that the backups form a complete chain
+		let mut manifests = Vec::with_capacity(dirs.len());
+		for dir in dirs {
+			let manifest = Manifest::load(dir).await?;
+			if !manifest.complete {
+				return Err(Error::Backup(format!("{} is not a complete backup", dir.display())));
+			}
+			match manifests.last() {
+				None if manifest.kind != Kind::Full => {
+					return Err(Error::Backup(format!(
+						"{} is not a full backup, and can not be restored on its own",
+						dir.display()
+					)))
+				}
+				Some(Manifest {
+					id,
+					..
+				}) if manifest.kind != Kind::Incremental || manifest.parent != Some(*id) => {
+					return Err(Error::Backup(format!(
+						"{} does not build on the backup before it",
+						dir.display()
+					)))
+				}
+				_ => manifests.push(manifest),
+			}
+		}
+		let Some(full) = manifests.first() else {
+			return Err(Error::Backup("no backup was specified".into()));
+		};
+		if full.resumed {
+			warn!("The full backup was resumed after an interruption, so it may not be consistent");
+		}
+		if let Some(Until::Timestamp(t)) = &cfg.until {
+			if full.created_at > *t {
+				return Err(Error::Backup(format!(
+					"the full backup was taken at {}, which is after the point in time to restore",
+					full.created_at
+				)));
+			}
+		}
+		// Verify the checksums before anything is written
+		for (dir, manifest) in dirs.iter().zip(manifests.iter()) {
+			for file in manifest.files.iter() {
+				verify(dir, file).await?;
+			}
+		}
+		// Check that this datastore does not contain any data
+		let txn = self.transaction(Read, Optimistic).await?;
+		let keys = txn.keys(vec![b'/']..vec![0xff], 1, None).await;
+		txn.cancel().await?;
+		if !keys?.is_empty() {
+			return Err(Error::Backup(
+				"backups can only be restored into an empty datastore".into(),
+			));
+		}
+		// Restore each of the backups in order
+		for (dir, manifest) in dirs.iter().zip(manifests.iter()) {
+			info!("Restoring the backup in {}", dir.display());
+			match manifest.kind {
+				Kind::Full => {
+					for file in manifest.files.iter() {
+						let mut reader = Reader::open(&dir.join(&file.name)).await?;
+						self.restore_entries(&mut reader).await?;
+					}
+				}
+				Kind::Incremental => {
+					let mut replay =
+						Replay::new(self, cfg.until.as_ref(), Some(manifest.created_at));
+					for file in manifest.files.iter() {
+						let mut reader = Reader::open(&dir.join(&file.name)).await?;
+						while let Some((k, v)) = reader.next().await? {
+							replay.push(&k, &v).await?;
+						}
+					}
+					replay.flush().await?;
+				}
+			}
+		}
+		manifests.pop().ok_or_else(|| Error::Backup("no backup was specified".into()))
+	}
+
+	/// Replays the changes from the change feeds of another datastore, which were made
+	/// after the given versionstamps, and up to the given point in time.
+	pub async fn replay_changefeeds(
+		&self,
+		source: &Datastore,
+		since: &[Versionstamp],
+		until: Option<&Until>,
+	) -> Result<(), Error> {
+		let txn = source.transaction(Read, Optimistic).await?;
+		let res = self.replay_changefeeds_inner(&txn, since, until).await;
+		txn.cancel().await?;
+		res
+	}
+
+	async fn replay_changefeeds_inner(
+		&self,
+		txn: &Transaction,
+		since: &[Versionstamp],
+		until: Option<&Until>,
+	) -> Result<(), Error> {
+		let mut replay = Replay::new(self, until, None);
+		for v in txn.backup_versionstamps().await? {
+			let since = since.iter().find(|s| s.ns == v.ns && s.db == v.db).map_or(0, |s| s.vs);
+			let beg = next_versionstamp(since)?;
+			// The timestamps of the database come first, followed by the changes
+			let ranges = [
+				ts::prefix(&v.ns, &v.db)?..ts::suffix(&v.ns, &v.db)?,
+				change::prefix_ts(&v.ns, &v.db, beg)?..change::suffix(&v.ns, &v.db)?,
+			];
+			for rng in ranges {
+				let mut next = Some(rng);
+				while let Some(rng) = next {
+					let batch = txn.batch_keys_vals(rng, *EXPORT_BATCH_SIZE, None).await?;
+					next = batch.next;
+					for (k, v) in batch.result {
+						replay.push(&k, &v).await?;
+					}
+				}
+			}
+		}
+		replay.flush().await
+	}
+
+	/// Writes the key-value pairs of a full backup in batches
+	async fn restore_entries(&self, reader: &mut Reader) -> Result<(), Error> {
+		let mut batch = Vec::with_capacity(*EXPORT_BATCH_SIZE as usize);
+		loop {
+			let entry = reader.next().await?;
+			let done = entry.is_none();
+			batch.extend(entry);
+			if done || batch.len() >= *EXPORT_BATCH_SIZE as usize {
+				let txn = self.transaction(Write, Optimistic).await?;
+				for (k, v) in batch.drain(..) {
+					if let Err(e) = txn.set(k, v, None).await {
+						txn.cancel().await?;
+						return Err(e);
+					}
+				}
+				txn.commit().await?;
+			}
+			if done {
+				return Ok(());
+			}
+		}
+	}
+}
+
+/// Replays change feed entries, applying the changes of each original
+/// transaction within a single transaction
+struct Replay<'a> {
+	ds: &'a Datastore,
+	until: Option<&'a Until>,
+	/// The time by which all of the replayed changes had been made, if known
+	copied_at: Option<DateTime<Utc>>,
+	/// The database of the entries which are currently replayed
+	db: Option<(String, String)>,
+	/// The changefeed timestamps of the current database
+	ticks: Vec<(u64, VersionStamp)>,
+	/// The versionstamp at which changes to the current database stop being replayed
+	end: Option<Option<VersionStamp>>,
+	/// The versionstamp of the current change set
+	vs: Option<VersionStamp>,
+	sql: String,
+}
+
+impl<'a> Replay<'a> {
+	fn new(ds: &'a Datastore, until: Option<&'a Until>, copied_at: Option<DateTime<Utc>>) -> Self {
+		Self {
+			ds,
+			until,
+			copied_at,
+			db: None,
+			ticks: Vec::new(),
+			end: None,
+			vs: None,
+			sql: String::new(),
+		}
+	}
+
+	/// Processes a changefeed timestamp or change feed entry
+	async fn push(&mut self, k: &[u8], v: &[u8]) -> Result<(), Error> {
+		let (ns, db, vs) = match change::Cf::decode(k) {
+			Ok(cf) if k.starts_with(&change::prefix(cf.ns, cf.db)?) => (cf.ns, cf.db, Some(cf.vs)),
+			_ => {
+				let ts = ts::Ts::decode(k)?;
+				(ts.ns, ts.db, None)
+			}
+		};
+		// Reset the state once the entries of another database start
+		if self.db.as_ref().is_none_or(|(n, d)| n != ns || d != db) {
+			self.flush().await?;
+			self.db = Some((ns.to_owned(), db.to_owned()));
+			self.ticks.clear();
+			self.end = None;
+		}
+		let Some(vs) = vs else {
+			let ts = ts::Ts::decode(k)?.ts;
+			self.ticks.push((ts, VersionStamp::from_slice(v)?));
+			return Ok(());
+		};
+		// Skip any changes after the point in time
+		let end = match self.end {
+			Some(end) => end,
+			None => *self.end.insert(self.cutoff()),
+		};
+		if end.is_some_and(|end| vs >= end) {
+			return Ok(());
+		}
+		// Apply the previous change set once a new one starts
+		if self.vs != Some(vs) {
+			self.flush().await?;
+			self.vs = Some(vs);
+		}
+		let muts: TableMutations = revision::from_slice(v)?;
+		for m in muts.1 {
+			replay_statement(&mut self.sql, m);
+		}
+		Ok(())
+	}
+
+	/// Calculates the versionstamp at which changes to the current database stop
+	fn cutoff(&self) -> Option<VersionStamp> {
+		match self.until? {
+			Until::Versionstamp(v) => VersionStamp::from_u64(*v).next(),
+			Until::Timestamp(t) => {
+				if self.copied_at.is_some_and(|c| c <= *t) {
+					return None;
+				}
+				// Every change before the latest timestamp which precedes the point in
+				// time was made before it, and later changes can not be proven to be
+				let ts = t.timestamp().max(0) as u64;
+				let tick = self.ticks.iter().rev().find(|(x, _)| *x <= ts);
+				Some(tick.map_or(VersionStamp::ZERO, |(_, vs)| *vs))
+			}
+		}
+	}
+
+	/// Applies the current change set
+	async fn flush(&mut self) -> Result<(), Error> {
+		self.vs = None;
+		if self.sql.is_empty() {
+			return Ok(());
+		}
+		let Some((ns, db)) = &self.db else {
+			return Ok(());
+		};
+		let sess = Session::owner().with_ns(ns).with_db(db);
+		let sql = format!("OPTION IMPORT;\nBEGIN;\n{}COMMIT;", self.sql);
+		self.sql.clear();
+		for res in self.ds.execute(&sql, &sess, None).await? {
+			res.result?;
+		}
+		Ok(())
+	}
+}
+
+/// Writes the statement which reapplies a single change feed mutation
+fn replay_statement(sql: &mut String, m: TableMutation) {
+	match m {
+		TableMutation::Set(id, v) | TableMutation::SetWithDiff(id, v, _) => {
+			match (v.pick(&*EDGE), v.pick(&*IN), v.pick(&*OUT)) {
+				// Graph edges are recreated, so that the edge pointers are written
+				(Value::Bool(true), Value::Thing(_), Value::Thing(_)) => {
+					let _ = writeln!(sql, "DELETE {id};\nINSERT RELATION {v};");
+				}
+				// Other records are replaced, leaving the edges they have intact
+				_ => {
+					let _ = writeln!(sql, "UPSERT {id} CONTENT {v};");
+				}
+			}
+		}
+		TableMutation::Del(id) | TableMutation::DelWithOriginal(id, _) => {
+			let _ = writeln!(sql, "DELETE {id};");
+		}
+		TableMutation::Def(mut tb) => {
+			tb.overwrite = true;
+			tb.if_not_exists = false;
+			let _ = writeln!(sql, "{tb};");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sql::Part;
+
+	async fn query(ds: &Datastore, sql: &str) -> Value {
+		let sess = Session::owner().with_ns("test").with_db("test");
+		let mut res = ds.execute(sql, &sess, None).await.unwrap();
+		res.pop().unwrap().result.unwrap()
+	}
+
+	#[tokio::test]
+	async fn full_and_incremental_backups_are_restored() {
+		let dir = tempfile::tempdir().unwrap();
+		let full = dir.path().join("full");
+		let incr = dir.path().join("incr");
+		// Create a datastore with a change feed
+		let ds = Datastore::new("memory").await.unwrap();
+		query(
+			&ds,
+			"DEFINE DATABASE test CHANGEFEED 1h; CREATE person:one, person:two SET age = 1;",
+		)
+		.await;
+		let parent = ds.backup(&full, Config::default()).await.unwrap();
+		assert_eq!(parent.kind, Kind::Full);
+		// Change the data after the full backup
+		query(&ds, "UPDATE person:one SET age = 2; DELETE person:two; CREATE person:three;").await;
+		let cfg = Config {
+			parent: Some(parent.clone()),
+			..Default::default()
+		};
+		let child = ds.backup(&incr, cfg).await.unwrap();
+		assert_eq!(child.kind, Kind::Incremental);
+		assert_eq!(child.parent, Some(parent.id));
+		// Restore only the full backup
+		let restored = Datastore::new("memory").await.unwrap();
+		restored.restore(std::slice::from_ref(&full), RestoreConfig::default()).await.unwrap();
+		let res = query(&restored, "SELECT VALUE id FROM person").await;
+		assert_eq!(res.to_string(), "[person:one, person:two]");
+		// Restore the full and the incremental backup
+		let restored = Datastore::new("memory").await.unwrap();
+		restored.restore(&[full.clone(), incr.clone()], RestoreConfig::default()).await.unwrap();
+		let res = query(&restored, "SELECT id, age FROM person").await;
+		assert_eq!(
+			res.to_string(),
+			"[{ age: 2, id: person:one }, { age: NONE, id: person:three }]"
+		);
+		// A backup can not be restored into a datastore with data
+		assert!(restored.restore(&[full], RestoreConfig::default()).await.is_err());
+	}
+
+	#[tokio::test]
+	async fn incremental_backups_require_every_change() {
+		let dir = tempfile::tempdir().unwrap();
+		let ds = Datastore::new("memory").await.unwrap();
+		// Tables without a change feed can not be backed up incrementally
+		query(&ds, "DEFINE TABLE person CHANGEFEED 1s; DEFINE TABLE other; CREATE person:one;")
+			.await;
+		let parent = ds.backup(&dir.path().join("full"), Config::default()).await.unwrap();
+		query(&ds, "CREATE person:two; CREATE other:one;").await;
+		let cfg = Config {
+			parent: Some(parent),
+			..Default::default()
+		};
+		let err = ds.backup(&dir.path().join("incr"), cfg).await.unwrap_err();
+		assert!(err.to_string().contains("does not have a change feed"), "{err}");
+		// Changes which were removed by the change feed retention can not be backed up
+		query(&ds, "REMOVE TABLE other;").await;
+		let parent = ds.backup(&dir.path().join("full2"), Config::default()).await.unwrap();
+		let now = Utc::now().timestamp() as u64;
+		ds.changefeed_process_at(now - 30).await.unwrap();
+		query(&ds, "UPDATE person:one SET age = 1;").await;
+		let cfg = Config {
+			parent: Some(parent.clone()),
+			..Default::default()
+		};
+		ds.backup(&dir.path().join("incr2"), cfg.clone()).await.unwrap();
+		ds.changefeed_process_at(now - 20).await.unwrap();
+		let err = ds.backup(&dir.path().join("incr3"), cfg).await.unwrap_err();
+		assert!(err.to_string().contains("change feed retention"), "{err}");
+	}
+
+	#[tokio::test]
+	async fn corrupted_backups_are_rejected() {
+		let dir = tempfile::tempdir().unwrap();
+		let ds = Datastore::new("memory").await.unwrap();
+		query(&ds, "CREATE person:one;").await;
+		let manifest = ds.backup(dir.path(), Config::default()).await.unwrap();
+		// A complete backup is not overwritten
+		assert!(ds.backup(dir.path(), Config::default()).await.is_err());
+		// Change a single byte of the data file
+		let path = dir.path().join(&manifest.files[0].name);
+		let mut buf = fs::read(&path).await.unwrap();
+		let last = buf.len() - 1;
+		buf[last] ^= 0xff;
+		fs::write(&path, buf).await.unwrap();
+		let restored = Datastore::new("memory").await.unwrap();
+		let err =
+			restored.restore(&[dir.path().to_owned()], RestoreConfig::default()).await.unwrap_err();
+		assert!(err.to_string().contains("checksum"), "{err}");
+	}
+
+	#[tokio::test]
+	async fn interrupted_backups_are_resumed() {
+		let dir = tempfile::tempdir().unwrap();
+		let ds = Datastore::new("memory").await.unwrap();
+		query(&ds, "FOR $i IN 0..100 { CREATE person SET name = string::repeat('x', 100) };").await;
+		// Write small data files, and mark the backup as interrupted after the first
+		let cfg = Config {
+			file_size: 4096,
+			..Default::default()
+		};
+		let mut manifest = ds.backup(dir.path(), cfg.clone()).await.unwrap();
+		assert!(manifest.files.len() > 2);
+		let total = manifest.files.iter().map(|f| f.entries).sum::<u64>();
+		for file in manifest.files.drain(1..) {
+			// Leave a partially written data file behind
+			fs::write(dir.path().join(&file.name), b"SDBK").await.unwrap();
+		}
+		let mut reader = Reader::open(&dir.path().join(&manifest.files[0].name)).await.unwrap();
+		let mut last = Key::new();
+		while let Some((k, _)) = reader.next().await.unwrap() {
+			last = k;
+		}
+		manifest.cursor = Some(hex::encode(last));
+		manifest.complete = false;
+		manifest.save(dir.path()).await.unwrap();
+		// Continue the backup
+		let manifest = ds.backup(dir.path(), cfg).await.unwrap();
+		assert!(manifest.complete);
+		assert!(manifest.resumed);
+		assert_eq!(manifest.files.iter().map(|f| f.entries).sum::<u64>(), total);
+		let restored = Datastore::new("memory").await.unwrap();
+		restored.restore(&[dir.path().to_owned()], RestoreConfig::default()).await.unwrap();
+		let res = query(&restored, "count(SELECT * FROM person)").await;
+		assert_eq!(res, Value::from(100));
+	}
+
+	#[tokio::test]
+	async fn incremental_backups_are_restored_up_to_a_versionstamp() {
+		let dir = tempfile::tempdir().unwrap();
+		let full = dir.path().join("full");
+		let incr = dir.path().join("incr");
+		let ds = Datastore::new("memory").await.unwrap();
+		query(&ds, "DEFINE DATABASE test CHANGEFEED 1h; CREATE person:one SET age = 1;").await;
+		let parent = ds.backup(&full, Config::default()).await.unwrap();
+		// Make two changes in separate transactions
+		query(&ds, "UPDATE person:one SET age = 2;").await;
+		let res = query(&ds, "SHOW CHANGES FOR TABLE person SINCE 0").await;
+		let Value::Array(changes) = res else {
+			panic!("expected an array of changes, found {res}");
+		};
+		let Value::Number(vs) = changes.last().unwrap().pick(&[Part::from("versionstamp")]) else {
+			panic!("expected a versionstamp");
+		};
+		query(&ds, "DELETE person:one;").await;
+		let cfg = Config {
+			parent: Some(parent),
+			..Default::default()
+		};
+		ds.backup(&incr, cfg).await.unwrap();
+		// Restore up to the versionstamp of the first change
+		let restored = Datastore::new("memory").await.unwrap();
+		// Changes are output with the full versionstamp, which includes a 16 bit suffix
+		let cfg = RestoreConfig {
+			until: Some(Until::Versionstamp((vs.as_int() >> 16) as u64)),
+		};
+		restored.restore(&[full, incr], cfg).await.unwrap();
+		let res = query(&restored, "SELECT VALUE age FROM person").await;
+		assert_eq!(res.to_string(), "[2]");
+	}
+
+	#[tokio::test]
+	async fn changefeeds_are_replayed_up_to_a_timestamp() {
+		let dir = tempfile::tempdir().unwrap();
+		let ds = Datastore::new("memory").await.unwrap();
+		query(&ds, "DEFINE DATABASE test CHANGEFEED 1d; CREATE person:one SET age = 1;").await;
+		let manifest = ds.backup(dir.path(), Config::default()).await.unwrap();
+		// Record changefeed timestamps between the changes
+		let now = Utc::now().timestamp() as u64;
+		ds.changefeed_process_at(now + 10).await.unwrap();
+		query(&ds, "UPDATE person:one SET age = 2;").await;
+		ds.changefeed_process_at(now + 20).await.unwrap();
+		query(&ds, "DELETE person:one;").await;
+		ds.changefeed_process_at(now + 30).await.unwrap();
+		// Recover the database to the state before the record was deleted
+		let until = Until::Timestamp(DateTime::from_timestamp((now + 25) as i64, 0).unwrap());
+		let restored = Datastore::new("memory").await.unwrap();
+		let base = restored.restore(&[dir.path().to_owned()], RestoreConfig::default()).await;
+		let base = base.unwrap();
+		assert_eq!(base.id, manifest.id);
+		restored.replay_changefeeds(&ds, &base.versionstamps, Some(&until)).await.unwrap();
+		let res = query(&restored, "SELECT VALUE age FROM person").await;
+		assert_eq!(res.to_string(), "[2]");
+		// Without a point in time, every change is replayed
+		let restored = Datastore::new("memory").await.unwrap();
+		restored.restore(&[dir.path().to_owned()], RestoreConfig::default()).await.unwrap();
+		restored.replay_changefeeds(&ds, &manifest.versionstamps, None).await.unwrap();
+		let res = query(&restored, "SELECT VALUE age FROM person").await;
+		assert_eq!(res.to_string(), "[]");
+	}
+}
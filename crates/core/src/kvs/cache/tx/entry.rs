@@ -10,9 +10,12 @@ use crate::sql::statements::DefineEventStatement;
 use crate::sql::statements::DefineFieldStatement;
 use crate::sql::statements::DefineFunctionStatement;
 use crate::sql::statements::DefineIndexStatement;
+use crate::sql::statements::DefineJobStatement;
 use crate::sql::statements::DefineModelStatement;
 use crate::sql::statements::DefineNamespaceStatement;
 use crate::sql::statements::DefineParamStatement;
+use crate::sql::statements::DefineRoleStatement;
+use crate::sql::statements::DefineSinkStatement;
 use crate::sql::statements::DefineTableStatement;
 use crate::sql::statements::DefineUserStatement;
 use crate::sql::statements::LiveStatement;
@@ -31,6 +34,8 @@ pub(crate) enum Entry {
 	Nds(Arc<[Node]>),
 	/// A slice of DefineUserStatement specified at the root.
 	Rus(Arc<[DefineUserStatement]>),
+	/// A slice of DefineRoleStatement specified at the root.
+	Rrs(Arc<[DefineRoleStatement]>),
 	/// A slice of DefineAccessStatement specified at the root.
 	Ras(Arc<[DefineAccessStatement]>),
 	/// A slice of AccessGrant specified at the root.
@@ -39,6 +44,8 @@ pub(crate) enum Entry {
 	Nss(Arc<[DefineNamespaceStatement]>),
 	/// A slice of DefineUserStatement specified on a namespace.
 	Nus(Arc<[DefineUserStatement]>),
+	/// A slice of DefineRoleStatement specified on a namespace.
+	Nrs(Arc<[DefineRoleStatement]>),
 	/// A slice of DefineAccessStatement specified on a namespace.
 	Nas(Arc<[DefineAccessStatement]>),
 	/// A slice of AccessGrant specified at on a namespace.
@@ -55,6 +62,12 @@ pub(crate) enum Entry {
 	Dag(Arc<[AccessGrant]>),
 	/// A slice of DefineUserStatement specified on a database.
 	Dus(Arc<[DefineUserStatement]>),
+	/// A slice of DefineRoleStatement specified on a database.
+	Drs(Arc<[DefineRoleStatement]>),
+	/// A slice of DefineSinkStatement specified on a database.
+	Sks(Arc<[DefineSinkStatement]>),
+	/// A slice of DefineJobStatement specified on a database.
+	Jbs(Arc<[DefineJobStatement]>),
 	/// A slice of DefineFunctionStatement specified on a database.
 	Fcs(Arc<[DefineFunctionStatement]>),
 	/// A slice of DefineTableStatement specified on a database.
@@ -104,6 +117,14 @@ impl Entry {
 			_ => Err(fail!("Unable to convert type into Entry::Rus")),
 		}
 	}
+	/// Converts this cache entry into a slice of [`DefineRoleStatement`].
+	/// This panics if called on a cache entry that is not an [`Entry::Rrs`].
+	pub(crate) fn try_into_rrs(self) -> Result<Arc<[DefineRoleStatement]>, Error> {
+		match self {
+			Entry::Rrs(v) => Ok(v),
+			_ => Err(fail!("Unable to convert type into Entry::Rrs")),
+		}
+	}
 	/// Converts this cache entry into a slice of [`DefineAccessStatement`].
 	/// This panics if called on a cache entry that is not an [`Entry::Ras`].
 	pub(crate) fn try_into_ras(self) -> Result<Arc<[DefineAccessStatement]>, Error> {
@@ -152,6 +173,14 @@ impl Entry {
 			_ => Err(fail!("Unable to convert type into Entry::Nus")),
 		}
 	}
+	/// Converts this cache entry into a slice of [`DefineRoleStatement`].
+	/// This panics if called on a cache entry that is not an [`Entry::Nrs`].
+	pub(crate) fn try_into_nrs(self) -> Result<Arc<[DefineRoleStatement]>, Error> {
+		match self {
+			Entry::Nrs(v) => Ok(v),
+			_ => Err(fail!("Unable to convert type into Entry::Nrs")),
+		}
+	}
 	/// Converts this cache entry into a slice of [`DefineDatabaseStatement`].
 	/// This panics if called on a cache entry that is not an [`Entry::Dbs`].
 	pub(crate) fn try_into_dbs(self) -> Result<Arc<[DefineDatabaseStatement]>, Error> {
@@ -184,6 +213,30 @@ impl Entry {
 			_ => Err(fail!("Unable to convert type into Entry::Dus")),
 		}
 	}
+	/// Converts this cache entry into a slice of [`DefineRoleStatement`].
+	/// This panics if called on a cache entry that is not an [`Entry::Drs`].
+	pub(crate) fn try_into_drs(self) -> Result<Arc<[DefineRoleStatement]>, Error> {
+		match self {
+			Entry::Drs(v) => Ok(v),
+			_ => Err(fail!("Unable to convert type into Entry::Drs")),
+		}
+	}
+	/// Converts this cache entry into a slice of [`DefineSinkStatement`].
+	/// This panics if called on a cache entry that is not an [`Entry::Sks`].
+	pub(crate) fn try_into_sks(self) -> Result<Arc<[DefineSinkStatement]>, Error> {
+		match self {
+			Entry::Sks(v) => Ok(v),
+			_ => Err(fail!("Unable to convert type into Entry::Sks")),
+		}
+	}
+	/// Converts this cache entry into a slice of [`DefineJobStatement`].
+	/// This panics if called on a cache entry that is not an [`Entry::Jbs`].
+	pub(crate) fn try_into_jbs(self) -> Result<Arc<[DefineJobStatement]>, Error> {
+		match self {
+			Entry::Jbs(v) => Ok(v),
+			_ => Err(fail!("Unable to convert type into Entry::Jbs")),
+		}
+	}
 	/// Converts this cache entry into a slice of [`ApiDefinition`].
 	/// This panics if called on a cache entry that is not an [`Entry::Aps`].
 	pub(crate) fn try_into_aps(self) -> Result<Arc<[ApiDefinition]>, Error> {
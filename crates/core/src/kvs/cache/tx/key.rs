@@ -8,6 +8,8 @@ pub(crate) enum Key {
 	Nds,
 	/// A cache key for root users
 	Rus,
+	/// A cache key for root roles
+	Rrs,
 	/// A cache key for root accesses
 	Ras,
 	/// A cache key for root access grants
@@ -16,6 +18,8 @@ pub(crate) enum Key {
 	Nss,
 	/// A cache key for namespace users
 	Nus(String),
+	/// A cache key for namespace roles
+	Nrs(String),
 	/// A cache key for namespace accesses
 	Nas(String),
 	/// A cache key for namespace access grants
@@ -24,6 +28,12 @@ pub(crate) enum Key {
 	Dbs(String),
 	/// A cache key for database users
 	Dus(String, String),
+	/// A cache key for database roles
+	Drs(String, String),
+	/// A cache key for database sinks
+	Sks(String, String),
+	/// A cache key for database jobs
+	Jbs(String, String),
 	/// A cache key for database accesses
 	Das(String, String),
 	/// A cache key for database access grants
@@ -56,6 +66,8 @@ pub(crate) enum Key {
 	Nd(Uuid),
 	/// A cache key for a root user
 	Ru(String),
+	/// A cache key for a root role
+	Rr(String),
 	/// A cache key for a root access
 	Ra(String),
 	/// A cache key for a root access grant
@@ -64,6 +76,8 @@ pub(crate) enum Key {
 	Ns(String),
 	/// A cache key for a namespace user
 	Nu(String, String),
+	/// A cache key for a namespace role
+	Nr(String, String),
 	/// A cache key for a namespace access
 	Na(String, String),
 	/// A cache key for a namespace access grant
@@ -72,6 +86,12 @@ pub(crate) enum Key {
 	Db(String, String),
 	/// A cache key for a database user
 	Du(String, String, String),
+	/// A cache key for a database role
+	Dr(String, String, String),
+	/// A cache key for a database sink
+	Sk(String, String, String),
+	/// A cache key for a database job
+	Jb(String, String, String),
 	/// A cache key for a database access
 	Da(String, String, String),
 	/// A cache key for a database access grant
@@ -107,14 +127,19 @@ impl<'a> From<Lookup<'a>> for Key {
 			//
 			Lookup::Nds => Key::Nds,
 			Lookup::Rus => Key::Rus,
+			Lookup::Rrs => Key::Rrs,
 			Lookup::Ras => Key::Ras,
 			Lookup::Rgs(a) => Key::Rgs(a.to_string()),
 			Lookup::Nss => Key::Nss,
 			Lookup::Nus(a) => Key::Nus(a.to_string()),
+			Lookup::Nrs(a) => Key::Nrs(a.to_string()),
 			Lookup::Nas(a) => Key::Nas(a.to_string()),
 			Lookup::Ngs(a, b) => Key::Ngs(a.to_string(), b.to_string()),
 			Lookup::Dbs(a) => Key::Dbs(a.to_string()),
 			Lookup::Dus(a, b) => Key::Dus(a.to_string(), b.to_string()),
+			Lookup::Drs(a, b) => Key::Drs(a.to_string(), b.to_string()),
+			Lookup::Sks(a, b) => Key::Sks(a.to_string(), b.to_string()),
+			Lookup::Jbs(a, b) => Key::Jbs(a.to_string(), b.to_string()),
 			Lookup::Das(a, b) => Key::Das(a.to_string(), b.to_string()),
 			Lookup::Dgs(a, b, c) => Key::Dgs(a.to_string(), b.to_string(), c.to_string()),
 			Lookup::Aps(a, b) => Key::Aps(a.to_string(), b.to_string()),
@@ -132,14 +157,19 @@ impl<'a> From<Lookup<'a>> for Key {
 			//
 			Lookup::Nd(a) => Key::Nd(a),
 			Lookup::Ru(a) => Key::Ru(a.to_string()),
+			Lookup::Rr(a) => Key::Rr(a.to_string()),
 			Lookup::Ra(a) => Key::Ra(a.to_string()),
 			Lookup::Rg(a, b) => Key::Rg(a.to_string(), b.to_string()),
 			Lookup::Ns(a) => Key::Ns(a.to_string()),
 			Lookup::Nu(a, b) => Key::Nu(a.to_string(), b.to_string()),
+			Lookup::Nr(a, b) => Key::Nr(a.to_string(), b.to_string()),
 			Lookup::Na(a, b) => Key::Na(a.to_string(), b.to_string()),
 			Lookup::Ng(a, b, c) => Key::Ng(a.to_string(), b.to_string(), c.to_string()),
 			Lookup::Db(a, b) => Key::Db(a.to_string(), b.to_string()),
 			Lookup::Du(a, b, c) => Key::Du(a.to_string(), b.to_string(), c.to_string()),
+			Lookup::Dr(a, b, c) => Key::Dr(a.to_string(), b.to_string(), c.to_string()),
+			Lookup::Sk(a, b, c) => Key::Sk(a.to_string(), b.to_string(), c.to_string()),
+			Lookup::Jb(a, b, c) => Key::Jb(a.to_string(), b.to_string(), c.to_string()),
 			Lookup::Da(a, b, c) => Key::Da(a.to_string(), b.to_string(), c.to_string()),
 			Lookup::Dg(a, b, c, d) => Key::Dg(a.to_string(), b.to_string(), c.to_string(), d.to_string()),
 			Lookup::Ap(a, b, c) => Key::Ap(a.to_string(), b.to_string(), c.to_string()),
@@ -9,6 +9,8 @@ pub(crate) enum Lookup<'a> {
 	Nds,
 	/// A cache key for root users
 	Rus,
+	/// A cache key for root roles
+	Rrs,
 	/// A cache key for root accesses
 	Ras,
 	/// A cache key for root access grants
@@ -17,6 +19,8 @@ pub(crate) enum Lookup<'a> {
 	Nss,
 	/// A cache key for namespace users
 	Nus(&'a str),
+	/// A cache key for namespace roles
+	Nrs(&'a str),
 	/// A cache key for namespace accesses
 	Nas(&'a str),
 	/// A cache key for namespace access grants
@@ -25,6 +29,12 @@ pub(crate) enum Lookup<'a> {
 	Dbs(&'a str),
 	/// A cache key for database users
 	Dus(&'a str, &'a str),
+	/// A cache key for database roles
+	Drs(&'a str, &'a str),
+	/// A cache key for database sinks
+	Sks(&'a str, &'a str),
+	/// A cache key for database jobs
+	Jbs(&'a str, &'a str),
 	/// A cache key for database accesses
 	Das(&'a str, &'a str),
 	/// A cache key for database access grants
@@ -57,6 +67,8 @@ pub(crate) enum Lookup<'a> {
 	Nd(Uuid),
 	/// A cache key for a root user
 	Ru(&'a str),
+	/// A cache key for a root role
+	Rr(&'a str),
 	/// A cache key for a root access
 	Ra(&'a str),
 	/// A cache key for a root access grant
@@ -65,6 +77,8 @@ pub(crate) enum Lookup<'a> {
 	Ns(&'a str),
 	/// A cache key for a namespace user
 	Nu(&'a str, &'a str),
+	/// A cache key for a namespace role
+	Nr(&'a str, &'a str),
 	/// A cache key for a namespace access
 	Na(&'a str, &'a str),
 	/// A cache key for a namespace access grant
@@ -73,6 +87,12 @@ pub(crate) enum Lookup<'a> {
 	Db(&'a str, &'a str),
 	/// A cache key for a database user
 	Du(&'a str, &'a str, &'a str),
+	/// A cache key for a database role
+	Dr(&'a str, &'a str, &'a str),
+	/// A cache key for a database sink
+	Sk(&'a str, &'a str, &'a str),
+	/// A cache key for a database job
+	Jb(&'a str, &'a str, &'a str),
 	/// A cache key for a database access
 	Da(&'a str, &'a str, &'a str),
 	/// A cache key for a database access grant
@@ -108,14 +128,19 @@ impl Equivalent<Key> for Lookup<'_> {
 			//
 			(Self::Nds, Key::Nds) => true,
 			(Self::Rus, Key::Rus) => true,
+			(Self::Rrs, Key::Rrs) => true,
 			(Self::Ras, Key::Ras) => true,
 			(Self::Rgs(la), Key::Rgs(ka)) => la == ka,
 			(Self::Nss, Key::Nss) => true,
 			(Self::Nus(la), Key::Nus(ka)) => la == ka,
+			(Self::Nrs(la), Key::Nrs(ka)) => la == ka,
 			(Self::Nas(la), Key::Nas(ka)) => la == ka,
 			(Self::Ngs(la, lb), Key::Ngs(ka, kb)) => la == ka && lb == kb,
 			(Self::Dbs(la), Key::Dbs(ka)) => la == ka,
 			(Self::Dus(la, lb), Key::Dus(ka, kb)) => la == ka && lb == kb,
+			(Self::Drs(la, lb), Key::Drs(ka, kb)) => la == ka && lb == kb,
+			(Self::Sks(la, lb), Key::Sks(ka, kb)) => la == ka && lb == kb,
+			(Self::Jbs(la, lb), Key::Jbs(ka, kb)) => la == ka && lb == kb,
 			(Self::Das(la, lb), Key::Das(ka, kb)) => la == ka && lb == kb,
 			(Self::Dgs(la, lb, lc), Key::Dgs(ka, kb, kc)) => la == ka && lb == kb && lc == kc,
 			(Self::Aps(la, lb), Key::Aps(ka, kb)) => la == ka && lb == kb,
@@ -133,14 +158,19 @@ impl Equivalent<Key> for Lookup<'_> {
 			//
 			(Self::Nd(la), Key::Nd(ka)) => la == ka,
 			(Self::Ru(la), Key::Ru(ka)) => la == ka,
+			(Self::Rr(la), Key::Rr(ka)) => la == ka,
 			(Self::Ra(la), Key::Ra(ka)) => la == ka,
 			(Self::Rg(la, lb), Key::Rg(ka, kb)) => la == ka && lb == kb,
 			(Self::Ns(la), Key::Ns(ka)) => la == ka,
 			(Self::Nu(la, lb), Key::Nu(ka, kb)) => la == ka && lb == kb,
+			(Self::Nr(la, lb), Key::Nr(ka, kb)) => la == ka && lb == kb,
 			(Self::Na(la, lb), Key::Na(ka, kb)) => la == ka && lb == kb,
 			(Self::Ng(la, lb, lc), Key::Ng(ka, kb, kc)) => la == ka && lb == kb && lc == kc,
 			(Self::Db(la, lb), Key::Db(ka, kb)) => la == ka && lb == kb,
 			(Self::Du(la, lb, lc), Key::Du(ka, kb, kc)) => la == ka && lb == kb && lc == kc,
+			(Self::Dr(la, lb, lc), Key::Dr(ka, kb, kc)) => la == ka && lb == kb && lc == kc,
+			(Self::Sk(la, lb, lc), Key::Sk(ka, kb, kc)) => la == ka && lb == kb && lc == kc,
+			(Self::Jb(la, lb, lc), Key::Jb(ka, kb, kc)) => la == ka && lb == kb && lc == kc,
 			(Self::Da(la, lb, lc), Key::Da(ka, kb, kc)) => la == ka && lb == kb && lc == kc,
 			(Self::Dg(la, lb, lc, ld), Key::Dg(ka, kb, kc, kd)) => la == ka && lb == kb && lc == kc && ld == kd,
 			(Self::Ap(la, lb, lc), Key::Ap(ka, kb, kc)) => la == ka && lb == kb && lc == kc,
@@ -0,0 +1,546 @@
+//! Conversion of table records to and from Arrow and Parquet.
+//!
+//! The columns of a table are derived from its top-level `DEFINE FIELD`
+//! statements, preceded by an `id` column. Fields with a boolean, integer,
+//! floating point, string, datetime or bytes type are stored as native
+//! columns, while all other fields are stored as SurrealQL text, which is
+//! marked in the column metadata so that it can be parsed when imported.
+use super::export::{Config, Format as ExportFormat, TableConfig};
+use super::import::Format as ImportFormat;
+use super::{Datastore, Transaction};
+use crate::cnf::EXPORT_BATCH_SIZE;
+use crate::dbs::{Response, Session};
+use crate::err::Error;
+use crate::sql::statements::{DefineFieldStatement, DefineTableStatement};
+use crate::sql::{Datetime, Id, Idiom, Kind, Number, Object, Part, Table, Thing, Value};
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{
+	Array, ArrayRef, BinaryArray, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray,
+	TimestampNanosecondArray,
+};
+use arrow_ipc::reader::StreamReader;
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+use async_channel::Sender;
+use bytes::Bytes;
+use chrono::{TimeZone, Utc};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The column metadata key describing how values are encoded
+const ENCODING: &str = "surrealdb.encoding";
+
+/// The encoding of columns which hold SurrealQL text
+const SURREALQL: &str = "surrealql";
+
+/// The column metadata key holding the type of the defined field
+const KIND: &str = "surrealdb.kind";
+
+impl From<ArrowError> for Error {
+	fn from(e: ArrowError) -> Self {
+		Error::Columnar(e.to_string())
+	}
+}
+
+impl From<ParquetError> for Error {
+	fn from(e: ParquetError) -> Self {
+		Error::Columnar(e.to_string())
+	}
+}
+
+/// How the values of a field are stored in a column
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Encoding {
+	Bool,
+	Int,
+	Float,
+	String,
+	Datetime,
+	Bytes,
+	Text,
+}
+
+impl Encoding {
+	fn from_kind(kind: Option<&Kind>) -> Self {
+		match kind {
+			Some(Kind::Option(kind)) => Self::from_kind(Some(kind)),
+			Some(Kind::Bool) => Self::Bool,
+			Some(Kind::Int) => Self::Int,
+			Some(Kind::Float | Kind::Number) => Self::Float,
+			Some(Kind::String) => Self::String,
+			Some(Kind::Datetime) => Self::Datetime,
+			Some(Kind::Bytes) => Self::Bytes,
+			_ => Self::Text,
+		}
+	}
+
+	fn data_type(&self) -> DataType {
+		match self {
+			Self::Bool => DataType::Boolean,
+			Self::Int => DataType::Int64,
+			Self::Float => DataType::Float64,
+			Self::String | Self::Text => DataType::Utf8,
+			Self::Datetime => DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+			Self::Bytes => DataType::Binary,
+		}
+	}
+}
+
+/// A column of an exported table
+struct Column {
+	name: Idiom,
+	kind: Option<Kind>,
+	encoding: Encoding,
+}
+
+impl Column {
+	/// Derives the columns of a table from its field definitions
+	fn from_table(table: &DefineTableStatement, fields: &[DefineFieldStatement]) -> Vec<Self> {
+		let mut columns = vec![Column {
+			name: Idiom::from("id"),
+			kind: Some(Kind::Record(vec![Table::from(table.name.as_str())])),
+			encoding: Encoding::Text,
+		}];
+		// Edges always have an in and an out record
+		if table.is_relation() {
+			for name in ["in", "out"] {
+				if !fields.iter().any(|fd| fd.name.to_string() == name) {
+					columns.push(Column {
+						name: Idiom::from(name),
+						kind: Some(Kind::Record(vec![])),
+						encoding: Encoding::Text,
+					});
+				}
+			}
+		}
+		// Nested fields are contained in their top-level field
+		for fd in fields.iter() {
+			if let [Part::Field(name)] = &fd.name.0[..] {
+				if name.0 != "id" {
+					columns.push(Column {
+						name: fd.name.clone(),
+						kind: fd.kind.clone(),
+						encoding: Encoding::from_kind(fd.kind.as_ref()),
+					});
+				}
+			}
+		}
+		columns
+	}
+
+	fn field(&self) -> Field {
+		let mut metadata = HashMap::new();
+		if let Some(kind) = &self.kind {
+			metadata.insert(KIND.to_owned(), kind.to_string());
+		}
+		if self.encoding == Encoding::Text {
+			metadata.insert(ENCODING.to_owned(), SURREALQL.to_owned());
+		}
+		Field::new(self.name.to_string(), self.encoding.data_type(), true).with_metadata(metadata)
+	}
+
+	/// Picks the values of this column from the records,
+	/// failing if a value does not match the column type
+	fn values<T>(
+		&self,
+		rows: &[Value],
+		f: impl Fn(Value) -> Option<T>,
+	) -> Result<Vec<Option<T>>, Error> {
+		rows.iter()
+			.map(|row| match row.pick(&self.name) {
+				Value::None | Value::Null => Ok(None),
+				v => f(v.clone()).map(Some).ok_or_else(|| {
+					Error::Columnar(format!(
+						"Found {v} for field `{}`, but expected a {}",
+						self.name,
+						self.kind.as_ref().unwrap_or(&Kind::Any)
+					))
+				}),
+			})
+			.collect()
+	}
+
+	fn array(&self, rows: &[Value]) -> Result<ArrayRef, Error> {
+		Ok(match self.encoding {
+			Encoding::Bool => Arc::new(BooleanArray::from(self.values(rows, |v| match v {
+				Value::Bool(v) => Some(v),
+				_ => None,
+			})?)),
+			Encoding::Int => Arc::new(Int64Array::from(self.values(rows, |v| match v {
+				Value::Number(v) if v.is_int() => Some(v.as_int()),
+				_ => None,
+			})?)),
+			Encoding::Float => Arc::new(Float64Array::from(self.values(rows, |v| match v {
+				Value::Number(v) => Some(v.as_float()),
+				_ => None,
+			})?)),
+			Encoding::String => Arc::new(StringArray::from(self.values(rows, |v| match v {
+				Value::Strand(v) => Some(v.0),
+				_ => None,
+			})?)),
+			Encoding::Datetime => Arc::new(
+				TimestampNanosecondArray::from(self.values(rows, |v| match v {
+					Value::Datetime(v) => v.0.timestamp_nanos_opt(),
+					_ => None,
+				})?)
+				.with_timezone("UTC"),
+			),
+			Encoding::Bytes => {
+				Arc::new(BinaryArray::from_iter(self.values(rows, |v| match v {
+					Value::Bytes(v) => Some(v.0),
+					_ => None,
+				})?))
+			}
+			Encoding::Text => {
+				Arc::new(StringArray::from(self.values(rows, |v| Some(v.to_string()))?))
+			}
+		})
+	}
+}
+
+/// Encodes record batches in a columnar format
+enum Writer {
+	Arrow(StreamWriter<Vec<u8>>),
+	Parquet(ArrowWriter<Vec<u8>>),
+}
+
+impl Writer {
+	fn new(format: ExportFormat, schema: SchemaRef) -> Result<Self, Error> {
+		match format {
+			ExportFormat::Arrow => Ok(Self::Arrow(StreamWriter::try_new(Vec::new(), &schema)?)),
+			ExportFormat::Parquet => {
+				Ok(Self::Parquet(ArrowWriter::try_new(Vec::new(), schema, None)?))
+			}
+			ExportFormat::Sql => {
+				Err(Error::Columnar(String::from("SurrealQL is not a columnar format")))
+			}
+		}
+	}
+
+	/// Writes a batch, returning the bytes which have been encoded so far
+	fn write(&mut self, batch: &RecordBatch) -> Result<Vec<u8>, Error> {
+		match self {
+			Self::Arrow(w) => {
+				w.write(batch)?;
+				Ok(std::mem::take(w.get_mut()))
+			}
+			Self::Parquet(w) => {
+				// Each batch is written as a separate row group
+				w.write(batch)?;
+				w.flush()?;
+				Ok(std::mem::take(w.inner_mut()))
+			}
+		}
+	}
+
+	/// Finishes the file, returning the remaining bytes
+	fn finish(self) -> Result<Vec<u8>, Error> {
+		match self {
+			Self::Arrow(w) => Ok(w.into_inner()?),
+			Self::Parquet(w) => Ok(w.into_inner()?),
+		}
+	}
+}
+
+impl Transaction {
+	/// Writes the records of a single table in a columnar format
+	pub(crate) async fn export_columnar(
+		&self,
+		ns: &str,
+		db: &str,
+		cfg: &Config,
+		chn: &Sender<Vec<u8>>,
+	) -> Result<(), Error> {
+		// A columnar file holds the records of a single table
+		let tb = match &cfg.tables {
+			TableConfig::Some(v) if v.len() == 1 => &v[0],
+			_ => {
+				return Err(Error::Columnar(format!(
+					"A {} export requires exactly one table",
+					cfg.format
+				)))
+			}
+		};
+		// Derive the columns from the table schema
+		let table = self.get_tb(ns, db, tb).await?;
+		let fields = self.all_tb_fields(ns, db, tb, None).await?;
+		let columns = Column::from_table(&table, &fields);
+		let schema = Arc::new(Schema::new(columns.iter().map(Column::field).collect::<Vec<_>>()));
+		let mut writer = Writer::new(cfg.format, schema.clone())?;
+		// Write the records in batches
+		let beg = crate::key::thing::prefix(ns, db, tb)?;
+		let end = crate::key::thing::suffix(ns, db, tb)?;
+		let mut next = Some(beg..end);
+		while let Some(rng) = next {
+			let batch = self.batch_keys_vals(rng, *EXPORT_BATCH_SIZE, None).await?;
+			next = batch.next;
+			// If there are no values, return early.
+			if batch.result.is_empty() {
+				break;
+			}
+			let rows = batch
+				.result
+				.iter()
+				.map(|(_, v)| revision::from_slice(v))
+				.collect::<Result<Vec<Value>, _>>()?;
+			let arrays =
+				columns.iter().map(|c| c.array(&rows)).collect::<Result<Vec<ArrayRef>, _>>()?;
+			let batch = RecordBatch::try_new(schema.clone(), arrays)?;
+			chn.send(writer.write(&batch)?).await?;
+		}
+		chn.send(writer.finish()?).await?;
+		Ok(())
+	}
+}
+
+impl Datastore {
+	/// Inserts the records of a columnar file into a table
+	pub(crate) async fn import_columnar(
+		&self,
+		sess: &Session,
+		tb: &str,
+		format: ImportFormat,
+		data: Bytes,
+	) -> Result<Vec<Response>, Error> {
+		// Records of edge tables are inserted as relations
+		let sql = self.insert_statement(sess, tb).await?;
+		// Insert each batch of records separately
+		let mut res = Vec::new();
+		for batch in decode(format, data)? {
+			let vars = map! {
+				String::from("table") => Value::from(Table::from(tb)),
+				String::from("data") => Value::from(records(&batch, tb)?),
+			};
+			res.extend(self.execute(sql, sess, Some(vars)).await?);
+		}
+		Ok(res)
+	}
+}
+
+/// Decodes the record batches of a columnar file
+fn decode(format: ImportFormat, data: Bytes) -> Result<Vec<RecordBatch>, Error> {
+	match format {
+		ImportFormat::Arrow => {
+			let reader = StreamReader::try_new(std::io::Cursor::new(data), None)?;
+			Ok(reader.collect::<Result<_, _>>()?)
+		}
+		ImportFormat::Parquet => {
+			let reader = ParquetRecordBatchReaderBuilder::try_new(data)?.build()?;
+			Ok(reader.collect::<Result<_, _>>()?)
+		}
+		format => Err(Error::Columnar(format!("{format} is not a columnar format"))),
+	}
+}
+
+/// Converts the rows of a record batch into records of a table
+fn records(batch: &RecordBatch, tb: &str) -> Result<Vec<Value>, Error> {
+	let schema = batch.schema();
+	(0..batch.num_rows())
+		.map(|i| {
+			let mut obj = Object::default();
+			for (field, array) in schema.fields().iter().zip(batch.columns()) {
+				if array.is_null(i) {
+					continue;
+				}
+				let v = cell(field, array, i)?;
+				let v = match field.name().as_str() {
+					// Record ids are moved to the imported table
+					"id" => Value::from(Thing {
+						tb: tb.to_owned(),
+						id: match v {
+							Value::Thing(v) => v.id,
+							Value::Number(Number::Int(v)) => Id::Number(v),
+							Value::Strand(v) => Id::String(v.0),
+							Value::Uuid(v) => Id::Uuid(v),
+							v => {
+								return Err(Error::Columnar(format!(
+									"Found {v} for the record id, but expected a string or integer"
+								)))
+							}
+						},
+					}),
+					_ => v,
+				};
+				obj.insert(field.name().to_owned(), v);
+			}
+			Ok(Value::from(obj))
+		})
+		.collect()
+}
+
+/// Converts a single value of a column
+fn cell(field: &Field, array: &ArrayRef, i: usize) -> Result<Value, Error> {
+	Ok(match array.data_type() {
+		DataType::Boolean => Value::from(array.as_boolean().value(i)),
+		DataType::Int8 => Value::from(array.as_primitive::<Int8Type>().value(i) as i64),
+		DataType::Int16 => Value::from(array.as_primitive::<Int16Type>().value(i) as i64),
+		DataType::Int32 => Value::from(array.as_primitive::<Int32Type>().value(i) as i64),
+		DataType::Int64 => Value::from(array.as_primitive::<Int64Type>().value(i)),
+		DataType::UInt8 => Value::from(array.as_primitive::<UInt8Type>().value(i) as i64),
+		DataType::UInt16 => Value::from(array.as_primitive::<UInt16Type>().value(i) as i64),
+		DataType::UInt32 => Value::from(array.as_primitive::<UInt32Type>().value(i) as i64),
+		DataType::UInt64 => {
+			let v = array.as_primitive::<UInt64Type>().value(i);
+			match i64::try_from(v) {
+				Ok(v) => Value::from(v),
+				Err(_) => Value::from(v as f64),
+			}
+		}
+		DataType::Float32 => Value::from(array.as_primitive::<Float32Type>().value(i) as f64),
+		DataType::Float64 => Value::from(array.as_primitive::<Float64Type>().value(i)),
+		DataType::Decimal128(_, scale) => {
+			let v = array.as_primitive::<Decimal128Type>().value(i);
+			match u32::try_from(*scale)
+				.ok()
+				.and_then(|s| Decimal::try_from_i128_with_scale(v, s).ok())
+			{
+				Some(v) => Value::from(v),
+				None => return Err(unsupported(field)),
+			}
+		}
+		DataType::Date32 => {
+			let v = array.as_primitive::<Date32Type>().value(i) as i64;
+			datetime(field, Utc.timestamp_opt(v * 86_400, 0).single())?
+		}
+		DataType::Date64 => {
+			let v = array.as_primitive::<Date64Type>().value(i);
+			datetime(field, Utc.timestamp_millis_opt(v).single())?
+		}
+		DataType::Timestamp(unit, _) => {
+			let v = match unit {
+				TimeUnit::Second => {
+					array.as_primitive::<TimestampSecondType>().value(i) * 1_000_000_000
+				}
+				TimeUnit::Millisecond => {
+					array.as_primitive::<TimestampMillisecondType>().value(i) * 1_000_000
+				}
+				TimeUnit::Microsecond => {
+					array.as_primitive::<TimestampMicrosecondType>().value(i) * 1_000
+				}
+				TimeUnit::Nanosecond => array.as_primitive::<TimestampNanosecondType>().value(i),
+			};
+			Value::from(Datetime::from(Utc.timestamp_nanos(v)))
+		}
+		DataType::Utf8 => text(field, array.as_string::<i32>().value(i))?,
+		DataType::LargeUtf8 => text(field, array.as_string::<i64>().value(i))?,
+		DataType::Utf8View => text(field, array.as_string_view().value(i))?,
+		DataType::Binary => Value::Bytes(array.as_binary::<i32>().value(i).to_vec().into()),
+		DataType::LargeBinary => Value::Bytes(array.as_binary::<i64>().value(i).to_vec().into()),
+		_ => return Err(unsupported(field)),
+	})
+}
+
+/// Parses SurrealQL text columns, or returns the string
+fn text(field: &Field, v: &str) -> Result<Value, Error> {
+	match field.metadata().get(ENCODING).map(String::as_str) {
+		Some(SURREALQL) => crate::syn::value(v),
+		_ => Ok(Value::from(v)),
+	}
+}
+
+fn datetime(field: &Field, v: Option<chrono::DateTime<Utc>>) -> Result<Value, Error> {
+	v.map(|v| Value::from(Datetime::from(v))).ok_or_else(|| unsupported(field))
+}
+
+fn unsupported(field: &Field) -> Error {
+	Error::Columnar(format!(
+		"Unable to import column `{}` of type {}",
+		field.name(),
+		field.data_type()
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::kvs::export;
+	use crate::kvs::import;
+
+	async fn export(ds: &Datastore, ses: &Session, format: ExportFormat) -> Vec<u8> {
+		let cfg = export::Config {
+			tables: TableConfig::from(vec!["person"]),
+			format,
+			..Default::default()
+		};
+		let (send, recv) = async_channel::unbounded();
+		ds.export_with_config(ses, send, cfg).await.unwrap().await.unwrap();
+		let mut data = Vec::new();
+		while let Ok(bytes) = recv.try_recv() {
+			data.extend(bytes);
+		}
+		data
+	}
+
+	async fn roundtrip(format: ExportFormat, import: ImportFormat) {
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let ds = Datastore::new("memory").await.unwrap();
+		let schema = r"
+			DEFINE TABLE person SCHEMAFULL;
+			DEFINE FIELD name ON person TYPE string;
+			DEFINE FIELD age ON person TYPE option<int>;
+			DEFINE FIELD score ON person TYPE float;
+			DEFINE FIELD born ON person TYPE datetime;
+			DEFINE FIELD tags ON person TYPE array<string>;
+		";
+		let sql = r"
+			CREATE person:tobie SET name = 'Tobie', age = 34, score = 1.5, born = d'1990-01-01T00:00:00Z', tags = ['a', 'b'];
+			CREATE person:⟨jaime⟩ SET name = 'Jaime', score = 2.5, born = d'1991-02-03T04:05:06Z', tags = [];
+		";
+		let res = ds.execute(&format!("{schema}{sql}"), &ses, None).await.unwrap();
+		for r in res {
+			r.result.unwrap();
+		}
+		let data = export(&ds, &ses, format).await;
+		// Import the records into a table with the same schema
+		let ds2 = Datastore::new("memory").await.unwrap();
+		let res = ds2.execute(schema, &ses, None).await.unwrap();
+		for r in res {
+			r.result.unwrap();
+		}
+		let cfg = import::Config {
+			format: import,
+			table: Some(String::from("person")),
+		};
+		let stream = futures::stream::once(async { Ok(Bytes::from(data)) });
+		let res = ds2.import_with_config(&ses, cfg, stream).await.unwrap();
+		for r in res {
+			r.result.unwrap();
+		}
+		// Check that the records are identical
+		let sql = "SELECT * FROM person ORDER BY id";
+		let mut a = ds.execute(sql, &ses, None).await.unwrap();
+		let mut b = ds2.execute(sql, &ses, None).await.unwrap();
+		let a = a.remove(0).result.unwrap();
+		let b = b.remove(0).result.unwrap();
+		assert_eq!(a, b);
+		assert_eq!(b.pick(&[Part::Index(0.into()), Part::from("age")]), Value::None);
+	}
+
+	#[tokio::test]
+	async fn tables_are_exported_and_imported_as_arrow() {
+		roundtrip(ExportFormat::Arrow, ImportFormat::Arrow).await;
+	}
+
+	#[tokio::test]
+	async fn tables_are_exported_and_imported_as_parquet() {
+		roundtrip(ExportFormat::Parquet, ImportFormat::Parquet).await;
+	}
+
+	#[tokio::test]
+	async fn columnar_exports_require_a_single_table() {
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let ds = Datastore::new("memory").await.unwrap();
+		let (send, _recv) = async_channel::unbounded();
+		let cfg = export::Config {
+			format: ExportFormat::Parquet,
+			..Default::default()
+		};
+		let res = ds.export_with_config(&ses, send, cfg).await.unwrap().await;
+		assert!(matches!(res, Err(Error::Columnar(_))));
+	}
+}
@@ -0,0 +1,475 @@
+//! Streaming import of CSV and NDJSON rows into a table.
+//!
+//! Rows are decoded as the stream arrives, and are inserted in batches.
+//! Columns which match a top-level `DEFINE FIELD` statement with a type
+//! are coerced to that type, while all other columns are inserted as
+//! they were decoded. A row which can not be decoded, coerced or inserted
+//! is reported in the import responses, without aborting the import.
+use super::import::Format;
+use super::{Datastore, LockType::*, TransactionType::*};
+use crate::cnf::IMPORT_BATCH_SIZE;
+use crate::dbs::{QueryType, Response, Session};
+use crate::err::Error;
+use crate::sql::{Id, Kind, Number, Object, Part, Table, Thing, Value};
+use bytes::{Buf, Bytes, BytesMut};
+use csv_core::ReadRecordResult;
+use futures::{Stream, StreamExt};
+use std::collections::HashMap;
+use std::pin::pin;
+use std::time::Duration;
+
+/// The result of decoding a single row, along with its row number
+type Row = (usize, Result<Value, Error>);
+
+impl Datastore {
+	/// Inserts the rows of a CSV or NDJSON stream into a table
+	pub(crate) async fn import_rows<S>(
+		&self,
+		sess: &Session,
+		tb: &str,
+		format: Format,
+		stream: S,
+	) -> Result<Vec<Response>, Error>
+	where
+		S: Stream<Item = Result<Bytes, Error>>,
+	{
+		// Retrieve the provided NS and DB
+		let (ns, db) = crate::iam::check::check_ns_db(sess)?;
+		// Records of edge tables are inserted as relations
+		let sql = self.insert_statement(sess, tb).await?;
+		// Fetch the types of the top-level fields
+		let txn = self.transaction(Read, Optimistic).await?;
+		let fields = txn.all_tb_fields(&ns, &db, tb, None).await;
+		txn.cancel().await?;
+		let kinds: HashMap<String, Kind> = fields?
+			.iter()
+			.filter_map(|fd| match (fd.name.0.as_slice(), &fd.kind) {
+				([Part::Field(name)], Some(kind)) => Some((name.to_raw(), kind.clone())),
+				_ => None,
+			})
+			.collect();
+		// Decode the rows as the stream arrives
+		let mut decoder = match format {
+			Format::Csv => Decoder::Csv(Box::new(CsvDecoder::new(tb, kinds))),
+			Format::Ndjson => Decoder::Ndjson(NdjsonDecoder::new(tb, kinds)),
+			format => {
+				return Err(Error::InvalidImportConfig(format!("{format} is not a row format")))
+			}
+		};
+		let mut res = Vec::new();
+		let mut batch = Vec::with_capacity(*IMPORT_BATCH_SIZE);
+		let mut rows = Vec::new();
+		let mut stream = pin!(stream);
+		loop {
+			// An empty chunk marks the end of the stream
+			let chunk = match stream.next().await {
+				Some(bytes) => bytes?,
+				None => Bytes::new(),
+			};
+			let done = chunk.is_empty();
+			decoder.decode(chunk, done, &mut rows);
+			for (row, v) in rows.drain(..) {
+				match v {
+					Ok(v) => batch.push((row, v)),
+					Err(e) => res.push(failure(row, e, Duration::ZERO)),
+				}
+				if batch.len() >= *IMPORT_BATCH_SIZE {
+					self.insert_rows(sess, tb, sql, std::mem::take(&mut batch), &mut res).await?;
+				}
+			}
+			if done {
+				break;
+			}
+		}
+		if !batch.is_empty() {
+			self.insert_rows(sess, tb, sql, batch, &mut res).await?;
+		}
+		Ok(res)
+	}
+
+	/// Inserts a batch of rows, falling back to inserting
+	/// each row separately in order to report failing rows
+	async fn insert_rows(
+		&self,
+		sess: &Session,
+		tb: &str,
+		sql: &str,
+		batch: Vec<(usize, Value)>,
+		res: &mut Vec<Response>,
+	) -> Result<(), Error> {
+		let data = batch.iter().map(|(_, v)| v.clone()).collect::<Vec<_>>();
+		let vars = map! {
+			String::from("table") => Value::from(Table::from(tb)),
+			String::from("data") => Value::from(data),
+		};
+		let sql = format!("{sql} RETURN NONE");
+		let out = self.execute(&sql, sess, Some(vars)).await?.remove(0);
+		if out.result.is_ok() {
+			res.push(out);
+			return Ok(());
+		}
+		for (row, v) in batch {
+			let vars = map! {
+				String::from("table") => Value::from(Table::from(tb)),
+				String::from("data") => v,
+			};
+			let out = self.execute(&sql, sess, Some(vars)).await?.remove(0);
+			if let Err(e) = out.result {
+				res.push(failure(row, e, out.time));
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Reports a row which could not be imported
+fn failure(row: usize, e: Error, time: Duration) -> Response {
+	Response {
+		time,
+		result: Err(match e {
+			Error::InvalidImportRow {
+				..
+			} => e,
+			e => invalid(row, e),
+		}),
+		query_type: QueryType::Other,
+	}
+}
+
+/// Returns the error for a row which could not be imported
+fn invalid(row: usize, message: impl ToString) -> Error {
+	Error::InvalidImportRow {
+		row,
+		message: message.to_string(),
+	}
+}
+
+/// Decodes the rows of a stream in a row format
+enum Decoder {
+	Csv(Box<CsvDecoder>),
+	Ndjson(NdjsonDecoder),
+}
+
+impl Decoder {
+	/// Decodes the rows which are completed by a chunk
+	/// of the stream, or all remaining rows once done
+	fn decode(&mut self, chunk: Bytes, done: bool, rows: &mut Vec<Row>) {
+		match self {
+			Self::Csv(d) => d.decode(&chunk, rows),
+			Self::Ndjson(d) => d.decode(chunk, done, rows),
+		}
+	}
+}
+
+/// Decodes CSV records, using the first record as the column names
+struct CsvDecoder {
+	tb: String,
+	kinds: HashMap<String, Kind>,
+	reader: csv_core::Reader,
+	header: Option<Vec<String>>,
+	output: Vec<u8>,
+	outlen: usize,
+	ends: Vec<usize>,
+	endlen: usize,
+	row: usize,
+}
+
+impl CsvDecoder {
+	fn new(tb: &str, kinds: HashMap<String, Kind>) -> Self {
+		Self {
+			tb: tb.to_owned(),
+			kinds,
+			reader: csv_core::Reader::new(),
+			header: None,
+			output: vec![0; 1024],
+			outlen: 0,
+			ends: vec![0; 32],
+			endlen: 0,
+			row: 0,
+		}
+	}
+
+	/// Decodes the records which are completed by the input,
+	/// where an empty input marks the end of the file
+	fn decode(&mut self, mut input: &[u8], rows: &mut Vec<Row>) {
+		loop {
+			let (res, nin, nout, nend) = self.reader.read_record(
+				input,
+				&mut self.output[self.outlen..],
+				&mut self.ends[self.endlen..],
+			);
+			input = &input[nin..];
+			self.outlen += nout;
+			self.endlen += nend;
+			match res {
+				ReadRecordResult::InputEmpty | ReadRecordResult::End => return,
+				ReadRecordResult::OutputFull => self.output.resize(self.output.len() * 2, 0),
+				ReadRecordResult::OutputEndsFull => self.ends.resize(self.ends.len() * 2, 0),
+				ReadRecordResult::Record => {
+					self.row += 1;
+					let fields = self.fields();
+					self.outlen = 0;
+					self.endlen = 0;
+					match (&self.header, fields) {
+						(None, Ok(mut fields)) => {
+							// Ignore any byte order mark
+							if let Some(first) = fields.first_mut() {
+								*first = first.trim_start_matches('\u{feff}').to_owned();
+							}
+							self.header = Some(fields);
+						}
+						(Some(header), Ok(fields)) => {
+							rows.push((self.row, self.record(header, fields)));
+						}
+						(_, Err(e)) => rows.push((self.row, Err(e))),
+					}
+				}
+			}
+		}
+	}
+
+	/// Returns the fields of the current record
+	fn fields(&self) -> Result<Vec<String>, Error> {
+		let mut start = 0;
+		self.ends[..self.endlen]
+			.iter()
+			.map(|&end| {
+				let field = std::str::from_utf8(&self.output[start..end]);
+				start = end;
+				field.map(str::to_owned).map_err(|e| invalid(self.row, e))
+			})
+			.collect()
+	}
+
+	/// Converts the fields of a record into a record of the table
+	fn record(&self, header: &[String], fields: Vec<String>) -> Result<Value, Error> {
+		if fields.len() != header.len() {
+			return Err(invalid(
+				self.row,
+				format!("Found {} fields, but expected {}", fields.len(), header.len()),
+			));
+		}
+		let mut obj = Object::default();
+		for (name, field) in header.iter().zip(fields) {
+			// Empty fields are left unset
+			if field.is_empty() {
+				continue;
+			}
+			let v = match self.kinds.get(name) {
+				Some(kind) => coerce(Value::from(field.as_str()), kind).or_else(|e| {
+					// Fall back to parsing the field as a SurrealQL value
+					crate::syn::value(&field).and_then(|v| coerce(v, kind)).map_err(|_| e)
+				})?,
+				None if name == "id" => match field.parse::<i64>() {
+					Ok(v) => Value::from(v),
+					Err(_) => Value::from(field),
+				},
+				None => Value::from(field),
+			};
+			let v = match name.as_str() {
+				"id" => record_id(&self.tb, v)?,
+				_ => v,
+			};
+			obj.insert(name.to_owned(), v);
+		}
+		Ok(Value::from(obj))
+	}
+}
+
+/// Decodes newline-delimited JSON objects
+struct NdjsonDecoder {
+	tb: String,
+	kinds: HashMap<String, Kind>,
+	buffer: BytesMut,
+	row: usize,
+}
+
+impl NdjsonDecoder {
+	fn new(tb: &str, kinds: HashMap<String, Kind>) -> Self {
+		Self {
+			tb: tb.to_owned(),
+			kinds,
+			buffer: BytesMut::new(),
+			row: 0,
+		}
+	}
+
+	/// Decodes the lines which are completed by a chunk,
+	/// or the remaining line once the stream is done
+	fn decode(&mut self, chunk: Bytes, done: bool, rows: &mut Vec<Row>) {
+		self.buffer.extend_from_slice(&chunk);
+		while let Some(pos) = self.buffer.iter().position(|&b| b == b'\n') {
+			let line = self.buffer.split_to(pos);
+			self.buffer.advance(1);
+			self.line(&line, rows);
+		}
+		if done && !self.buffer.is_empty() {
+			let line = self.buffer.split();
+			self.line(&line, rows);
+		}
+	}
+
+	/// Decodes a single line, skipping blank lines
+	fn line(&mut self, line: &[u8], rows: &mut Vec<Row>) {
+		self.row += 1;
+		let line = match std::str::from_utf8(line) {
+			Ok(line) => line.trim(),
+			Err(e) => {
+				rows.push((self.row, Err(invalid(self.row, e))));
+				return;
+			}
+		};
+		if !line.is_empty() {
+			rows.push((self.row, self.record(line)));
+		}
+	}
+
+	/// Converts a JSON object into a record of the table
+	fn record(&self, line: &str) -> Result<Value, Error> {
+		let Value::Object(obj) = crate::syn::json(line)? else {
+			return Err(invalid(self.row, "Expected each line to be a JSON object"));
+		};
+		let mut out = Object::default();
+		for (name, v) in obj {
+			let v = match self.kinds.get(&name) {
+				Some(kind) => coerce(v, kind)?,
+				None => v,
+			};
+			let v = match name.as_str() {
+				"id" => record_id(&self.tb, v)?,
+				_ => v,
+			};
+			out.insert(name, v);
+		}
+		Ok(Value::from(out))
+	}
+}
+
+/// Coerces a value to the type of a field, converting
+/// the value if it can not be coerced without loss
+fn coerce(v: Value, kind: &Kind) -> Result<Value, Error> {
+	v.clone().coerce_to(kind).or_else(|_| v.convert_to(kind))
+}
+
+/// Converts the value of an `id` column into a record id of the table
+fn record_id(tb: &str, v: Value) -> Result<Value, Error> {
+	let id = match v {
+		Value::Thing(v) => v.id,
+		Value::Number(Number::Int(v)) => Id::Number(v),
+		Value::Strand(v) => match crate::syn::thing(&v) {
+			// Record ids of the imported table are kept as they are
+			Ok(t) if t.tb == tb => t.id,
+			_ => Id::String(v.0),
+		},
+		Value::Uuid(v) => Id::Uuid(v),
+		Value::Array(v) => Id::Array(v),
+		Value::Object(v) => Id::Object(v),
+		v => {
+			return Err(Error::IdInvalid {
+				value: v.to_string(),
+			})
+		}
+	};
+	Ok(Value::from(Thing {
+		tb: tb.to_owned(),
+		id,
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::kvs::import::Config;
+
+	const SCHEMA: &str = r"
+		DEFINE TABLE person SCHEMAFULL;
+		DEFINE FIELD name ON person TYPE string;
+		DEFINE FIELD age ON person TYPE option<int>;
+		DEFINE FIELD born ON person TYPE option<datetime>;
+		DEFINE FIELD tags ON person TYPE option<array<string>>;
+	";
+
+	async fn import(format: Format, chunks: &[&'static str]) -> (Datastore, Vec<Response>) {
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let ds = Datastore::new("memory").await.unwrap();
+		for r in ds.execute(SCHEMA, &ses, None).await.unwrap() {
+			r.result.unwrap();
+		}
+		let cfg = Config {
+			format,
+			table: Some(String::from("person")),
+		};
+		let stream =
+			futures::stream::iter(chunks.iter().map(|c| Ok(Bytes::from_static(c.as_bytes()))));
+		let res = ds.import_with_config(&ses, cfg, stream).await.unwrap();
+		(ds, res)
+	}
+
+	async fn select(ds: &Datastore) -> Value {
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let sql = "SELECT * FROM person ORDER BY id";
+		ds.execute(sql, &ses, None).await.unwrap().remove(0).result.unwrap()
+	}
+
+	fn failures(res: Vec<Response>) -> Vec<String> {
+		res.into_iter().filter_map(|r| r.result.err()).map(|e| e.to_string()).collect()
+	}
+
+	#[tokio::test]
+	async fn csv_rows_are_coerced_to_field_types() {
+		let (ds, res) = import(
+			Format::Csv,
+			&[
+				"\u{feff}id,name,age,born,tags\n",
+				"1,Tobie,34,1990-01-01T00:00:00Z,\"['a', \"\"b\"\"]\"\n2,\"Jai",
+				"me\",,,\nperson:3,Jon,unknown,,\n",
+				"4,Lisa\n",
+			],
+		)
+		.await;
+		assert_eq!(
+			failures(res),
+			vec![
+				"Unable to import row 4: Expected a option<int> but cannot convert 'unknown' into a option<int>",
+				"Unable to import row 5: Found 2 fields, but expected 5",
+			]
+		);
+		let expected = crate::syn::value(
+			"[
+				{ id: person:1, name: 'Tobie', age: 34, born: d'1990-01-01T00:00:00Z', tags: ['a', 'b'] },
+				{ id: person:2, name: 'Jaime' },
+			]",
+		)
+		.unwrap();
+		assert_eq!(select(&ds).await, expected);
+	}
+
+	#[tokio::test]
+	async fn ndjson_rows_are_coerced_to_field_types() {
+		let (ds, res) = import(
+			Format::Ndjson,
+			&[
+				"{\"id\": \"tobie\", \"name\": \"Tobie\", \"age\": \"34\", \"born\": \"1990-01-01T00:00:00Z\"}\n\n",
+				"{\"id\": \"person:jaime\", \"name\": \"Jaime\"}\n{\"id\": 3, \"name\": 5",
+				"}\n[1, 2]\n{\"id\": \"jon\"}",
+			],
+		)
+		.await;
+		assert_eq!(
+			failures(res),
+			vec![
+				"Unable to import row 5: Expected each line to be a JSON object",
+				"Unable to import row 6: Found NONE for field `name`, with record `person:jon`, but expected a string",
+			]
+		);
+		let expected = crate::syn::value(
+			"[
+				{ id: person:3, name: '5' },
+				{ id: person:jaime, name: 'Jaime' },
+				{ id: person:tobie, name: 'Tobie', age: 34, born: d'1990-01-01T00:00:00Z' },
+			]",
+		)
+		.unwrap();
+		assert_eq!(select(&ds).await, expected);
+	}
+}
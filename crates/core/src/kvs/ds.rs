@@ -25,6 +25,7 @@ use crate::kvs::clock::SystemClock;
 #[cfg(not(target_family = "wasm"))]
 use crate::kvs::index::IndexBuilder;
 use crate::kvs::{LockType, LockType::*, TransactionType, TransactionType::*};
+use crate::options::EngineOptions;
 use crate::sql::FlowResultExt as _;
 use crate::sql::{statements::DefineUserStatement, Base, Query, Value};
 use crate::syn;
@@ -73,6 +74,8 @@ pub struct Datastore {
 	query_timeout: Option<Duration>,
 	/// The maximum duration timeout for running multiple statements in a transaction.
 	transaction_timeout: Option<Duration>,
+	/// The time after its last heartbeat, after which a node can no longer lead the cluster.
+	leader_timeout: Duration,
 	/// The security and feature capabilities for this datastore.
 	capabilities: Arc<Capabilities>,
 	// Whether this datastore enables live query notifications to subscribers.
@@ -168,6 +171,7 @@ impl TransactionFactory {
 				stash: super::stash::Stash::default(),
 				cf: cf::Writer::new(),
 				clock: self.clock.clone(),
+				save_points: 0,
 			},
 		))
 	}
@@ -415,6 +419,7 @@ impl Datastore {
 				auth_enabled: false,
 				query_timeout: None,
 				transaction_timeout: None,
+				leader_timeout: EngineOptions::default().leader_timeout,
 				notification_channel: None,
 				capabilities: Arc::new(Capabilities::default()),
 				index_stores: IndexStores::default(),
@@ -439,6 +444,7 @@ impl Datastore {
 			auth_enabled: self.auth_enabled,
 			query_timeout: self.query_timeout,
 			transaction_timeout: self.transaction_timeout,
+			leader_timeout: self.leader_timeout,
 			capabilities: self.capabilities,
 			notification_channel: self.notification_channel,
 			index_stores: Default::default(),
@@ -483,6 +489,12 @@ impl Datastore {
 		self
 	}
 
+	/// Set the time after which a node without a heartbeat can no longer lead the cluster
+	pub fn with_leader_timeout(mut self, duration: Duration) -> Self {
+		self.leader_timeout = duration;
+		self
+	}
+
 	/// Set whether authentication is enabled for this Datastore
 	pub fn with_auth_enabled(mut self, enabled: bool) -> Self {
 		self.auth_enabled = enabled;
@@ -548,6 +560,10 @@ impl Datastore {
 		&self.jwks_cache
 	}
 
+	pub(super) fn leader_timeout(&self) -> Duration {
+		self.leader_timeout
+	}
+
 	pub(super) async fn clock_now(&self) -> Timestamp {
 		self.transaction_factory.clock.now().await
 	}
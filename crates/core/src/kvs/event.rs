@@ -0,0 +1,227 @@
+use crate::cnf::NORMAL_FETCH_SIZE;
+use crate::ctx::Context;
+use crate::dbs::event::{timestamp, QueuedEvent};
+use crate::dbs::{Options, Session};
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::key::root::eq::Eq;
+use crate::kvs::Datastore;
+use crate::kvs::KeyDecode as _;
+use crate::kvs::KeyEncode;
+use crate::kvs::LockType::*;
+use crate::kvs::TransactionType::*;
+use crate::sql::statements::define::DefineEventStatement;
+use crate::sql::statements::CreateStatement;
+use crate::sql::{Data, Datetime, FlowResultExt as _, Table, Value, Values};
+use reblessive::tree::Stk;
+use reblessive::TreeStack;
+use std::fmt::Debug;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const TARGET: &str = "surrealdb::core::kvs::event";
+
+impl Datastore {
+	/// Processes any queued asynchronous events which are currently due.
+	///
+	/// This function should be run periodically at an interval.
+	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::event", skip(self))]
+	pub async fn event_process(&self) -> Result<(), Error> {
+		self.event_process_at(timestamp()?).await
+	}
+
+	/// Processes any queued asynchronous events which are due at the given time.
+	///
+	/// Each event is processed in its own transaction, which also removes the
+	/// event from the queue. If processing fails, the event is queued again
+	/// with an exponential backoff. Once all retries have been exhausted, the
+	/// event is written to the dead letter table of the event, if one is set,
+	/// or is otherwise dropped. A failing event does not stop the processing
+	/// of the other due events.
+	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::event", skip(self))]
+	pub async fn event_process_at(&self, ts: u64) -> Result<(), Error> {
+		// Log when this method is run
+		trace!(target: TARGET, "Processing queued asynchronous events");
+		// Fetch the events which are due
+		let due = {
+			let txn = self.transaction(Read, Optimistic).await?;
+			let beg = crate::key::root::eq::prefix();
+			let end = crate::key::root::eq::suffix(ts)?;
+			let res = catch!(txn, txn.scan(beg..end, *NORMAL_FETCH_SIZE, None).await);
+			catch!(txn, txn.cancel().await);
+			res
+		};
+		// Process each of the due events
+		for (k, v) in due.iter() {
+			// Drop any event which can not be decoded, so that it does not block the queue
+			let decoded = Eq::decode(k).and_then(|key| Ok((key, revision::from_slice(v)?)));
+			let (key, val): (Eq, QueuedEvent) = match decoded {
+				Ok(decoded) => decoded,
+				Err(e) => {
+					error!(target: TARGET, "Dropping an asynchronous event which can not be decoded: {e}");
+					if let Err(e) = self.event_drop(k).await {
+						error!(target: TARGET, "Failed to drop an asynchronous event: {e}");
+					}
+					continue;
+				}
+			};
+			if let Err(e) = self.event_run(&key, &val).await {
+				// Log the failed event processing
+				debug!(target: TARGET, ev = %val.ev, id = %val.id, "Asynchronous event failed: {e}");
+				// Queue the event for another attempt
+				let (ev, id) = (val.ev.clone(), val.id.clone());
+				if let Err(e) = self.event_retry(ts, &key, val, e).await {
+					error!(target: TARGET, ev = %ev, id = %id, "Failed to retry an asynchronous event: {e}");
+				}
+			}
+			// Pause and yield execution
+			yield_now!();
+		}
+		// Everything was successful
+		Ok(())
+	}
+
+	/// Runs the THEN clause of a queued event, removing it from the queue
+	async fn event_run(&self, key: &Eq, val: &QueuedEvent) -> Result<(), Error> {
+		// Open a writeable transaction
+		let txn = self.transaction(Write, Optimistic).await?.enclose();
+		// Check that the event has not been processed elsewhere
+		if !catch!(txn, txn.exists(key, None).await) {
+			return txn.cancel().await;
+		}
+		// Remove the event from the queue
+		catch!(txn, txn.del(key).await);
+		// Fetch the event definition
+		let ev = match txn.get_tb_event(&val.ns, &val.db, &val.tb, &val.ev).await {
+			Ok(ev) => ev,
+			// The event has since been removed
+			Err(Error::EvNotFound {
+				..
+			}) => return txn.commit().await,
+			Err(e) => {
+				let _ = txn.cancel().await;
+				return Err(e);
+			}
+		};
+		// Configure the context
+		let mut ctx = catch!(txn, self.setup_ctx());
+		ctx.set_transaction(txn.clone());
+		ctx.add_value("event", val.event.clone().into());
+		ctx.add_value("value", val.value().clone().into());
+		ctx.add_value("after", val.after.clone().into());
+		ctx.add_value("before", val.before.clone().into());
+		let ctx = ctx.freeze();
+		// Process the event clauses against the document
+		let opt = self.event_options(val);
+		let doc = CursorDoc::new(Some(Arc::new(val.id.clone())), None, val.value().clone());
+		let mut stack = TreeStack::new();
+		let res = stack.enter(|stk| Self::event_then(stk, &ctx, &opt, &ev, &doc)).finish().await;
+		run!(txn, res)
+	}
+
+	async fn event_then(
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		ev: &DefineEventStatement,
+		doc: &CursorDoc,
+	) -> Result<(), Error> {
+		for v in ev.then.iter() {
+			v.compute(stk, ctx, opt, Some(doc)).await.catch_return()?;
+		}
+		Ok(())
+	}
+
+	/// Queues a failed event for another attempt, or moves it to the dead letter table
+	async fn event_retry(
+		&self,
+		ts: u64,
+		key: &Eq,
+		mut val: QueuedEvent,
+		err: Error,
+	) -> Result<(), Error> {
+		// Open a writeable transaction
+		let txn = self.transaction(Write, Optimistic).await?.enclose();
+		// Check that the event has not been processed elsewhere
+		if !catch!(txn, txn.exists(key, None).await) {
+			return txn.cancel().await;
+		}
+		// Remove the event from the queue
+		catch!(txn, txn.del(key).await);
+		// Record the failed attempt
+		val.attempts += 1;
+		val.error = Some(err.to_string());
+		// Fetch the async configuration for the event
+		let cfg = match txn.get_tb_event(&val.ns, &val.db, &val.tb, &val.ev).await {
+			Ok(ev) => ev.asynchronous.clone().unwrap_or_default(),
+			// The event has since been removed
+			Err(Error::EvNotFound {
+				..
+			}) => return txn.commit().await,
+			Err(e) => {
+				let _ = txn.cancel().await;
+				return Err(e);
+			}
+		};
+		// Check if the event can be retried
+		if val.attempts <= cfg.retry {
+			// Double the backoff delay on each attempt
+			let delay = (cfg.backoff.as_millis() as u64)
+				.saturating_mul(1u64.checked_shl(val.attempts - 1).unwrap_or(u64::MAX));
+			let key = crate::key::root::eq::new(ts.saturating_add(delay), Uuid::now_v7());
+			catch!(txn, txn.set(key, revision::to_vec(&val)?, None).await);
+			return txn.commit().await;
+		}
+		// Log the exhausted event
+		warn!(target: TARGET, ev = %val.ev, id = %val.id, "Asynchronous event failed after {} attempts", val.attempts);
+		// Write the event to the dead letter table
+		if let Some(tb) = cfg.dead_letter {
+			let stm = CreateStatement {
+				what: Values(vec![Value::Table(Table(tb.to_raw()))]),
+				data: Some(Data::ContentExpression(Value::from(map! {
+					"event".to_string() => Value::from(val.ev.clone()),
+					"table".to_string() => Value::from(val.tb.clone()),
+					"record".to_string() => Value::from(val.id.clone()),
+					"action".to_string() => val.event.clone(),
+					"before".to_string() => val.before.clone(),
+					"after".to_string() => val.after.clone(),
+					"attempts".to_string() => Value::from(val.attempts),
+					"error".to_string() => Value::from(val.error.clone()),
+					"time".to_string() => Value::from(Datetime::default()),
+				}))),
+				..Default::default()
+			};
+			let mut ctx = catch!(txn, self.setup_ctx());
+			ctx.set_transaction(txn.clone());
+			let ctx = ctx.freeze();
+			let opt = self.event_options(&val);
+			let mut stack = TreeStack::new();
+			let res = stack.enter(|stk| stm.compute(stk, &ctx, &opt, None)).finish().await;
+			if let Err(e) = res {
+				let _ = txn.cancel().await;
+				// The event is dropped instead, so that it is not retried indefinitely
+				error!(target: TARGET, ev = %val.ev, id = %val.id, "Dropping an asynchronous event which could not be written to the dead letter table `{tb}`: {e}");
+				return self.event_drop(key).await;
+			}
+		}
+		txn.commit().await
+	}
+
+	/// Removes an event from the queue without processing it
+	async fn event_drop<K>(&self, key: K) -> Result<(), Error>
+	where
+		K: KeyEncode + Debug,
+	{
+		let txn = self.transaction(Write, Optimistic).await?;
+		catch!(txn, txn.del(key).await);
+		txn.commit().await
+	}
+
+	/// Asynchronous events run with the authentication of the session which
+	/// triggered them, without processing permissions, like synchronous events
+	fn event_options(&self, val: &QueuedEvent) -> Options {
+		let mut sess = Session::default().with_ns(&val.ns).with_db(&val.db);
+		sess.au = Arc::new(val.auth.clone());
+		self.setup_options(&sess).with_perms(false)
+	}
+}
@@ -12,6 +12,7 @@ use async_channel::Sender;
 use chrono::prelude::Utc;
 use chrono::TimeZone;
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -23,6 +24,7 @@ pub struct Config {
 	pub tables: TableConfig,
 	pub versions: bool,
 	pub records: bool,
+	pub format: Format,
 }
 
 impl Default for Config {
@@ -36,6 +38,7 @@ impl Default for Config {
 			tables: TableConfig::default(),
 			versions: false,
 			records: true,
+			format: Format::default(),
 		}
 	}
 }
@@ -50,6 +53,7 @@ impl From<Config> for Value {
 			"analyzers" => config.analyzers.into(),
 			"versions" => config.versions.into(),
 			"records" => config.records.into(),
+			"format" => config.format.to_string().into(),
 			"tables" => match config.tables {
 				TableConfig::All => true.into(),
 				TableConfig::None => false.into(),
@@ -97,6 +101,10 @@ impl TryFrom<&Value> for Config {
 					config.tables = v.try_into()?;
 				}
 
+				if let Some(v) = obj.get("format") {
+					config.format = v.try_into()?;
+				}
+
 				Ok(config)
 			}
 			v => Err(Error::InvalidExportConfig(v.to_owned(), "an object".into())),
@@ -104,6 +112,50 @@ impl TryFrom<&Value> for Config {
 	}
 }
 
+/// The file format which an export is written in
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+	/// SurrealQL statements
+	#[default]
+	Sql,
+	/// An Arrow IPC stream of a single table
+	Arrow,
+	/// A Parquet file of a single table
+	Parquet,
+}
+
+impl fmt::Display for Format {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Sql => f.write_str("sql"),
+			Self::Arrow => f.write_str("arrow"),
+			Self::Parquet => f.write_str("parquet"),
+		}
+	}
+}
+
+impl FromStr for Format {
+	type Err = Error;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().as_str() {
+			"sql" | "surql" => Ok(Self::Sql),
+			"arrow" => Ok(Self::Arrow),
+			"parquet" => Ok(Self::Parquet),
+			_ => Err(Error::InvalidExportConfig(s.into(), "one of sql, arrow or parquet".into())),
+		}
+	}
+}
+
+impl TryFrom<&Value> for Format {
+	type Error = Error;
+	fn try_from(value: &Value) -> Result<Self, Self::Error> {
+		match value {
+			Value::Strand(s) => s.as_str().parse(),
+			v => Err(Error::InvalidExportConfig(v.to_owned(), "a string".into())),
+		}
+	}
+}
+
 #[derive(Clone, Debug, Default)]
 pub enum TableConfig {
 	#[default]
@@ -206,7 +258,8 @@ impl<F: fmt::Display> fmt::Display for InlineCommentDisplay<F> {
 }
 
 impl Transaction {
-	/// Writes the full database contents as binary SQL.
+	/// Writes the full database contents as binary SQL, or
+	/// the contents of a single table in a columnar format.
 	pub async fn export(
 		&self,
 		ns: &str,
@@ -214,6 +267,13 @@ impl Transaction {
 		cfg: Config,
 		chn: Sender<Vec<u8>>,
 	) -> Result<(), Error> {
+		// Output a single table in a columnar format
+		if cfg.format != Format::Sql {
+			#[cfg(feature = "columnar")]
+			return self.export_columnar(ns, db, &cfg, &chn).await;
+			#[cfg(not(feature = "columnar"))]
+			return Err(Error::Columnar(String::from("Columnar exports are not enabled.")));
+		}
 		// Output USERS, ACCESSES, PARAMS, FUNCTIONS, ANALYZERS
 		self.export_metadata(&cfg, &chn, ns, db).await?;
 		// Output TABLES
@@ -231,6 +291,12 @@ impl Transaction {
 		// Output OPTIONS
 		self.export_section("OPTION", vec!["OPTION IMPORT"], chn).await?;
 
+		// Output ROLES
+		if cfg.users {
+			let roles = self.all_db_roles(ns, db).await?;
+			self.export_section("ROLES", roles.to_vec(), chn).await?;
+		}
+
 		// Output USERS
 		if cfg.users {
 			let users = self.all_db_users(ns, db).await?;
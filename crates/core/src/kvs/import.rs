@@ -0,0 +1,143 @@
+use super::{Datastore, LockType::*, TransactionType::*};
+use crate::dbs::{Response, Session};
+use crate::err::Error;
+use bytes::{Bytes, BytesMut};
+use futures::{Stream, StreamExt};
+use std::fmt;
+use std::pin::pin;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+	/// The format of the imported data
+	pub format: Format,
+	/// The table which the records are imported into,
+	/// which is required by all formats but SurrealQL
+	pub table: Option<String>,
+}
+
+/// The file format which an import is read from
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+	/// SurrealQL statements
+	#[default]
+	Sql,
+	/// An Arrow IPC stream of records
+	Arrow,
+	/// A Parquet file of records
+	Parquet,
+	/// Comma-separated values with a header row
+	Csv,
+	/// Newline-delimited JSON objects
+	Ndjson,
+}
+
+impl fmt::Display for Format {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Sql => f.write_str("sql"),
+			Self::Arrow => f.write_str("arrow"),
+			Self::Parquet => f.write_str("parquet"),
+			Self::Csv => f.write_str("csv"),
+			Self::Ndjson => f.write_str("ndjson"),
+		}
+	}
+}
+
+impl FromStr for Format {
+	type Err = Error;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().as_str() {
+			"sql" | "surql" => Ok(Self::Sql),
+			"arrow" => Ok(Self::Arrow),
+			"parquet" => Ok(Self::Parquet),
+			"csv" => Ok(Self::Csv),
+			"ndjson" | "jsonl" => Ok(Self::Ndjson),
+			_ => Err(Error::InvalidImportConfig(format!(
+				"Expected one of sql, arrow, parquet, csv or ndjson, but found '{s}'"
+			))),
+		}
+	}
+}
+
+impl Datastore {
+	/// Performs a database import in the specified format
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::import", skip_all)]
+	pub async fn import_with_config<S>(
+		&self,
+		sess: &Session,
+		cfg: Config,
+		stream: S,
+	) -> Result<Vec<Response>, Error>
+	where
+		S: Stream<Item = Result<Bytes, Error>>,
+	{
+		// SurrealQL is parsed as the stream arrives
+		if cfg.format == Format::Sql {
+			return self.import_stream(sess, stream).await;
+		}
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		// All other formats are imported into a single table
+		let Some(table) = cfg.table else {
+			return Err(Error::InvalidImportConfig(format!(
+				"A table is required to import {} data",
+				cfg.format
+			)));
+		};
+		// Rows are inserted in batches as the stream arrives
+		if let Format::Csv | Format::Ndjson = cfg.format {
+			return self.import_rows(sess, &table, cfg.format, stream).await;
+		}
+		// Columnar files can only be decoded once complete
+		let mut data = BytesMut::new();
+		let mut stream = pin!(stream);
+		while let Some(bytes) = stream.next().await {
+			data.extend_from_slice(&bytes?);
+		}
+		let data = data.freeze();
+		// Import the records into the table
+		match cfg.format {
+			Format::Sql | Format::Csv | Format::Ndjson => {
+				Err(Error::InvalidImportConfig(format!("{} is not a columnar format", cfg.format)))
+			}
+			#[cfg(feature = "columnar")]
+			Format::Arrow | Format::Parquet => self.import_columnar(sess, &table, cfg.format, data).await,
+			#[cfg(not(feature = "columnar"))]
+			Format::Arrow | Format::Parquet => {
+				let _ = (table, data);
+				Err(Error::Columnar(String::from("Columnar imports are not enabled.")))
+			}
+		}
+	}
+
+	/// Returns the statement which inserts records into a table,
+	/// with records of edge tables being inserted as relations
+	pub(crate) async fn insert_statement(
+		&self,
+		sess: &Session,
+		tb: &str,
+	) -> Result<&'static str, Error> {
+		// Retrieve the provided NS and DB
+		let (ns, db) = crate::iam::check::check_ns_db(sess)?;
+		// Check whether the table is a relation
+		let txn = self.transaction(Read, Optimistic).await?;
+		let relation = match txn.get_tb(&ns, &db, tb).await {
+			Ok(table) => table.is_relation(),
+			Err(Error::TbNotFound {
+				..
+			}) => false,
+			Err(e) => {
+				txn.cancel().await?;
+				return Err(e);
+			}
+		};
+		txn.cancel().await?;
+		Ok(match relation {
+			true => "INSERT RELATION INTO $table $data",
+			false => "INSERT INTO $table $data",
+		})
+	}
+}
@@ -10,6 +10,7 @@ use crate::key::thing;
 use crate::kvs::ds::TransactionFactory;
 use crate::kvs::LockType::Optimistic;
 use crate::kvs::{Key, Transaction, TransactionType, Val};
+use crate::sql::index::HnswParams;
 use crate::sql::statements::DefineIndexStatement;
 use crate::sql::{Id, Object, Thing, Value};
 use dashmap::mapref::entry::Entry;
@@ -184,6 +185,41 @@ impl IndexBuilder {
 		Ok(())
 	}
 
+	/// Trains the quantizer of an HNSW index in the background, within its own transaction.
+	pub(crate) fn train_hnsw(
+		&self,
+		ctx: &Context,
+		opt: Options,
+		ix: DefineIndexStatement,
+		p: HnswParams,
+	) {
+		let tf = self.tf.clone();
+		let ctx = MutableContext::new_concurrent(ctx).freeze();
+		task::spawn(async move {
+			if let Err(err) = Self::run_hnsw_training(tf, &ctx, &opt, &ix, &p).await {
+				warn!("The training of the quantizer of the index {} failed: {err}", ix.name);
+			}
+		});
+	}
+
+	async fn run_hnsw_training(
+		tf: TransactionFactory,
+		ctx: &Context,
+		opt: &Options,
+		ix: &DefineIndexStatement,
+		p: &HnswParams,
+	) -> Result<(), Error> {
+		let tx = tf.transaction(TransactionType::Write, Optimistic).await?.into();
+		let mut ctx = MutableContext::new(ctx);
+		ctx.set_transaction(tx);
+		let ctx = ctx.freeze();
+		let tx = ctx.tx();
+		let hnsw = catch!(tx, ctx.get_index_stores().get_index_hnsw(&ctx, opt, ix, p).await);
+		let mut hnsw = hnsw.write().await;
+		catch!(tx, hnsw.train(&tx).await);
+		tx.commit().await
+	}
+
 	pub(crate) async fn consume(
 		&self,
 		ctx: &Context,
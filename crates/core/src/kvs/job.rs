@@ -0,0 +1,295 @@
+use crate::dbs::event::timestamp;
+use crate::dbs::job::{Cron, JobState, JobStatus};
+use crate::dbs::{Options, Session};
+use crate::err::Error;
+use crate::kvs::Datastore;
+use crate::kvs::LockType::*;
+use crate::kvs::TransactionType::*;
+use crate::sql::statements::define::{DefineJobStatement, JobSchedule};
+use crate::sql::{Datetime, FlowResultExt as _};
+use chrono::{TimeZone, Utc};
+use reblessive::TreeStack;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const TARGET: &str = "surrealdb::core::kvs::job";
+
+impl Datastore {
+	/// Runs any scheduled jobs which are currently due.
+	///
+	/// This function should be run periodically at an interval.
+	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::job", skip(self))]
+	pub async fn job_process(&self) -> Result<(), Error> {
+		self.job_process_at(timestamp()?).await
+	}
+
+	/// Runs any scheduled jobs which are due at the given time.
+	///
+	/// Jobs are only run by the leader of the cluster. Each due job is first
+	/// claimed by moving its next run time forward, so that a run is never
+	/// repeated, even if the leader changes. Runs which were missed while no
+	/// node was available are not caught up on. A run which was interrupted,
+	/// because its node left the cluster, is recorded as a failure. A job
+	/// which fails does not prevent any of the other jobs from running.
+	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::job", skip(self))]
+	pub async fn job_process_at(&self, ts: u64) -> Result<(), Error> {
+		// Only the leader of the cluster runs jobs
+		if !self.is_leader().await? {
+			return Ok(());
+		}
+		// Log when this method is run
+		trace!(target: TARGET, "Running scheduled jobs");
+		// Fetch the nodes which can still be running jobs
+		let live = self.live_nodes().await?;
+		// Fetch all namespaces
+		let nss = {
+			let txn = self.transaction(Read, Optimistic).await?;
+			catch!(txn, txn.all_ns().await)
+		};
+		// Loop over all namespaces
+		for ns in nss.iter() {
+			// Fetch all databases
+			let dbs = {
+				let txn = self.transaction(Read, Optimistic).await?;
+				catch!(txn, txn.all_db(&ns.name).await)
+			};
+			// Loop over all databases
+			for db in dbs.iter() {
+				// Fetch all jobs
+				let jbs = {
+					let txn = self.transaction(Read, Optimistic).await?;
+					catch!(txn, txn.all_db_jobs(&ns.name, &db.name).await)
+				};
+				// Run each of the due jobs
+				for jb in jbs.iter() {
+					match self.job_claim(ts, &ns.name, &db.name, jb, &live).await {
+						Ok(true) => {
+							if let Err(e) = self.job_run(&ns.name, &db.name, jb).await {
+								error!(target: TARGET, ns = %ns.name, db = %db.name, jb = %jb.name, "Failed to run scheduled job: {e}");
+							}
+						}
+						Ok(false) => (),
+						Err(e) => {
+							error!(target: TARGET, ns = %ns.name, db = %db.name, jb = %jb.name, "Failed to schedule job: {e}");
+						}
+					}
+					// Pause and yield execution
+					yield_now!();
+				}
+			}
+		}
+		// Everything was successful
+		Ok(())
+	}
+
+	/// Schedules the next run of a job, returning whether the job is due now
+	async fn job_claim(
+		&self,
+		ts: u64,
+		ns: &str,
+		db: &str,
+		jb: &DefineJobStatement,
+		live: &[Uuid],
+	) -> Result<bool, Error> {
+		let key = crate::key::database::js::new(ns, db, &jb.name);
+		// Open a writeable transaction
+		let txn = self.transaction(Write, Optimistic).await?;
+		// Fetch the current scheduling state
+		let (mut state, due) = match catch!(txn, txn.get(&key, None).await) {
+			Some(v) => {
+				let state: JobState = revision::from_slice(&v)?;
+				let due = state.next <= ts;
+				(state, due)
+			}
+			// The job has not been scheduled yet
+			None => (JobState::default(), false),
+		};
+		// Check if the last run was interrupted by its node leaving the cluster
+		let interrupted = state.status == Some(JobStatus::Running)
+			&& state.node.is_some_and(|id| id != self.id() && !live.contains(&id));
+		if interrupted {
+			state.status = Some(JobStatus::Failure);
+			state.error = Some("The job was interrupted before it completed".to_string());
+		}
+		// Check if the job needs to be scheduled
+		let schedule = due || state.next == 0;
+		if schedule {
+			state.next = catch!(txn, next_run(&jb.schedule, ts));
+			if due {
+				state.last = Some(Datetime::default());
+				state.status = Some(JobStatus::Running);
+				state.error = None;
+				state.node = Some(self.id());
+			}
+		}
+		// Store the updated scheduling state
+		if schedule || interrupted {
+			catch!(txn, txn.set(&key, revision::to_vec(&state)?, None).await);
+			catch!(txn, txn.commit().await);
+		} else {
+			catch!(txn, txn.cancel().await);
+		}
+		Ok(due)
+	}
+
+	/// Runs the THEN clause of a job, and records the status of the run
+	async fn job_run(&self, ns: &str, db: &str, jb: &DefineJobStatement) -> Result<(), Error> {
+		// Log the job run
+		debug!(target: TARGET, ns, db, jb = %jb.name, "Running scheduled job");
+		// Open a writeable transaction
+		let txn = self.transaction(Write, Optimistic).await?.enclose();
+		// Configure the context
+		let mut ctx = catch!(txn, self.setup_ctx());
+		ctx.set_transaction(txn.clone());
+		let ctx = ctx.freeze();
+		// Process the job within the database
+		let opt = self.job_options(ns, db, jb);
+		let mut stack = TreeStack::new();
+		let res = stack.enter(|stk| jb.then.compute(stk, &ctx, &opt, None)).finish().await;
+		let res = run!(txn, res.catch_return());
+		// Record the status of the run
+		let (status, error) = match res {
+			Ok(_) => (JobStatus::Success, None),
+			Err(e) => {
+				warn!(target: TARGET, ns, db, jb = %jb.name, "Scheduled job failed: {e}");
+				(JobStatus::Failure, Some(e.to_string()))
+			}
+		};
+		let key = crate::key::database::js::new(ns, db, &jb.name);
+		let txn = self.transaction(Write, Optimistic).await?;
+		// The job may have been removed or redefined while running
+		if let Some(v) = catch!(txn, txn.get(&key, None).await) {
+			let mut state: JobState = revision::from_slice(&v)?;
+			state.status = Some(status);
+			state.error = error;
+			catch!(txn, txn.set(&key, revision::to_vec(&state)?, None).await);
+		}
+		txn.commit().await
+	}
+
+	/// Scheduled jobs run with the authentication of the user who defined them
+	fn job_options(&self, ns: &str, db: &str, jb: &DefineJobStatement) -> Options {
+		let mut sess = Session::default().with_ns(ns).with_db(db);
+		sess.au = Arc::new(jb.auth.clone());
+		self.setup_options(&sess)
+	}
+}
+
+/// Calculates the time in milliseconds at which a job is next due
+fn next_run(schedule: &JobSchedule, ts: u64) -> Result<u64, Error> {
+	match schedule {
+		JobSchedule::Every(v) => Ok(ts.saturating_add(v.as_millis() as u64)),
+		JobSchedule::Cron(v) => {
+			let cron: Cron = v.as_str().parse()?;
+			let now = Utc
+				.timestamp_millis_opt(ts as i64)
+				.single()
+				.ok_or_else(|| Error::Internal(format!("Invalid timestamp: {ts}")))?;
+			match cron.next_after(now) {
+				Some(v) => Ok(v.timestamp_millis() as u64),
+				// The expression never matches, so the job never runs
+				None => Ok(u64::MAX),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::dbs::job::{JobState, JobStatus};
+	use crate::dbs::node::{Node, Timestamp};
+	use crate::dbs::Session;
+	use crate::kvs::Datastore;
+	use crate::kvs::LockType::*;
+	use crate::kvs::TransactionType::*;
+	use crate::sql::Value;
+	use crate::syn::Parse;
+	use uuid::Uuid;
+
+	const TS: u64 = 1_704_067_200_000;
+
+	async fn setup(sql: &str) -> (Datastore, Session) {
+		let ds = Datastore::new("memory").await.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test");
+		for res in ds.execute(sql, &ses, None).await.unwrap() {
+			res.result.unwrap();
+		}
+		// Schedule the jobs
+		ds.job_process_at(TS).await.unwrap();
+		(ds, ses)
+	}
+
+	async fn set_state(ds: &Datastore, jb: &str, val: Vec<u8>) {
+		let key = crate::key::database::js::new("test", "test", jb);
+		let txn = ds.transaction(Write, Optimistic).await.unwrap();
+		txn.set(&key, val, None).await.unwrap();
+		txn.commit().await.unwrap();
+	}
+
+	async fn get_state(ds: &Datastore, jb: &str) -> JobState {
+		let key = crate::key::database::js::new("test", "test", jb);
+		let txn = ds.transaction(Read, Optimistic).await.unwrap();
+		let val = txn.get(&key, None).await.unwrap().unwrap();
+		txn.cancel().await.unwrap();
+		revision::from_slice(&val).unwrap()
+	}
+
+	#[tokio::test]
+	async fn job_runs_after_failed_job() {
+		let (ds, ses) = setup(
+			"
+			DEFINE JOB a EVERY 1m THEN { CREATE tick };
+			DEFINE JOB b EVERY 1m THEN { CREATE tick };
+		",
+		)
+		.await;
+		// The state of the first job can not be read
+		set_state(&ds, "a", vec![0xff]).await;
+		ds.job_process_at(TS + 60_000).await.unwrap();
+		// The second job still runs
+		let mut res = ds.execute("SELECT count() FROM tick GROUP ALL", &ses, None).await.unwrap();
+		assert_eq!(res.remove(0).result.unwrap(), Value::parse("[{ count: 1 }]"));
+		assert_eq!(get_state(&ds, "b").await.status, Some(JobStatus::Success));
+	}
+
+	#[tokio::test]
+	async fn job_recovers_interrupted_run() {
+		let (ds, _) = setup("DEFINE JOB a EVERY 1h THEN { CREATE tick };").await;
+		// The job was running on a node which has left the cluster
+		let mut state = get_state(&ds, "a").await;
+		state.status = Some(JobStatus::Running);
+		state.node = Some(Uuid::new_v4());
+		set_state(&ds, "a", revision::to_vec(&state).unwrap()).await;
+		ds.job_process_at(TS + 60_000).await.unwrap();
+		// The run is recorded as a failure, without rescheduling the job
+		let tmp = get_state(&ds, "a").await;
+		assert_eq!(tmp.status, Some(JobStatus::Failure));
+		assert!(tmp.error.is_some(), "{tmp:?}");
+		assert_eq!(tmp.next, state.next);
+	}
+
+	#[tokio::test]
+	async fn job_waits_for_a_live_leader() {
+		let (ds, ses) = setup("DEFINE JOB a EVERY 1m THEN { CREATE tick };").await;
+		// This node and another node are registered, but their heartbeats have expired
+		let txn = ds.transaction(Write, Optimistic).await.unwrap();
+		for id in [ds.id(), Uuid::new_v4()] {
+			let val = Node::new(id, Timestamp::default(), false);
+			txn.set(crate::key::root::nd::new(id), revision::to_vec(&val).unwrap(), None)
+				.await
+				.unwrap();
+		}
+		txn.commit().await.unwrap();
+		// No node is the leader, so the job does not run
+		assert!(!ds.is_leader().await.unwrap());
+		ds.job_process_at(TS + 60_000).await.unwrap();
+		let mut res = ds.execute("SELECT count() FROM tick GROUP ALL", &ses, None).await.unwrap();
+		assert_eq!(res.remove(0).result.unwrap(), Value::parse("[{ count: 0 }]"));
+		// The job runs once this node sends a heartbeat again
+		ds.update_node(ds.id()).await.unwrap();
+		assert!(ds.is_leader().await.unwrap());
+		ds.job_process_at(TS + 60_000).await.unwrap();
+		let mut res = ds.execute("SELECT count() FROM tick GROUP ALL", &ses, None).await.unwrap();
+		assert_eq!(res.remove(0).result.unwrap(), Value::parse("[{ count: 1 }]"));
+	}
+}
@@ -13,17 +13,26 @@
 //! - `tikv`: [TiKV](https://github.com/tikv/tikv) a distributed, and transactional key-value database
 //! - `mem`: in-memory database
 
+#[cfg(not(target_family = "wasm"))]
+pub mod backup;
 pub mod export;
+pub mod import;
 
 mod api;
 mod batch;
 mod cf;
 mod clock;
+#[cfg(feature = "columnar")]
+mod columnar;
+mod delimited;
 mod ds;
+mod event;
+mod job;
 mod key;
 mod live;
 mod node;
 mod scanner;
+mod sink;
 mod stash;
 mod threadpool;
 mod tr;
@@ -78,6 +78,52 @@ impl Datastore {
 		run!(txn, txn.replace(key, revision::to_vec(&val)?).await)
 	}
 
+	/// Checks if this node is the leader of the cluster.
+	///
+	/// The leader is the live node with the lowest id. When this node has
+	/// not been registered, such as for an embedded datastore, this node is
+	/// considered to be the leader. When no registered node is live, there
+	/// is no leader until a node sends a heartbeat again.
+	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::node", skip(self))]
+	pub(crate) async fn is_leader(&self) -> Result<bool, Error> {
+		// Fetch all of the nodes
+		let txn = self.transaction(Read, Optimistic).await?;
+		let nds = catch!(txn, txn.all_nodes().await);
+		let now = self.clock_now().await;
+		catch!(txn, txn.cancel().await);
+		// Check if this node has been registered
+		if !nds.iter().any(|n| n.id() == self.id()) {
+			return Ok(true);
+		}
+		// Elect the live node with the lowest id
+		let leader = nds
+			.iter()
+			.filter(|n| n.is_active() && n.hb >= now - self.leader_timeout())
+			.map(Node::id)
+			.min();
+		// Check if this node is the leader
+		Ok(leader == Some(self.id()))
+	}
+
+	/// Fetches the ids of the live nodes in the cluster.
+	///
+	/// A node is live when it is active, and has sent a heartbeat within
+	/// the configured leader timeout.
+	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::node", skip(self))]
+	pub(crate) async fn live_nodes(&self) -> Result<Vec<uuid::Uuid>, Error> {
+		// Fetch all of the active nodes
+		let txn = self.transaction(Read, Optimistic).await?;
+		let nds = catch!(txn, txn.all_nodes().await);
+		let now = self.clock_now().await;
+		catch!(txn, txn.cancel().await);
+		// Filter the nodes with a recent heartbeat
+		Ok(nds
+			.iter()
+			.filter(|n| n.is_active() && n.hb >= now - self.leader_timeout())
+			.map(Node::id)
+			.collect())
+	}
+
 	/// Expires nodes which have timedout from the cluster.
 	///
 	/// This function should be run periodically at an interval.
@@ -799,7 +799,8 @@ impl Transaction {
 		Ok(())
 	}
 
-	pub(crate) fn release_last_save_point(&mut self) -> Result<(), Error> {
-		Ok(())
+	/// The savepoint remains set in the datastore transaction until it is rolled back
+	pub(crate) fn release_last_save_point(&mut self) -> Result<bool, Error> {
+		Ok(false)
 	}
 }
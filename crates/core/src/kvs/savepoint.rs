@@ -67,6 +67,18 @@ impl SavePoints {
 		}
 	}
 
+	/// Removes the current savepoint, keeping the saved values of its keys in the previous
+	/// savepoint so that they are restored if the previous savepoint is rolled back.
+	pub(super) fn release(&mut self) -> Result<(), Error> {
+		let sp = self.pop()?;
+		if let Some(current) = &mut self.current {
+			for (key, saved_value) in sp {
+				current.entry(key).or_insert(saved_value);
+			}
+		}
+		Ok(())
+	}
+
 	pub(super) fn is_saved_key(&self, key: &Key) -> Option<bool> {
 		self.current.as_ref().map(|current| current.contains_key(key))
 	}
@@ -129,9 +141,9 @@ pub(super) trait SavePointImpl: Transaction + Sized {
 		SavePoints::rollback(sp, self).await
 	}
 
-	fn release_last_save_point(&mut self) -> Result<(), Error> {
-		self.get_save_points().pop()?;
-		Ok(())
+	fn release_last_save_point(&mut self) -> Result<bool, Error> {
+		self.get_save_points().release()?;
+		Ok(true)
 	}
 
 	async fn save_point_prepare(
@@ -0,0 +1,303 @@
+use crate::cf::ChangeSet;
+use crate::dbs::event::timestamp;
+use crate::err::Error;
+use crate::kvs::Datastore;
+use crate::kvs::LockType::*;
+use crate::kvs::TransactionType::*;
+use crate::sql::statements::define::{DefineSinkStatement, SinkTarget};
+use crate::sql::statements::show::ShowSince;
+use crate::sql::{Array, Value};
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+
+const TARGET: &str = "surrealdb::core::kvs::sink";
+
+/// The maximum number of changefeed entries delivered in a single batch
+const BATCH_SIZE: u32 = 100;
+
+/// The maximum delay between delivery attempts of a failing sink
+const MAX_BACKOFF: u64 = 300_000;
+
+/// The delivery progress of a sink
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+struct SinkOffset {
+	/// The last versionstamp which was delivered
+	vs: Option<u64>,
+	/// The number of consecutive failed delivery attempts
+	attempts: u32,
+	/// The time in milliseconds before which no delivery is attempted
+	next: u64,
+	/// The error from the last failed delivery attempt
+	error: Option<String>,
+	/// The first versionstamp of the changes which failed to be delivered
+	pending: Option<u64>,
+	/// The last changes which were removed by the changefeed retention
+	/// period before they could be delivered
+	gap: Option<String>,
+}
+
+impl Datastore {
+	/// Delivers any pending changefeed entries to the defined sinks.
+	///
+	/// This function should be run periodically at an interval.
+	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::sink", skip(self))]
+	pub async fn sink_process(&self) -> Result<(), Error> {
+		self.sink_process_at(timestamp()?).await
+	}
+
+	/// Delivers any pending changefeed entries to the defined sinks at the given time.
+	///
+	/// Changes are only delivered by the leader of the cluster. Each sink
+	/// tracks the last versionstamp which it has delivered, and only advances
+	/// once a batch has been delivered successfully, so changes are delivered
+	/// at least once. A failing sink is retried with an exponential backoff,
+	/// without holding up the other sinks. If the changes which failed to be
+	/// delivered are removed by the changefeed retention period in the
+	/// meantime, a warning is logged and the gap is recorded for the sink.
+	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::sink", skip(self))]
+	pub async fn sink_process_at(&self, ts: u64) -> Result<(), Error> {
+		// Only the leader of the cluster delivers changes
+		if !self.is_leader().await? {
+			return Ok(());
+		}
+		// Log when this method is run
+		trace!(target: TARGET, "Delivering changefeed entries to sinks");
+		// Fetch all namespaces
+		let nss = {
+			let txn = self.transaction(Read, Optimistic).await?;
+			catch!(txn, txn.all_ns().await)
+		};
+		// Loop over all namespaces
+		for ns in nss.iter() {
+			// Fetch all databases
+			let dbs = {
+				let txn = self.transaction(Read, Optimistic).await?;
+				catch!(txn, txn.all_db(&ns.name).await)
+			};
+			// Loop over all databases
+			for db in dbs.iter() {
+				// Fetch all sinks
+				let sks = {
+					let txn = self.transaction(Read, Optimistic).await?;
+					catch!(txn, txn.all_db_sinks(&ns.name, &db.name).await)
+				};
+				// Deliver the changes for each sink
+				for sk in sks.iter() {
+					if let Err(e) = self.sink_run(ts, &ns.name, &db.name, sk).await {
+						error!(target: TARGET, sk = %sk.name, "Failed to process sink: {e}");
+					}
+					// Pause and yield execution
+					yield_now!();
+				}
+			}
+		}
+		// Everything was successful
+		Ok(())
+	}
+
+	/// Delivers batches of changes for a single sink until it has caught up
+	async fn sink_run(
+		&self,
+		ts: u64,
+		ns: &str,
+		db: &str,
+		sk: &DefineSinkStatement,
+	) -> Result<(), Error> {
+		let key = crate::key::database::so::new(ns, db, &sk.name);
+		loop {
+			// Fetch the current progress and the next batch of changes
+			let (mut offset, changes, last) = {
+				let txn = self.transaction(Read, Optimistic).await?;
+				let offset: SinkOffset = match catch!(txn, txn.get(&key, None).await) {
+					Some(v) => revision::from_slice(&v)?,
+					None => SinkOffset::default(),
+				};
+				// Check if the sink is waiting to be retried
+				if offset.next > ts {
+					return txn.cancel().await;
+				}
+				let start = offset.vs.map(|v| v.saturating_add(1)).unwrap_or_default();
+				let (changes, last) = catch!(
+					txn,
+					crate::cf::reader::read_scanned(
+						&txn,
+						ns,
+						db,
+						Some(&sk.what),
+						ShowSince::Versionstamp(start),
+						Some(BATCH_SIZE),
+					)
+					.await
+				);
+				catch!(txn, txn.cancel().await);
+				(offset, changes, last)
+			};
+			// Check if the changes which failed to be delivered have since been removed
+			let first = changes.first().map(|c| c.0.into_u64_lossy());
+			if let Some(pending) = offset.pending.take() {
+				if first != Some(pending) {
+					let gap = format!("The changes from versionstamp {pending} were removed by the changefeed retention period before they could be delivered");
+					warn!(target: TARGET, sk = %sk.name, "{gap}");
+					offset.gap = Some(gap);
+					// Record the gap, even if there are no further changes
+					if last.is_none() {
+						let txn = self.transaction(Write, Optimistic).await?;
+						catch!(txn, txn.set(&key, revision::to_vec(&offset)?, None).await);
+						return txn.commit().await;
+					}
+				}
+			}
+			// Check if there are any further changes
+			let Some(last) = last else {
+				return Ok(());
+			};
+			// Deliver the changes to the sink target
+			let res = match changes.is_empty() {
+				true => Ok(()),
+				false => self.sink_deliver(&sk.target, changes).await,
+			};
+			// Update the progress of the sink
+			let done = match res {
+				Ok(_) => {
+					offset = SinkOffset {
+						vs: Some(last.into_u64_lossy()),
+						gap: offset.gap,
+						..Default::default()
+					};
+					false
+				}
+				Err(e) => {
+					// Log the failed delivery
+					debug!(target: TARGET, sk = %sk.name, "Sink delivery failed: {e}");
+					// Double the backoff delay on each attempt
+					offset.attempts = offset.attempts.saturating_add(1);
+					let delay = 1000u64
+						.saturating_mul(1u64.checked_shl(offset.attempts - 1).unwrap_or(u64::MAX))
+						.min(MAX_BACKOFF);
+					offset.next = ts.saturating_add(delay);
+					offset.error = Some(e.to_string());
+					offset.pending = first;
+					true
+				}
+			};
+			let txn = self.transaction(Write, Optimistic).await?;
+			catch!(txn, txn.set(&key, revision::to_vec(&offset)?, None).await);
+			catch!(txn, txn.commit().await);
+			// Stop once a delivery has failed
+			if done {
+				return Ok(());
+			}
+		}
+	}
+
+	/// Delivers a batch of changes to the target of a sink
+	async fn sink_deliver(
+		&self,
+		target: &SinkTarget,
+		changes: Vec<ChangeSet>,
+	) -> Result<(), Error> {
+		let changes = changes.into_iter().map(ChangeSet::into_value);
+		match target {
+			SinkTarget::Http(url) => {
+				// Send the batch as a JSON array of change sets
+				let ctx = self.setup_ctx()?.freeze();
+				let body = Value::Array(Array::from(changes.collect::<Vec<_>>()));
+				crate::fnc::http::post(&ctx, (Value::from(url.clone()), Some(body), None)).await?;
+				Ok(())
+			}
+			SinkTarget::File(path) => {
+				// Write each change set as a line of JSON
+				let mut out = String::new();
+				for v in changes {
+					out.push_str(&v.into_json().to_string());
+					out.push('\n');
+				}
+				append(path.as_str(), out.as_bytes()).await
+			}
+		}
+	}
+}
+
+/// Appends the data to the file at the given path, creating it if necessary
+#[cfg(not(target_family = "wasm"))]
+async fn append(path: &str, data: &[u8]) -> Result<(), Error> {
+	use tokio::io::AsyncWriteExt;
+	let path = allowed_path(path)?;
+	let mut file = tokio::fs::OpenOptions::new().create(true).append(true).open(path).await?;
+	file.write_all(data).await?;
+	file.flush().await?;
+	Ok(())
+}
+
+/// Appends the data to the file at the given path, creating it if necessary
+#[cfg(target_family = "wasm")]
+async fn append(path: &str, data: &[u8]) -> Result<(), Error> {
+	use std::io::Write;
+	let path = allowed_path(path)?;
+	let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+	file.write_all(data)?;
+	file.flush()?;
+	Ok(())
+}
+
+/// Checks that the directory of the file is within the allowed file paths
+fn allowed_path(path: &str) -> Result<std::path::PathBuf, Error> {
+	let path = std::path::Path::new(path);
+	let name =
+		path.file_name().ok_or_else(|| Error::FileAccessDenied(path.display().to_string()))?;
+	let dir = match path.parent() {
+		Some(v) if !v.as_os_str().is_empty() => v,
+		_ => std::path::Path::new("."),
+	};
+	Ok(crate::iam::file::is_path_allowed(dir)?.join(name))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SinkOffset;
+	use crate::dbs::Session;
+	use crate::kvs::Datastore;
+	use crate::kvs::LockType::*;
+	use crate::kvs::TransactionType::*;
+
+	#[tokio::test]
+	async fn sink_records_removed_changes() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("missing").join("person.ndjson");
+		let ds = Datastore::new("memory").await.unwrap();
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let sql = format!(
+			"
+			DEFINE TABLE person CHANGEFEED 1s;
+			DEFINE SINK audit ON TABLE person TO FILE '{}';
+			CREATE person:one;
+		",
+			path.display()
+		);
+		for res in ds.execute(&sql, &ses, None).await.unwrap() {
+			res.result.unwrap();
+		}
+		// The changes can not be delivered, as the directory does not exist
+		ds.sink_process_at(1_000).await.unwrap();
+		// The changes are then removed by the changefeed retention period
+		ds.changefeed_process_at(5).await.unwrap();
+		ds.changefeed_process_at(100).await.unwrap();
+		// Further changes are delivered once the directory exists
+		std::fs::create_dir(path.parent().unwrap()).unwrap();
+		for res in ds.execute("CREATE person:two", &ses, None).await.unwrap() {
+			res.result.unwrap();
+		}
+		ds.sink_process_at(1_000_000).await.unwrap();
+		let out = std::fs::read_to_string(&path).unwrap();
+		assert!(out.contains("person:two") && !out.contains("person:one"), "{out}");
+		// The removed changes are recorded for the sink
+		let key = crate::key::database::so::new("test", "test", "audit");
+		let txn = ds.transaction(Read, Optimistic).await.unwrap();
+		let val = txn.get(&key, None).await.unwrap().unwrap();
+		txn.cancel().await.unwrap();
+		let offset: SinkOffset = revision::from_slice(&val).unwrap();
+		assert!(offset.gap.is_some(), "{offset:?}");
+		assert!(offset.error.is_none(), "{offset:?}");
+	}
+}
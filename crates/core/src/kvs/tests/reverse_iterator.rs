@@ -148,6 +148,60 @@ pub async fn unique(new_ds: impl CreateDs) {
 	check_array_is_sorted(&r.remove(0).result.unwrap(), 1500);
 }
 
+pub async fn compound(new_ds: impl CreateDs) {
+	// Create a new datastore
+	let node_id = Uuid::parse_str("056804f2-b379-4397-9ceb-af8ebd527beb").unwrap();
+	let clock = Arc::new(SizedClock::Fake(FakeClock::new(Timestamp::default())));
+	let (ds, _) = new_ds.create_ds(node_id, clock).await;
+
+	// Run the test
+	let sql = "
+		USE NS test; USE DB test;
+		DEFINE INDEX idx ON TABLE i COLUMNS t, v;
+		CREATE |i:1500| SET t = rand::enum('a', 'b'), v = rand::uuid::v7() RETURN NONE;
+		SELECT t, v FROM i WHERE t = 'a' ORDER BY v DESC LIMIT 3 EXPLAIN;
+		SELECT v FROM i WHERE t = 'a' ORDER BY v DESC LIMIT 3;
+	";
+	let mut r = ds.execute(sql, &Session::owner(), None).await.unwrap();
+	assert_eq!(r.len(), 6);
+	// Check the first statements are successful
+	for _ in 0..4 {
+		r.remove(0).result.unwrap();
+	}
+	check(
+		&mut r,
+		"[
+			{
+				detail: {
+					plan: {
+						direction: 'backward',
+						from: {
+							inclusive: false,
+							value: NONE
+						},
+						index: 'idx',
+						prefix: 'a',
+						to: {
+							inclusive: false,
+							value: NONE
+						}
+					},
+					table: 'i'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					limit: 3,
+					type: 'MemoryOrderedLimit'
+				},
+				operation: 'Collector'
+			}
+		]",
+	);
+	check_array_is_sorted(&r.remove(0).result.unwrap(), 3);
+}
+
 pub async fn range(new_ds: impl CreateDs) {
 	// Create a new datastore
 	let node_id = Uuid::parse_str("056804f2-b379-4397-9ceb-af8ebd527beb").unwrap();
@@ -212,6 +266,11 @@ macro_rules! define_tests {
 		async fn reverse_iterator_range() {
 			super::reverse_iterator::range($new_ds).await;
 		}
+		#[tokio::test]
+		#[serial_test::serial]
+		async fn reverse_iterator_compound() {
+			super::reverse_iterator::compound($new_ds).await;
+		}
 	};
 }
 use crate::sql::Value;
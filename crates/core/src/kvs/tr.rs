@@ -81,6 +81,8 @@ pub struct Transactor {
 	pub(super) stash: Stash,
 	pub(super) cf: cf::Writer,
 	pub(super) clock: Arc<SizedClock>,
+	/// The number of savepoints which are set in the datastore transaction
+	pub(super) save_points: usize,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -835,15 +837,47 @@ impl Transactor {
 		Ok(None)
 	}
 
-	pub(crate) async fn new_save_point(&mut self) {
-		expand_inner!(&mut self.inner, v => { v.new_save_point() })
+	/// Sets a new savepoint, returning the number of savepoints which are now set
+	pub(crate) async fn new_save_point(&mut self) -> usize {
+		expand_inner!(&mut self.inner, v => {
+			v.new_save_point();
+			self.cf.new_save_point();
+			self.save_points += 1;
+			self.save_points
+		})
 	}
 
 	pub(crate) async fn rollback_to_save_point(&mut self) -> Result<(), Error> {
-		expand_inner!(&mut self.inner, v => { v.rollback_to_save_point().await })
+		expand_inner!(&mut self.inner, v => {
+			v.rollback_to_save_point().await?;
+			self.cf.rollback_to_save_point();
+			self.save_points = self.save_points.saturating_sub(1);
+			Ok(())
+		})
 	}
 
 	pub(crate) async fn release_last_save_point(&mut self) -> Result<(), Error> {
-		expand_inner!(&mut self.inner, v => { v.release_last_save_point() })
+		expand_inner!(&mut self.inner, v => {
+			// Some datastores can not release a savepoint, which then remains set until it
+			// is rolled back together with any savepoint set before it
+			if v.release_last_save_point()? {
+				self.cf.release_last_save_point();
+				self.save_points = self.save_points.saturating_sub(1);
+			}
+			Ok(())
+		})
+	}
+
+	/// Rolls back every change since the given savepoint was set, where the savepoint is
+	/// identified by the number of savepoints returned when it was set. This also rolls back
+	/// any savepoint set after it, including the released savepoints which remain set.
+	pub(crate) async fn rollback_to_save_point_at(
+		&mut self,
+		save_point: usize,
+	) -> Result<(), Error> {
+		while self.save_points >= save_point && self.save_points > 0 {
+			self.rollback_to_save_point().await?;
+		}
+		Ok(())
 	}
 }
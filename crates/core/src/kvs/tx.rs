@@ -24,9 +24,12 @@ use crate::sql::statements::DefineEventStatement;
 use crate::sql::statements::DefineFieldStatement;
 use crate::sql::statements::DefineFunctionStatement;
 use crate::sql::statements::DefineIndexStatement;
+use crate::sql::statements::DefineJobStatement;
 use crate::sql::statements::DefineModelStatement;
 use crate::sql::statements::DefineNamespaceStatement;
 use crate::sql::statements::DefineParamStatement;
+use crate::sql::statements::DefineRoleStatement;
+use crate::sql::statements::DefineSinkStatement;
 use crate::sql::statements::DefineTableStatement;
 use crate::sql::statements::DefineUserStatement;
 use crate::sql::statements::LiveStatement;
@@ -498,6 +501,24 @@ impl Transaction {
 		}
 	}
 
+	/// Retrieve all ROOT level roles in a datastore.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn all_root_roles(&self) -> Result<Arc<[DefineRoleStatement]>, Error> {
+		let qey = cache::tx::Lookup::Rrs;
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_rrs(),
+			None => {
+				let beg = crate::key::root::rl::prefix();
+				let end = crate::key::root::rl::suffix();
+				let val = self.getr(beg..end, None).await?;
+				let val = util::deserialize_cache(val.iter().map(|x| x.1.as_slice()))?;
+				let entry = cache::tx::Entry::Rrs(val.clone());
+				self.cache.insert(qey, entry);
+				Ok(val)
+			}
+		}
+	}
+
 	/// Retrieve all ROOT level accesses in a datastore.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_root_accesses(&self) -> Result<Arc<[DefineAccessStatement]>, Error> {
@@ -570,6 +591,24 @@ impl Transaction {
 		}
 	}
 
+	/// Retrieve all namespace role definitions for a specific namespace.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn all_ns_roles(&self, ns: &str) -> Result<Arc<[DefineRoleStatement]>, Error> {
+		let qey = cache::tx::Lookup::Nrs(ns);
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_nrs(),
+			None => {
+				let beg = crate::key::namespace::rl::prefix(ns)?;
+				let end = crate::key::namespace::rl::suffix(ns)?;
+				let val = self.getr(beg..end, None).await?;
+				let val = util::deserialize_cache(val.iter().map(|x| x.1.as_slice()))?;
+				let entry = cache::tx::Entry::Nrs(val.clone());
+				self.cache.insert(qey, entry);
+				Ok(val)
+			}
+		}
+	}
+
 	/// Retrieve all namespace access definitions for a specific namespace.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_ns_accesses(&self, ns: &str) -> Result<Arc<[DefineAccessStatement]>, Error> {
@@ -650,6 +689,72 @@ impl Transaction {
 		}
 	}
 
+	/// Retrieve all database role definitions for a specific database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn all_db_roles(
+		&self,
+		ns: &str,
+		db: &str,
+	) -> Result<Arc<[DefineRoleStatement]>, Error> {
+		let qey = cache::tx::Lookup::Drs(ns, db);
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_drs(),
+			None => {
+				let beg = crate::key::database::rl::prefix(ns, db)?;
+				let end = crate::key::database::rl::suffix(ns, db)?;
+				let val = self.getr(beg..end, None).await?;
+				let val = util::deserialize_cache(val.iter().map(|x| x.1.as_slice()))?;
+				let entry = cache::tx::Entry::Drs(val.clone());
+				self.cache.insert(qey, entry);
+				Ok(val)
+			}
+		}
+	}
+
+	/// Retrieve all sink definitions for a specific database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn all_db_sinks(
+		&self,
+		ns: &str,
+		db: &str,
+	) -> Result<Arc<[DefineSinkStatement]>, Error> {
+		let qey = cache::tx::Lookup::Sks(ns, db);
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_sks(),
+			None => {
+				let beg = crate::key::database::sk::prefix(ns, db)?;
+				let end = crate::key::database::sk::suffix(ns, db)?;
+				let val = self.getr(beg..end, None).await?;
+				let val = util::deserialize_cache(val.iter().map(|x| x.1.as_slice()))?;
+				let entry = cache::tx::Entry::Sks(val.clone());
+				self.cache.insert(qey, entry);
+				Ok(val)
+			}
+		}
+	}
+
+	/// Retrieve all job definitions for a specific database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn all_db_jobs(
+		&self,
+		ns: &str,
+		db: &str,
+	) -> Result<Arc<[DefineJobStatement]>, Error> {
+		let qey = cache::tx::Lookup::Jbs(ns, db);
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_jbs(),
+			None => {
+				let beg = crate::key::database::jb::prefix(ns, db)?;
+				let end = crate::key::database::jb::suffix(ns, db)?;
+				let val = self.getr(beg..end, None).await?;
+				let val = util::deserialize_cache(val.iter().map(|x| x.1.as_slice()))?;
+				let entry = cache::tx::Entry::Jbs(val.clone());
+				self.cache.insert(qey, entry);
+				Ok(val)
+			}
+		}
+	}
+
 	/// Retrieve all database access definitions for a specific database.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_db_accesses(
@@ -1003,6 +1108,26 @@ impl Transaction {
 		}
 	}
 
+	/// Retrieve a specific root role definition.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_root_role(&self, rl: &str) -> Result<Arc<DefineRoleStatement>, Error> {
+		let qey = cache::tx::Lookup::Rr(rl);
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_type(),
+			None => {
+				let key = crate::key::root::rl::new(rl).encode()?;
+				let val = self.get(key, None).await?.ok_or_else(|| Error::RoleRootNotFound {
+					name: rl.to_owned(),
+				})?;
+				let val: DefineRoleStatement = revision::from_slice(&val)?;
+				let val = Arc::new(val);
+				let entr = cache::tx::Entry::Any(val.clone());
+				self.cache.insert(qey, entr);
+				Ok(val)
+			}
+		}
+	}
+
 	/// Retrieve a specific root access definition.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_root_access(&self, ra: &str) -> Result<Arc<DefineAccessStatement>, Error> {
@@ -1090,6 +1215,27 @@ impl Transaction {
 		}
 	}
 
+	/// Retrieve a specific namespace role definition.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_ns_role(&self, ns: &str, rl: &str) -> Result<Arc<DefineRoleStatement>, Error> {
+		let qey = cache::tx::Lookup::Nr(ns, rl);
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_type(),
+			None => {
+				let key = crate::key::namespace::rl::new(ns, rl).encode()?;
+				let val = self.get(key, None).await?.ok_or_else(|| Error::RoleNsNotFound {
+					name: rl.to_owned(),
+					ns: ns.to_owned(),
+				})?;
+				let val: DefineRoleStatement = revision::from_slice(&val)?;
+				let val = Arc::new(val);
+				let entr = cache::tx::Entry::Any(val.clone());
+				self.cache.insert(qey, entr);
+				Ok(val)
+			}
+		}
+	}
+
 	/// Retrieve a specific namespace access definition.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_ns_access(
@@ -1190,6 +1336,83 @@ impl Transaction {
 		}
 	}
 
+	/// Retrieve a specific role definition from a database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_db_role(
+		&self,
+		ns: &str,
+		db: &str,
+		rl: &str,
+	) -> Result<Arc<DefineRoleStatement>, Error> {
+		let qey = cache::tx::Lookup::Dr(ns, db, rl);
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_type(),
+			None => {
+				let key = crate::key::database::rl::new(ns, db, rl).encode()?;
+				let val = self.get(key, None).await?.ok_or_else(|| Error::RoleDbNotFound {
+					name: rl.to_owned(),
+					ns: ns.to_owned(),
+					db: db.to_owned(),
+				})?;
+				let val: DefineRoleStatement = revision::from_slice(&val)?;
+				let val = Arc::new(val);
+				let entr = cache::tx::Entry::Any(val.clone());
+				self.cache.insert(qey, entr);
+				Ok(val)
+			}
+		}
+	}
+
+	/// Retrieve a specific sink definition from a database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_db_sink(
+		&self,
+		ns: &str,
+		db: &str,
+		sk: &str,
+	) -> Result<Arc<DefineSinkStatement>, Error> {
+		let qey = cache::tx::Lookup::Sk(ns, db, sk);
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_type(),
+			None => {
+				let key = crate::key::database::sk::new(ns, db, sk).encode()?;
+				let val = self.get(key, None).await?.ok_or_else(|| Error::SkNotFound {
+					name: sk.to_owned(),
+				})?;
+				let val: DefineSinkStatement = revision::from_slice(&val)?;
+				let val = Arc::new(val);
+				let entr = cache::tx::Entry::Any(val.clone());
+				self.cache.insert(qey, entr);
+				Ok(val)
+			}
+		}
+	}
+
+	/// Retrieve a specific job definition from a database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_db_job(
+		&self,
+		ns: &str,
+		db: &str,
+		jb: &str,
+	) -> Result<Arc<DefineJobStatement>, Error> {
+		let qey = cache::tx::Lookup::Jb(ns, db, jb);
+		match self.cache.get(&qey) {
+			Some(val) => val.try_into_type(),
+			None => {
+				let key = crate::key::database::jb::new(ns, db, jb).encode()?;
+				let val = self.get(key, None).await?.ok_or_else(|| Error::JbNotFound {
+					name: jb.to_owned(),
+				})?;
+				let val: DefineJobStatement = revision::from_slice(&val)?;
+				let val = Arc::new(val);
+				let entr = cache::tx::Entry::Any(val.clone());
+				self.cache.insert(qey, entr);
+				Ok(val)
+			}
+		}
+	}
+
 	/// Retrieve a specific database access definition.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_db_access(
@@ -1916,4 +2139,11 @@ impl Transaction {
 	pub(crate) fn index_caches(&self) -> &IndexTreeCaches {
 		&self.index_caches
 	}
+
+	/// Clears the cached entries and index trees, which may no longer
+	/// match the datastore after rolling back to a savepoint.
+	pub(crate) fn clear_cache(&self) {
+		self.cache.clear();
+		self.index_caches.clear();
+	}
 }
@@ -10,6 +10,10 @@ pub struct EngineOptions {
 	pub node_membership_check_interval: Duration,
 	pub node_membership_cleanup_interval: Duration,
 	pub changefeed_gc_interval: Duration,
+	pub event_queue_interval: Duration,
+	pub sink_interval: Duration,
+	pub job_interval: Duration,
+	pub leader_timeout: Duration,
 }
 
 impl Default for EngineOptions {
@@ -19,6 +23,10 @@ impl Default for EngineOptions {
 			node_membership_check_interval: Duration::from_secs(15),
 			node_membership_cleanup_interval: Duration::from_secs(300),
 			changefeed_gc_interval: Duration::from_secs(10),
+			event_queue_interval: Duration::from_secs(1),
+			sink_interval: Duration::from_secs(1),
+			job_interval: Duration::from_secs(1),
+			leader_timeout: Duration::from_secs(30),
 		}
 	}
 }
@@ -40,4 +48,20 @@ impl EngineOptions {
 		self.changefeed_gc_interval = interval;
 		self
 	}
+	pub fn with_event_queue_interval(mut self, interval: Duration) -> Self {
+		self.event_queue_interval = interval;
+		self
+	}
+	pub fn with_sink_interval(mut self, interval: Duration) -> Self {
+		self.sink_interval = interval;
+		self
+	}
+	pub fn with_job_interval(mut self, interval: Duration) -> Self {
+		self.job_interval = interval;
+		self
+	}
+	pub fn with_leader_timeout(mut self, timeout: Duration) -> Self {
+		self.leader_timeout = timeout;
+		self
+	}
 }
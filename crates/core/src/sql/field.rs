@@ -3,7 +3,7 @@ use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::sql::statements::info::InfoStructure;
-use crate::sql::{fmt::Fmt, Idiom, Part, Value};
+use crate::sql::{fmt::Fmt, Idiom, Part, Value, Window};
 use crate::syn;
 use reblessive::tree::Stk;
 use revision::revisioned;
@@ -55,6 +55,10 @@ impl Fields {
 			_ => None,
 		}
 	}
+	/// Check if any of the fields is computed over a window of rows
+	pub(crate) fn has_window(&self) -> bool {
+		self.0.iter().any(|v| matches!(v, Field::Window { .. }))
+	}
 	/// Check if the fields are only about counting
 	pub(crate) fn is_count_all_only(&self) -> bool {
 		let mut is_count_only = false;
@@ -141,6 +145,25 @@ impl Fields {
 		for v in self.other() {
 			match v {
 				Field::All => (),
+				// This expression is computed over a window of rows
+				Field::Window {
+					expr: Value::Function(f),
+					window,
+					..
+				} => {
+					// Only the inputs of the function are computed here, the
+					// function is computed once all the rows have been collected
+					let x = window.input(stk, ctx, opt, doc, f).await?;
+					// Check if this is a single VALUE field expression
+					if self.single().is_some() {
+						out = x;
+					} else if let Some(name) = v.name() {
+						out.set(stk, ctx, opt, name.as_ref(), x).await?;
+					}
+				}
+				Field::Window {
+					..
+				} => (),
 				Field::Single {
 					expr,
 					alias,
@@ -287,7 +310,7 @@ impl Fields {
 	}
 }
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -301,6 +324,35 @@ pub enum Field {
 		/// The `quality` in `SELECT rating AS quality FROM ...`
 		alias: Option<Idiom>,
 	},
+	/// The 'math::sum(amount) OVER (ORDER BY time)' in `SELECT math::sum(amount) OVER (ORDER BY time) FROM ...`
+	#[revision(start = 2)]
+	Window {
+		/// The function computed over the window, always a `Value::Function`
+		expr: Value,
+		window: Window,
+		/// The `total` in `SELECT math::sum(amount) OVER (ORDER BY time) AS total FROM ...`
+		alias: Option<Idiom>,
+	},
+}
+
+impl Field {
+	/// The path at which the output of this field is stored
+	pub(crate) fn name(&self) -> Option<Cow<'_, Idiom>> {
+		match self {
+			Self::All => None,
+			Self::Single {
+				expr,
+				alias,
+			}
+			| Self::Window {
+				expr,
+				alias,
+				..
+			} => Some(
+				alias.as_ref().map(Cow::Borrowed).unwrap_or_else(|| Cow::Owned(expr.to_idiom())),
+			),
+		}
+	}
 }
 
 impl Display for Field {
@@ -319,6 +371,19 @@ impl Display for Field {
 					Ok(())
 				}
 			}
+			Self::Window {
+				expr,
+				window,
+				alias,
+			} => {
+				write!(f, "{expr} OVER ({window})")?;
+				if let Some(alias) = alias {
+					f.write_str(" AS ")?;
+					Display::fmt(alias, f)
+				} else {
+					Ok(())
+				}
+			}
 		}
 	}
 }
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Display;
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -16,6 +16,12 @@ pub enum Filter {
 	Snowball(Language),
 	Uppercase,
 	Mapper(String),
+	#[revision(start = 2)]
+	Length(u16, u16),
+	#[revision(start = 2)]
+	Stopwords(Language),
+	#[revision(start = 2)]
+	Synonym(String),
 }
 
 impl Display for Filter {
@@ -28,6 +34,9 @@ impl Display for Filter {
 			Self::Snowball(lang) => write!(f, "SNOWBALL({lang})"),
 			Self::Uppercase => f.write_str("UPPERCASE"),
 			Self::Mapper(path) => write!(f, "MAPPER({path})"),
+			Self::Length(min, max) => write!(f, "LENGTH({min},{max})"),
+			Self::Stopwords(lang) => write!(f, "STOPWORDS({lang})"),
+			Self::Synonym(path) => write!(f, "SYNONYM({path})"),
 		}
 	}
 }
@@ -20,6 +20,26 @@ use super::{ControlFlow, FlowResult, FlowResultExt as _, Kind};
 
 pub(crate) const TOKEN: &str = "$surrealdb::private::sql::Function";
 
+/// The functions which can only be computed over a window of rows
+pub(crate) const WINDOW_FUNCTIONS: [&str; 7] =
+	["dense_rank", "first_value", "lag", "last_value", "lead", "rank", "row_number"];
+
+/// The function packages whose functions only depend on their arguments
+const PURE_FUNCTIONS: [&str; 12] = [
+	"array::",
+	"bytes::",
+	"duration::",
+	"encoding::",
+	"geo::",
+	"math::",
+	"object::",
+	"parse::",
+	"string::",
+	"time::",
+	"type::",
+	"vector::",
+];
+
 #[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
 #[serde(rename = "$surrealdb::private::sql::Function")]
@@ -138,6 +158,20 @@ impl Function {
 		}
 	}
 
+	/// Check if this function returns the same result for the same arguments,
+	/// without reading from the document, the session or the database
+	pub(crate) fn is_pure(&self) -> bool {
+		match self {
+			Self::Normal(f, _) => {
+				!matches!(
+					f.as_str(),
+					"array::shuffle" | "time::now" | "type::field" | "type::fields"
+				) && PURE_FUNCTIONS.iter().any(|p| f.starts_with(p))
+			}
+			_ => false,
+		}
+	}
+
 	/// Check if this function is a closure function
 	pub fn is_inline(&self) -> bool {
 		matches!(self, Self::Anonymous(_, _, _))
@@ -187,6 +221,13 @@ impl Function {
 			_ => false,
 		}
 	}
+	/// Check if this function can only be computed over a window of rows
+	pub fn is_window(&self) -> bool {
+		match self {
+			Self::Normal(f, _) => WINDOW_FUNCTIONS.contains(&f.as_str()),
+			_ => false,
+		}
+	}
 	pub(crate) fn get_optimised_aggregate(&self) -> OptimisedAggregate {
 		match self {
 			Self::Normal(f, v) if f == "count" => {
@@ -229,6 +270,13 @@ impl Function {
 			Self::Normal(s, x) => {
 				// Check this function is allowed
 				ctx.check_allowed_function(s)?;
+				// Window functions are computed over the rows of a SELECT statement
+				if self.is_window() {
+					return Err(ControlFlow::from(Error::InvalidFunction {
+						name: s.to_owned(),
+						message: "This function can only be used with an OVER clause".to_owned(),
+					}));
+				}
 				// Compute the function arguments
 				let a = stk
 					.scope(|scope| {
@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
-#[revisioned(revision = 2)]
+#[revisioned(revision = 3)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -29,6 +29,9 @@ pub enum Index {
 	/// HNSW index for distance based metrics
 	#[revision(start = 2)]
 	Hnsw(HnswParams),
+	/// Geohash index for geospatial queries
+	#[revision(start = 3)]
+	Geohash(GeohashParams),
 }
 
 #[revisioned(revision = 2)]
@@ -122,7 +125,7 @@ pub enum Distance1 {
 	Minkowski(Number),
 }
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -136,6 +139,9 @@ pub struct HnswParams {
 	pub extend_candidates: bool,
 	pub keep_pruned_connections: bool,
 	pub ml: Number,
+	/// Compact representation used for the vectors kept in memory
+	#[revision(start = 2)]
+	pub quantization: Option<VectorQuantization>,
 }
 
 impl HnswParams {
@@ -150,6 +156,7 @@ impl HnswParams {
 		ef_construction: u16,
 		extend_candidates: bool,
 		keep_pruned_connections: bool,
+		quantization: Option<VectorQuantization>,
 	) -> Self {
 		Self {
 			dimension,
@@ -161,6 +168,50 @@ impl HnswParams {
 			ml,
 			extend_candidates,
 			keep_pruned_connections,
+			quantization,
+		}
+	}
+}
+
+/// The length of the longest geohash, its cells are less than 4 cm wide
+pub const MAX_GEOHASH_PRECISION: u8 = 12;
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct GeohashParams {
+	/// The length of the geohash of the finest cells
+	pub precision: u8,
+}
+
+impl GeohashParams {
+	pub fn new(precision: u8) -> Self {
+		Self {
+			precision,
+		}
+	}
+}
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum VectorQuantization {
+	/// Every dimension is stored on a single byte (256 levels between the min and the max)
+	Scalar,
+	/// Every dimension is stored on a single bit (above or below the mean)
+	Binary,
+	/// The vector is split in subspaces, each stored as the id of its nearest centroid
+	Product(u16),
+}
+
+impl Display for VectorQuantization {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Scalar => f.write_str("SCALAR"),
+			Self::Binary => f.write_str("BINARY"),
+			Self::Product(subspaces) => write!(f, "PRODUCT {}", subspaces),
 		}
 	}
 }
@@ -280,8 +331,12 @@ impl Display for Index {
 				if p.keep_pruned_connections {
 					f.write_str(" KEEP_PRUNED_CONNECTIONS")?
 				}
+				if let Some(q) = &p.quantization {
+					write!(f, " QUANTIZE {q}")?
+				}
 				Ok(())
 			}
+			Self::Geohash(p) => write!(f, "GEOHASH PRECISION {}", p.precision),
 		}
 	}
 }
@@ -6,7 +6,7 @@ use std::fmt;
 use std::fmt::Write;
 
 /// Binary operators.
-#[revisioned(revision = 2)]
+#[revisioned(revision = 3)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -35,11 +35,14 @@ pub enum Operator {
 	AllEqual, // *=
 	AnyEqual, // ?=
 	//
-	Like,                      // ~
-	NotLike,                   // !~
-	AllLike,                   // *~
-	AnyLike,                   // ?~
+	Like,    // ~
+	NotLike, // !~
+	AllLike, // *~
+	AnyLike, // ?~
+	#[revision(end = 3, convert_fn = "convert_old_matches", fields_name = "OldMatchesFields")]
 	Matches(Option<MatchRef>), // @{ref}@
+	#[revision(start = 3)]
+	Matches(Option<MatchRef>, Option<u8>), // @{ref}[,{fuzziness}]@
 	//
 	LessThan,        // <
 	LessThanOrEqual, // <=
@@ -74,6 +77,13 @@ impl Default for Operator {
 }
 
 impl Operator {
+	fn convert_old_matches(
+		fields: OldMatchesFields,
+		_revision: u16,
+	) -> Result<Self, revision::Error> {
+		Ok(Operator::Matches(fields.0, None))
+	}
+
 	#[inline]
 	pub fn precedence(&self) -> u8 {
 		match self {
@@ -134,12 +144,15 @@ impl fmt::Display for Operator {
 			Self::NoneInside => f.write_str("NONEINSIDE"),
 			Self::Outside => f.write_str("OUTSIDE"),
 			Self::Intersects => f.write_str("INTERSECTS"),
-			Self::Matches(reference) => {
+			Self::Matches(reference, fuzziness) => {
+				f.write_char('@')?;
 				if let Some(r) = reference {
-					write!(f, "@{r}@")
-				} else {
-					f.write_str("@@")
+					write!(f, "{r}")?;
+				}
+				if let Some(z) = fuzziness {
+					write!(f, ",{z}")?;
 				}
+				f.write_char('@')
 			}
 			Self::Knn(k, dist) => {
 				if let Some(d) = dist {
@@ -1,9 +1,10 @@
+use crate::sql::fmt::Fmt;
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, PartialOrd, Serialize, Deserialize)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -13,6 +14,16 @@ pub enum Scoring {
 		b: f32,
 	}, // BestMatching25
 	Vs, // VectorSearch
+	/// BestMatching25 with a weight for each field of the index
+	#[revision(start = 2)]
+	Bm25f {
+		k1: f32,
+		b: f32,
+		weights: Vec<u32>,
+	},
+	/// Term frequency - inverse document frequency
+	#[revision(start = 2)]
+	Tfidf,
 }
 
 impl Eq for Scoring {}
@@ -30,7 +41,23 @@ impl PartialEq for Scoring {
 					b: other_b,
 				},
 			) => k1.to_bits() == other_k1.to_bits() && b.to_bits() == other_b.to_bits(),
-			(Scoring::Vs, Scoring::Vs) => true,
+			(
+				Scoring::Bm25f {
+					k1,
+					b,
+					weights,
+				},
+				Scoring::Bm25f {
+					k1: other_k1,
+					b: other_b,
+					weights: other_weights,
+				},
+			) => {
+				k1.to_bits() == other_k1.to_bits()
+					&& b.to_bits() == other_b.to_bits()
+					&& weights == other_weights
+			}
+			(Scoring::Vs, Scoring::Vs) | (Scoring::Tfidf, Scoring::Tfidf) => true,
 			_ => false,
 		}
 	}
@@ -47,6 +74,16 @@ impl Hash for Scoring {
 				b.to_bits().hash(state);
 			}
 			Scoring::Vs => 0.hash(state),
+			Scoring::Bm25f {
+				k1,
+				b,
+				weights,
+			} => {
+				k1.to_bits().hash(state);
+				b.to_bits().hash(state);
+				weights.hash(state);
+			}
+			Scoring::Tfidf => 1.hash(state),
 		}
 	}
 }
@@ -68,6 +105,12 @@ impl fmt::Display for Scoring {
 				b,
 			} => write!(f, "BM25({},{})", k1, b),
 			Self::Vs => f.write_str("VS"),
+			Self::Bm25f {
+				k1,
+				b,
+				weights,
+			} => write!(f, "BM25F({},{},[{}])", k1, b, Fmt::comma_separated(weights)),
+			Self::Tfidf => f.write_str("TFIDF"),
 		}
 	}
 }
@@ -9,9 +9,10 @@ use crate::sql::{
 		AlterStatement, AnalyzeStatement, BeginStatement, BreakStatement, CancelStatement,
 		CommitStatement, ContinueStatement, CreateStatement, DefineStatement, DeleteStatement,
 		ForeachStatement, IfelseStatement, InfoStatement, InsertStatement, KillStatement,
-		LiveStatement, OptionStatement, OutputStatement, RelateStatement, RemoveStatement,
-		SelectStatement, SetStatement, ShowStatement, SleepStatement, ThrowStatement,
-		UpdateStatement, UpsertStatement, UseStatement,
+		LiveStatement, OptionStatement, OutputStatement, RelateStatement, ReleaseStatement,
+		RemoveStatement, RollbackStatement, SavepointStatement, SelectStatement, SetStatement,
+		ShowStatement, SleepStatement, ThrowStatement, UpdateStatement, UpsertStatement,
+		UseStatement,
 	},
 	value::Value,
 };
@@ -56,7 +57,7 @@ impl Display for Statements {
 	}
 }
 
-#[revisioned(revision = 5)]
+#[revisioned(revision = 6)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -97,6 +98,12 @@ pub enum Statement {
 	// TODO(gguillemas): Document once bearer access is no longer experimental.
 	#[revision(start = 5)]
 	Access(AccessStatement),
+	#[revision(start = 6)]
+	Savepoint(SavepointStatement),
+	#[revision(start = 6)]
+	Rollback(RollbackStatement),
+	#[revision(start = 6)]
+	Release(ReleaseStatement),
 }
 
 impl Statement {
@@ -224,7 +231,10 @@ impl Display for Statement {
 			Self::Output(v) => write!(Pretty::from(f), "{v}"),
 			Self::Rebuild(v) => write!(Pretty::from(f), "{v}"),
 			Self::Relate(v) => write!(Pretty::from(f), "{v}"),
+			Self::Release(v) => write!(Pretty::from(f), "{v}"),
 			Self::Remove(v) => write!(Pretty::from(f), "{v}"),
+			Self::Rollback(v) => write!(Pretty::from(f), "{v}"),
+			Self::Savepoint(v) => write!(Pretty::from(f), "{v}"),
 			Self::Select(v) => write!(Pretty::from(f), "{v}"),
 			Self::Set(v) => write!(Pretty::from(f), "{v}"),
 			Self::Show(v) => write!(Pretty::from(f), "{v}"),
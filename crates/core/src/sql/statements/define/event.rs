@@ -5,14 +5,14 @@ use crate::err::Error;
 use crate::iam::{Action, ResourceKind};
 use crate::sql::statements::define::DefineTableStatement;
 use crate::sql::statements::info::InfoStructure;
-use crate::sql::{Base, Ident, Strand, Value, Values};
+use crate::sql::{Base, Duration, Ident, Strand, Value, Values};
 
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display};
 use uuid::Uuid;
 
-#[revisioned(revision = 3)]
+#[revisioned(revision = 4)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -26,6 +26,53 @@ pub struct DefineEventStatement {
 	pub if_not_exists: bool,
 	#[revision(start = 3)]
 	pub overwrite: bool,
+	#[revision(start = 4)]
+	pub asynchronous: Option<EventAsync>,
+}
+
+/// Configures an event to be queued and processed in the background,
+/// once the transaction which triggered the event has been committed.
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct EventAsync {
+	/// The number of times a failed event is retried
+	pub retry: u32,
+	/// The delay before the first retry, doubled on each subsequent retry
+	pub backoff: Duration,
+	/// The table into which events are written once all retries have failed
+	pub dead_letter: Option<Ident>,
+}
+
+impl Default for EventAsync {
+	fn default() -> Self {
+		Self {
+			retry: 3,
+			backoff: Duration::from_secs(1),
+			dead_letter: None,
+		}
+	}
+}
+
+impl Display for EventAsync {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "ASYNC RETRY {} BACKOFF {}", self.retry, self.backoff)?;
+		if let Some(ref v) = self.dead_letter {
+			write!(f, " DEADLETTER {v}")?
+		}
+		Ok(())
+	}
+}
+
+impl InfoStructure for EventAsync {
+	fn structure(self) -> Value {
+		Value::from(map! {
+			"retry".to_string() => self.retry.into(),
+			"backoff".to_string() => self.backoff.into(),
+			"dead_letter".to_string(), if let Some(v) = self.dead_letter => v.structure(),
+		})
+	}
 }
 
 impl DefineEventStatement {
@@ -101,6 +148,9 @@ impl Display for DefineEventStatement {
 			write!(f, " OVERWRITE")?
 		}
 		write!(f, " {} ON {} WHEN {} THEN {}", self.name, self.what, self.when, self.then)?;
+		if let Some(ref v) = self.asynchronous {
+			write!(f, " {v}")?
+		}
 		if let Some(ref v) = self.comment {
 			write!(f, " COMMENT {v}")?
 		}
@@ -115,6 +165,7 @@ impl InfoStructure for DefineEventStatement {
 			"what".to_string() => self.what.structure(),
 			"when".to_string() => self.when.structure(),
 			"then".to_string() => self.then.structure(),
+			"async".to_string(), if let Some(v) = self.asynchronous => v.structure(),
 			"comment".to_string(), if let Some(v) = self.comment => v.into(),
 		})
 	}
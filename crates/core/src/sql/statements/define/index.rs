@@ -6,7 +6,7 @@ use crate::iam::{Action, ResourceKind};
 use crate::sql::statements::info::InfoStructure;
 use crate::sql::statements::DefineTableStatement;
 use crate::sql::statements::UpdateStatement;
-use crate::sql::{Base, Ident, Idioms, Index, Output, Part, Strand, Value, Values};
+use crate::sql::{Base, Cond, Ident, Idioms, Index, Output, Part, Strand, Value, Values};
 
 use reblessive::tree::Stk;
 use revision::revisioned;
@@ -15,7 +15,7 @@ use std::fmt::{self, Display};
 use std::sync::Arc;
 use uuid::Uuid;
 
-#[revisioned(revision = 4)]
+#[revisioned(revision = 6)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -31,6 +31,12 @@ pub struct DefineIndexStatement {
 	pub overwrite: bool,
 	#[revision(start = 4)]
 	pub concurrently: bool,
+	/// Only the records matching this condition are indexed
+	#[revision(start = 5)]
+	pub cond: Option<Cond>,
+	/// Additional fields stored in the index entries, so queries can be answered from the index
+	#[revision(start = 6)]
+	pub include: Option<Idioms>,
 }
 
 impl DefineIndexStatement {
@@ -71,7 +77,8 @@ impl DefineIndexStatement {
 				// Are we SchemaFull?
 				if tb.full {
 					// Check that the fields exists
-					for idiom in self.cols.iter() {
+					for idiom in self.cols.iter().chain(self.include.iter().flat_map(|i| i.iter()))
+					{
 						let Some(Part::Field(first)) = idiom.0.first() else {
 							continue;
 						};
@@ -176,6 +183,12 @@ impl Display for DefineIndexStatement {
 		if Index::Idx != self.index {
 			write!(f, " {}", self.index)?;
 		}
+		if let Some(ref v) = self.include {
+			write!(f, " INCLUDE {v}")?
+		}
+		if let Some(ref v) = self.cond {
+			write!(f, " {v}")?
+		}
 		if let Some(ref v) = self.comment {
 			write!(f, " COMMENT {v}")?
 		}
@@ -193,6 +206,8 @@ impl InfoStructure for DefineIndexStatement {
 			"what".to_string() => self.what.structure(),
 			"cols".to_string() => self.cols.structure(),
 			"index".to_string() => self.index.structure(),
+			"include".to_string(), if let Some(v) = self.include => v.structure(),
+			"cond".to_string(), if let Some(v) = self.cond => v.structure(),
 			"comment".to_string(), if let Some(v) = self.comment => v.into(),
 		})
 	}
@@ -0,0 +1,136 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::iam::{Action, Auth, ResourceKind};
+use crate::sql::statements::info::InfoStructure;
+use crate::sql::{Base, Duration, Ident, Strand, Value};
+
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DefineJobStatement {
+	pub name: Ident,
+	pub schedule: JobSchedule,
+	pub then: Value,
+	pub comment: Option<Strand>,
+	pub if_not_exists: bool,
+	pub overwrite: bool,
+	/// The authentication of the definer, which the job runs as
+	pub auth: Auth,
+}
+
+/// When a scheduled job runs
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum JobSchedule {
+	/// Run the job repeatedly at a fixed interval
+	Every(Duration),
+	/// Run the job at the times matching a cron expression
+	Cron(Strand),
+}
+
+impl Default for JobSchedule {
+	fn default() -> Self {
+		Self::Every(Duration::default())
+	}
+}
+
+impl Display for JobSchedule {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Every(v) => write!(f, "EVERY {v}"),
+			Self::Cron(v) => write!(f, "CRON {v}"),
+		}
+	}
+}
+
+impl DefineJobStatement {
+	/// Process this type returning a computed simple Value
+	pub(crate) async fn compute(
+		&self,
+		ctx: &Context,
+		opt: &Options,
+		_doc: Option<&CursorDoc>,
+	) -> Result<Value, Error> {
+		// Allowed to run?
+		opt.is_allowed(Action::Edit, ResourceKind::Database, &Base::Db)?;
+		// Fetch the transaction
+		let txn = ctx.tx();
+		// Check if the definition exists
+		let (ns, db) = opt.ns_db()?;
+		if txn.get_db_job(ns, db, &self.name).await.is_ok() {
+			if self.if_not_exists {
+				return Ok(Value::None);
+			} else if !self.overwrite {
+				return Err(Error::JbAlreadyExists {
+					name: self.name.to_string(),
+				});
+			}
+		}
+		// Process the statement
+		let key = crate::key::database::jb::new(ns, db, &self.name);
+		txn.get_or_add_ns(ns, opt.strict).await?;
+		txn.get_or_add_db(ns, db, opt.strict).await?;
+		txn.set(
+			key,
+			revision::to_vec(&DefineJobStatement {
+				// Don't persist the `IF NOT EXISTS` clause to schema
+				if_not_exists: false,
+				overwrite: false,
+				// Run the job with the privileges of the definer
+				auth: opt.auth.as_ref().clone(),
+				..self.clone()
+			})?,
+			None,
+		)
+		.await?;
+		// Reschedule the job from its new definition
+		let key = crate::key::database::js::new(ns, db, &self.name);
+		txn.del(key).await?;
+		// Clear the cache
+		txn.clear();
+		// Ok all good
+		Ok(Value::None)
+	}
+}
+
+impl Display for DefineJobStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DEFINE JOB")?;
+		if self.if_not_exists {
+			write!(f, " IF NOT EXISTS")?
+		}
+		if self.overwrite {
+			write!(f, " OVERWRITE")?
+		}
+		write!(f, " {} {} THEN {}", self.name, self.schedule, self.then)?;
+		if let Some(ref v) = self.comment {
+			write!(f, " COMMENT {v}")?
+		}
+		Ok(())
+	}
+}
+
+impl InfoStructure for DefineJobStatement {
+	fn structure(self) -> Value {
+		let (kind, schedule) = match self.schedule {
+			JobSchedule::Every(v) => ("EVERY", v.structure()),
+			JobSchedule::Cron(v) => ("CRON", v.into()),
+		};
+		Value::from(map! {
+			"name".to_string() => self.name.structure(),
+			"kind".to_string() => kind.into(),
+			"schedule".to_string() => schedule,
+			"then".to_string() => self.then.structure(),
+			"comment".to_string(), if let Some(v) = self.comment => v.into(),
+		})
+	}
+}
@@ -8,9 +8,12 @@ mod event;
 mod field;
 mod function;
 mod index;
+mod job;
 mod model;
 mod namespace;
 mod param;
+mod role;
+mod sink;
 mod table;
 mod user;
 
@@ -19,13 +22,16 @@ pub use analyzer::DefineAnalyzerStatement;
 pub use api::DefineApiStatement;
 pub use config::DefineConfigStatement;
 pub use database::DefineDatabaseStatement;
-pub use event::DefineEventStatement;
+pub use event::{DefineEventStatement, EventAsync};
 pub use field::DefineFieldStatement;
 pub use function::DefineFunctionStatement;
 pub use index::DefineIndexStatement;
+pub use job::{DefineJobStatement, JobSchedule};
 pub use model::DefineModelStatement;
 pub use namespace::DefineNamespaceStatement;
 pub use param::DefineParamStatement;
+pub use role::DefineRoleStatement;
+pub use sink::{DefineSinkStatement, SinkTarget};
 pub use table::DefineTableStatement;
 pub use user::DefineUserStatement;
 
@@ -47,7 +53,7 @@ use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display};
 
-#[revisioned(revision = 3)]
+#[revisioned(revision = 6)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -80,6 +86,12 @@ pub enum DefineStatement {
 	Config(DefineConfigStatement),
 	#[revision(start = 3)]
 	Api(DefineApiStatement),
+	#[revision(start = 4)]
+	Role(DefineRoleStatement),
+	#[revision(start = 5)]
+	Sink(DefineSinkStatement),
+	#[revision(start = 6)]
+	Job(DefineJobStatement),
 }
 
 // Revision implementations
@@ -123,6 +135,9 @@ impl DefineStatement {
 			Self::Index(ref v) => v.compute(stk, ctx, opt, doc).await,
 			Self::Analyzer(ref v) => v.compute(ctx, opt, doc).await,
 			Self::User(ref v) => v.compute(ctx, opt, doc).await,
+			Self::Role(ref v) => v.compute(ctx, opt, doc).await,
+			Self::Sink(ref v) => v.compute(ctx, opt, doc).await,
+			Self::Job(ref v) => v.compute(ctx, opt, doc).await,
 			Self::Model(ref v) => v.compute(ctx, opt, doc).await,
 			Self::Access(ref v) => v.compute(ctx, opt, doc).await,
 			Self::Config(ref v) => v.compute(ctx, opt, doc).await,
@@ -138,6 +153,7 @@ impl Display for DefineStatement {
 			Self::Database(v) => Display::fmt(v, f),
 			Self::Function(v) => Display::fmt(v, f),
 			Self::User(v) => Display::fmt(v, f),
+			Self::Role(v) => Display::fmt(v, f),
 			Self::Param(v) => Display::fmt(v, f),
 			Self::Table(v) => Display::fmt(v, f),
 			Self::Event(v) => Display::fmt(v, f),
@@ -148,6 +164,8 @@ impl Display for DefineStatement {
 			Self::Access(v) => Display::fmt(v, f),
 			Self::Config(v) => Display::fmt(v, f),
 			Self::Api(v) => Display::fmt(v, f),
+			Self::Sink(v) => Display::fmt(v, f),
+			Self::Job(v) => Display::fmt(v, f),
 		}
 	}
 }
@@ -0,0 +1,180 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::iam::{Action, Grant, ResourceKind, Role};
+use crate::sql::statements::info::InfoStructure;
+use crate::sql::{Base, Ident, Strand, Value};
+
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DefineRoleStatement {
+	pub name: Ident,
+	pub base: Base,
+	pub grants: Vec<Grant>,
+	pub comment: Option<Strand>,
+	pub if_not_exists: bool,
+	pub overwrite: bool,
+}
+
+impl DefineRoleStatement {
+	/// Process this type returning a computed simple Value
+	pub(crate) async fn compute(
+		&self,
+		ctx: &Context,
+		opt: &Options,
+		_doc: Option<&CursorDoc>,
+	) -> Result<Value, Error> {
+		// Allowed to run?
+		opt.is_allowed(Action::Edit, ResourceKind::Actor, &self.base)?;
+		// Built-in roles can not be redefined
+		if Role::from_str(&self.name).is_ok() {
+			return Err(Error::RoleBuiltIn {
+				name: self.name.to_raw(),
+			});
+		}
+		// Check the statement type
+		match self.base {
+			Base::Root => {
+				// Fetch the transaction
+				let txn = ctx.tx();
+				// Check if the definition exists
+				if txn.get_root_role(&self.name).await.is_ok() {
+					if self.if_not_exists {
+						return Ok(Value::None);
+					} else if !self.overwrite {
+						return Err(Error::RoleRootAlreadyExists {
+							name: self.name.to_string(),
+						});
+					}
+				}
+				// Process the statement
+				let key = crate::key::root::rl::new(&self.name);
+				txn.set(
+					key,
+					revision::to_vec(&DefineRoleStatement {
+						// Don't persist the `IF NOT EXISTS` clause to schema
+						if_not_exists: false,
+						overwrite: false,
+						..self.clone()
+					})?,
+					None,
+				)
+				.await?;
+				// Clear the cache
+				txn.clear();
+				// Ok all good
+				Ok(Value::None)
+			}
+			Base::Ns => {
+				// Fetch the transaction
+				let txn = ctx.tx();
+				// Check if the definition exists
+				if txn.get_ns_role(opt.ns()?, &self.name).await.is_ok() {
+					if self.if_not_exists {
+						return Ok(Value::None);
+					} else if !self.overwrite {
+						return Err(Error::RoleNsAlreadyExists {
+							name: self.name.to_string(),
+							ns: opt.ns()?.into(),
+						});
+					}
+				}
+				// Process the statement
+				let key = crate::key::namespace::rl::new(opt.ns()?, &self.name);
+				txn.get_or_add_ns(opt.ns()?, opt.strict).await?;
+				txn.set(
+					key,
+					revision::to_vec(&DefineRoleStatement {
+						// Don't persist the `IF NOT EXISTS` clause to schema
+						if_not_exists: false,
+						overwrite: false,
+						..self.clone()
+					})?,
+					None,
+				)
+				.await?;
+				// Clear the cache
+				txn.clear();
+				// Ok all good
+				Ok(Value::None)
+			}
+			Base::Db => {
+				// Fetch the transaction
+				let txn = ctx.tx();
+				// Check if the definition exists
+				let (ns, db) = opt.ns_db()?;
+				if txn.get_db_role(ns, db, &self.name).await.is_ok() {
+					if self.if_not_exists {
+						return Ok(Value::None);
+					} else if !self.overwrite {
+						return Err(Error::RoleDbAlreadyExists {
+							name: self.name.to_string(),
+							ns: ns.into(),
+							db: db.into(),
+						});
+					}
+				}
+				// Process the statement
+				let key = crate::key::database::rl::new(ns, db, &self.name);
+				txn.get_or_add_ns(ns, opt.strict).await?;
+				txn.get_or_add_db(ns, db, opt.strict).await?;
+				txn.set(
+					key,
+					revision::to_vec(&DefineRoleStatement {
+						// Don't persist the `IF NOT EXISTS` clause to schema
+						if_not_exists: false,
+						overwrite: false,
+						..self.clone()
+					})?,
+					None,
+				)
+				.await?;
+				// Clear the cache
+				txn.clear();
+				// Ok all good
+				Ok(Value::None)
+			}
+			// Other levels are not supported
+			_ => Err(Error::InvalidLevel(self.base.to_string())),
+		}
+	}
+}
+
+impl Display for DefineRoleStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DEFINE ROLE")?;
+		if self.if_not_exists {
+			write!(f, " IF NOT EXISTS")?
+		}
+		if self.overwrite {
+			write!(f, " OVERWRITE")?
+		}
+		write!(f, " {} ON {}", self.name, self.base)?;
+		for grant in self.grants.iter() {
+			write!(f, " GRANT {grant}")?;
+		}
+		if let Some(ref v) = self.comment {
+			write!(f, " COMMENT {v}")?
+		}
+		Ok(())
+	}
+}
+
+impl InfoStructure for DefineRoleStatement {
+	fn structure(self) -> Value {
+		Value::from(map! {
+			"name".to_string() => self.name.structure(),
+			"base".to_string() => self.base.structure(),
+			"grants".to_string() => self.grants.iter().map(|g| Value::from(g.to_string())).collect::<Vec<_>>().into(),
+			"comment".to_string(), if let Some(v) = self.comment => v.into(),
+		})
+	}
+}
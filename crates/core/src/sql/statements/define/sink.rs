@@ -0,0 +1,148 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::iam::{Action, ResourceKind};
+use crate::sql::statements::info::InfoStructure;
+use crate::sql::{Base, Ident, Strand, Value};
+
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DefineSinkStatement {
+	pub name: Ident,
+	pub what: Ident,
+	pub target: SinkTarget,
+	pub comment: Option<Strand>,
+	pub if_not_exists: bool,
+	pub overwrite: bool,
+}
+
+/// The destination to which the changes of a sink are delivered
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum SinkTarget {
+	/// POST each batch of changes as a JSON array to a URL
+	Http(Strand),
+	/// Append each change as a line of JSON to a local file
+	File(Strand),
+}
+
+impl Default for SinkTarget {
+	fn default() -> Self {
+		Self::Http(Strand::default())
+	}
+}
+
+impl Display for SinkTarget {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Http(v) => write!(f, "HTTP {v}"),
+			Self::File(v) => write!(f, "FILE {v}"),
+		}
+	}
+}
+
+impl DefineSinkStatement {
+	/// Process this type returning a computed simple Value
+	pub(crate) async fn compute(
+		&self,
+		ctx: &Context,
+		opt: &Options,
+		_doc: Option<&CursorDoc>,
+	) -> Result<Value, Error> {
+		// Allowed to run?
+		opt.is_allowed(Action::Edit, ResourceKind::Table, &Base::Db)?;
+		// Fetch the transaction
+		let txn = ctx.tx();
+		// Check if the definition exists
+		let (ns, db) = opt.ns_db()?;
+		if txn.get_db_sink(ns, db, &self.name).await.is_ok() {
+			if self.if_not_exists {
+				return Ok(Value::None);
+			} else if !self.overwrite {
+				return Err(Error::SkAlreadyExists {
+					name: self.name.to_string(),
+				});
+			}
+		}
+		// Check that changes are being recorded for the table
+		let db_cf = match txn.get_db(ns, db).await {
+			Ok(v) => v.changefeed.is_some(),
+			Err(Error::DbNotFound {
+				..
+			}) => false,
+			Err(e) => return Err(e),
+		};
+		let tb_cf = match txn.get_tb(ns, db, &self.what).await {
+			Ok(v) => v.changefeed.is_some(),
+			Err(Error::TbNotFound {
+				..
+			}) => false,
+			Err(e) => return Err(e),
+		};
+		if !db_cf && !tb_cf {
+			return Err(Error::SkChangefeedRequired {
+				name: self.name.to_string(),
+				tb: self.what.to_string(),
+			});
+		}
+		// Process the statement
+		let key = crate::key::database::sk::new(ns, db, &self.name);
+		txn.set(
+			key,
+			revision::to_vec(&DefineSinkStatement {
+				// Don't persist the `IF NOT EXISTS` clause to schema
+				if_not_exists: false,
+				overwrite: false,
+				..self.clone()
+			})?,
+			None,
+		)
+		.await?;
+		// Clear the cache
+		txn.clear();
+		// Ok all good
+		Ok(Value::None)
+	}
+}
+
+impl Display for DefineSinkStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DEFINE SINK")?;
+		if self.if_not_exists {
+			write!(f, " IF NOT EXISTS")?
+		}
+		if self.overwrite {
+			write!(f, " OVERWRITE")?
+		}
+		write!(f, " {} ON {} TO {}", self.name, self.what, self.target)?;
+		if let Some(ref v) = self.comment {
+			write!(f, " COMMENT {v}")?
+		}
+		Ok(())
+	}
+}
+
+impl InfoStructure for DefineSinkStatement {
+	fn structure(self) -> Value {
+		let (kind, target) = match self.target {
+			SinkTarget::Http(v) => ("HTTP", v),
+			SinkTarget::File(v) => ("FILE", v),
+		};
+		Value::from(map! {
+			"name".to_string() => self.name.structure(),
+			"what".to_string() => self.what.structure(),
+			"kind".to_string() => kind.into(),
+			"target".to_string() => target.into(),
+			"comment".to_string(), if let Some(v) = self.comment => v.into(),
+		})
+	}
+}
@@ -2,7 +2,7 @@ use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
-use crate::iam::{Action, ResourceKind};
+use crate::iam::{Action, ResourceKind, Role};
 use crate::sql::statements::info::InfoStructure;
 use crate::sql::{
 	escape::QuoteStr, fmt::Fmt, user::UserDuration, Base, Duration, Ident, Strand, Value,
@@ -16,6 +16,7 @@ use rand::{distributions::Alphanumeric, rngs::OsRng, Rng};
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display};
+use std::str::FromStr;
 
 #[revisioned(revision = 4)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
@@ -99,6 +100,27 @@ impl DefineUserStatement {
 		self.duration.session = duration;
 	}
 
+	/// Checks that any custom roles assigned to the user are defined on the same level
+	async fn check_roles(&self, ctx: &Context, opt: &Options) -> Result<(), Error> {
+		let txn = ctx.tx();
+		for role in self.roles.iter().filter(|r| Role::from_str(r).is_err()) {
+			match self.base {
+				Base::Root => {
+					txn.get_root_role(role).await?;
+				}
+				Base::Ns => {
+					txn.get_ns_role(opt.ns()?, role).await?;
+				}
+				Base::Db => {
+					let (ns, db) = opt.ns_db()?;
+					txn.get_db_role(ns, db, role).await?;
+				}
+				_ => return Err(Error::InvalidLevel(self.base.to_string())),
+			}
+		}
+		Ok(())
+	}
+
 	/// Process this type returning a computed simple Value
 	pub(crate) async fn compute(
 		&self,
@@ -108,6 +130,8 @@ impl DefineUserStatement {
 	) -> Result<Value, Error> {
 		// Allowed to run?
 		opt.is_allowed(Action::Edit, ResourceKind::Actor, &self.base)?;
+		// Ensure that any custom roles exist
+		self.check_roles(ctx, opt).await?;
 		// Check the statement type
 		match self.base {
 			Base::Root => {
@@ -232,7 +256,15 @@ impl Display for DefineUserStatement {
 			self.base,
 			QuoteStr(&self.hash),
 			Fmt::comma_separated(
-				&self.roles.iter().map(|r| r.to_string().to_uppercase()).collect::<Vec<String>>()
+				&self
+					.roles
+					.iter()
+					.map(|r| match Role::from_str(r) {
+						// Built-in roles are displayed in uppercase
+						Ok(_) => r.to_string().to_uppercase(),
+						Err(_) => r.to_string(),
+					})
+					.collect::<Vec<String>>()
 			),
 		)?;
 		// Always print relevant durations so defaults can be changed in the future
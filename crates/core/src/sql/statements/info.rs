@@ -1,4 +1,5 @@
 use crate::ctx::Context;
+use crate::dbs::job::JobState;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
@@ -62,6 +63,7 @@ impl InfoStatement {
 						"namespaces".to_string() => process(txn.all_ns().await?),
 						"nodes".to_string() => process(txn.all_nodes().await?),
 						"system".to_string() => system().await,
+						"roles".to_string() => process(txn.all_root_roles().await?),
 						"users".to_string() => process(txn.all_root_users().await?),
 					}),
 					false => Value::from(map! {
@@ -87,6 +89,13 @@ impl InfoStatement {
 							out.into()
 						},
 						"system".to_string() => system().await,
+						"roles".to_string() => {
+							let mut out = Object::default();
+							for v in txn.all_root_roles().await?.iter() {
+								out.insert(v.name.to_raw(), v.to_string().into());
+							}
+							out.into()
+						},
 						"users".to_string() => {
 							let mut out = Object::default();
 							for v in txn.all_root_users().await?.iter() {
@@ -109,6 +118,7 @@ impl InfoStatement {
 					true => Value::from(map! {
 						"accesses".to_string() => process(txn.all_ns_accesses(ns).await?.iter().map(|v| v.redacted()).collect()),
 						"databases".to_string() => process(txn.all_db(ns).await?),
+						"roles".to_string() => process(txn.all_ns_roles(ns).await?),
 						"users".to_string() => process(txn.all_ns_users(ns).await?),
 					}),
 					false => Value::from(map! {
@@ -126,6 +136,13 @@ impl InfoStatement {
 							}
 							out.into()
 						},
+						"roles".to_string() => {
+							let mut out = Object::default();
+							for v in txn.all_ns_roles(ns).await?.iter() {
+								out.insert(v.name.to_raw(), v.to_string().into());
+							}
+							out.into()
+						},
 						"users".to_string() => {
 							let mut out = Object::default();
 							for v in txn.all_ns_users(ns).await?.iter() {
@@ -155,9 +172,27 @@ impl InfoStatement {
 						"apis".to_string() => process(txn.all_db_apis(ns, db).await?),
 						"analyzers".to_string() => process(txn.all_db_analyzers(ns, db).await?),
 						"functions".to_string() => process(txn.all_db_functions(ns, db).await?),
+						"jobs".to_string() => {
+							let mut out = Vec::new();
+							for v in txn.all_db_jobs(ns, db).await?.iter() {
+								let mut val = v.clone().structure();
+								// Include the scheduling state of the job
+								let key = crate::key::database::js::new(ns, db, &v.name);
+								if let Some(state) = txn.get(key, None).await? {
+									let state: JobState = revision::from_slice(&state)?;
+									if let Value::Object(ref mut obj) = val {
+										obj.insert("state".to_string(), state.structure());
+									}
+								}
+								out.push(val);
+							}
+							Value::from(out)
+						},
 						"models".to_string() => process(txn.all_db_models(ns, db).await?),
 						"params".to_string() => process(txn.all_db_params(ns, db).await?),
 						"tables".to_string() => process(txn.all_tb(ns, db, version).await?),
+						"roles".to_string() => process(txn.all_db_roles(ns, db).await?),
+						"sinks".to_string() => process(txn.all_db_sinks(ns, db).await?),
 						"users".to_string() => process(txn.all_db_users(ns, db).await?),
 						"configs".to_string() => process(txn.all_db_configs(ns, db).await?),
 					}),
@@ -190,6 +225,13 @@ impl InfoStatement {
 							}
 							out.into()
 						},
+						"jobs".to_string() => {
+							let mut out = Object::default();
+							for v in txn.all_db_jobs(ns, db).await?.iter() {
+								out.insert(v.name.to_raw(), v.to_string().into());
+							}
+							out.into()
+						},
 						"models".to_string() => {
 							let mut out = Object::default();
 							for v in txn.all_db_models(ns, db).await?.iter() {
@@ -211,6 +253,20 @@ impl InfoStatement {
 							}
 							out.into()
 						},
+						"roles".to_string() => {
+							let mut out = Object::default();
+							for v in txn.all_db_roles(ns, db).await?.iter() {
+								out.insert(v.name.to_raw(), v.to_string().into());
+							}
+							out.into()
+						},
+						"sinks".to_string() => {
+							let mut out = Object::default();
+							for v in txn.all_db_sinks(ns, db).await?.iter() {
+								out.insert(v.name.to_raw(), v.to_string().into());
+							}
+							out.into()
+						},
 						"users".to_string() => {
 							let mut out = Object::default();
 							for v in txn.all_db_users(ns, db).await?.iter() {
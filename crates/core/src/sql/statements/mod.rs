@@ -19,7 +19,10 @@ pub(crate) mod option;
 pub(crate) mod output;
 pub(crate) mod rebuild;
 pub(crate) mod relate;
+pub(crate) mod release;
 pub(crate) mod remove;
+pub(crate) mod rollback;
+pub(crate) mod savepoint;
 pub(crate) mod select;
 pub(crate) mod set;
 pub(crate) mod show;
@@ -48,6 +51,9 @@ pub use self::r#break::BreakStatement;
 pub use self::r#continue::ContinueStatement;
 pub use self::r#use::UseStatement;
 pub use self::relate::RelateStatement;
+pub use self::release::ReleaseStatement;
+pub use self::rollback::RollbackStatement;
+pub use self::savepoint::SavepointStatement;
 pub use self::select::SelectStatement;
 pub use self::set::SetStatement;
 pub use self::show::ShowStatement;
@@ -61,15 +67,16 @@ pub use self::alter::{AlterStatement, AlterTableStatement};
 pub use self::define::{
 	DefineAccessStatement, DefineAnalyzerStatement, DefineApiStatement, DefineDatabaseStatement,
 	DefineEventStatement, DefineFieldStatement, DefineFunctionStatement, DefineIndexStatement,
-	DefineModelStatement, DefineNamespaceStatement, DefineParamStatement, DefineStatement,
-	DefineTableStatement, DefineUserStatement,
+	DefineJobStatement, DefineModelStatement, DefineNamespaceStatement, DefineParamStatement,
+	DefineRoleStatement, DefineSinkStatement, DefineStatement, DefineTableStatement,
+	DefineUserStatement,
 };
 
 pub use self::remove::{
 	RemoveAccessStatement, RemoveAnalyzerStatement, RemoveDatabaseStatement, RemoveEventStatement,
-	RemoveFieldStatement, RemoveFunctionStatement, RemoveIndexStatement, RemoveModelStatement,
-	RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement, RemoveTableStatement,
-	RemoveUserStatement,
+	RemoveFieldStatement, RemoveFunctionStatement, RemoveIndexStatement, RemoveJobStatement,
+	RemoveModelStatement, RemoveNamespaceStatement, RemoveParamStatement, RemoveRoleStatement,
+	RemoveSinkStatement, RemoveStatement, RemoveTableStatement, RemoveUserStatement,
 };
 
 pub use self::define::FindApi;
@@ -5,9 +5,12 @@ mod event;
 mod field;
 mod function;
 mod index;
+mod job;
 mod model;
 mod namespace;
 mod param;
+mod role;
+mod sink;
 mod table;
 mod user;
 
@@ -18,9 +21,12 @@ pub use event::RemoveEventStatement;
 pub use field::RemoveFieldStatement;
 pub use function::RemoveFunctionStatement;
 pub use index::RemoveIndexStatement;
+pub use job::RemoveJobStatement;
 pub use model::RemoveModelStatement;
 pub use namespace::RemoveNamespaceStatement;
 pub use param::RemoveParamStatement;
+pub use role::RemoveRoleStatement;
+pub use sink::RemoveSinkStatement;
 pub use table::RemoveTableStatement;
 pub use user::RemoveUserStatement;
 
@@ -34,7 +40,7 @@ use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 4)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -51,6 +57,12 @@ pub enum RemoveStatement {
 	Index(RemoveIndexStatement),
 	User(RemoveUserStatement),
 	Model(RemoveModelStatement),
+	#[revision(start = 2)]
+	Role(RemoveRoleStatement),
+	#[revision(start = 3)]
+	Sink(RemoveSinkStatement),
+	#[revision(start = 4)]
+	Job(RemoveJobStatement),
 }
 
 impl RemoveStatement {
@@ -77,6 +89,9 @@ impl RemoveStatement {
 			Self::Index(ref v) => v.compute(ctx, opt).await,
 			Self::Analyzer(ref v) => v.compute(ctx, opt).await,
 			Self::User(ref v) => v.compute(ctx, opt).await,
+			Self::Role(ref v) => v.compute(ctx, opt).await,
+			Self::Sink(ref v) => v.compute(ctx, opt).await,
+			Self::Job(ref v) => v.compute(ctx, opt).await,
 			Self::Model(ref v) => v.compute(ctx, opt).await,
 		}
 	}
@@ -96,6 +111,9 @@ impl Display for RemoveStatement {
 			Self::Index(v) => Display::fmt(v, f),
 			Self::Analyzer(v) => Display::fmt(v, f),
 			Self::User(v) => Display::fmt(v, f),
+			Self::Role(v) => Display::fmt(v, f),
+			Self::Sink(v) => Display::fmt(v, f),
+			Self::Job(v) => Display::fmt(v, f),
 			Self::Model(v) => Display::fmt(v, f),
 		}
 	}
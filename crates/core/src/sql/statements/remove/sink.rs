@@ -0,0 +1,61 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::err::Error;
+use crate::iam::{Action, ResourceKind};
+use crate::sql::{Base, Ident, Value};
+
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct RemoveSinkStatement {
+	pub name: Ident,
+	pub if_exists: bool,
+}
+
+impl RemoveSinkStatement {
+	/// Process this type returning a computed simple Value
+	pub(crate) async fn compute(&self, ctx: &Context, opt: &Options) -> Result<Value, Error> {
+		let future = async {
+			// Allowed to run?
+			opt.is_allowed(Action::Edit, ResourceKind::Table, &Base::Db)?;
+			// Get the transaction
+			let txn = ctx.tx();
+			// Get the definition
+			let (ns, db) = opt.ns_db()?;
+			let sk = txn.get_db_sink(ns, db, &self.name).await?;
+			// Delete the definition
+			let key = crate::key::database::sk::new(ns, db, &sk.name);
+			txn.del(key).await?;
+			// Delete the delivery progress
+			let key = crate::key::database::so::new(ns, db, &sk.name);
+			txn.del(key).await?;
+			// Clear the cache
+			txn.clear();
+			// Ok all good
+			Ok(Value::None)
+		}
+		.await;
+		match future {
+			Err(Error::SkNotFound {
+				..
+			}) if self.if_exists => Ok(Value::None),
+			v => v,
+		}
+	}
+}
+
+impl Display for RemoveSinkStatement {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "REMOVE SINK")?;
+		if self.if_exists {
+			write!(f, " IF EXISTS")?
+		}
+		write!(f, " {}", self.name)?;
+		Ok(())
+	}
+}
@@ -86,6 +86,11 @@ impl SelectStatement {
 				expr,
 				..
 			} => expr.writeable(),
+			Field::Window {
+				expr,
+				window,
+				..
+			} => expr.writeable() || window.partition.iter().any(Value::writeable),
 		}) {
 			return true;
 		}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Display;
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -12,6 +12,11 @@ pub enum Tokenizer {
 	Camel,
 	Class,
 	Punct,
+	/// Unicode word boundaries (UAX #29).
+	/// Scripts written without spaces, such as Chinese or Japanese,
+	/// are split into single characters (unigrams), not into dictionary words.
+	#[revision(start = 2)]
+	Unicode,
 }
 
 impl Display for Tokenizer {
@@ -21,6 +26,7 @@ impl Display for Tokenizer {
 			Self::Camel => "CAMEL",
 			Self::Class => "CLASS",
 			Self::Punct => "PUNCT",
+			Self::Unicode => "UNICODE",
 		})
 	}
 }
@@ -0,0 +1,140 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::sql::fmt::Fmt;
+use crate::sql::order::OrderList;
+use crate::sql::{Function, Value};
+use reblessive::tree::Stk;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+use super::FlowResultExt as _;
+
+/// The window of rows over which a function is computed, as in
+/// `math::sum(amount) OVER (PARTITION BY account ORDER BY time)`
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Window {
+	/// The expressions splitting the rows into separate partitions
+	pub partition: Vec<Value>,
+	/// The ordering of the rows within each partition
+	pub order: Option<OrderList>,
+	/// The rows of the partition over which an aggregate function is computed
+	pub frame: Option<Frame>,
+}
+
+impl Window {
+	/// Computes the inputs of the window function for a single row. The function itself is
+	/// computed once all the rows have been collected, see `dbs::window`.
+	pub(crate) async fn input(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		doc: &CursorDoc,
+		func: &Function,
+	) -> Result<Value, Error> {
+		let args = func.args();
+		// The value which is aggregated, or which is returned by the navigation functions
+		let value = match args.first() {
+			Some(v) => v.compute(stk, ctx, opt, Some(doc)).await.catch_return()?,
+			None if func.is_aggregate() => {
+				func.compute(stk, ctx, opt, Some(doc)).await.catch_return()?
+			}
+			None => Value::None,
+		};
+		// The remaining arguments of window functions, like the offset of `lag()`, are computed
+		// for each row, those of aggregate functions are computed with the aggregation
+		let mut extra = Vec::new();
+		if func.is_window() {
+			for v in args.iter().skip(1) {
+				extra.push(v.compute(stk, ctx, opt, Some(doc)).await.catch_return()?);
+			}
+		}
+		let mut partition = Vec::with_capacity(self.partition.len());
+		for v in &self.partition {
+			partition.push(v.compute(stk, ctx, opt, Some(doc)).await.catch_return()?);
+		}
+		let mut order = Vec::new();
+		if let Some(orders) = &self.order {
+			for o in orders.iter() {
+				order.push(o.value.compute(stk, ctx, opt, Some(doc)).await.catch_return()?);
+			}
+		}
+		Ok(Value::from(vec![value, Value::from(extra), Value::from(partition), Value::from(order)]))
+	}
+}
+
+impl Display for Window {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let mut clauses = Vec::with_capacity(3);
+		if !self.partition.is_empty() {
+			clauses.push(format!("PARTITION BY {}", Fmt::comma_separated(&self.partition)));
+		}
+		if let Some(order) = &self.order {
+			clauses.push(format!("ORDER BY {order}"));
+		}
+		if let Some(frame) = &self.frame {
+			clauses.push(frame.to_string());
+		}
+		f.write_str(&clauses.join(" "))
+	}
+}
+
+/// The rows, relative to the current row, over which an aggregate function is computed
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Frame {
+	pub start: FrameBound,
+	pub end: FrameBound,
+}
+
+impl Display for Frame {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "ROWS BETWEEN {} AND {}", self.start, self.end)
+	}
+}
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum FrameBound {
+	UnboundedPreceding,
+	Preceding(u64),
+	#[default]
+	CurrentRow,
+	Following(u64),
+	UnboundedFollowing,
+}
+
+impl FrameBound {
+	/// The position of the bound relative to the current row
+	pub(crate) fn offset(&self) -> i128 {
+		match self {
+			Self::UnboundedPreceding => i128::MIN,
+			Self::Preceding(n) => -(*n as i128),
+			Self::CurrentRow => 0,
+			Self::Following(n) => *n as i128,
+			Self::UnboundedFollowing => i128::MAX,
+		}
+	}
+}
+
+impl Display for FrameBound {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::UnboundedPreceding => f.write_str("UNBOUNDED PRECEDING"),
+			Self::Preceding(n) => write!(f, "{n} PRECEDING"),
+			Self::CurrentRow => f.write_str("CURRENT ROW"),
+			Self::Following(n) => write!(f, "{n} FOLLOWING"),
+			Self::UnboundedFollowing => f.write_str("UNBOUNDED FOLLOWING"),
+		}
+	}
+}
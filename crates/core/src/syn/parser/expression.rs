@@ -5,6 +5,7 @@ use std::ops::Bound;
 use reblessive::Stk;
 
 use super::mac::{expected_whitespace, unexpected};
+use crate::idx::ft::fuzzy::MAX_FUZZINESS;
 use crate::sql::Range;
 use crate::sql::{value::TryNeg, Cast, Expression, Number, Operator, Value};
 use crate::syn::error::bail;
@@ -294,6 +295,31 @@ impl Parser<'_> {
 		Ok(op)
 	}
 
+	/// Parses the reference and the fuzziness of the MATCHES operator: `@[ref][,fuzziness]@`
+	fn parse_matches(&mut self) -> ParseResult<Operator> {
+		if self.eat(t!("@")) {
+			return Ok(Operator::Matches(None, None));
+		}
+		let reference = if self.peek_kind() == t!(",") {
+			None
+		} else {
+			Some(self.next_token_value()?)
+		};
+		let fuzziness = if self.eat(t!(",")) {
+			let token = self.peek();
+			let fuzziness: u8 = self.next_token_value()?;
+			if fuzziness > MAX_FUZZINESS {
+				bail!("Invalid fuzziness {fuzziness}",
+					@token.span => "The fuzziness of the MATCHES operator must be at most {MAX_FUZZINESS}")
+			}
+			Some(fuzziness)
+		} else {
+			None
+		};
+		expected!(self, t!("@"));
+		Ok(Operator::Matches(reference, fuzziness))
+	}
+
 	fn expression_is_relation(value: &Value) -> bool {
 		if let Value::Expression(x) = value {
 			return Self::operator_is_relation(x.operator());
@@ -349,16 +375,7 @@ impl Parser<'_> {
 			t!("*~") => Operator::AllLike,
 			t!("?~") => Operator::AnyLike,
 			t!("~") => Operator::Like,
-			t!("@") => {
-				let reference = (!self.eat(t!("@")))
-					.then(|| {
-						let number = self.next_token_value()?;
-						expected!(self, t!("@"));
-						Ok(number)
-					})
-					.transpose()?;
-				Operator::Matches(reference)
-			}
+			t!("@") => self.parse_matches()?,
 			t!("<=") => Operator::LessThanOrEqual,
 			t!("<") => Operator::LessThan,
 			t!(">=") => Operator::MoreThanOrEqual,
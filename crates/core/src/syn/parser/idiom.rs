@@ -30,36 +30,29 @@ impl Parser<'_> {
 	/// # Parser State
 	/// Expects the next tokens to be of a field set.
 	pub(super) async fn parse_fields(&mut self, ctx: &mut Stk) -> ParseResult<Fields> {
+		self.parse_field_set(ctx, false).await
+	}
+
+	/// Parse fields of a SELECT statement, which can also be computed over a window of rows:
+	/// `rank() OVER (ORDER BY foo)` in `SELECT rank() OVER (ORDER BY foo) FROM baz`.
+	///
+	/// # Parser State
+	/// Expects the next tokens to be of a field set.
+	pub(super) async fn parse_select_fields(&mut self, ctx: &mut Stk) -> ParseResult<Fields> {
+		self.parse_field_set(ctx, true).await
+	}
+
+	async fn parse_field_set(&mut self, ctx: &mut Stk, window: bool) -> ParseResult<Fields> {
 		if self.eat(t!("VALUE")) {
-			let expr = ctx.run(|ctx| self.parse_value_field(ctx)).await?;
-			let alias = if self.eat(t!("AS")) {
-				Some(self.parse_plain_idiom(ctx).await?)
-			} else {
-				None
-			};
-			Ok(Fields(
-				vec![Field::Single {
-					expr,
-					alias,
-				}],
-				true,
-			))
+			let field = self.parse_field(ctx, window).await?;
+			Ok(Fields(vec![field], true))
 		} else {
 			let mut fields = Vec::new();
 			loop {
 				let field = if self.eat(t!("*")) {
 					Field::All
 				} else {
-					let expr = ctx.run(|ctx| self.parse_value_field(ctx)).await?;
-					let alias = if self.eat(t!("AS")) {
-						Some(self.parse_plain_idiom(ctx).await?)
-					} else {
-						None
-					};
-					Field::Single {
-						expr,
-						alias,
-					}
+					self.parse_field(ctx, window).await?
 				};
 				fields.push(field);
 				if !self.eat(t!(",")) {
@@ -70,6 +63,42 @@ impl Parser<'_> {
 		}
 	}
 
+	async fn parse_field(&mut self, ctx: &mut Stk, window: bool) -> ParseResult<Field> {
+		let start = self.peek().span;
+		let expr = ctx.run(|ctx| self.parse_value_field(ctx)).await?;
+		let window = match expr {
+			Value::Function(ref f) if window && self.peek_contextual("OVER") => {
+				let span = start.covers(self.last_span());
+				Some(ctx.run(|ctx| self.parse_window(ctx, f, span)).await?)
+			}
+			Value::Function(ref f) if f.is_window() => {
+				let span = start.covers(self.last_span());
+				bail!(
+					"The function `{}` can only be used with an OVER clause in the fields of a SELECT statement",
+					f.name().unwrap_or_default(),
+					@span
+				)
+			}
+			_ => None,
+		};
+		let alias = if self.eat(t!("AS")) {
+			Some(self.parse_plain_idiom(ctx).await?)
+		} else {
+			None
+		};
+		Ok(match window {
+			Some(window) => Field::Window {
+				expr,
+				window,
+				alias,
+			},
+			None => Field::Single {
+				expr,
+				alias,
+			},
+		})
+	}
+
 	/// Parses a list of idioms separated by a `,`
 	pub(super) async fn parse_idiom_list(&mut self, ctx: &mut Stk) -> ParseResult<Vec<Idiom>> {
 		let mut res = vec![self.parse_plain_idiom(ctx).await?];
@@ -592,6 +621,15 @@ impl Parser<'_> {
 	/// end.
 	pub(super) async fn parse_local_idiom(&mut self, ctx: &mut Stk) -> ParseResult<Idiom> {
 		let start = self.next_token_value()?;
+		self.parse_remaining_local_idiom(ctx, start).await
+	}
+
+	/// Parses the remaining parts of a local idiom after its starting field.
+	pub(super) async fn parse_remaining_local_idiom(
+		&mut self,
+		ctx: &mut Stk,
+		start: Ident,
+	) -> ParseResult<Idiom> {
 		let mut parts = vec![Part::Field(start)];
 		loop {
 			let token = self.peek();
@@ -348,6 +348,27 @@ impl<'a> Parser<'a> {
 		}
 	}
 
+	/// Checks if the next token is an identifier spelled as the given word, ignoring case.
+	///
+	/// The keyword table is full, newer keywords are recognised from identifiers
+	/// at the places where they can appear.
+	pub fn peek_contextual(&mut self, word: &str) -> bool {
+		let peek = self.peek();
+		peek.kind == TokenKind::Identifier
+			&& self.lexer.span_str(peek.span).eq_ignore_ascii_case(word)
+	}
+
+	/// Eat the next token if it is an identifier spelled as the given word, ignoring case.
+	/// Returns whether a token was eaten.
+	pub fn eat_contextual(&mut self, word: &str) -> bool {
+		if self.peek_contextual(word) {
+			self.pop_peek();
+			true
+		} else {
+			false
+		}
+	}
+
 	/// Forces the next token to be the given one.
 	/// Used in token gluing to replace the current one with the glued token.
 	fn prepend_token(&mut self, token: Token) {
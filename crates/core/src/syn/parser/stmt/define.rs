@@ -2,12 +2,17 @@ use reblessive::Stk;
 
 use crate::api::method::Method;
 use crate::api::middleware::RequestMiddleware;
+use crate::dbs::job::Cron;
+use crate::iam::{Action, Grant, ResourceKind};
 use crate::sql::access_type::JwtAccessVerify;
-use crate::sql::index::HnswParams;
+use crate::sql::index::{GeohashParams, HnswParams, MAX_GEOHASH_PRECISION};
 use crate::sql::statements::define::config::api::ApiConfig;
 use crate::sql::statements::define::config::graphql::{GraphQLConfig, TableConfig};
 use crate::sql::statements::define::config::ConfigInner;
-use crate::sql::statements::define::{ApiAction, DefineConfigStatement};
+use crate::sql::statements::define::{
+	ApiAction, DefineConfigStatement, DefineJobStatement, DefineSinkStatement, EventAsync,
+	JobSchedule, SinkTarget,
+};
 use crate::sql::statements::DefineApiStatement;
 use crate::sql::Value;
 use crate::syn::error::bail;
@@ -21,12 +26,13 @@ use crate::{
 			define::config::graphql, DefineAccessStatement, DefineAnalyzerStatement,
 			DefineDatabaseStatement, DefineEventStatement, DefineFieldStatement,
 			DefineFunctionStatement, DefineIndexStatement, DefineNamespaceStatement,
-			DefineParamStatement, DefineStatement, DefineTableStatement, DefineUserStatement,
+			DefineParamStatement, DefineRoleStatement, DefineStatement, DefineTableStatement,
+			DefineUserStatement,
 		},
 		table_type,
 		tokenizer::Tokenizer,
-		user, AccessType, Ident, Idioms, Index, Kind, Param, Permissions, Scoring, Strand,
-		TableType, Values,
+		user, AccessType, Expression, Ident, Idiom, Idioms, Index, Kind, Param, Part, Permissions,
+		Scoring, Strand, TableType, Values,
 	},
 	syn::{
 		parser::{
@@ -42,6 +48,15 @@ impl Parser<'_> {
 		&mut self,
 		ctx: &mut Stk,
 	) -> ParseResult<DefineStatement> {
+		if self.eat_contextual("ROLE") {
+			return self.parse_define_role().map(DefineStatement::Role);
+		}
+		if self.eat_contextual("SINK") {
+			return self.parse_define_sink().map(DefineStatement::Sink);
+		}
+		if self.eat_contextual("JOB") {
+			return self.parse_define_job(ctx).await.map(DefineStatement::Job);
+		}
 		let next = self.next();
 		match next.kind {
 			t!("NAMESPACE") => self.parse_define_namespace().map(DefineStatement::Namespace),
@@ -245,16 +260,8 @@ impl Parser<'_> {
 					self.pop_peek();
 					let mut roles = Vec::new();
 					loop {
-						let token = self.peek();
-						let role = self.next_token_value::<Ident>()?;
-						// NOTE(gguillemas): This hardcoded list is a temporary fix in order
-						// to avoid making breaking changes to the DefineUserStatement structure
-						// while still providing parsing feedback to users referencing unexistent roles.
-						// This list should be removed once arbitrary roles can be defined by users.
-						if !matches!(role.to_lowercase().as_str(), "viewer" | "editor" | "owner") {
-							unexpected!(self, token, "an existent role");
-						}
-						roles.push(role);
+						// Custom roles are checked for existence when the user is defined
+						roles.push(self.next_token_value::<Ident>()?);
 
 						if !self.eat(t!(",")) {
 							res.roles = roles;
@@ -300,6 +307,187 @@ impl Parser<'_> {
 		Ok(res)
 	}
 
+	pub fn parse_define_role(&mut self) -> ParseResult<DefineRoleStatement> {
+		let (if_not_exists, overwrite) = if self.eat(t!("IF")) {
+			expected!(self, t!("NOT"));
+			expected!(self, t!("EXISTS"));
+			(true, false)
+		} else if self.eat(t!("OVERWRITE")) {
+			(false, true)
+		} else {
+			(false, false)
+		};
+		let name = self.next_token_value()?;
+		expected!(self, t!("ON"));
+		let base = self.parse_base(false)?;
+
+		let mut res = DefineRoleStatement {
+			name,
+			base,
+			if_not_exists,
+			overwrite,
+			..Default::default()
+		};
+
+		loop {
+			match self.peek_kind() {
+				t!("COMMENT") => {
+					self.pop_peek();
+					res.comment = Some(self.next_token_value()?);
+				}
+				t!("GRANT") => {
+					self.pop_peek();
+					self.parse_role_grant(&mut res.grants)?;
+				}
+				_ => break,
+			}
+		}
+
+		Ok(res)
+	}
+
+	pub fn parse_define_sink(&mut self) -> ParseResult<DefineSinkStatement> {
+		let (if_not_exists, overwrite) = if self.eat(t!("IF")) {
+			expected!(self, t!("NOT"));
+			expected!(self, t!("EXISTS"));
+			(true, false)
+		} else if self.eat(t!("OVERWRITE")) {
+			(false, true)
+		} else {
+			(false, false)
+		};
+		let name = self.next_token_value()?;
+		expected!(self, t!("ON"));
+		self.eat(t!("TABLE"));
+		let what = self.next_token_value()?;
+		expected!(self, t!("TO"));
+		let token = self.peek();
+		let target = match token.kind {
+			TokenKind::Identifier if self.eat_contextual("HTTP") => {
+				SinkTarget::Http(self.next_token_value()?)
+			}
+			TokenKind::Identifier if self.eat_contextual("FILE") => {
+				SinkTarget::File(self.next_token_value()?)
+			}
+			_ => unexpected!(self, token, "HTTP or FILE"),
+		};
+
+		let mut res = DefineSinkStatement {
+			name,
+			what,
+			target,
+			if_not_exists,
+			overwrite,
+			..Default::default()
+		};
+
+		while let t!("COMMENT") = self.peek_kind() {
+			self.pop_peek();
+			res.comment = Some(self.next_token_value()?);
+		}
+
+		Ok(res)
+	}
+
+	pub async fn parse_define_job(&mut self, ctx: &mut Stk) -> ParseResult<DefineJobStatement> {
+		let (if_not_exists, overwrite) = if self.eat(t!("IF")) {
+			expected!(self, t!("NOT"));
+			expected!(self, t!("EXISTS"));
+			(true, false)
+		} else if self.eat(t!("OVERWRITE")) {
+			(false, true)
+		} else {
+			(false, false)
+		};
+		let name = self.next_token_value()?;
+		let token = self.peek();
+		let schedule = match token.kind {
+			TokenKind::Identifier if self.eat_contextual("EVERY") => {
+				let every: crate::sql::Duration = self.next_token_value()?;
+				if every.is_zero() {
+					bail!("Job interval must be greater than zero", @self.last_span());
+				}
+				JobSchedule::Every(every)
+			}
+			TokenKind::Identifier if self.eat_contextual("CRON") => {
+				let cron: Strand = self.next_token_value()?;
+				if let Err(e) = cron.as_str().parse::<Cron>() {
+					bail!("{e}", @self.last_span());
+				}
+				JobSchedule::Cron(cron)
+			}
+			_ => unexpected!(self, token, "EVERY or CRON"),
+		};
+		expected!(self, t!("THEN"));
+		let then = ctx.run(|ctx| self.parse_value_field(ctx)).await?;
+
+		let mut res = DefineJobStatement {
+			name,
+			schedule,
+			then,
+			if_not_exists,
+			overwrite,
+			..Default::default()
+		};
+
+		while let t!("COMMENT") = self.peek_kind() {
+			self.pop_peek();
+			res.comment = Some(self.next_token_value()?);
+		}
+
+		Ok(res)
+	}
+
+	/// Parses a single `VIEW | EDIT ON ...` grant clause of a role definition,
+	/// which can target several resource kinds, or a list of specific tables.
+	fn parse_role_grant(&mut self, grants: &mut Vec<Grant>) -> ParseResult<()> {
+		let token = self.peek();
+		let action = match token.kind {
+			TokenKind::Identifier if self.eat_contextual("VIEW") => Action::View,
+			TokenKind::Identifier if self.eat_contextual("EDIT") => Action::Edit,
+			_ => unexpected!(self, token, "VIEW or EDIT"),
+		};
+		expected!(self, t!("ON"));
+		loop {
+			let next = self.next();
+			let kind = match next.kind {
+				t!("NAMESPACE") => ResourceKind::Namespace,
+				t!("DATABASE") => ResourceKind::Database,
+				t!("TABLE") => ResourceKind::Table,
+				t!("FIELD") => ResourceKind::Field,
+				t!("INDEX") => ResourceKind::Index,
+				t!("EVENT") => ResourceKind::Event,
+				t!("FUNCTION") => ResourceKind::Function,
+				t!("PARAM") => ResourceKind::Parameter,
+				t!("ANALYZER") => ResourceKind::Analyzer,
+				t!("MODEL") => ResourceKind::Model,
+				t!("API") => ResourceKind::Api,
+				// Users and access methods can only be edited by owners, so that a
+				// custom role can not be used to define users or roles with more privileges
+				t!("USER") | t!("ACCESS") => {
+					bail!("Users and access methods can not be granted to a role", @next.span)
+				}
+				_ => unexpected!(self, next, "a resource kind"),
+			};
+			// A grant on tables can be restricted to specific tables
+			if kind == ResourceKind::Table {
+				let peek = self.peek_kind();
+				if Self::kind_is_identifier(peek) && !matches!(peek, t!("GRANT") | t!("COMMENT")) {
+					loop {
+						grants.push(Grant::on_table(action, self.next_token_value()?));
+						if !self.eat(t!(",")) {
+							return Ok(());
+						}
+					}
+				}
+			}
+			grants.push(Grant::new(action, kind));
+			if !self.eat(t!(",")) {
+				return Ok(());
+			}
+		}
+	}
+
 	pub async fn parse_define_access(
 		&mut self,
 		stk: &mut Stk,
@@ -924,6 +1112,9 @@ impl Parser<'_> {
 						res.then.0.push(ctx.run(|ctx| self.parse_value_field(ctx)).await?)
 					}
 				}
+				TokenKind::Identifier if self.eat_contextual("ASYNC") => {
+					res.asynchronous = Some(self.parse_event_async()?);
+				}
 				t!("COMMENT") => {
 					self.pop_peek();
 					res.comment = Some(self.next_token_value()?);
@@ -934,6 +1125,22 @@ impl Parser<'_> {
 		Ok(res)
 	}
 
+	fn parse_event_async(&mut self) -> ParseResult<EventAsync> {
+		let mut res = EventAsync::default();
+		loop {
+			if self.eat_contextual("RETRY") {
+				res.retry = self.next_token_value()?;
+			} else if self.eat_contextual("BACKOFF") {
+				res.backoff = self.next_token_value()?;
+			} else if self.eat_contextual("DEADLETTER") {
+				res.dead_letter = Some(self.next_token_value()?);
+			} else {
+				break;
+			}
+		}
+		Ok(res)
+	}
+
 	pub async fn parse_define_field(&mut self, ctx: &mut Stk) -> ParseResult<DefineFieldStatement> {
 		let (if_not_exists, overwrite) = if self.eat(t!("IF")) {
 			expected!(self, t!("NOT"));
@@ -1037,15 +1244,17 @@ impl Parser<'_> {
 			overwrite,
 			..Default::default()
 		};
+		let mut include_span = None;
+		let mut weights_span = None;
 
 		loop {
 			match self.peek_kind() {
 				// COLUMNS and FIELDS are the same tokenkind
 				t!("FIELDS") => {
 					self.pop_peek();
-					res.cols = Idioms(vec![self.parse_local_idiom(ctx).await?]);
+					res.cols = Idioms(vec![self.parse_index_column(ctx).await?]);
 					while self.eat(t!(",")) {
-						res.cols.0.push(self.parse_local_idiom(ctx).await?);
+						res.cols.0.push(self.parse_index_column(ctx).await?);
 					}
 				}
 				t!("UNIQUE") => {
@@ -1092,6 +1301,45 @@ impl Parser<'_> {
 									scoring = Some(Default::default());
 								};
 							}
+							TokenKind::Identifier if self.eat_contextual("BM25F") => {
+								let open = expected!(self, t!("(")).span;
+								let (k1, b) = if self.peek_kind() == t!("[") {
+									(1.2, 0.75)
+								} else {
+									let k1 = self.next_token_value()?;
+									expected!(self, t!(","));
+									let b = self.next_token_value()?;
+									expected!(self, t!(","));
+									(k1, b)
+								};
+								let weights_open = expected!(self, t!("[")).span;
+								let mut weights = Vec::new();
+								loop {
+									if self.eat(t!("]")) {
+										break;
+									}
+									let span = self.peek().span;
+									let weight: u32 = self.next_token_value()?;
+									if weight == 0 {
+										bail!("Field weights must be greater than 0", @span);
+									}
+									weights.push(weight);
+									if !self.eat(t!(",")) {
+										self.expect_closing_delimiter(t!("]"), weights_open)?;
+										break;
+									}
+								}
+								self.expect_closing_delimiter(t!(")"), open)?;
+								weights_span = Some(self.last_span());
+								scoring = Some(Scoring::Bm25f {
+									k1,
+									b,
+									weights,
+								});
+							}
+							TokenKind::Identifier if self.eat_contextual("TFIDF") => {
+								scoring = Some(Scoring::Tfidf);
+							}
 							t!("DOC_IDS_ORDER") => {
 								self.pop_peek();
 								doc_ids_order = self.next_token_value()?;
@@ -1207,6 +1455,7 @@ impl Parser<'_> {
 					let mut ef_construction = 150;
 					let mut extend_candidates = false;
 					let mut keep_pruned_connections = false;
+					let mut quantization = None;
 					loop {
 						match self.peek_kind() {
 							t!("DISTANCE") => {
@@ -1241,6 +1490,9 @@ impl Parser<'_> {
 								self.pop_peek();
 								keep_pruned_connections = true;
 							}
+							TokenKind::Identifier if self.eat_contextual("QUANTIZE") => {
+								quantization = Some(self.parse_vector_quantization(dimension)?);
+							}
 							_ => {
 								break;
 							}
@@ -1260,8 +1512,32 @@ impl Parser<'_> {
 						ef_construction,
 						extend_candidates,
 						keep_pruned_connections,
+						quantization,
 					));
 				}
+				TokenKind::Identifier if self.peek_contextual("GEOHASH") => {
+					self.pop_peek();
+					let mut precision = 8;
+					if self.eat_contextual("PRECISION") {
+						let span = self.peek().span;
+						precision = self.next_token_value()?;
+						if !(1..=MAX_GEOHASH_PRECISION).contains(&precision) {
+							bail!("The precision of a geohash index must be between 1 and {MAX_GEOHASH_PRECISION}", @span => "Invalid precision");
+						}
+					}
+					res.index = Index::Geohash(GeohashParams::new(precision));
+				}
+				t!("INCLUDE") => {
+					include_span = Some(self.pop_peek().span);
+					let mut include = Idioms(vec![self.parse_local_idiom(ctx).await?]);
+					while self.eat(t!(",")) {
+						include.0.push(self.parse_local_idiom(ctx).await?);
+					}
+					res.include = Some(include);
+				}
+				t!("WHERE") => {
+					res.cond = self.try_parse_condition(ctx).await?;
+				}
 				t!("CONCURRENTLY") => {
 					self.pop_peek();
 					res.concurrently = true;
@@ -1274,9 +1550,62 @@ impl Parser<'_> {
 			}
 		}
 
+		if let Some(span) = include_span {
+			if !matches!(res.index, Index::Idx | Index::Uniq) {
+				bail!("Only standard and unique indexes can include fields", @span => "Fields can't be included in this index");
+			}
+		}
+
+		if let Some(span) = weights_span {
+			if let Index::Search(crate::sql::index::SearchParams {
+				sc: Scoring::Bm25f {
+					weights,
+					..
+				},
+				..
+			}) = &res.index
+			{
+				if weights.len() != res.cols.len() {
+					bail!("Expected one weight for each field of the index", @span => "found {} weights for {} fields", weights.len(), res.cols.len());
+				}
+			}
+		}
+
 		Ok(res)
 	}
 
+	/// Parses a column of an index, which is either a local idiom,
+	/// or an expression (e.g. a function call) computed for each record.
+	async fn parse_index_column(&mut self, ctx: &mut Stk) -> ParseResult<Idiom> {
+		let token = self.peek();
+		let v = match token.kind {
+			t!("fn") | t!("(") => ctx.run(|ctx| self.parse_value_field(ctx)).await?,
+			x if Self::kind_is_identifier(x) => {
+				// The identifier is consumed before looking at the following token, as lexing a
+				// following identifier, like an index option, would replace its string.
+				let start = self.next_token_value()?;
+				if !matches!(self.peek_kind(), t!("::") | t!("(")) {
+					return self.parse_remaining_local_idiom(ctx, start).await;
+				}
+				let v = ctx.run(|ctx| self.parse_builtin(ctx, token.span)).await?;
+				if self.peek_continues_idiom() {
+					bail!("Expected a field or a function call as an index column", @token.span.covers(self.recent_span()));
+				}
+				v
+			}
+			_ => return self.parse_local_idiom(ctx).await,
+		};
+		// Only function calls are matched against query expressions by the planner
+		if !matches!(v, Value::Function(_)) {
+			bail!("Expected a field or a function call as an index column", @token.span.covers(self.last_span()));
+		}
+		// The index entries must be recomputed identically when the record changes
+		if !is_deterministic_index_value(&v) {
+			bail!("Expected an index column which only calls deterministic built-in functions", @token.span.covers(self.last_span()));
+		}
+		Ok(Idiom(vec![Part::Start(v)]))
+	}
+
 	pub fn parse_define_analyzer(&mut self) -> ParseResult<DefineAnalyzerStatement> {
 		let (if_not_exists, overwrite) = if self.eat(t!("IF")) {
 			expected!(self, t!("NOT"));
@@ -1305,18 +1634,22 @@ impl Parser<'_> {
 					self.pop_peek();
 					let mut filters = Vec::new();
 					loop {
-						let next = self.next();
-						match next.kind {
+						let peek = self.peek();
+						match peek.kind {
 							t!("ASCII") => {
+								self.pop_peek();
 								filters.push(Filter::Ascii);
 							}
 							t!("LOWERCASE") => {
+								self.pop_peek();
 								filters.push(Filter::Lowercase);
 							}
 							t!("UPPERCASE") => {
+								self.pop_peek();
 								filters.push(Filter::Uppercase);
 							}
 							t!("EDGENGRAM") => {
+								self.pop_peek();
 								let open_span = expected!(self, t!("(")).span;
 								let a = self.next_token_value()?;
 								expected!(self, t!(","));
@@ -1325,6 +1658,7 @@ impl Parser<'_> {
 								filters.push(Filter::EdgeNgram(a, b));
 							}
 							t!("NGRAM") => {
+								self.pop_peek();
 								let open_span = expected!(self, t!("(")).span;
 								let a = self.next_token_value()?;
 								expected!(self, t!(","));
@@ -1333,18 +1667,46 @@ impl Parser<'_> {
 								filters.push(Filter::Ngram(a, b));
 							}
 							t!("SNOWBALL") => {
+								self.pop_peek();
 								let open_span = expected!(self, t!("(")).span;
 								let language = self.next_token_value()?;
 								self.expect_closing_delimiter(t!(")"), open_span)?;
 								filters.push(Filter::Snowball(language))
 							}
 							t!("MAPPER") => {
+								self.pop_peek();
 								let open_span = expected!(self, t!("(")).span;
 								let path: Strand = self.next_token_value()?;
 								self.expect_closing_delimiter(t!(")"), open_span)?;
 								filters.push(Filter::Mapper(path.into()))
 							}
-							_ => unexpected!(self, next, "a filter"),
+							TokenKind::Identifier if self.eat_contextual("LENGTH") => {
+								let open_span = expected!(self, t!("(")).span;
+								let a: u16 = self.next_token_value()?;
+								expected!(self, t!(","));
+								let b: u16 = self.next_token_value()?;
+								self.expect_closing_delimiter(t!(")"), open_span)?;
+								if a > b {
+									bail!(
+										"The minimum length {a} should not exceed the maximum length {b}",
+										@open_span.covers(self.last_span()) => "Invalid length range"
+									);
+								}
+								filters.push(Filter::Length(a, b));
+							}
+							TokenKind::Identifier if self.eat_contextual("STOPWORDS") => {
+								let open_span = expected!(self, t!("(")).span;
+								let language = self.next_token_value()?;
+								self.expect_closing_delimiter(t!(")"), open_span)?;
+								filters.push(Filter::Stopwords(language))
+							}
+							TokenKind::Identifier if self.eat_contextual("SYNONYM") => {
+								let open_span = expected!(self, t!("(")).span;
+								let path: Strand = self.next_token_value()?;
+								self.expect_closing_delimiter(t!(")"), open_span)?;
+								filters.push(Filter::Synonym(path.into()))
+							}
+							_ => unexpected!(self, peek, "a filter"),
 						}
 						if !self.eat(t!(",")) {
 							break;
@@ -1357,13 +1719,17 @@ impl Parser<'_> {
 					let mut tokenizers = Vec::new();
 
 					loop {
-						let next = self.next();
-						let tokenizer = match next.kind {
-							t!("BLANK") => Tokenizer::Blank,
-							t!("CAMEL") => Tokenizer::Camel,
-							t!("CLASS") => Tokenizer::Class,
-							t!("PUNCT") => Tokenizer::Punct,
-							_ => unexpected!(self, next, "a tokenizer"),
+						let tokenizer = if self.eat_contextual("UNICODE") {
+							Tokenizer::Unicode
+						} else {
+							let next = self.next();
+							match next.kind {
+								t!("BLANK") => Tokenizer::Blank,
+								t!("CAMEL") => Tokenizer::Camel,
+								t!("CLASS") => Tokenizer::Class,
+								t!("PUNCT") => Tokenizer::Punct,
+								_ => unexpected!(self, next, "a tokenizer"),
+							}
 						};
 						tokenizers.push(tokenizer);
 						if !self.eat(t!(",")) {
@@ -1705,3 +2071,35 @@ impl Parser<'_> {
 		Ok(res)
 	}
 }
+
+/// Checks that an index column always computes the same value from the same record
+fn is_deterministic_index_value(v: &Value) -> bool {
+	match v {
+		Value::Idiom(i) => i.iter().all(|p| {
+			matches!(
+				p,
+				Part::All
+					| Part::Flatten | Part::Last
+					| Part::First | Part::Field(_)
+					| Part::Index(_)
+					| Part::Optional
+			)
+		}),
+		Value::Array(a) => a.iter().all(is_deterministic_index_value),
+		Value::Object(o) => o.values().all(is_deterministic_index_value),
+		Value::Cast(c) => is_deterministic_index_value(&c.1),
+		Value::Expression(e) => match e.as_ref() {
+			Expression::Unary {
+				v,
+				..
+			} => is_deterministic_index_value(v),
+			Expression::Binary {
+				l,
+				r,
+				..
+			} => is_deterministic_index_value(l) && is_deterministic_index_value(r),
+		},
+		Value::Function(f) => f.is_pure() && f.args().iter().all(is_deterministic_index_value),
+		v => v.is_static(),
+	}
+}
@@ -9,7 +9,8 @@ use crate::sql::statements::{
 		AccessStatement, AccessStatementGrant, AccessStatementPurge, AccessStatementRevoke,
 		AccessStatementShow, Subject,
 	},
-	KillStatement, LiveStatement, OptionStatement, SetStatement, ThrowStatement,
+	KillStatement, LiveStatement, OptionStatement, ReleaseStatement, RollbackStatement,
+	SavepointStatement, SetStatement, ThrowStatement,
 };
 use crate::sql::{Duration, Fields, Ident, Param};
 use crate::syn::lexer::compound;
@@ -214,6 +215,28 @@ impl Parser<'_> {
 				self.pop_peek();
 				self.parse_use_stmt().map(Statement::Use)
 			}
+			// The savepoint statements are not keywords, so they are only recognised when the
+			// identifier is followed by a savepoint name, or by `TO` in the case of `ROLLBACK`.
+			TokenKind::Identifier
+				if self.peek_contextual("SAVEPOINT")
+					&& Self::kind_is_identifier(self.peek1().kind) =>
+			{
+				self.pop_peek();
+				self.parse_savepoint_stmt().map(Statement::Savepoint)
+			}
+			TokenKind::Identifier
+				if self.peek_contextual("ROLLBACK") && self.peek1().kind == t!("TO") =>
+			{
+				self.pop_peek();
+				self.parse_rollback_stmt().map(Statement::Rollback)
+			}
+			TokenKind::Identifier
+				if self.peek_contextual("RELEASE")
+					&& Self::kind_is_identifier(self.peek1().kind) =>
+			{
+				self.pop_peek();
+				self.parse_release_stmt().map(Statement::Release)
+			}
 			_ => {
 				// TODO: Provide information about keywords.
 				let value = ctx.run(|ctx| self.parse_value_field(ctx)).await?;
@@ -529,6 +552,40 @@ impl Parser<'_> {
 		Ok(CommitStatement)
 	}
 
+	/// Parsers a savepoint statement.
+	///
+	/// # Parser State
+	/// Expects `SAVEPOINT` to already be consumed.
+	fn parse_savepoint_stmt(&mut self) -> ParseResult<SavepointStatement> {
+		let name = self.next_token_value()?;
+		Ok(SavepointStatement {
+			name,
+		})
+	}
+
+	/// Parsers a rollback to savepoint statement.
+	///
+	/// # Parser State
+	/// Expects `ROLLBACK` to already be consumed.
+	fn parse_rollback_stmt(&mut self) -> ParseResult<RollbackStatement> {
+		expected!(self, t!("TO"));
+		let name = self.next_token_value()?;
+		Ok(RollbackStatement {
+			name,
+		})
+	}
+
+	/// Parsers a release savepoint statement.
+	///
+	/// # Parser State
+	/// Expects `RELEASE` to already be consumed.
+	fn parse_release_stmt(&mut self) -> ParseResult<ReleaseStatement> {
+		let name = self.next_token_value()?;
+		Ok(ReleaseStatement {
+			name,
+		})
+	}
+
 	/// Parsers a USE statement.
 	///
 	/// # Parser State
@@ -8,7 +8,7 @@ use crate::syn::error::bail;
 use crate::{
 	sql::{
 		changefeed::ChangeFeed,
-		index::{Distance, VectorType},
+		index::{Distance, VectorQuantization, VectorType},
 		Base, Cond, Data, Duration, Fetchs, Field, Fields, Group, Groups, Ident, Idiom, Output,
 		Permission, Permissions, Tables, Timeout, Value, View,
 	},
@@ -169,10 +169,15 @@ impl Parser<'_> {
 	) -> ParseResult<&'a Field> {
 		let mut found = None;
 		for field in fields.iter() {
-			let Field::Single {
+			let (Field::Single {
 				expr,
 				alias,
-			} = field
+			}
+			| Field::Window {
+				expr,
+				alias,
+				..
+			}) = field
 			else {
 				unreachable!()
 			};
@@ -520,6 +525,28 @@ impl Parser<'_> {
 		}
 	}
 
+	pub fn parse_vector_quantization(&mut self, dimension: u16) -> ParseResult<VectorQuantization> {
+		if self.eat_contextual("SCALAR") {
+			return Ok(VectorQuantization::Scalar);
+		}
+		if self.eat_contextual("BINARY") {
+			return Ok(VectorQuantization::Binary);
+		}
+		if self.eat_contextual("PRODUCT") {
+			let span = self.peek().span;
+			let subspaces: u16 = self.next_token_value()?;
+			if subspaces == 0 || dimension % subspaces != 0 {
+				bail!(
+					"The number of subspaces should be a divisor of the dimension {dimension}",
+					@span => "Invalid number of subspaces"
+				);
+			}
+			return Ok(VectorQuantization::Product(subspaces));
+		}
+		let next = self.next();
+		unexpected!(self, next, "a vector quantization")
+	}
+
 	pub fn parse_custom_function_name(&mut self) -> ParseResult<Ident> {
 		expected!(self, t!("fn"));
 		expected!(self, t!("::"));
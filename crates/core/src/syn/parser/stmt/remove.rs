@@ -5,7 +5,8 @@ use crate::{
 		statements::{
 			remove::RemoveAnalyzerStatement, RemoveAccessStatement, RemoveDatabaseStatement,
 			RemoveEventStatement, RemoveFieldStatement, RemoveFunctionStatement,
-			RemoveIndexStatement, RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement,
+			RemoveIndexStatement, RemoveJobStatement, RemoveNamespaceStatement,
+			RemoveParamStatement, RemoveRoleStatement, RemoveSinkStatement, RemoveStatement,
 			RemoveUserStatement,
 		},
 		Param,
@@ -21,6 +22,51 @@ use crate::{
 
 impl Parser<'_> {
 	pub async fn parse_remove_stmt(&mut self, ctx: &mut Stk) -> ParseResult<RemoveStatement> {
+		if self.eat_contextual("ROLE") {
+			let if_exists = if self.eat(t!("IF")) {
+				expected!(self, t!("EXISTS"));
+				true
+			} else {
+				false
+			};
+			let name = self.next_token_value()?;
+			expected!(self, t!("ON"));
+			let base = self.parse_base(false)?;
+
+			return Ok(RemoveStatement::Role(RemoveRoleStatement {
+				name,
+				base,
+				if_exists,
+			}));
+		}
+		if self.eat_contextual("JOB") {
+			let if_exists = if self.eat(t!("IF")) {
+				expected!(self, t!("EXISTS"));
+				true
+			} else {
+				false
+			};
+			let name = self.next_token_value()?;
+
+			return Ok(RemoveStatement::Job(RemoveJobStatement {
+				name,
+				if_exists,
+			}));
+		}
+		if self.eat_contextual("SINK") {
+			let if_exists = if self.eat(t!("IF")) {
+				expected!(self, t!("EXISTS"));
+				true
+			} else {
+				false
+			};
+			let name = self.next_token_value()?;
+
+			return Ok(RemoveStatement::Sink(RemoveSinkStatement {
+				name,
+				if_exists,
+			}));
+		}
 		let next = self.next();
 		let res = match next.kind {
 			t!("NAMESPACE") => {
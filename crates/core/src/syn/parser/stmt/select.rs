@@ -4,11 +4,17 @@ use crate::{
 	sql::{
 		order::{OrderList, Ordering},
 		statements::SelectStatement,
-		Field, Fields, Idioms, Limit, Order, Split, Splits, Start, Values, Version,
+		window::{Frame, FrameBound},
+		Field, Fields, Function, Idioms, Limit, Order, Split, Splits, Start, Values, Version,
+		Window,
 	},
 	syn::{
-		parser::{mac::expected, ParseResult, Parser},
-		token::{t, Span},
+		error::bail,
+		parser::{
+			mac::{expected, unexpected},
+			ParseResult, Parser,
+		},
+		token::{t, Span, TokenKind},
 	},
 };
 
@@ -20,7 +26,7 @@ impl Parser<'_> {
 		stk: &mut Stk,
 	) -> ParseResult<SelectStatement> {
 		let before = self.peek().span;
-		let expr = self.parse_fields(stk).await?;
+		let expr = self.parse_select_fields(stk).await?;
 		let fields_span = before.covers(self.last_span());
 
 		let omit = if self.eat(t!("OMIT")) {
@@ -43,6 +49,9 @@ impl Parser<'_> {
 		let cond = self.try_parse_condition(stk).await?;
 		let split = self.try_parse_split(stk, &expr, fields_span).await?;
 		let group = self.try_parse_group(stk, &expr, fields_span).await?;
+		if group.is_some() && expr.has_window() {
+			bail!("Functions computed over a window can not be used with a GROUP BY clause", @fields_span => "Window used here")
+		}
 		let order = self.try_parse_orders(stk, &expr, fields_span).await?;
 		let (limit, start) = if let t!("START") = self.peek_kind() {
 			let start = self.try_parse_start(stk).await?;
@@ -163,6 +172,102 @@ impl Parser<'_> {
 		Ok(Some(Ordering::Order(OrderList(orders))))
 	}
 
+	/// Parses the window over which a function is computed:
+	/// `OVER (PARTITION BY foo ORDER BY bar ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)`
+	///
+	/// # Parser State
+	/// Expects the next token to be the `OVER` identifier, the span is the one of the function.
+	pub(crate) async fn parse_window(
+		&mut self,
+		ctx: &mut Stk,
+		func: &Function,
+		span: Span,
+	) -> ParseResult<Window> {
+		let name = func.name().unwrap_or_default();
+		let args = func.args().len();
+		if func.is_window() {
+			let expected = match name {
+				"lag" | "lead" => 1..=3,
+				"first_value" | "last_value" => 1..=1,
+				_ => 0..=0,
+			};
+			if !expected.contains(&args) {
+				bail!("Incorrect number of arguments for the window function `{name}`", @span)
+			}
+		} else if !func.is_aggregate() {
+			bail!("Only aggregate and window functions can be computed over a window", @span => "Not an aggregate function")
+		}
+		self.pop_peek();
+		let start = expected!(self, t!("(")).span;
+		let mut window = Window::default();
+		if self.eat_contextual("PARTITION") {
+			expected!(self, t!("BY"));
+			window.partition.push(ctx.run(|ctx| self.parse_value_field(ctx)).await?);
+			while self.eat(t!(",")) {
+				window.partition.push(ctx.run(|ctx| self.parse_value_field(ctx)).await?);
+			}
+		}
+		if self.eat(t!("ORDER")) {
+			expected!(self, t!("BY"));
+			let mut orders = vec![self.parse_order(ctx).await?];
+			while self.eat(t!(",")) {
+				orders.push(self.parse_order(ctx).await?);
+			}
+			window.order = Some(OrderList(orders));
+		}
+		if self.peek_contextual("ROWS") {
+			let before = self.pop_peek().span;
+			let frame = if self.eat_contextual("BETWEEN") {
+				let start = self.parse_frame_bound()?;
+				expected!(self, t!("AND"));
+				Frame {
+					start,
+					end: self.parse_frame_bound()?,
+				}
+			} else {
+				Frame {
+					start: self.parse_frame_bound()?,
+					end: FrameBound::CurrentRow,
+				}
+			};
+			if frame.start == FrameBound::UnboundedFollowing
+				|| frame.end == FrameBound::UnboundedPreceding
+				|| frame.start.offset() > frame.end.offset()
+			{
+				let span = before.covers(self.last_span());
+				bail!("The start of a window frame can not be after its end", @span => "Invalid frame")
+			}
+			window.frame = Some(frame);
+		}
+		self.expect_closing_delimiter(t!(")"), start)?;
+		Ok(window)
+	}
+
+	fn parse_frame_bound(&mut self) -> ParseResult<FrameBound> {
+		let peek = self.peek();
+		let rows = match peek.kind {
+			TokenKind::Identifier if self.peek_contextual("CURRENT") => {
+				self.pop_peek();
+				if !self.eat_contextual("ROW") {
+					unexpected!(self, self.peek(), "ROW")
+				}
+				return Ok(FrameBound::CurrentRow);
+			}
+			TokenKind::Identifier if self.peek_contextual("UNBOUNDED") => {
+				self.pop_peek();
+				None
+			}
+			_ => Some(self.next_token_value::<u64>()?),
+		};
+		if self.eat_contextual("PRECEDING") {
+			Ok(rows.map(FrameBound::Preceding).unwrap_or(FrameBound::UnboundedPreceding))
+		} else if self.eat_contextual("FOLLOWING") {
+			Ok(rows.map(FrameBound::Following).unwrap_or(FrameBound::UnboundedFollowing))
+		} else {
+			unexpected!(self, self.peek(), "PRECEDING or FOLLOWING")
+		}
+	}
+
 	async fn parse_order(&mut self, ctx: &mut Stk) -> ParseResult<Order> {
 		let start = self.parse_basic_idiom(ctx).await?;
 		let collate = self.eat(t!("COLLATE"));
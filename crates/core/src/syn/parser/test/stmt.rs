@@ -1,4 +1,5 @@
 use crate::{
+	iam::{Action, Grant, ResourceKind},
 	sql::{
 		access::AccessDuration,
 		access_type::{
@@ -8,7 +9,10 @@ use crate::{
 		block::Entry,
 		changefeed::ChangeFeed,
 		filter::Filter,
-		index::{Distance, HnswParams, MTreeParams, SearchParams, VectorType},
+		index::{
+			Distance, GeohashParams, HnswParams, MTreeParams, SearchParams, VectorQuantization,
+			VectorType,
+		},
 		language::Language,
 		order::{OrderList, Ordering},
 		statements::{
@@ -17,28 +21,33 @@ use crate::{
 				AccessStatementShow,
 			},
 			analyze::AnalyzeStatement,
+			define::{EventAsync, JobSchedule, SinkTarget},
 			show::{ShowSince, ShowStatement},
 			sleep::SleepStatement,
 			AccessStatement, BeginStatement, BreakStatement, CancelStatement, CommitStatement,
 			ContinueStatement, CreateStatement, DefineAccessStatement, DefineAnalyzerStatement,
 			DefineDatabaseStatement, DefineEventStatement, DefineFieldStatement,
-			DefineFunctionStatement, DefineIndexStatement, DefineNamespaceStatement,
-			DefineParamStatement, DefineStatement, DefineTableStatement, DeleteStatement,
+			DefineFunctionStatement, DefineIndexStatement, DefineJobStatement,
+			DefineNamespaceStatement, DefineParamStatement, DefineRoleStatement,
+			DefineSinkStatement, DefineStatement, DefineTableStatement, DeleteStatement,
 			ForeachStatement, IfelseStatement, InfoStatement, InsertStatement, KillStatement,
-			OptionStatement, OutputStatement, RelateStatement, RemoveAccessStatement,
-			RemoveAnalyzerStatement, RemoveDatabaseStatement, RemoveEventStatement,
-			RemoveFieldStatement, RemoveFunctionStatement, RemoveIndexStatement,
-			RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement, RemoveTableStatement,
-			RemoveUserStatement, SelectStatement, SetStatement, ThrowStatement, UpdateStatement,
-			UpsertStatement, UseStatement,
+			OptionStatement, OutputStatement, RelateStatement, ReleaseStatement,
+			RemoveAccessStatement, RemoveAnalyzerStatement, RemoveDatabaseStatement,
+			RemoveEventStatement, RemoveFieldStatement, RemoveFunctionStatement,
+			RemoveIndexStatement, RemoveJobStatement, RemoveNamespaceStatement,
+			RemoveParamStatement, RemoveRoleStatement, RemoveSinkStatement, RemoveStatement,
+			RemoveTableStatement, RemoveUserStatement, RollbackStatement, SavepointStatement,
+			SelectStatement, SetStatement, ThrowStatement, UpdateStatement, UpsertStatement,
+			UseStatement,
 		},
 		tokenizer::Tokenizer,
 		user::UserDuration,
+		window::{Frame, FrameBound},
 		Algorithm, Array, Base, Block, Cond, Data, Datetime, Dir, Duration, Edges, Explain,
-		Expression, Fetch, Fetchs, Field, Fields, Future, Graph, Group, Groups, Id, Ident, Idiom,
-		Idioms, Index, Kind, Limit, Number, Object, Operator, Order, Output, Param, Part,
-		Permission, Permissions, Scoring, Split, Splits, Start, Statement, Strand, Subquery, Table,
-		TableType, Tables, Thing, Timeout, Uuid, Value, Values, Version, With,
+		Expression, Fetch, Fetchs, Field, Fields, Function, Future, Graph, Group, Groups, Id,
+		Ident, Idiom, Idioms, Index, Kind, Limit, Number, Object, Operator, Order, Output, Param,
+		Part, Permission, Permissions, Scoring, Split, Splits, Start, Statement, Strand, Subquery,
+		Table, TableType, Tables, Thing, Timeout, Uuid, Value, Values, Version, Window, With,
 	},
 	syn::parser::{
 		mac::{test_parse, test_parse_with_settings},
@@ -90,6 +99,37 @@ pub fn parse_commit() {
 	assert_eq!(res, Statement::Commit(CommitStatement));
 }
 
+#[test]
+pub fn parse_savepoint() {
+	let res = test_parse!(parse_stmt, r#"SAVEPOINT step"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Savepoint(SavepointStatement {
+			name: Ident("step".to_owned()),
+		})
+	);
+	let res = test_parse!(parse_stmt, r#"ROLLBACK TO step"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Rollback(RollbackStatement {
+			name: Ident("step".to_owned()),
+		})
+	);
+	let res = test_parse!(parse_stmt, r#"release step"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Release(ReleaseStatement {
+			name: Ident("step".to_owned()),
+		})
+	);
+	// Without a savepoint name, these are plain identifiers
+	let res = test_parse!(parse_stmt, r#"savepoint"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Value(Value::Idiom(Idiom(vec![Part::Field(Ident("savepoint".to_owned()))])))
+	);
+}
+
 #[test]
 pub fn parse_continue() {
 	let res = test_parse!(parse_stmt, r#"CONTINUE"#).unwrap();
@@ -363,30 +403,183 @@ fn parse_define_user() {
 			res
 		);
 	}
-	// With nonexistent role.
+	// With custom role.
 	{
 		let res = test_parse!(
 			parse_stmt,
 			r#"DEFINE USER user ON ROOT COMMENT 'test' PASSHASH 'hunter2' ROLES foo"#
-		);
-		assert!(
-			res.is_err(),
-			"Unexpected successful parsing of user with nonexistent role: {:?}",
-			res
-		);
+		)
+		.unwrap();
+
+		let Statement::Define(DefineStatement::User(stmt)) = res else {
+			panic!()
+		};
+
+		assert_eq!(stmt.roles, vec![Ident("foo".to_string())]);
 	}
-	// With existent and nonexistent roles.
+	// With built-in and custom roles.
 	{
 		let res = test_parse!(
 			parse_stmt,
 			r#"DEFINE USER user ON ROOT COMMENT 'test' PASSHASH 'hunter2' ROLES Viewer, foo"#
+		)
+		.unwrap();
+
+		let Statement::Define(DefineStatement::User(stmt)) = res else {
+			panic!()
+		};
+
+		assert_eq!(stmt.roles, vec![Ident("Viewer".to_string()), Ident("foo".to_string())]);
+	}
+}
+
+#[test]
+fn parse_define_role() {
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE ROLE indexer ON DATABASE GRANT EDIT ON INDEX GRANT VIEW ON TABLE person, post COMMENT 'test'"#
+	)
+	.unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Role(DefineRoleStatement {
+			name: Ident("indexer".to_string()),
+			base: Base::Db,
+			grants: vec![
+				Grant::new(Action::Edit, ResourceKind::Index),
+				Grant::on_table(Action::View, Ident("person".to_string())),
+				Grant::on_table(Action::View, Ident("post".to_string())),
+			],
+			comment: Some(Strand("test".to_string())),
+			if_not_exists: false,
+			overwrite: false,
+		}))
+	);
+	// Multiple resource kinds in a single grant.
+	{
+		let res = test_parse!(
+			parse_stmt,
+			r#"DEFINE ROLE reader ON NAMESPACE GRANT VIEW ON TABLE, FIELD, PARAM"#
+		)
+		.unwrap();
+
+		let Statement::Define(DefineStatement::Role(stmt)) = res else {
+			panic!()
+		};
+
+		assert_eq!(
+			stmt.grants,
+			vec![
+				Grant::new(Action::View, ResourceKind::Table),
+				Grant::new(Action::View, ResourceKind::Field),
+				Grant::new(Action::View, ResourceKind::Parameter),
+			]
 		);
-		assert!(
-			res.is_err(),
-			"Unexpected successful parsing of user with nonexistent role: {:?}",
-			res
+	}
+	// Only viewing and editing can be granted.
+	{
+		let res = test_parse!(parse_stmt, r#"DEFINE ROLE reader ON ROOT GRANT DELETE ON TABLE"#);
+		assert!(res.is_err(), "Unexpected successful parsing of invalid grant: {:?}", res);
+	}
+	// Users and access methods can only be edited by owners.
+	{
+		let res = test_parse!(parse_stmt, r#"DEFINE ROLE admin ON ROOT GRANT EDIT ON USER"#);
+		assert!(res.is_err(), "Unexpected successful parsing of invalid grant: {:?}", res);
+		let res =
+			test_parse!(parse_stmt, r#"DEFINE ROLE admin ON ROOT GRANT VIEW ON TABLE, ACCESS"#);
+		assert!(res.is_err(), "Unexpected successful parsing of invalid grant: {:?}", res);
+	}
+}
+
+#[test]
+fn parse_define_sink() {
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE SINK orders ON TABLE order TO HTTP 'https://example.com/hook' COMMENT 'test'"#
+	)
+	.unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Sink(DefineSinkStatement {
+			name: Ident("orders".to_string()),
+			what: Ident("order".to_string()),
+			target: SinkTarget::Http(Strand("https://example.com/hook".to_string())),
+			comment: Some(Strand("test".to_string())),
+			if_not_exists: false,
+			overwrite: false,
+		}))
+	);
+	// The TABLE keyword is optional.
+	{
+		let res = test_parse!(
+			parse_stmt,
+			r#"DEFINE SINK IF NOT EXISTS audit ON person TO FILE '/tmp/person.ndjson'"#
+		)
+		.unwrap();
+
+		let Statement::Define(DefineStatement::Sink(stmt)) = res else {
+			panic!()
+		};
+
+		assert_eq!(stmt.what, Ident("person".to_string()));
+		assert_eq!(stmt.target, SinkTarget::File(Strand("/tmp/person.ndjson".to_string())));
+		assert!(stmt.if_not_exists);
+	}
+	// Only HTTP and FILE targets are supported.
+	{
+		let res = test_parse!(parse_stmt, r#"DEFINE SINK audit ON person TO KAFKA 'topic'"#);
+		assert!(res.is_err(), "Unexpected successful parsing of invalid target: {:?}", res);
+	}
+}
+
+#[test]
+fn parse_define_job() {
+	let res =
+		test_parse!(parse_stmt, r#"DEFINE JOB cleanup CRON '0 3 * * *' THEN true COMMENT 'test'"#)
+			.unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Job(DefineJobStatement {
+			name: Ident("cleanup".to_string()),
+			schedule: JobSchedule::Cron(Strand("0 3 * * *".to_string())),
+			then: Value::Bool(true),
+			comment: Some(Strand("test".to_string())),
+			if_not_exists: false,
+			overwrite: false,
+			auth: Default::default(),
+		}))
+	);
+	{
+		let res = test_parse!(
+			parse_stmt,
+			r#"DEFINE JOB OVERWRITE tick EVERY 1h THEN { DELETE session WHERE expired }"#
+		)
+		.unwrap();
+
+		let Statement::Define(DefineStatement::Job(stmt)) = res else {
+			panic!()
+		};
+
+		assert_eq!(
+			stmt.schedule,
+			JobSchedule::Every(Duration(std::time::Duration::from_secs(3600)))
+		);
+		assert!(stmt.overwrite);
+		assert_eq!(
+			stmt.to_string(),
+			"DEFINE JOB OVERWRITE tick EVERY 1h THEN { DELETE session WHERE expired; }"
 		);
 	}
+	// Cron expressions are validated when parsed.
+	{
+		let res = test_parse!(parse_stmt, r#"DEFINE JOB tick CRON '61 * * * *' THEN true"#);
+		assert!(res.is_err(), "Unexpected successful parsing of invalid cron: {:?}", res);
+	}
+	// Intervals must not be empty.
+	{
+		let res = test_parse!(parse_stmt, r#"DEFINE JOB tick EVERY 0s THEN true"#);
+		assert!(res.is_err(), "Unexpected successful parsing of empty interval: {:?}", res);
+	}
 }
 
 // TODO(gguillemas): This test is kept in 2.0.0 for backward compatibility. Drop in 3.0.0.
@@ -1882,8 +2075,41 @@ fn parse_define_event() {
 			comment: None,
 			if_not_exists: false,
 			overwrite: false,
+			asynchronous: None,
 		}))
+	);
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE EVENT event ON TABLE table THEN null ASYNC RETRY 5 BACKOFF 2s DEADLETTER failed"#
 	)
+	.unwrap();
+
+	let Statement::Define(DefineStatement::Event(stmt)) = res else {
+		panic!()
+	};
+
+	assert_eq!(
+		stmt.asynchronous,
+		Some(EventAsync {
+			retry: 5,
+			backoff: Duration::from_secs(2),
+			dead_letter: Some(Ident("failed".to_owned())),
+		})
+	);
+	assert_eq!(
+		stmt.to_string(),
+		"DEFINE EVENT event ON `table` WHEN true THEN NULL ASYNC RETRY 5 BACKOFF 2s DEADLETTER failed"
+	);
+
+	let res =
+		test_parse!(parse_stmt, r#"DEFINE EVENT event ON TABLE table THEN null ASYNC"#).unwrap();
+
+	let Statement::Define(DefineStatement::Event(stmt)) = res else {
+		panic!()
+	};
+
+	assert_eq!(stmt.asynchronous, Some(EventAsync::default()));
 }
 
 #[test]
@@ -2009,7 +2235,9 @@ fn parse_define_index() {
 			comment: None,
 			if_not_exists: false,
 			overwrite: false,
-			concurrently: false
+			concurrently: false,
+			cond: None,
+			include: None,
 		}))
 	);
 
@@ -2026,10 +2254,188 @@ fn parse_define_index() {
 			comment: None,
 			if_not_exists: false,
 			overwrite: false,
-			concurrently: false
+			concurrently: false,
+			cond: None,
+			include: None,
+		}))
+	);
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a UNIQUE WHERE active = true"#
+	)
+	.unwrap();
+
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Index(DefineIndexStatement {
+			name: Ident("index".to_owned()),
+			what: Ident("table".to_owned()),
+			cols: Idioms(vec![Idiom(vec![Part::Field(Ident("a".to_owned()))]),]),
+			index: Index::Uniq,
+			comment: None,
+			if_not_exists: false,
+			overwrite: false,
+			concurrently: false,
+			cond: Some(Cond(Value::Expression(Box::new(Expression::Binary {
+				l: ident_field("active"),
+				o: Operator::Equal,
+				r: Value::Bool(true),
+			})))),
+			include: None,
+		}))
+	);
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a UNIQUE INCLUDE b, c.d"#
+	)
+	.unwrap();
+
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Index(DefineIndexStatement {
+			name: Ident("index".to_owned()),
+			what: Ident("table".to_owned()),
+			cols: Idioms(vec![Idiom(vec![Part::Field(Ident("a".to_owned()))]),]),
+			index: Index::Uniq,
+			comment: None,
+			if_not_exists: false,
+			overwrite: false,
+			concurrently: false,
+			cond: None,
+			include: Some(Idioms(vec![
+				Idiom(vec![Part::Field(Ident("b".to_owned()))]),
+				Idiom(vec![Part::Field(Ident("c".to_owned())), Part::Field(Ident("d".to_owned()))]),
+			])),
 		}))
 	);
 
+	test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a MTREE DIMENSION 4 INCLUDE b"#
+	)
+	.unwrap_err();
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a, b SEARCH ANALYZER ana BM25F(1.5,0.5,[3,1])"#
+	)
+	.unwrap();
+	let Statement::Define(DefineStatement::Index(ix)) = res else {
+		panic!()
+	};
+	assert_eq!(
+		ix.index,
+		Index::Search(SearchParams {
+			az: Ident("ana".to_owned()),
+			hl: false,
+			sc: Scoring::Bm25f {
+				k1: 1.5,
+				b: 0.5,
+				weights: vec![3, 1],
+			},
+			doc_ids_order: 100,
+			doc_lengths_order: 100,
+			postings_order: 100,
+			terms_order: 100,
+			doc_ids_cache: 100,
+			doc_lengths_cache: 100,
+			postings_cache: 100,
+			terms_cache: 100,
+		})
+	);
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a, b SEARCH ANALYZER ana BM25F([2,1])"#
+	)
+	.unwrap();
+	let Statement::Define(DefineStatement::Index(ix)) = res else {
+		panic!()
+	};
+	assert!(matches!(
+		ix.index,
+		Index::Search(SearchParams {
+			sc: Scoring::Bm25f { k1, b, ref weights },
+			..
+		}) if k1 == 1.2 && b == 0.75 && weights == &[2, 1]
+	));
+
+	let res = test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS a SEARCH TFIDF"#)
+		.unwrap();
+	let Statement::Define(DefineStatement::Index(ix)) = res else {
+		panic!()
+	};
+	assert!(matches!(
+		ix.index,
+		Index::Search(SearchParams {
+			sc: Scoring::Tfidf,
+			..
+		})
+	));
+
+	// One weight is expected for each field
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS a, b SEARCH BM25F([2])"#)
+		.unwrap_err();
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS a SEARCH BM25F([0])"#)
+		.unwrap_err();
+
+	// The scoring keywords remain usable as identifiers
+	test_parse!(parse_stmt, r#"DEFINE INDEX tfidf ON TABLE bm25f FIELDS tfidf SEARCH TFIDF"#)
+		.unwrap();
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS string::lowercase(a), b"#
+	)
+	.unwrap();
+
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Index(DefineIndexStatement {
+			name: Ident("index".to_owned()),
+			what: Ident("table".to_owned()),
+			cols: Idioms(vec![
+				Idiom(vec![Part::Start(Value::Function(Box::new(Function::Normal(
+					"string::lowercase".to_owned(),
+					vec![ident_field("a")]
+				))))]),
+				Idiom(vec![Part::Field(Ident("b".to_owned()))]),
+			]),
+			index: Index::Idx,
+			comment: None,
+			if_not_exists: false,
+			overwrite: false,
+			concurrently: false,
+			cond: None,
+			include: None,
+		}))
+	);
+
+	// Only function calls can be used as index expressions
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS (a + b)"#).unwrap_err();
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS math::pi"#).unwrap_err();
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS string::len(a).b"#)
+		.unwrap_err();
+	// Only deterministic built-in functions can be used as index expressions
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS rand::int()"#).unwrap_err();
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS time::now()"#).unwrap_err();
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS fn::custom(a)"#)
+		.unwrap_err();
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS string::concat(a, $auth)"#)
+		.unwrap_err();
+	test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS math::abs(rand::int() + a)"#
+	)
+	.unwrap_err();
+	test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS string::lowercase(a.b[0])"#
+	)
+	.unwrap();
+
 	let res =
 		test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS a MTREE DIMENSION 4 DISTANCE MINKOWSKI 5 CAPACITY 6 TYPE I16 DOC_IDS_ORDER 7 DOC_IDS_CACHE 8 MTREE_CACHE 9"#).unwrap();
 
@@ -2051,7 +2457,9 @@ fn parse_define_index() {
 			comment: None,
 			if_not_exists: false,
 			overwrite: false,
-			concurrently: false
+			concurrently: false,
+			cond: None,
+			include: None,
 		}))
 	);
 
@@ -2074,20 +2482,126 @@ fn parse_define_index() {
 				extend_candidates: true,
 				keep_pruned_connections: true,
 				ml: 0.5.into(),
+				quantization: None,
+			}),
+			comment: None,
+			if_not_exists: false,
+			overwrite: false,
+			concurrently: false,
+			cond: None,
+			include: None,
+		}))
+	);
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a HNSW DIMENSION 128 TYPE F32 QUANTIZE PRODUCT 16"#
+	)
+	.unwrap();
+
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Index(DefineIndexStatement {
+			name: Ident("index".to_owned()),
+			what: Ident("table".to_owned()),
+			cols: Idioms(vec![Idiom(vec![Part::Field(Ident("a".to_owned()))]),]),
+			index: Index::Hnsw(HnswParams {
+				dimension: 128,
+				distance: Distance::Euclidean,
+				vector_type: VectorType::F32,
+				m: 12,
+				m0: 24,
+				ef_construction: 150,
+				extend_candidates: false,
+				keep_pruned_connections: false,
+				ml: (1.0 / 12f64.ln()).into(),
+				quantization: Some(VectorQuantization::Product(16)),
+			}),
+			comment: None,
+			if_not_exists: false,
+			overwrite: false,
+			concurrently: false,
+			cond: None,
+			include: None,
+		}))
+	);
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a HNSW DIMENSION 4 QUANTIZE SCALAR"#
+	)
+	.unwrap();
+	let Statement::Define(DefineStatement::Index(ix)) = res else {
+		panic!()
+	};
+	assert!(matches!(
+		ix.index,
+		Index::Hnsw(HnswParams {
+			quantization: Some(VectorQuantization::Scalar),
+			..
+		})
+	));
+
+	test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a HNSW DIMENSION 128 QUANTIZE PRODUCT 3"#
+	)
+	.unwrap_err();
+
+	// The quantization keywords remain usable as identifiers
+	test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX quantize ON TABLE product FIELDS scalar, binary HNSW DIMENSION 4 QUANTIZE BINARY"#
+	)
+	.unwrap();
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a GEOHASH PRECISION 6"#
+	)
+	.unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Index(DefineIndexStatement {
+			name: Ident("index".to_owned()),
+			what: Ident("table".to_owned()),
+			cols: Idioms(vec![Idiom(vec![Part::Field(Ident("a".to_owned()))]),]),
+			index: Index::Geohash(GeohashParams {
+				precision: 6,
 			}),
 			comment: None,
 			if_not_exists: false,
 			overwrite: false,
-			concurrently: false
+			concurrently: false,
+			cond: None,
+			include: None,
 		}))
 	);
+
+	let res =
+		test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS a geohash"#).unwrap();
+	let Statement::Define(DefineStatement::Index(ix)) = res else {
+		panic!()
+	};
+	assert_eq!(ix.index, Index::Geohash(GeohashParams::new(8)));
+
+	// An escaped column followed by an index option keeps its own name
+	let res = test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS ⟨a b⟩ GEOHASH"#)
+		.unwrap();
+	let Statement::Define(DefineStatement::Index(ix)) = res else {
+		panic!()
+	};
+	assert_eq!(ix.cols, Idioms(vec![Idiom(vec![Part::Field(Ident("a b".to_owned()))])]));
+
+	test_parse!(parse_stmt, r#"DEFINE INDEX index ON TABLE table FIELDS a GEOHASH PRECISION 13"#)
+		.unwrap_err();
 }
 
 #[test]
 fn parse_define_analyzer() {
 	let res = test_parse!(
 		parse_stmt,
-		r#"DEFINE ANALYZER ana FILTERS ASCII, EDGENGRAM(1,2), NGRAM(3,4), LOWERCASE, SNOWBALL(NLD), UPPERCASE TOKENIZERS BLANK, CAMEL, CLASS, PUNCT FUNCTION fn::foo::bar"#
+		r#"DEFINE ANALYZER ana FILTERS ASCII, EDGENGRAM(1,2), NGRAM(3,4), LOWERCASE, SNOWBALL(NLD), UPPERCASE, LENGTH(2,10), STOPWORDS(english), SYNONYM('syn.txt') TOKENIZERS BLANK, CAMEL, CLASS, PUNCT, UNICODE FUNCTION fn::foo::bar"#
 	).unwrap();
 
 	assert_eq!(
@@ -2099,6 +2613,7 @@ fn parse_define_analyzer() {
 				Tokenizer::Camel,
 				Tokenizer::Class,
 				Tokenizer::Punct,
+				Tokenizer::Unicode,
 			]),
 			filters: Some(vec![
 				Filter::Ascii,
@@ -2107,13 +2622,25 @@ fn parse_define_analyzer() {
 				Filter::Lowercase,
 				Filter::Snowball(Language::Dutch),
 				Filter::Uppercase,
+				Filter::Length(2, 10),
+				Filter::Stopwords(Language::English),
+				Filter::Synonym("syn.txt".to_string()),
 			]),
 			comment: None,
 			function: Some(Ident("foo::bar".to_string())),
 			if_not_exists: false,
 			overwrite: false,
 		})),
+	);
+
+	test_parse!(parse_stmt, r#"DEFINE ANALYZER ana FILTERS LENGTH(10,2)"#).unwrap_err();
+
+	// The filter and tokenizer keywords remain usable as identifiers
+	test_parse!(
+		parse_stmt,
+		r#"DEFINE FIELD length ON TABLE unicode TYPE option<string> VALUE $stopwords OR synonym"#
 	)
+	.unwrap();
 }
 
 #[test]
@@ -2364,6 +2891,98 @@ SELECT bar as foo,[1,2],bar OMIT bar FROM ONLY a,1
 	);
 }
 
+#[test]
+fn parse_select_window() {
+	let res = test_parse!(
+		parse_stmt,
+		r#"SELECT math::sum(amount) OVER (PARTITION BY team ORDER BY day DESC ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) AS total, row_number() over () FROM sale"#
+	)
+	.unwrap();
+	let Statement::Select(stmt) = res else {
+		panic!()
+	};
+	assert_eq!(
+		stmt.expr,
+		Fields(
+			vec![
+				Field::Window {
+					expr: Value::Function(Box::new(Function::Normal(
+						"math::sum".to_owned(),
+						vec![ident_field("amount")]
+					))),
+					window: Window {
+						partition: vec![ident_field("team")],
+						order: Some(OrderList(vec![Order {
+							value: Idiom(vec![Part::Field(Ident("day".to_owned()))]),
+							collate: false,
+							numeric: false,
+							direction: false,
+						}])),
+						frame: Some(Frame {
+							start: FrameBound::Preceding(2),
+							end: FrameBound::CurrentRow,
+						}),
+					},
+					alias: Some(Idiom(vec![Part::Field(Ident("total".to_owned()))])),
+				},
+				Field::Window {
+					expr: Value::Function(Box::new(Function::Normal(
+						"row_number".to_owned(),
+						vec![]
+					))),
+					window: Window::default(),
+					alias: None,
+				},
+			],
+			false,
+		)
+	);
+	assert_eq!(
+		stmt.expr.to_string(),
+		"math::sum(amount) OVER (PARTITION BY team ORDER BY day DESC ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) AS total, row_number() OVER ()"
+	);
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"SELECT VALUE lag(amount, 1, 0) OVER (ORDER BY day ROWS UNBOUNDED PRECEDING) FROM sale"#
+	)
+	.unwrap();
+	let Statement::Select(stmt) = res else {
+		panic!()
+	};
+	let Some(Field::Window {
+		window,
+		..
+	}) = stmt.expr.single()
+	else {
+		panic!()
+	};
+	assert_eq!(
+		window.frame,
+		Some(Frame {
+			start: FrameBound::UnboundedPreceding,
+			end: FrameBound::CurrentRow,
+		})
+	);
+
+	// Window functions require an OVER clause
+	test_parse!(parse_stmt, r#"SELECT row_number() FROM sale"#).unwrap_err();
+	test_parse!(parse_stmt, r#"CREATE sale RETURN rank() OVER ()"#).unwrap_err();
+	// Only aggregate and window functions can be computed over a window
+	test_parse!(parse_stmt, r#"SELECT string::len(name) OVER () FROM sale"#).unwrap_err();
+	test_parse!(parse_stmt, r#"SELECT lag() OVER () FROM sale"#).unwrap_err();
+	// Invalid frames
+	test_parse!(
+		parse_stmt,
+		r#"SELECT count() OVER (ROWS BETWEEN 1 FOLLOWING AND CURRENT ROW) FROM sale"#
+	)
+	.unwrap_err();
+	test_parse!(parse_stmt, r#"SELECT count() OVER (ROWS UNBOUNDED FOLLOWING) FROM sale"#)
+		.unwrap_err();
+	// Windows are computed over the rows, not the groups
+	test_parse!(parse_stmt, r#"SELECT count() OVER () FROM sale GROUP BY team"#).unwrap_err();
+}
+
 #[test]
 fn parse_let() {
 	let res = test_parse!(parse_stmt, r#"LET $param = 1"#).unwrap();
@@ -2858,6 +3477,34 @@ fn parse_remove() {
 			if_exists: false,
 		}))
 	);
+
+	let res = test_parse!(parse_stmt, r#"REMOVE ROLE IF EXISTS indexer ON NAMESPACE"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Remove(RemoveStatement::Role(RemoveRoleStatement {
+			name: Ident("indexer".to_owned()),
+			base: Base::Ns,
+			if_exists: true,
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"REMOVE SINK IF EXISTS orders"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Remove(RemoveStatement::Sink(RemoveSinkStatement {
+			name: Ident("orders".to_owned()),
+			if_exists: true,
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"REMOVE JOB IF EXISTS cleanup"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Remove(RemoveStatement::Job(RemoveJobStatement {
+			name: Ident("cleanup".to_owned()),
+			if_exists: true,
+		}))
+	);
 }
 
 #[test]
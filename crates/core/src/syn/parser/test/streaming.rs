@@ -73,7 +73,7 @@ static SOURCE: &str = r#"
 			HIGHLIGHTS;
 	DEFINE INDEX index ON TABLE table FIELDS a UNIQUE;
 	DEFINE INDEX index ON TABLE table FIELDS a MTREE DIMENSION 4 DISTANCE MINKOWSKI 5 CAPACITY 6 DOC_IDS_ORDER 7 DOC_IDS_CACHE 8 MTREE_CACHE 9;
-	DEFINE ANALYZER ana FILTERS ASCII, EDGENGRAM(1,2), NGRAM(3,4), LOWERCASE, SNOWBALL(NLD), UPPERCASE TOKENIZERS BLANK, CAMEL, CLASS, PUNCT FUNCTION fn::foo::bar;
+	DEFINE ANALYZER ana FILTERS ASCII, EDGENGRAM(1,2), NGRAM(3,4), LOWERCASE, SNOWBALL(NLD), UPPERCASE, LENGTH(2,10), STOPWORDS(english), SYNONYM('syn.txt') TOKENIZERS BLANK, CAMEL, CLASS, PUNCT, UNICODE FUNCTION fn::foo::bar;
 	DELETE FROM ONLY |foo:32..64| WITH INDEX index,index_2 Where 2 RETURN AFTER TIMEOUT 1s PARALLEL EXPLAIN FULL;
 	DELETE FROM ONLY a:b->?[$][?true] WITH INDEX index,index_2 WHERE null RETURN NULL TIMEOUT 1h PARALLEL EXPLAIN FULL;
 	FOR $foo IN (SELECT foo FROM bar) * 2 {
@@ -305,6 +305,7 @@ fn statements() -> Vec<Statement> {
 			comment: None,
 			if_not_exists: false,
 			overwrite: false,
+			asynchronous: None,
 		})),
 		Statement::Define(DefineStatement::Field(DefineFieldStatement {
 			name: Idiom(vec![
@@ -362,6 +363,8 @@ fn statements() -> Vec<Statement> {
 			if_not_exists: false,
 			overwrite: false,
 			concurrently: false,
+			cond: None,
+			include: None,
 		})),
 		Statement::Define(DefineStatement::Index(DefineIndexStatement {
 			name: Ident("index".to_owned()),
@@ -372,6 +375,8 @@ fn statements() -> Vec<Statement> {
 			if_not_exists: false,
 			overwrite: false,
 			concurrently: false,
+			cond: None,
+			include: None,
 		})),
 		Statement::Define(DefineStatement::Index(DefineIndexStatement {
 			name: Ident("index".to_owned()),
@@ -390,6 +395,8 @@ fn statements() -> Vec<Statement> {
 			if_not_exists: false,
 			overwrite: false,
 			concurrently: false,
+			cond: None,
+			include: None,
 		})),
 		Statement::Define(DefineStatement::Analyzer(DefineAnalyzerStatement {
 			name: Ident("ana".to_owned()),
@@ -398,6 +405,7 @@ fn statements() -> Vec<Statement> {
 				Tokenizer::Camel,
 				Tokenizer::Class,
 				Tokenizer::Punct,
+				Tokenizer::Unicode,
 			]),
 			filters: Some(vec![
 				Filter::Ascii,
@@ -406,6 +414,9 @@ fn statements() -> Vec<Statement> {
 				Filter::Lowercase,
 				Filter::Snowball(Language::Dutch),
 				Filter::Uppercase,
+				Filter::Length(2, 10),
+				Filter::Stopwords(Language::English),
+				Filter::Synonym("syn.txt".to_string()),
 			]),
 			function: Some(Ident("foo::bar".to_string())),
 			comment: None,
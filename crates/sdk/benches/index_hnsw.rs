@@ -214,6 +214,7 @@ async fn hnsw(tx: &Transaction) -> HnswIndex {
 		EF_CONSTRUCTION,
 		false,
 		false,
+		None,
 	);
 	HnswIndex::new(tx, IndexKeyBase::default(), "test".to_string(), &p).await.unwrap()
 }
@@ -46,6 +46,8 @@ use std::{
 };
 #[cfg(not(target_family = "wasm"))]
 use surrealdb_core::kvs::export::Config as DbExportConfig;
+#[cfg(not(target_family = "wasm"))]
+use surrealdb_core::kvs::import::Format as ImportFormat;
 use surrealdb_core::sql::Function;
 use surrealdb_core::{
 	dbs::{Response, Session},
@@ -985,6 +987,7 @@ async fn router(
 		#[cfg(not(target_family = "wasm"))]
 		Command::ImportFile {
 			path,
+			config,
 		} => {
 			let mut file = match OpenOptions::new().read(true).open(&path).await {
 				Ok(path) => path,
@@ -1021,9 +1024,31 @@ async fn router(
 				}
 			});
 
-			let responses = kvs
-				.execute_import(&*session.read().await, Some(vars.read().await.clone()), stream)
-				.await?;
+			let format = config.format;
+			let responses = match format {
+				ImportFormat::Sql => {
+					kvs.execute_import(
+						&*session.read().await,
+						Some(vars.read().await.clone()),
+						stream,
+					)
+					.await?
+				}
+				_ => kvs.import_with_config(&*session.read().await, config, stream).await?,
+			};
+
+			// Rows which failed to import are reported together
+			if let ImportFormat::Csv | ImportFormat::Ndjson = format {
+				let errors = responses
+					.into_iter()
+					.filter_map(|response| response.result.err())
+					.map(|e| e.to_string())
+					.collect::<Vec<_>>();
+				if !errors.is_empty() {
+					return Err(Error::Query(errors.join("\n")).into());
+				}
+				return Ok(DbResponse::Other(CoreValue::None));
+			}
 
 			for response in responses {
 				response.result?;
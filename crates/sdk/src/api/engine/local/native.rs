@@ -114,7 +114,6 @@ pub(crate) async fn run_router(
 	#[cfg(storage)]
 	let kvs = kvs.with_temporary_directory(address.config.temporary_directory);
 
-	let kvs = Arc::new(kvs);
 	let vars = Arc::new(RwLock::new(BTreeMap::default()));
 	let live_queries = Arc::new(RwLock::new(HashMap::new()));
 	let session = Arc::new(RwLock::new(Session::default().with_rt(true)));
@@ -134,6 +133,19 @@ pub(crate) async fn run_router(
 	if let Some(interval) = address.config.changefeed_gc_interval {
 		opt.changefeed_gc_interval = interval;
 	}
+	if let Some(interval) = address.config.event_queue_interval {
+		opt.event_queue_interval = interval;
+	}
+	if let Some(interval) = address.config.sink_interval {
+		opt.sink_interval = interval;
+	}
+	if let Some(interval) = address.config.job_interval {
+		opt.job_interval = interval;
+	}
+	if let Some(timeout) = address.config.leader_timeout {
+		opt.leader_timeout = timeout;
+	}
+	let kvs = Arc::new(kvs.with_leader_timeout(opt.leader_timeout));
 	let tasks = tasks::init(kvs.clone(), canceller.clone(), &opt);
 
 	let mut notifications = kvs.notifications().map(Box::pin);
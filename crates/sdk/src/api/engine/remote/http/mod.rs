@@ -34,6 +34,8 @@ use url::Url;
 #[cfg(not(target_family = "wasm"))]
 use std::path::PathBuf;
 #[cfg(not(target_family = "wasm"))]
+use surrealdb_core::kvs::import::Format as ImportFormat;
+#[cfg(not(target_family = "wasm"))]
 use tokio::fs::OpenOptions;
 #[cfg(not(target_family = "wasm"))]
 use tokio::io;
@@ -250,10 +252,14 @@ async fn import(request: RequestBuilder, path: PathBuf) -> Result<()> {
 		}
 	} else {
 		let response: Vec<QueryMethodResponse> = deserialize(&res.bytes().await?, false)?;
-		for res in response {
-			if let Status::Err = res.status {
-				return Err(Error::Query(res.result.0.as_string()).into());
-			}
+		// Rows which failed to import are reported together
+		let errors = response
+			.into_iter()
+			.filter(|res| matches!(res.status, Status::Err))
+			.map(|res| res.result.0.as_string())
+			.collect::<Vec<_>>();
+		if !errors.is_empty() {
+			return Err(Error::Query(errors.join("\n")).into());
 		}
 	}
 
@@ -515,13 +521,24 @@ async fn router(
 		#[cfg(not(target_family = "wasm"))]
 		Command::ImportFile {
 			path,
+			config,
 		} => {
 			let req_path = base_url.join("import")?;
-			let request = client
+			let content_type = match config.format {
+				ImportFormat::Sql => "application/octet-stream",
+				ImportFormat::Arrow => "application/vnd.apache.arrow.stream",
+				ImportFormat::Parquet => "application/vnd.apache.parquet",
+				ImportFormat::Csv => "text/csv",
+				ImportFormat::Ndjson => "application/x-ndjson",
+			};
+			let mut request = client
 				.post(req_path)
 				.headers(headers.clone())
 				.auth(auth)
-				.header(CONTENT_TYPE, "application/octet-stream");
+				.header(CONTENT_TYPE, content_type);
+			if let Some(table) = config.table {
+				request = request.query(&[("table", table)]);
+			}
 			import(request, path).await?;
 			Ok(DbResponse::Other(CoreValue::None))
 		}
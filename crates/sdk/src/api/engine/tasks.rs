@@ -47,7 +47,10 @@ pub fn init(dbs: Arc<Datastore>, canceller: CancellationToken, opts: &EngineOpti
 	let task2 = spawn_task_node_membership_check(dbs.clone(), canceller.clone(), opts);
 	let task3 = spawn_task_node_membership_cleanup(dbs.clone(), canceller.clone(), opts);
 	let task4 = spawn_task_changefeed_cleanup(dbs.clone(), canceller.clone(), opts);
-	Tasks(vec![task1, task2, task3, task4])
+	let task5 = spawn_task_event_processing(dbs.clone(), canceller.clone(), opts);
+	let task6 = spawn_task_sink_delivery(dbs.clone(), canceller.clone(), opts);
+	let task7 = spawn_task_job_processing(dbs.clone(), canceller.clone(), opts);
+	Tasks(vec![task1, task2, task3, task4, task5, task6, task7])
 }
 
 fn spawn_task_node_membership_refresh(
@@ -174,6 +177,99 @@ fn spawn_task_changefeed_cleanup(
 	}))
 }
 
+fn spawn_task_event_processing(
+	dbs: Arc<Datastore>,
+	canceller: CancellationToken,
+	opts: &EngineOptions,
+) -> Task {
+	// Get the delay interval from the config
+	let delay = opts.event_queue_interval;
+	// Spawn a future
+	Box::pin(spawn(async move {
+		// Log the interval frequency
+		trace!("Processing queued asynchronous events every {delay:?}");
+		// Create a new time-based interval ticket
+		let mut ticker = interval_ticker(delay).await;
+		// Loop continuously until the task is cancelled
+		loop {
+			tokio::select! {
+				biased;
+				// Check if this has shutdown
+				_ = canceller.cancelled() => break,
+				// Receive a notification on the channel
+				Some(_) = ticker.next() => {
+					if let Err(e) = dbs.event_process().await {
+						error!("Error processing queued asynchronous events: {e}");
+					}
+				}
+			}
+		}
+		trace!("Background task exited: Processing queued asynchronous events");
+	}))
+}
+
+fn spawn_task_sink_delivery(
+	dbs: Arc<Datastore>,
+	canceller: CancellationToken,
+	opts: &EngineOptions,
+) -> Task {
+	// Get the delay interval from the config
+	let delay = opts.sink_interval;
+	// Spawn a future
+	Box::pin(spawn(async move {
+		// Log the interval frequency
+		trace!("Delivering changefeed entries to sinks every {delay:?}");
+		// Create a new time-based interval ticket
+		let mut ticker = interval_ticker(delay).await;
+		// Loop continuously until the task is cancelled
+		loop {
+			tokio::select! {
+				biased;
+				// Check if this has shutdown
+				_ = canceller.cancelled() => break,
+				// Receive a notification on the channel
+				Some(_) = ticker.next() => {
+					if let Err(e) = dbs.sink_process().await {
+						error!("Error delivering changefeed entries to sinks: {e}");
+					}
+				}
+			}
+		}
+		trace!("Background task exited: Delivering changefeed entries to sinks");
+	}))
+}
+
+fn spawn_task_job_processing(
+	dbs: Arc<Datastore>,
+	canceller: CancellationToken,
+	opts: &EngineOptions,
+) -> Task {
+	// Get the delay interval from the config
+	let delay = opts.job_interval;
+	// Spawn a future
+	Box::pin(spawn(async move {
+		// Log the interval frequency
+		trace!("Running scheduled jobs every {delay:?}");
+		// Create a new time-based interval ticket
+		let mut ticker = interval_ticker(delay).await;
+		// Loop continuously until the task is cancelled
+		loop {
+			tokio::select! {
+				biased;
+				// Check if this has shutdown
+				_ = canceller.cancelled() => break,
+				// Receive a notification on the channel
+				Some(_) = ticker.next() => {
+					if let Err(e) = dbs.job_process().await {
+						error!("Error running scheduled jobs: {e}");
+					}
+				}
+			}
+		}
+		trace!("Background task exited: Running scheduled jobs");
+	}))
+}
+
 async fn interval_ticker(interval: Duration) -> IntervalStream {
 	#[cfg(not(target_family = "wasm"))]
 	use tokio::{time, time::MissedTickBehavior};
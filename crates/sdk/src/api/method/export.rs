@@ -22,6 +22,8 @@ use std::task::Context;
 use std::task::Poll;
 use surrealdb_core::kvs::export::{Config as DbExportConfig, TableConfig};
 
+pub use surrealdb_core::kvs::export::Format as ExportFormat;
+
 /// A database export future
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -149,6 +151,23 @@ where
 		}
 		self
 	}
+
+	/// The format of the export, which defaults to SurrealQL
+	///
+	/// Columnar formats hold the records of a single table,
+	/// with a column for each of its top-level fields:
+	/// ```
+	/// # let db = surrealdb::Surreal::<surrealdb::engine::any::Any>::init();
+	/// use surrealdb::method::ExportFormat;
+	///
+	/// db.export("person.parquet").with_config().tables(vec!["person"]).format(ExportFormat::Parquet);
+	/// ```
+	pub fn format(mut self, format: ExportFormat) -> Self {
+		if let Some(cfg) = self.db_config.as_mut() {
+			cfg.format = format;
+		}
+		self
+	}
 }
 
 impl<C, R, T> Export<'_, C, R, T>
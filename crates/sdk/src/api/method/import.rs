@@ -11,6 +11,9 @@ use std::borrow::Cow;
 use std::future::IntoFuture;
 use std::marker::PhantomData;
 use std::path::PathBuf;
+use surrealdb_core::kvs::import::Config as DbImportConfig;
+
+pub use surrealdb_core::kvs::import::Format as ImportFormat;
 
 /// An database import future
 #[derive(Debug)]
@@ -19,6 +22,7 @@ pub struct Import<'r, C: Connection, T = ()> {
 	pub(super) client: Cow<'r, Surreal<C>>,
 	pub(super) file: PathBuf,
 	pub(super) is_ml: bool,
+	pub(super) db_config: DbImportConfig,
 	pub(super) import_type: PhantomData<T>,
 }
 
@@ -32,9 +36,31 @@ where
 			client: self.client,
 			file: self.file,
 			is_ml: true,
+			db_config: self.db_config,
 			import_type: PhantomData,
 		}
 	}
+
+	/// The format of the file, which defaults to SurrealQL
+	///
+	/// Files in any other format hold the records of a single table,
+	/// which has to be specified using [`Import::table`]:
+	/// ```
+	/// # let db = surrealdb::Surreal::<surrealdb::engine::any::Any>::init();
+	/// use surrealdb::method::ImportFormat;
+	///
+	/// db.import("person.parquet").format(ImportFormat::Parquet).table("person");
+	/// ```
+	pub fn format(mut self, format: ImportFormat) -> Self {
+		self.db_config.format = format;
+		self
+	}
+
+	/// The table which the records of the file are imported into
+	pub fn table(mut self, table: impl Into<String>) -> Self {
+		self.db_config.table = Some(table.into());
+		self
+	}
 }
 
 impl<C, T> Import<'_, C, T>
@@ -75,6 +101,7 @@ where
 			router
 				.execute_unit(Command::ImportFile {
 					path: self.file,
+					config: self.db_config,
 				})
 				.await
 		})
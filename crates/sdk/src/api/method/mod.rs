@@ -72,10 +72,10 @@ pub use commit::Commit;
 pub use content::Content;
 pub use create::Create;
 pub use delete::Delete;
-pub use export::{Backup, Export};
+pub use export::{Backup, Export, ExportFormat};
 use futures::Future;
 pub use health::Health;
-pub use import::Import;
+pub use import::{Import, ImportFormat};
 pub use insert::Insert;
 pub use invalidate::Invalidate;
 pub use live::Stream;
@@ -1429,6 +1429,7 @@ where
 			client: Cow::Borrowed(self),
 			file: file.as_ref().to_owned(),
 			is_ml: false,
+			db_config: Default::default(),
 			import_type: PhantomData,
 		}
 	}
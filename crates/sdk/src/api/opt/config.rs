@@ -25,6 +25,10 @@ pub struct Config {
 	pub(crate) node_membership_check_interval: Option<Duration>,
 	pub(crate) node_membership_cleanup_interval: Option<Duration>,
 	pub(crate) changefeed_gc_interval: Option<Duration>,
+	pub(crate) event_queue_interval: Option<Duration>,
+	pub(crate) sink_interval: Option<Duration>,
+	pub(crate) job_interval: Option<Duration>,
+	pub(crate) leader_timeout: Option<Duration>,
 }
 
 impl Config {
@@ -140,4 +144,28 @@ impl Config {
 		self.changefeed_gc_interval = interval.into().filter(|x| !x.is_zero());
 		self
 	}
+
+	/// Set the interval at which the database should process queued asynchronous events
+	pub fn event_queue_interval(mut self, interval: impl Into<Option<Duration>>) -> Self {
+		self.event_queue_interval = interval.into().filter(|x| !x.is_zero());
+		self
+	}
+
+	/// Set the interval at which the database should deliver changefeed entries to sinks
+	pub fn sink_interval(mut self, interval: impl Into<Option<Duration>>) -> Self {
+		self.sink_interval = interval.into().filter(|x| !x.is_zero());
+		self
+	}
+
+	/// Set the interval at which the database should run scheduled jobs
+	pub fn job_interval(mut self, interval: impl Into<Option<Duration>>) -> Self {
+		self.job_interval = interval.into().filter(|x| !x.is_zero());
+		self
+	}
+
+	/// Set the time after which a node without a heartbeat can no longer lead the cluster
+	pub fn leader_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+		self.leader_timeout = timeout.into().filter(|x| !x.is_zero());
+		self
+	}
 }
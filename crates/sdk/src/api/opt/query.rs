@@ -99,6 +99,24 @@ impl IntoQuery for CommitStatement {
 	}
 }
 
+impl IntoQuery for SavepointStatement {
+	fn into_query(self) -> Result<Vec<Statement>> {
+		Ok(vec![Statement::Savepoint(self)])
+	}
+}
+
+impl IntoQuery for RollbackStatement {
+	fn into_query(self) -> Result<Vec<Statement>> {
+		Ok(vec![Statement::Rollback(self)])
+	}
+}
+
+impl IntoQuery for ReleaseStatement {
+	fn into_query(self) -> Result<Vec<Statement>> {
+		Ok(vec![Statement::Release(self)])
+	}
+}
+
 impl IntoQuery for OutputStatement {
 	fn into_query(self) -> Result<Vec<Statement>> {
 		Ok(vec![Statement::Output(self)])
@@ -36,6 +36,7 @@ async fn define_statement_namespace() -> Result<(), Error> {
 			accesses: {},
 			namespaces: { test: 'DEFINE NAMESPACE test' },
 			nodes: {},
+			roles: {},
 			system: {
 				available_parallelism: 0,
 				cpu_usage: 0.0f,
@@ -76,6 +77,7 @@ async fn define_statement_database() -> Result<(), Error> {
 		"{
 			accesses: {},
 			databases: { test: 'DEFINE DATABASE test' },
+			roles: {},
 			users: {},
 		}",
 	);
@@ -160,6 +162,292 @@ async fn define_statement_event_when_logic() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn define_statement_event_async() -> Result<(), Error> {
+	let sql = "
+		DEFINE EVENT test ON user WHEN $event = 'CREATE' THEN (
+			CREATE activity SET user = $this, value = $after.email, action = $event
+		) ASYNC;
+		DEFINE EVENT fail ON user THEN {
+			THROW 'failed';
+		} ASYNC RETRY 1 BACKOFF 0s DEADLETTER failed;
+		CREATE user:test SET email = 'info@surrealdb.com';
+		SELECT * FROM activity;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	skip_ok(res, 3)?;
+	// The events are only queued by the write
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	// Process the queued events, retrying the failed event once
+	dbs.event_process().await?;
+	dbs.event_process().await?;
+	dbs.event_process().await?;
+	let sql = "
+		SELECT count() FROM activity GROUP ALL;
+		SELECT event, record, action, attempts, error FROM failed;
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ count: 1 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[{
+			action: 'CREATE',
+			attempts: 2,
+			error: 'An error occurred: failed',
+			event: 'fail',
+			record: user:test,
+		}]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn define_statement_event_async_auth() -> Result<(), Error> {
+	let sql = "
+		DEFINE EVENT escalate ON user THEN {
+			DEFINE USER hacker ON DATABASE PASSWORD 'secret' ROLES OWNER;
+		} ASYNC RETRY 0 DEADLETTER failed;
+		DEFINE USER editor ON DATABASE PASSWORD 'secret' ROLES EDITOR;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	skip_ok(res, 2)?;
+	// The event is triggered by an editor
+	let editor =
+		Session::for_level(("test", "test").into(), Role::Editor).with_ns("test").with_db("test");
+	let res = &mut dbs.execute("CREATE user:test", &editor, None).await?;
+	skip_ok(res, 1)?;
+	// The event runs with the authentication of the editor
+	dbs.event_process().await?;
+	let sql = "
+		INFO FOR DB;
+		SELECT VALUE event FROM failed;
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?.to_string();
+	assert!(tmp.contains("editor") && !tmp.contains("hacker"), "{tmp}");
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("['escalate']");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn define_statement_event_async_failed_dead_letter() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE failed SCHEMAFULL;
+		DEFINE FIELD event ON failed TYPE int;
+		DEFINE EVENT fail ON user THEN {
+			THROW 'failed';
+		} ASYNC RETRY 0 DEADLETTER failed;
+		DEFINE EVENT test ON user THEN (
+			CREATE activity SET user = $this
+		) ASYNC;
+		CREATE user:one, user:two;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	skip_ok(res, 5)?;
+	// The failing events can not be written to the dead letter table
+	dbs.event_process().await?;
+	dbs.event_process().await?;
+	let sql = "
+		SELECT count() FROM activity GROUP ALL;
+		SELECT * FROM failed;
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	// The other events are still processed
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ count: 2 }]");
+	assert_eq!(tmp, val);
+	// The failing events are dropped
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn define_statement_sink_file() -> Result<(), Error> {
+	let dir = temp_dir::TempDir::new().unwrap();
+	let path = dir.child("person.ndjson");
+	let sql = format!(
+		"
+		DEFINE TABLE person CHANGEFEED 1h;
+		DEFINE SINK audit ON TABLE person TO FILE '{}';
+		CREATE person:one SET name = 'Tobie';
+		CREATE person:two SET name = 'Jaime';
+		CREATE other:one;
+		INFO FOR DB;
+	",
+		path.display()
+	);
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	skip_ok(res, 5)?;
+	//
+	let tmp = res.remove(0).result?.to_string();
+	assert!(
+		tmp.contains(&format!(
+			"sinks: {{ audit: \"DEFINE SINK audit ON person TO FILE '{}'\" }}",
+			path.display()
+		)),
+		"{tmp}"
+	);
+	// Each committed transaction is written as a line of JSON
+	dbs.sink_process().await?;
+	let out = std::fs::read_to_string(&path).unwrap();
+	let lines = out.lines().collect::<Vec<_>>();
+	assert_eq!(lines.len(), 3, "{out}");
+	assert!(lines[0].contains("define_table"), "{out}");
+	assert!(lines[1].contains("person:one"), "{out}");
+	assert!(lines[2].contains("person:two"), "{out}");
+	assert!(!out.contains("other:one"), "{out}");
+	// Delivered changes are not delivered again
+	dbs.sink_process().await?;
+	let res = &mut dbs.execute("UPDATE person:one SET name = 'Tobias';", &ses, None).await?;
+	skip_ok(res, 1)?;
+	dbs.sink_process().await?;
+	let out = std::fs::read_to_string(&path).unwrap();
+	let lines = out.lines().collect::<Vec<_>>();
+	assert_eq!(lines.len(), 4, "{out}");
+	assert!(lines[3].contains("Tobias"), "{out}");
+	//
+	let sql = "
+		DEFINE SINK audit ON TABLE person TO FILE 'other.ndjson';
+		DEFINE SINK missing ON TABLE other TO HTTP 'https://example.com';
+		REMOVE SINK audit;
+		REMOVE SINK audit;
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert!(matches!(tmp, Error::SkAlreadyExists { .. }), "{tmp}");
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert!(matches!(tmp, Error::SkChangefeedRequired { .. }), "{tmp}");
+	//
+	skip_ok(res, 1)?;
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert!(matches!(tmp, Error::SkNotFound { .. }), "{tmp}");
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn define_statement_job_auth() -> Result<(), Error> {
+	let sql = "DEFINE USER editor ON DATABASE PASSWORD 'secret' ROLES EDITOR;";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	skip_ok(res, 1)?;
+	// The job is defined by an editor
+	let sql = "
+		DEFINE JOB escalate EVERY 1m THEN {
+			DEFINE USER hacker ON DATABASE PASSWORD 'secret' ROLES OWNER;
+		};
+	";
+	let editor =
+		Session::for_level(("test", "test").into(), Role::Editor).with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &editor, None).await?;
+	skip_ok(res, 1)?;
+	// The job runs with the authentication of the editor
+	let ts = 1_704_067_200_000;
+	dbs.job_process_at(ts).await?;
+	dbs.job_process_at(ts + 60_000).await?;
+	let res = &mut dbs.execute("INFO FOR DB STRUCTURE", &ses, None).await?;
+	let tmp = res.remove(0).result?.to_string();
+	assert!(!tmp.contains("name: 'hacker'"), "{tmp}");
+	assert!(tmp.contains("status: 'failure'"), "{tmp}");
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn define_statement_job() -> Result<(), Error> {
+	let sql = "
+		DEFINE JOB tick EVERY 1m THEN { CREATE tick };
+		DEFINE JOB fail CRON '@hourly' THEN { THROW 'failed' };
+		INFO FOR DB;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	skip_ok(res, 2)?;
+	//
+	let tmp = res.remove(0).result?.to_string();
+	assert!(
+		tmp.contains(
+			"jobs: { fail: \"DEFINE JOB fail CRON '@hourly' THEN { THROW 'failed'; }\", tick: 'DEFINE JOB tick EVERY 1m THEN { CREATE tick; }' }"
+		),
+		"{tmp}"
+	);
+	// Jobs are scheduled when first seen, and run once due
+	let ts = 1_704_067_200_000;
+	dbs.job_process_at(ts).await?;
+	dbs.job_process_at(ts + 30_000).await?;
+	let res = &mut dbs.execute("SELECT * FROM tick", &ses, None).await?;
+	assert_eq!(res.remove(0).result?, Value::parse("[]"));
+	dbs.job_process_at(ts + 3_600_000).await?;
+	// Each due job only runs once
+	dbs.job_process_at(ts + 3_600_000).await?;
+	let sql = "
+		SELECT count() FROM tick GROUP ALL;
+		INFO FOR DB STRUCTURE;
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::parse("[{ count: 1 }]"));
+	// The status of the last run is recorded for each job
+	let tmp = res.remove(0).result?.to_string();
+	assert!(tmp.contains("error: 'An error occurred: failed'"), "{tmp}");
+	assert!(tmp.contains("status: 'failure'"), "{tmp}");
+	assert!(tmp.contains("status: 'success'"), "{tmp}");
+	//
+	let sql = "
+		DEFINE JOB tick EVERY 1h THEN { CREATE tick };
+		REMOVE JOB tick;
+		REMOVE JOB tick;
+	";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert!(matches!(tmp, Error::JbAlreadyExists { .. }), "{tmp}");
+	//
+	skip_ok(res, 1)?;
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert!(matches!(tmp, Error::JbNotFound { .. }), "{tmp}");
+	//
+	Ok(())
+}
+
 async fn define_statement_index_concurrently_building_status(
 	def_index: &str,
 	skip_def: usize,
@@ -347,8 +635,11 @@ async fn define_statement_analyzer() -> Result<(), Error> {
 			functions: {
 				stripHtml: "DEFINE FUNCTION fn::stripHtml($html: string) { RETURN string::replace($html, /<[^>]*>/, ''); } PERMISSIONS FULL"
 			},
+			jobs: {},
 			models: {},
 			params: {},
+			roles: {},
+			sinks: {},
 			tables: {},
 			users: {},
 		}"#,
@@ -592,8 +883,8 @@ async fn permissions_checks_define_ns() {
 	]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let access1 = "{ accesses: {  }, namespaces: { NS: 'DEFINE NAMESPACE NS' }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
-	let access2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let access1 = "{ accesses: {  }, namespaces: { NS: 'DEFINE NAMESPACE NS' }, nodes: {  }, roles: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let access2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
 	let check_results = [vec![access1], vec![access2]];
 
 	let test_cases = [
@@ -631,8 +922,10 @@ async fn permissions_checks_define_db() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-		vec!["{ accesses: {  }, databases: { DB: 'DEFINE DATABASE DB' }, users: {  } }"],
-		vec!["{ accesses: {  }, databases: {  }, users: {  } }"],
+		vec![
+			"{ accesses: {  }, databases: { DB: 'DEFINE DATABASE DB' }, roles: {  }, users: {  } }",
+		],
+		vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: {  } }"],
 	];
 
 	let test_cases = [
@@ -673,8 +966,8 @@ async fn permissions_checks_define_function() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: { greet: \"DEFINE FUNCTION fn::greet() { RETURN 'Hello'; } PERMISSIONS FULL\" }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: { greet: \"DEFINE FUNCTION fn::greet() { RETURN 'Hello'; } PERMISSIONS FULL\" }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -715,8 +1008,8 @@ async fn permissions_checks_define_analyzer() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: { analyzer: 'DEFINE ANALYZER analyzer TOKENIZERS BLANK' }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: { analyzer: 'DEFINE ANALYZER analyzer TOKENIZERS BLANK' }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -756,8 +1049,8 @@ async fn permissions_checks_define_access_root() {
 	]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let access1 = r#"{ accesses: { access: "DEFINE ACCESS access ON ROOT TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }"#;
-	let access2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let access1 = r#"{ accesses: { access: "DEFINE ACCESS access ON ROOT TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, namespaces: {  }, nodes: {  }, roles: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }"#;
+	let access2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
 	let check_results = [vec![access1], vec![access2]];
 
 	let test_cases = [
@@ -798,8 +1091,8 @@ async fn permissions_checks_define_access_ns() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: { access: \"DEFINE ACCESS access ON NAMESPACE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, databases: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, databases: {  }, users: {  } }"]
+        vec!["{ accesses: { access: \"DEFINE ACCESS access ON NAMESPACE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, databases: {  }, roles: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -840,8 +1133,8 @@ async fn permissions_checks_define_access_db() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: { access: \"DEFINE ACCESS access ON DATABASE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: { access: \"DEFINE ACCESS access ON DATABASE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -881,8 +1174,8 @@ async fn permissions_checks_define_user_root() {
 	]);
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
-	let check1 = r#"{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: { user: "DEFINE USER user ON ROOT PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h" } }"#;
-	let check2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
+	let check1 = r#"{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: { user: "DEFINE USER user ON ROOT PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h" } }"#;
+	let check2 = "{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 }, users: {  } }";
 	let check_results = [vec![check1], vec![check2]];
 
 	let test_cases = [
@@ -923,8 +1216,8 @@ async fn permissions_checks_define_user_ns() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, databases: {  }, users: { user: \"DEFINE USER user ON NAMESPACE PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
-		vec!["{ accesses: {  }, databases: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: { user: \"DEFINE USER user ON NAMESPACE PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
+		vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -965,8 +1258,8 @@ async fn permissions_checks_define_user_db() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: { user: \"DEFINE USER user ON DATABASE PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
-        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: { user: \"DEFINE USER user ON DATABASE PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
+        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1007,8 +1300,8 @@ async fn permissions_checks_define_access_record() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: { account: \"DEFINE ACCESS account ON DATABASE TYPE RECORD WITH JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 15m, FOR SESSION 12h\" }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: { account: \"DEFINE ACCESS account ON DATABASE TYPE RECORD WITH JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 15m, FOR SESSION 12h\" }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1049,8 +1342,8 @@ async fn permissions_checks_define_param() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: { param: \"DEFINE PARAM $param VALUE 'foo' PERMISSIONS FULL\" }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: { param: \"DEFINE PARAM $param VALUE 'foo' PERMISSIONS FULL\" }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1088,8 +1381,8 @@ async fn permissions_checks_define_table() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: { TB: 'DEFINE TABLE TB TYPE ANY SCHEMALESS PERMISSIONS NONE' }, users: {  } }"],
-        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: { TB: 'DEFINE TABLE TB TYPE ANY SCHEMALESS PERMISSIONS NONE' }, users: {  } }"],
+        vec!["{ accesses: {  }, analyzers: {  }, apis: {  }, configs: {  }, functions: {  }, jobs: {  }, models: {  }, params: {  }, roles: {  }, sinks: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -502,6 +502,40 @@ async fn select_where_matches_using_index_and_score() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn select_where_matches_using_index_and_scoring_models() -> Result<(), Error> {
+	let sql = r"
+		CREATE doc:1 SET title = 'rust', body = 'a guide about databases';
+		CREATE doc:2 SET title = 'databases', body = 'a guide about rust';
+		CREATE doc:3 SET title = 'cooking', body = 'a guide about recipes';
+		CREATE doc:4 SET title = 'gardening', body = 'a guide about plants';
+		CREATE doc:5 SET title = 'travel', body = 'a guide about countries';
+		CREATE doc:6 SET title = 'music', body = 'a guide about songs';
+		DEFINE ANALYZER simple TOKENIZERS blank,class;
+		DEFINE INDEX doc_search ON doc FIELDS title, body SEARCH ANALYZER simple BM25F([3,1]);
+		DEFINE INDEX doc_body ON doc FIELDS body SEARCH ANALYZER simple TFIDF;
+		(SELECT id, search::score(1) AS score FROM doc WHERE title @1@ 'rust' ORDER BY score DESC).id;
+		(SELECT id, search::score(1) AS score FROM doc WHERE title @1@ 'databases' ORDER BY score DESC).id;
+		(SELECT id, search::score(1) AS score FROM doc WHERE title @1@ 'rust OR cooking' ORDER BY score DESC).id;
+		(SELECT id, search::score(1) AS score FROM doc WHERE title @1@ 'rust^3 OR cooking' ORDER BY score DESC).id;
+		SELECT id, search::score(1) AS score FROM doc WHERE body @1@ 'rust';
+		SELECT id, search::score(1) AS score FROM doc WHERE body @1@ 'rust^2';
+	";
+	let mut t = Test::new(sql).await?;
+	t.expect_size(15)?;
+	t.skip_ok(9)?;
+	// A term in the title weights more than a term in the body
+	t.expect_val("[doc:1, doc:2]")?;
+	t.expect_val("[doc:2, doc:1]")?;
+	t.expect_val("[doc:3, doc:1, doc:2]")?;
+	// The boost multiplies the score of the term
+	t.expect_val("[doc:1, doc:3, doc:2]")?;
+	// (1 + ln(1)) * (ln((6 + 1) / (1 + 1)) + 1)
+	t.expect_val("[{ id: doc:2, score: 2.252762794494629f }]")?;
+	t.expect_val("[{ id: doc:2, score: 4.505525588989258f }]")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn select_where_matches_without_using_index_and_score() -> Result<(), Error> {
 	let sql = r"
@@ -757,3 +791,309 @@ async fn select_where_matches_analyser_with_mapper() -> Result<(), Error> {
 	t.skip_ok(2)?;
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_where_matches_analyser_with_synonyms_and_stopwords() -> Result<(), Error> {
+	let sql = r#"
+		DEFINE ANALYZER syn TOKENIZERS unicode FILTERS lowercase,stopwords(english),length(2,20),synonym('../../tests/data/synonyms-en.txt');
+		CREATE t:1 SET text = 'The quick car of the neighbour';
+		CREATE t:2 SET text = 'A slow bicycle';
+		DEFINE INDEX search_idx ON TABLE t COLUMNS text SEARCH ANALYZER syn BM25;
+		SELECT id FROM t WHERE text @@ 'automobile';
+		SELECT id FROM t WHERE text @@ 'rapid vehicle';
+		SELECT id FROM t WHERE text @@ '"fast automobile"';
+		SELECT id FROM t WHERE text @@ 'the';
+		SELECT id FROM t WHERE text @@ 'bicycle';
+		REMOVE ANALYZER syn;
+	"#;
+	let mut t = Test::new(sql).await?;
+	t.expect_size(10)?;
+	t.skip_ok(4)?;
+	t.expect_val("[{ id: t:1 }]")?;
+	t.expect_val("[{ id: t:1 }]")?;
+	t.expect_val("[{ id: t:1 }]")?;
+	t.expect_val("[]")?;
+	t.expect_val("[{ id: t:2 }]")?;
+	t.skip_ok(1)?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_with_facets() -> Result<(), Error> {
+	let sql = r#"
+		CREATE product:1 SET name = 'red wireless mouse', brand = 'acme', tags = ['usb', 'wireless'];
+		CREATE product:2 SET name = 'blue wireless keyboard', brand = 'acme', tags = ['wireless'];
+		CREATE product:3 SET name = 'wireless headphones', brand = 'sonic', tags = ['audio', 'wireless'];
+		CREATE product:4 SET name = 'wired mouse', brand = 'sonic', tags = ['usb'];
+		CREATE product:5 SET name = 'wireless charger', tags = ['usb'];
+		DEFINE ANALYZER simple TOKENIZERS blank,class FILTERS lowercase;
+		DEFINE INDEX product_name ON product FIELDS name SEARCH ANALYZER simple BM25;
+		SELECT VALUE search::facets(1, 'brand') FROM product WHERE name @1@ 'wireless' LIMIT 1;
+		SELECT VALUE search::facets(1, 'tags', 2) FROM product WHERE name @1@ 'wireless' LIMIT 1;
+		SELECT VALUE search::facets(1, 'brand') FROM product WHERE name @1@ '"wireless mouse"' LIMIT 1;
+		SELECT VALUE search::facets(1, 'brand', -1) FROM product WHERE name @1@ 'wireless' LIMIT 1;
+	"#;
+	let mut t = Test::new(sql).await?;
+	t.expect_size(11)?;
+	t.skip_ok(7)?;
+	t.expect_val(
+		"[[
+			{ value: 'acme', count: 2 },
+			{ value: 'sonic', count: 1 }
+		]]",
+	)?;
+	t.expect_val(
+		"[[
+			{ value: 'wireless', count: 3 },
+			{ value: 'usb', count: 2 }
+		]]",
+	)?;
+	// The candidates of a phrase are checked against the indexed value
+	t.expect_val("[[{ value: 'acme', count: 1 }]]")?;
+	t.expect_error(
+		"Incorrect arguments for function search::facets(). The limit must be a non-negative number.",
+	)?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_with_facets_and_permissions() -> Result<(), Error> {
+	let sql = r"
+		DEFINE TABLE product SCHEMALESS PERMISSIONS FOR select WHERE public = true;
+		DEFINE FIELD internal ON product PERMISSIONS FOR select NONE;
+		CREATE product:1 SET name = 'red wireless mouse', brand = 'acme', public = true, internal = 'a';
+		CREATE product:2 SET name = 'blue wireless keyboard', brand = 'acme', public = false, internal = 'a';
+		CREATE product:3 SET name = 'wireless headphones', brand = 'sonic', public = true, internal = 'b';
+		CREATE product:4 SET name = 'wireless charger', brand = 'hidden', public = false, internal = 'b';
+		DEFINE ANALYZER simple TOKENIZERS blank,class FILTERS lowercase;
+		DEFINE INDEX product_name ON product FIELDS name SEARCH ANALYZER simple BM25;
+		SELECT VALUE search::facets(1, 'brand') FROM product WHERE name @1@ 'wireless' LIMIT 1;
+	";
+	let mut t = Test::new(sql).await?;
+	t.expect_size(9)?;
+	t.skip_ok(8)?;
+	t.expect_val(
+		"[[
+			{ value: 'acme', count: 2 },
+			{ value: 'hidden', count: 1 },
+			{ value: 'sonic', count: 1 }
+		]]",
+	)?;
+	// A record user only counts the records and the fields it can select
+	let sql = r"
+		SELECT search::facets(1, 'brand') AS f FROM product WHERE name @1@ 'wireless' LIMIT 1;
+		SELECT search::facets(1, 'internal') AS f FROM product WHERE name @1@ 'wireless' LIMIT 1;
+		SELECT search::facets(1, 'brand') AS f FROM product WHERE name @1@ 'wireless' AND brand = 'sonic' LIMIT 1;
+	";
+	let ses = Session::for_record("test", "test", "test", Value::parse("user:1"));
+	let mut t = Test::new_ds_session(t.ds, ses, sql).await?;
+	t.expect_size(3)?;
+	t.expect_val(
+		"[{
+			f: [
+				{ value: 'acme', count: 1 },
+				{ value: 'sonic', count: 1 }
+			]
+		}]",
+	)?;
+	t.expect_val("[{ f: [] }]")?;
+	t.expect_val("[{ f: [{ value: 'sonic', count: 1 }] }]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_or_knn_with_rrf() -> Result<(), Error> {
+	let sql = r"
+		CREATE blog:1 SET title = 'the quick brown fox jumped over the lazy dog', emb = [1, 1];
+		CREATE blog:2 SET title = 'the fast fox jumped over the lazy dog', emb = [2, 2];
+		CREATE blog:3 SET title = 'the other animals sat there watching', emb = [3, 3];
+		CREATE blog:4 SET title = 'the dog sat there and did nothing', emb = [4, 4];
+		CREATE blog:5 SET title = 'the cat', emb = [5, 5];
+		DEFINE ANALYZER simple TOKENIZERS blank,class;
+		DEFINE INDEX blog_title ON blog FIELDS title SEARCH ANALYZER simple BM25;
+		DEFINE INDEX blog_emb ON blog FIELDS emb HNSW DIMENSION 2;
+		SELECT id, search::rrf(1) AS rrf FROM blog
+			WHERE title @1@ 'fox' OR emb <|3,40|> [2.2, 2.2] ORDER BY rrf DESC;
+		SELECT id, search::rrf(1, 0) AS rrf FROM blog
+			WHERE title @1@ 'fox' OR emb <|3,40|> [2.2, 2.2] ORDER BY rrf DESC;
+		SELECT id, search::rrf(1, -1) AS rrf FROM blog WHERE title @1@ 'fox';
+		SELECT id, search::rrf(1, 0) AS rrf FROM blog WHERE title @1@ 'fox' AND id != blog:2;
+	";
+	let mut t = Test::new(sql).await?;
+	t.expect_size(12)?;
+	t.skip_ok(8)?;
+	// blog:2 is ranked first by both searches
+	t.expect_val(
+		"[
+			{ id: blog:2, rrf: 0.03278688524590164f },
+			{ id: blog:1, rrf: 0.03200204813108039f },
+			{ id: blog:3, rrf: 0.016129032258064516f }
+		]",
+	)?;
+	t.expect_val(
+		"[
+			{ id: blog:2, rrf: 2f },
+			{ id: blog:1, rrf: 0.8333333333333333f },
+			{ id: blog:3, rrf: 0.5f }
+		]",
+	)?;
+	t.expect_error(
+		"Incorrect arguments for function search::rrf(). The constant k must be a non-negative number.",
+	)?;
+	// Records excluded by the condition are not ranked
+	t.expect_val("[{ id: blog:1, rrf: 1f }]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_with_rrf_and_permissions() -> Result<(), Error> {
+	let sql = r"
+		DEFINE TABLE blog SCHEMALESS PERMISSIONS FOR select WHERE public = true;
+		CREATE blog:1 SET title = 'the fox', public = false;
+		CREATE blog:2 SET title = 'fox fox fox', public = true;
+		CREATE blog:3 SET title = 'the quick brown fox jumped over the lazy dog', public = true;
+		CREATE blog:4 SET title = 'the cat', public = true;
+		CREATE blog:5 SET title = 'the dog', public = true;
+		CREATE blog:6 SET title = 'the bird', public = true;
+		DEFINE ANALYZER simple TOKENIZERS blank,class;
+		DEFINE INDEX blog_title ON blog FIELDS title SEARCH ANALYZER simple BM25;
+		SELECT id, search::rrf(1, 0) AS rrf FROM blog WHERE title @1@ 'fox' ORDER BY rrf DESC;
+	";
+	let mut t = Test::new(sql).await?;
+	t.expect_size(10)?;
+	t.skip_ok(9)?;
+	t.expect_val(
+		"[
+			{ id: blog:1, rrf: 1f },
+			{ id: blog:2, rrf: 0.5f },
+			{ id: blog:3, rrf: 0.3333333333333333f }
+		]",
+	)?;
+	// A record user only ranks the records it can select
+	let sql = r"
+		SELECT id, search::rrf(1, 0) AS rrf FROM blog WHERE title @1@ 'fox' ORDER BY rrf DESC;
+	";
+	let ses = Session::for_record("test", "test", "test", Value::parse("user:1"));
+	let mut t = Test::new_ds_session(t.ds, ses, sql).await?;
+	t.expect_size(1)?;
+	t.expect_val(
+		"[
+			{ id: blog:2, rrf: 1f },
+			{ id: blog:3, rrf: 0.5f }
+		]",
+	)?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_with_query_operators() -> Result<(), Error> {
+	let sql = r#"
+		CREATE blog:1 SET title = 'the quick brown fox jumped over the lazy dog';
+		CREATE blog:2 SET title = 'the fast fox jumped over the lazy dog';
+		CREATE blog:3 SET title = 'the other animals sat there watching';
+		CREATE blog:4 SET title = 'the dog sat there and did nothing';
+		CREATE blog:5 SET title = 'the cat';
+		DEFINE ANALYZER simple TOKENIZERS blank,class;
+		DEFINE INDEX blog_title ON blog FIELDS title SEARCH ANALYZER simple BM25;
+		SELECT id FROM blog WHERE title @@ '"lazy dog"' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ '"fox lazy"' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'quick NEAR/1 fox' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'fox NEAR/2 lazy' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'fox NEAR/3 "lazy dog"' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'fox OR cat' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'dog NOT fox' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'sat AND (dog OR animals)' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'wat*' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'fast ju*' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'fox dog)' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'fox OR' ORDER BY id;
+	"#;
+	let mut t = Test::new(sql).await?;
+	t.expect_size(19)?;
+	t.skip_ok(7)?;
+	t.expect_val("[{ id: blog:1 }, { id: blog:2 }]")?;
+	t.expect_val("[]")?;
+	t.expect_val("[{ id: blog:1 }]")?;
+	t.expect_val("[]")?;
+	t.expect_val("[{ id: blog:1 }, { id: blog:2 }]")?;
+	t.expect_val("[{ id: blog:1 }, { id: blog:2 }, { id: blog:5 }]")?;
+	t.expect_val("[{ id: blog:4 }]")?;
+	t.expect_val("[{ id: blog:3 }, { id: blog:4 }]")?;
+	t.expect_val("[{ id: blog:3 }]")?;
+	t.expect_val("[{ id: blog:2 }]")?;
+	// Invalid queries don't fail, all of their terms (including ')' and 'OR') must match
+	t.expect_val("[]")?;
+	t.expect_val("[]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_phrase_without_using_index_iterator() -> Result<(), Error> {
+	let sql = r#"
+		CREATE blog:1 SET title = 'the quick brown fox jumped over the lazy dog';
+		CREATE blog:2 SET title = 'the lazy fox jumped over the dog';
+		DEFINE ANALYZER simple TOKENIZERS blank,class;
+		DEFINE INDEX blog_title ON blog FIELDS title SEARCH ANALYZER simple BM25;
+		SELECT id FROM blog WHERE title @@ '"lazy dog"' OR id = blog:3 ORDER BY id;
+		SELECT id FROM blog WHERE title @@ '"lazy dog"' AND id != blog:3 ORDER BY id;
+	"#;
+	let mut t = Test::new(sql).await?;
+	t.expect_size(6)?;
+	t.skip_ok(4)?;
+	t.expect_val("[{ id: blog:1 }]")?;
+	t.expect_val("[{ id: blog:1 }]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_with_fuzziness() -> Result<(), Error> {
+	let sql = r#"
+		CREATE product:1 SET name = 'Wireless Keyboard';
+		CREATE product:2 SET name = 'Wired Mouse';
+		CREATE product:3 SET name = 'Keyboard cover';
+		DEFINE ANALYZER simple TOKENIZERS blank,class FILTERS lowercase;
+		DEFINE INDEX product_name ON product FIELDS name SEARCH ANALYZER simple BM25 HIGHLIGHTS;
+		SELECT id FROM product WHERE name @@ 'keybord';
+		SELECT id FROM product WHERE name @,1@ 'keybord' ORDER BY id;
+		SELECT id, search::highlight('<b>', '</b>', 1) AS name FROM product WHERE name @1,1@ 'wirless keybord';
+		SELECT id FROM product WHERE name @,1@ 'wird' ORDER BY id;
+		SELECT id FROM product WHERE name @,2@ 'wirles' ORDER BY id;
+		SELECT id FROM product WHERE name @,1@ '"keybord cover"';
+		SELECT id FROM product WHERE name @1,2@ 'keybord' EXPLAIN;
+	"#;
+	let mut t = Test::new(sql).await?;
+	t.expect_size(12)?;
+	t.skip_ok(5)?;
+	t.expect_val("[]")?;
+	t.expect_val("[{ id: product:1 }, { id: product:3 }]")?;
+	t.expect_val("[{ id: product:1, name: '<b>Wireless</b> <b>Keyboard</b>' }]")?;
+	t.expect_val("[{ id: product:2 }]")?;
+	t.expect_val("[{ id: product:1 }, { id: product:2 }]")?;
+	// Phrases are matched exactly
+	t.expect_val("[]")?;
+	t.expect_val(
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'product_name',
+						operator: '@1,2@',
+						value: 'keybord'
+					},
+					table: 'product'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	)?;
+	//
+	let res = Test::new("SELECT id FROM product WHERE name @,3@ 'keybord';").await;
+	assert!(matches!(res, Err(Error::InvalidQuery(_))));
+	Ok(())
+}
@@ -31,8 +31,11 @@ async fn define_global_param() -> Result<(), Error> {
 			apis: {},
 			configs: {},
 			functions: {},
+			jobs: {},
 			models: {},
 			params: { test: 'DEFINE PARAM $test VALUE 12345 PERMISSIONS FULL' },
+			roles: {},
+			sinks: {},
 			tables: {},
 			users: {},
 		}",
@@ -3275,6 +3275,516 @@ async fn select_composite_unique_index() -> Result<(), Error> {
 	select_composite_index(true).await
 }
 
+async fn select_composite_index_range_order(unique: bool) -> Result<(), Error> {
+	//
+	let sql = format!(
+		"
+		DEFINE INDEX tenant_created ON TABLE post COLUMNS tenant, created_at {};
+		CREATE post:1 SET tenant = 'a', created_at = d'2024-01-01T00:00:00Z';
+		CREATE post:2 SET tenant = 'b', created_at = d'2024-01-02T00:00:00Z';
+		CREATE post:3 SET tenant = 'a', created_at = d'2024-01-03T00:00:00Z';
+		CREATE post:4 SET tenant = 'a', created_at = d'2024-01-04T00:00:00Z';
+		CREATE post:5 SET tenant = 'b', created_at = d'2024-01-05T00:00:00Z';
+		SELECT id FROM post WHERE tenant = 'a' AND created_at > d'2024-01-01T00:00:00Z' EXPLAIN;
+		SELECT id FROM post WHERE tenant = 'a' AND created_at > d'2024-01-01T00:00:00Z';
+		SELECT id, created_at FROM post WHERE tenant = 'a' AND created_at <= d'2024-01-03T00:00:00Z' ORDER BY created_at LIMIT 1 EXPLAIN;
+		SELECT id, created_at FROM post WHERE tenant = 'a' AND created_at <= d'2024-01-03T00:00:00Z' ORDER BY created_at LIMIT 1;
+		SELECT id, tenant, created_at FROM post WHERE tenant = 'b' ORDER BY tenant, created_at LIMIT 2 EXPLAIN;
+		SELECT id, tenant, created_at FROM post WHERE tenant = 'b' ORDER BY tenant, created_at LIMIT 2;
+	",
+		if unique {
+			"UNIQUE"
+		} else {
+			""
+		}
+	);
+	let mut t = Test::new(&sql).await?;
+	//
+	t.expect_size(12)?;
+	t.skip_ok(6)?;
+	//
+	t.expect_vals(&[
+		"[
+			{
+				detail: {
+					plan: {
+						direction: 'forward',
+						from: {
+							inclusive: false,
+							value: d'2024-01-01T00:00:00Z'
+						},
+						index: 'tenant_created',
+						prefix: 'a',
+						to: {
+							inclusive: false,
+							value: NONE
+						}
+					},
+					table: 'post'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				id: post:3
+			},
+			{
+				id: post:4
+			}
+		]",
+		"[
+			{
+				detail: {
+					plan: {
+						direction: 'forward',
+						from: {
+							inclusive: false,
+							value: NONE
+						},
+						index: 'tenant_created',
+						prefix: 'a',
+						to: {
+							inclusive: true,
+							value: d'2024-01-03T00:00:00Z'
+						}
+					},
+					table: 'post'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					limit: 1,
+					type: 'MemoryOrderedLimit'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				created_at: d'2024-01-01T00:00:00Z',
+				id: post:1
+			}
+		]",
+		"[
+			{
+				detail: {
+					plan: {
+						direction: 'forward',
+						from: {
+							inclusive: false,
+							value: NONE
+						},
+						index: 'tenant_created',
+						prefix: 'b',
+						to: {
+							inclusive: false,
+							value: NONE
+						}
+					},
+					table: 'post'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					limit: 2,
+					type: 'MemoryOrderedLimit'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				created_at: d'2024-01-02T00:00:00Z',
+				id: post:2,
+				tenant: 'b'
+			},
+			{
+				created_at: d'2024-01-05T00:00:00Z',
+				id: post:5,
+				tenant: 'b'
+			}
+		]",
+	])?;
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_composite_standard_index_range_order() -> Result<(), Error> {
+	select_composite_index_range_order(false).await
+}
+
+#[tokio::test]
+async fn select_composite_unique_index_range_order() -> Result<(), Error> {
+	select_composite_index_range_order(true).await
+}
+
+#[tokio::test]
+async fn select_partial_unique_index() -> Result<(), Error> {
+	//
+	let sql = "
+		DEFINE INDEX active_email ON TABLE user FIELDS email UNIQUE WHERE deleted_at IS NONE;
+		CREATE user:1 SET email = 'a@example.com', deleted_at = d'2024-01-01T00:00:00Z';
+		CREATE user:2 SET email = 'a@example.com';
+		CREATE user:3 SET email = 'a@example.com';
+		SELECT id FROM user WHERE email = 'a@example.com' AND deleted_at IS NONE EXPLAIN;
+		SELECT id FROM user WHERE email = 'a@example.com' AND deleted_at IS NONE;
+		SELECT id FROM user WHERE email = 'a@example.com' EXPLAIN;
+		SELECT id FROM user WHERE email = 'a@example.com';
+		UPDATE user:2 SET deleted_at = d'2024-02-01T00:00:00Z';
+		CREATE user:3 SET email = 'a@example.com';
+		SELECT id FROM user WHERE email = 'a@example.com' AND deleted_at IS NONE;
+	";
+	let mut t = Test::new(sql).await?;
+	//
+	t.expect_size(11)?;
+	t.skip_ok(3)?;
+	t.expect_error(
+		"Database index `active_email` already contains 'a@example.com', with record `user:2`",
+	)?;
+	//
+	t.expect_vals(&[
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'active_email',
+						operator: '=',
+						value: 'a@example.com'
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				id: user:2
+			}
+		]",
+		"[
+			{
+				detail: {
+					direction: 'forward',
+					table: 'user'
+				},
+				operation: 'Iterate Table'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				id: user:1
+			},
+			{
+				id: user:2
+			}
+		]",
+	])?;
+	t.skip_ok(2)?;
+	t.expect_val(
+		"[
+			{
+				id: user:3
+			}
+		]",
+	)?;
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_expression_index() -> Result<(), Error> {
+	//
+	let sql = "
+		DEFINE INDEX email_lower ON TABLE user FIELDS string::lowercase(email) UNIQUE;
+		DEFINE INDEX created_day ON TABLE user FIELDS time::day(created_at);
+		CREATE user:1 SET email = 'Tobie@Example.com', created_at = d'2024-01-01T10:00:00Z';
+		CREATE user:2 SET email = 'jaime@example.com', created_at = d'2024-01-02T10:00:00Z';
+		CREATE user:3 SET email = 'TOBIE@example.COM', created_at = d'2024-01-03T10:00:00Z';
+		SELECT id FROM user WHERE string::lowercase(email) = 'tobie@example.com' EXPLAIN;
+		SELECT id FROM user WHERE string::lowercase(email) = 'tobie@example.com';
+		SELECT id FROM user WHERE time::day(created_at) = 2 EXPLAIN;
+		SELECT id FROM user WHERE time::day(created_at) = 2;
+	";
+	let mut t = Test::new(sql).await?;
+	//
+	t.expect_size(9)?;
+	t.skip_ok(4)?;
+	t.expect_error(
+		"Database index `email_lower` already contains 'tobie@example.com', with record `user:1`",
+	)?;
+	//
+	t.expect_vals(&[
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'email_lower',
+						operator: '=',
+						value: 'tobie@example.com'
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				id: user:1
+			}
+		]",
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'created_day',
+						operator: '=',
+						value: 2
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				id: user:2
+			}
+		]",
+	])?;
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_covering_index() -> Result<(), Error> {
+	//
+	let sql = "
+		DEFINE INDEX email ON TABLE user FIELDS email UNIQUE INCLUDE name, address.city;
+		CREATE user:1 SET email = 'a@example.com', name = 'Alice', address = { city: 'London', street: 'Baker' }, age = 30;
+		CREATE user:2 SET email = 'b@example.com', name = 'Bob', address = { city: 'Paris' }, age = 40;
+		SELECT id, name, address.city AS city FROM user WHERE email = 'a@example.com' EXPLAIN;
+		SELECT id, name, address.city AS city FROM user WHERE email = 'a@example.com';
+		UPDATE user:1 SET name = 'Alicia';
+		SELECT email, name FROM user WHERE email >= 'a' EXPLAIN;
+		SELECT email, name FROM user WHERE email >= 'a';
+		SELECT id, age FROM user WHERE email = 'b@example.com' EXPLAIN;
+		SELECT id, age FROM user WHERE email = 'b@example.com';
+		SELECT address FROM user WHERE email = 'b@example.com' EXPLAIN;
+		SELECT string::uppercase(name) AS name FROM user WHERE email = 'b@example.com' EXPLAIN;
+		SELECT type::field('age') AS age FROM user WHERE email = 'b@example.com' EXPLAIN;
+		SELECT name, $parent AS parent FROM user WHERE email = 'b@example.com' EXPLAIN;
+	";
+	let mut t = Test::new(sql).await?;
+	//
+	t.expect_size(14)?;
+	t.skip_ok(3)?;
+	t.expect_vals(&[
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'email',
+						operator: '=',
+						value: 'a@example.com'
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index Covering'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				city: 'London',
+				id: user:1,
+				name: 'Alice'
+			}
+		]",
+	])?;
+	t.skip_ok(1)?;
+	t.expect_vals(&[
+		"[
+			{
+				detail: {
+					plan: {
+						from: {
+							inclusive: true,
+							value: 'a'
+						},
+						index: 'email',
+						to: {
+							inclusive: false,
+							value: NONE
+						}
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index Covering'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				email: 'a@example.com',
+				name: 'Alicia'
+			},
+			{
+				email: 'b@example.com',
+				name: 'Bob'
+			}
+		]",
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'email',
+						operator: '=',
+						value: 'b@example.com'
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				age: 40,
+				id: user:2
+			}
+		]",
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'email',
+						operator: '=',
+						value: 'b@example.com'
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		// Pure functions are computed from the index entries
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'email',
+						operator: '=',
+						value: 'b@example.com'
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index Covering'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		// Values which depend on the rest of the document are not
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'email',
+						operator: '=',
+						value: 'b@example.com'
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'email',
+						operator: '=',
+						value: 'b@example.com'
+					},
+					table: 'user'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	])?;
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn select_where_index_boolean_behaviour() -> Result<(), Error> {
 	let sql = r"
@@ -3576,6 +4086,108 @@ async fn select_count_group_all_with_or_without_index() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn select_geohash_index() -> Result<(), Error> {
+	let sql = r"
+		DEFINE INDEX location ON TABLE place FIELDS location GEOHASH PRECISION 8;
+		DEFINE INDEX boundary ON TABLE area FIELDS boundary GEOHASH;
+		CREATE place:1 SET location = (-0.118092, 51.509865);
+		CREATE place:2 SET location = (-0.1226, 51.5117);
+		CREATE place:3 SET location = (-0.0098, 51.4769);
+		CREATE place:4 SET location = (-0.105092, 51.518065);
+		CREATE place:5 SET location = (2.3522, 48.8566);
+		CREATE area:1 SET boundary = { type: 'Polygon', coordinates: [[[-0.15, 51.49], [-0.10, 51.49], [-0.10, 51.53], [-0.15, 51.53], [-0.15, 51.49]]] };
+		CREATE area:2 SET boundary = { type: 'Polygon', coordinates: [[[2.30, 48.83], [2.40, 48.83], [2.40, 48.88], [2.30, 48.88], [2.30, 48.83]]] };
+		SELECT id FROM place WHERE geo::distance(location, (-0.118092, 51.509865)) < 1000 EXPLAIN;
+		SELECT id FROM place WHERE geo::distance(location, (-0.118092, 51.509865)) < 1000 ORDER BY id;
+		SELECT count() FROM place WHERE 1000 > geo::distance(location, (-0.118092, 51.509865)) GROUP ALL;
+		SELECT id FROM place WHERE location INSIDE { type: 'Polygon', coordinates: [[[-0.13, 51.50], [-0.11, 51.50], [-0.11, 51.52], [-0.13, 51.52], [-0.13, 51.50]]] } EXPLAIN;
+		SELECT id FROM place WHERE location INSIDE { type: 'Polygon', coordinates: [[[-0.13, 51.50], [-0.11, 51.50], [-0.11, 51.52], [-0.13, 51.52], [-0.13, 51.50]]] } ORDER BY id;
+		SELECT id FROM area WHERE boundary CONTAINS (-0.118092, 51.509865);
+		SELECT id FROM area WHERE (2.3522, 48.8566) INSIDE boundary;
+		SELECT id FROM area WHERE boundary INTERSECTS { type: 'LineString', coordinates: [[-0.2, 51.5], [-0.12, 51.5]] } EXPLAIN;
+		SELECT id FROM area WHERE boundary INTERSECTS { type: 'LineString', coordinates: [[-0.2, 51.5], [-0.12, 51.5]] };
+		UPDATE place:2 SET location = (2.2945, 48.8584);
+		SELECT id FROM place WHERE geo::distance(location, (-0.118092, 51.509865)) < 1000;
+	";
+	let mut t = Test::new(sql).await?;
+	t.expect_size(20)?;
+	t.skip_ok(9)?;
+	t.expect_val(
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'location',
+						operator: 'geo::distance <',
+						origin: (-0.118092, 51.509865),
+						value: 1000
+					},
+					table: 'place'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	)?;
+	t.expect_val("[{ id: place:1 }, { id: place:2 }]")?;
+	t.expect_val("[{ count: 2 }]")?;
+	t.expect_val(
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'location',
+						operator: 'INSIDE',
+						value: { type: 'Polygon', coordinates: [[[-0.13, 51.50], [-0.11, 51.50], [-0.11, 51.52], [-0.13, 51.52], [-0.13, 51.50]]] }
+					},
+					table: 'place'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	)?;
+	t.expect_val("[{ id: place:1 }, { id: place:2 }]")?;
+	t.expect_val("[{ id: area:1 }]")?;
+	t.expect_val("[{ id: area:2 }]")?;
+	t.expect_val(
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'boundary',
+						operator: 'INTERSECTS',
+						value: { type: 'LineString', coordinates: [[-0.2, 51.5], [-0.12, 51.5]] }
+					},
+					table: 'area'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	)?;
+	t.expect_val("[{ id: area:1 }]")?;
+	t.skip_ok(1)?;
+	t.expect_val("[{ id: place:1 }]")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn select_limit_start_array() -> Result<(), Error> {
 	Test::new("SELECT * FROM [1,2,3,4,5,6,7,8,9,10] LIMIT 5 START 0;")
@@ -285,8 +285,11 @@ async fn relate_enforced() -> Result<(), Error> {
 	apis: {},
 	configs: {},
 	functions: {},
+	jobs: {},
 	models: {},
 	params: {},
+	roles: {},
+	sinks: {},
 	tables: {
 		a: 'DEFINE TABLE a TYPE ANY SCHEMALESS PERMISSIONS NONE',
 		edge: 'DEFINE TABLE edge TYPE RELATION ENFORCED SCHEMALESS PERMISSIONS NONE'
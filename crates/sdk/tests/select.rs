@@ -1475,3 +1475,98 @@ async fn select_from_none() -> Result<(), Error> {
 	}
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_window_functions() -> Result<(), Error> {
+	let sql: &str = "
+		CREATE sale:1 SET team = 'a', day = 1, amount = 10;
+		CREATE sale:2 SET team = 'a', day = 2, amount = 20;
+		CREATE sale:3 SET team = 'a', day = 3, amount = 30;
+		CREATE sale:4 SET team = 'b', day = 1, amount = 5;
+		CREATE sale:5 SET team = 'b', day = 2, amount = 5;
+		CREATE sale:6 SET team = 'b', day = 3, amount = 15;
+		SELECT id, row_number() OVER (PARTITION BY team ORDER BY day) AS n, math::sum(amount) OVER (PARTITION BY team ORDER BY day) AS total FROM sale ORDER BY id;
+		SELECT id, rank() OVER (ORDER BY amount DESC) AS rank, dense_rank() OVER (ORDER BY amount DESC) AS dense FROM sale ORDER BY id;
+		SELECT id, lag(amount, 1, 0) OVER (PARTITION BY team ORDER BY day) AS prev, lead(amount) OVER (PARTITION BY team ORDER BY day) AS next FROM sale WHERE team = 'a' ORDER BY id;
+		SELECT id, math::mean(amount) OVER (PARTITION BY team ORDER BY day ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS avg FROM sale WHERE team = 'a' ORDER BY id;
+		SELECT id, first_value(amount) OVER (PARTITION BY team ORDER BY amount DESC) AS top, count() OVER (PARTITION BY team) AS count FROM sale WHERE team = 'b' ORDER BY id;
+		SELECT VALUE count() OVER () FROM sale;
+		SELECT id, row_number() OVER (ORDER BY amount) AS n FROM sale ORDER BY n DESC LIMIT 2;
+		SELECT id, row_number() OVER (ORDER BY amount) AS n FROM sale EXPLAIN;
+		RETURN row_number();
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(6)?;
+	t.expect_val(
+		"[
+			{ id: sale:1, n: 1, total: 10 },
+			{ id: sale:2, n: 2, total: 30 },
+			{ id: sale:3, n: 3, total: 60 },
+			{ id: sale:4, n: 1, total: 5 },
+			{ id: sale:5, n: 2, total: 10 },
+			{ id: sale:6, n: 3, total: 25 },
+		]",
+	)?;
+	t.expect_val(
+		"[
+			{ id: sale:1, rank: 4, dense: 4 },
+			{ id: sale:2, rank: 2, dense: 2 },
+			{ id: sale:3, rank: 1, dense: 1 },
+			{ id: sale:4, rank: 5, dense: 5 },
+			{ id: sale:5, rank: 5, dense: 5 },
+			{ id: sale:6, rank: 3, dense: 3 },
+		]",
+	)?;
+	t.expect_val(
+		"[
+			{ id: sale:1, prev: 0, next: 20 },
+			{ id: sale:2, prev: 10, next: 30 },
+			{ id: sale:3, prev: 20, next: NONE },
+		]",
+	)?;
+	t.expect_val(
+		"[
+			{ id: sale:1, avg: 10 },
+			{ id: sale:2, avg: 15 },
+			{ id: sale:3, avg: 25 },
+		]",
+	)?;
+	t.expect_val(
+		"[
+			{ id: sale:4, top: 15, count: 3 },
+			{ id: sale:5, top: 15, count: 3 },
+			{ id: sale:6, top: 15, count: 3 },
+		]",
+	)?;
+	t.expect_val("[6, 6, 6, 6, 6, 6]")?;
+	t.expect_val(
+		"[
+			{ id: sale:3, n: 6 },
+			{ id: sale:2, n: 5 },
+		]",
+	)?;
+	t.expect_val(
+		"[
+			{
+				detail: {
+					direction: 'forward',
+					table: 'sale'
+				},
+				operation: 'Iterate Table'
+			},
+			{
+				detail: {
+					idioms: {
+						n: 'row_number() OVER (ORDER BY amount)'
+					},
+					type: 'Windows'
+				},
+				operation: 'Collector'
+			}
+		]",
+	)?;
+	t.expect_error(
+		"There was a problem running the row_number() function. This function can only be used with an OVER clause",
+	)?;
+	Ok(())
+}
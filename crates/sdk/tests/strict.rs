@@ -237,6 +237,7 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 			accesses: { },
 			namespaces: { test: 'DEFINE NAMESPACE test' },
 			nodes: { },
+			roles: { },
 			system: { available_parallelism: 0, cpu_usage: 0.0f, load_average: [0.0f, 0.0f, 0.0f], memory_allocated: 0, memory_usage: 0, physical_cores: 0, threads: 0 },
 			users: { },
 		}"
@@ -248,6 +249,7 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 		"{
 			accesses: {},
 			databases: { test: 'DEFINE DATABASE test' },
+			roles: {},
 			users: {},
 		}",
 	);
@@ -261,8 +263,11 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 			apis: {},
 			configs: {},
 			functions: {},
+			jobs: {},
 			models: {},
 			params: {},
+			roles: {},
+			sinks: {},
 			tables: { test: 'DEFINE TABLE test TYPE ANY SCHEMALESS PERMISSIONS NONE' },
 			users: {},
 		}",
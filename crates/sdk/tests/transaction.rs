@@ -200,3 +200,263 @@ async fn transaction_with_throw_and_return() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn transaction_with_savepoint_rollback() -> Result<(), Error> {
+	let sql = "
+		BEGIN;
+		CREATE person:tobie;
+		SAVEPOINT import;
+		CREATE person:jaime;
+		ROLLBACK TO import;
+		CREATE person:john;
+		COMMIT;
+		SELECT VALUE id FROM person;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"The query was rolled back to a savepoint"#
+	));
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:john }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[person:john, person:tobie]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn transaction_with_savepoint_rollback_and_changefeed() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person CHANGEFEED 1h;
+		BEGIN;
+		CREATE person:tobie;
+		SAVEPOINT import;
+		CREATE person:jaime;
+		ROLLBACK TO import;
+		COMMIT;
+		SHOW CHANGES FOR TABLE person SINCE 0;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	//
+	for _ in 0..5 {
+		res.remove(0).result.ok();
+	}
+	// The changes rolled back to the savepoint are not in the change feed
+	let tmp = res.remove(0).result?.to_string();
+	assert!(tmp.contains("person:tobie"), "{tmp}");
+	assert!(!tmp.contains("person:jaime"), "{tmp}");
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn transaction_with_savepoint_recovery() -> Result<(), Error> {
+	let sql = "
+		BEGIN;
+		CREATE person:tobie;
+		SAVEPOINT step;
+		CREATE person:jaime;
+		CREATE person:tobie;
+		CREATE person:other;
+		ROLLBACK TO step;
+		RELEASE step;
+		SAVEPOINT step;
+		CREATE person:john;
+		RELEASE step;
+		COMMIT;
+		SELECT VALUE id FROM person;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 11);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"The query was rolled back to a savepoint"#
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"Database record `person:tobie` already exists"#
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"The query was not executed due to a failed transaction"#
+	));
+	//
+	for _ in 0..3 {
+		let tmp = res.remove(0).result?;
+		assert_eq!(tmp, Value::None);
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:john }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[person:john, person:tobie]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn transaction_with_failed_savepoint_release() -> Result<(), Error> {
+	let sql = "
+		BEGIN;
+		CREATE person:tobie;
+		SAVEPOINT step;
+		CREATE person:tobie;
+		RELEASE step;
+		COMMIT;
+		SELECT VALUE id FROM person;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	// The failed savepoint can not be released, so the transaction is not committed
+	for _ in 0..2 {
+		let tmp = res.remove(0).result;
+		assert!(matches!(
+			tmp.err(),
+			Some(e) if e.to_string() == r#"The query was not executed due to a failed transaction"#
+		));
+	}
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"Database record `person:tobie` already exists"#
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"The savepoint 'step' can not be released after a statement failed, use ROLLBACK TO to discard the changes"#
+	));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn transaction_with_nested_savepoints() -> Result<(), Error> {
+	let sql = "
+		BEGIN;
+		SAVEPOINT outer;
+		CREATE person:one;
+		SAVEPOINT inner;
+		CREATE person:two;
+		RELEASE inner;
+		INSERT INTO person { id: person:three } ON DUPLICATE KEY UPDATE count += 1;
+		ROLLBACK TO outer;
+		CREATE person:four;
+		RELEASE outer;
+		COMMIT;
+		SELECT VALUE id FROM person;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 10);
+	//
+	let tmp = res.pop().unwrap().result?;
+	let val = Value::parse("[person:four]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn transaction_with_savepoint_failure() -> Result<(), Error> {
+	let sql = "
+		BEGIN;
+		CREATE person:tobie;
+		SAVEPOINT step;
+		RELEASE step;
+		ROLLBACK TO step;
+		CREATE person:jaime;
+		COMMIT;
+		SAVEPOINT step;
+		SELECT VALUE id FROM person;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 7);
+	//
+	for _ in 0..3 {
+		let tmp = res.remove(0).result;
+		assert!(matches!(
+			tmp.err(),
+			Some(e) if e.to_string() == r#"The query was not executed due to a failed transaction"#
+		));
+	}
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"The savepoint 'step' does not exist in the current transaction"#
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"The query was not executed due to a failed transaction"#
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == r#"Savepoints can only be used within a transaction"#
+	));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
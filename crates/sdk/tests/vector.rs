@@ -294,6 +294,77 @@ async fn select_where_hnsw_knn() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn select_where_hnsw_knn_quantized() -> Result<(), Error> {
+	let sql = r"
+		DEFINE INDEX hnsw_pts ON scalar FIELDS point HNSW DIMENSION 4 DIST EUCLIDEAN TYPE F32 QUANTIZE SCALAR;
+		DEFINE INDEX hnsw_pts ON binary FIELDS point HNSW DIMENSION 4 DIST EUCLIDEAN TYPE F32 QUANTIZE BINARY;
+		DEFINE INDEX hnsw_pts ON product FIELDS point HNSW DIMENSION 4 DIST EUCLIDEAN TYPE F32 QUANTIZE PRODUCT 2;
+		FOR $tb IN ['scalar', 'binary', 'product'] {
+			CREATE type::thing($tb, 1) SET point = [1,2,3,4];
+			CREATE type::thing($tb, 2) SET point = [4,5,6,7];
+			CREATE type::thing($tb, 3) SET point = [8,9,10,11];
+		};
+		LET $pt = [2,3,4,5];
+		SELECT id, vector::distance::knn() AS dist FROM scalar WHERE point <|2,100|> $pt;
+		SELECT id, vector::distance::knn() AS dist FROM binary WHERE point <|2,100|> $pt;
+		SELECT id, vector::distance::knn() AS dist FROM product WHERE point <|2,100|> $pt;
+		SELECT id, vector::distance::knn() AS dist FROM product WHERE point <|2,100|> $pt EXPLAIN;
+		INFO FOR TABLE product;
+	";
+	let mut t = Test::new(sql).await?;
+	t.expect_size(10)?;
+	t.skip_ok(5)?;
+	// The distances are computed on the full precision vectors
+	for tb in ["scalar", "binary", "product"] {
+		t.expect_val(&format!(
+			"[
+				{{
+					id: {tb}:1,
+					dist: 2f
+				}},
+				{{
+					id: {tb}:2,
+					dist: 4f
+				}}
+			]"
+		))?;
+	}
+	t.expect_val(
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'hnsw_pts',
+						operator: '<|2,100|>',
+						value: [2,3,4,5]
+					},
+					table: 'product',
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	)?;
+	t.expect_val(
+		"{
+			events: {},
+			fields: {},
+			indexes: {
+				hnsw_pts: 'DEFINE INDEX hnsw_pts ON product FIELDS point HNSW DIMENSION 4 DIST EUCLIDEAN TYPE F32 EFC 150 M 12 M0 24 LM 0.40242960438184466f QUANTIZE PRODUCT 2'
+			},
+			lives: {},
+			tables: {}
+		}",
+	)?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn select_mtree_knn_with_condition() -> Result<(), Error> {
 	let sql = r"
@@ -438,6 +509,51 @@ async fn select_hnsw_knn_with_condition() -> Result<(), Error> {
 	Ok(())
 }
 
+#[test_log::test(tokio::test)]
+async fn select_hnsw_knn_with_selective_condition() -> Result<(), Error> {
+	let sql = r"
+		DEFINE INDEX hn_emb ON pts FIELDS embedding HNSW DIMENSION 3 M 4 EFC 20;
+		FOR $i IN 0..500 {
+			CREATE type::thing('pts', $i) SET tenant = $i % 50, embedding = [$i, ($i * 7) % 13, ($i * 3) % 11];
+		};
+		LET $v = [250, 6, 5];
+		SELECT VALUE id FROM pts WHERE tenant = 7 AND embedding <|10,40|> $v ORDER BY id;
+		SELECT VALUE id FROM pts WHERE tenant = 7 AND embedding <|10,4|> $v ORDER BY id;
+		SELECT VALUE id FROM pts WHERE tenant = 7 AND embedding <|10,EUCLIDEAN|> $v ORDER BY id;
+		SELECT id, vector::distance::knn() AS dist FROM pts WHERE tenant = 7 AND embedding <|3,40|> $v ORDER BY dist;
+		SELECT id, vector::distance::knn() AS dist FROM pts WHERE tenant = 7 AND embedding <|3,EUCLIDEAN|> $v ORDER BY dist;
+	";
+	let mut t = Test::new(sql).await?;
+	t.expect_size(8)?;
+	t.skip_ok(3)?;
+	// Every record of the tenant is returned, even when EF is lower than K
+	for _ in 0..3 {
+		t.expect_val(
+			"[pts:7, pts:57, pts:107, pts:157, pts:207, pts:257, pts:307, pts:357, pts:407, pts:457]",
+		)?;
+	}
+	// The nearest neighbours of the tenant match the brute force result
+	for _ in 0..2 {
+		t.expect_val(
+			"[
+				{
+					dist: 8.12403840463596f,
+					id: pts:257
+				},
+				{
+					dist: 43f,
+					id: pts:207
+				},
+				{
+					dist: 57.113921245174545f,
+					id: pts:307
+				}
+			]",
+		)?;
+	}
+	Ok(())
+}
+
 #[test_log::test(tokio::test)]
 async fn select_bruteforce_knn_with_condition() -> Result<(), Error> {
 	let sql = r"
@@ -0,0 +1,59 @@
+use crate::err::Error;
+use clap::Args;
+use std::path::PathBuf;
+use surrealdb::engine::any::IntoEndpoint;
+use surrealdb::kvs::backup::{Config, Manifest, DEFAULT_FILE_SIZE};
+use surrealdb::kvs::Datastore;
+
+#[derive(Args, Debug)]
+pub struct CreateCommandArguments {
+	#[arg(help = "Database path used for storing data")]
+	#[arg(env = "SURREAL_PATH", index = 1)]
+	#[arg(value_parser = crate::cli::validator::path_valid)]
+	path: String,
+	#[arg(help = "Directory into which the backup is written")]
+	#[arg(index = 2)]
+	dir: PathBuf,
+	#[arg(help = "Directory of a previous backup, which this incremental backup builds on")]
+	#[arg(long = "incremental")]
+	#[arg(value_parser = crate::cli::validator::dir_exists)]
+	incremental: Option<PathBuf>,
+	#[arg(help = "Size in bytes after which a new backup data file is started")]
+	#[arg(long = "file-size")]
+	#[arg(default_value_t = DEFAULT_FILE_SIZE)]
+	file_size: u64,
+}
+
+pub async fn init(
+	CreateCommandArguments {
+		path,
+		dir,
+		incremental,
+		file_size,
+	}: CreateCommandArguments,
+) -> Result<(), Error> {
+	// Load the backup which an incremental backup builds on
+	let parent = match incremental {
+		Some(parent) => Some(Manifest::load(&parent).await?),
+		None => None,
+	};
+	// Clean the path
+	let endpoint = path.into_endpoint()?;
+	let path = if endpoint.path.is_empty() {
+		endpoint.url.to_string()
+	} else {
+		endpoint.path
+	};
+	// Open the datastore
+	let dbs = Datastore::new(&path).await?;
+	// Write the backup
+	let cfg = Config {
+		parent,
+		file_size,
+	};
+	let manifest = dbs.backup(&dir, cfg).await?;
+	// Log success
+	info!("The {:?} backup {} was written to {}", manifest.kind, manifest.id, dir.display());
+	// All ok
+	Ok(())
+}
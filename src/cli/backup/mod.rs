@@ -0,0 +1,22 @@
+mod create;
+mod restore;
+
+use self::create::CreateCommandArguments;
+use self::restore::RestoreCommandArguments;
+use crate::err::Error;
+use clap::Subcommand;
+
+#[derive(Debug, Subcommand)]
+pub enum BackupCommand {
+	#[command(about = "Create a full or incremental backup of a datastore")]
+	Create(CreateCommandArguments),
+	#[command(about = "Restore a chain of backups into an empty datastore")]
+	Restore(RestoreCommandArguments),
+}
+
+pub async fn init(command: BackupCommand) -> Result<(), Error> {
+	match command {
+		BackupCommand::Create(args) => create::init(args).await,
+		BackupCommand::Restore(args) => restore::init(args).await,
+	}
+}
@@ -0,0 +1,67 @@
+use crate::err::Error;
+use clap::Args;
+use std::path::PathBuf;
+use surrealdb::engine::any::IntoEndpoint;
+use surrealdb::kvs::backup::{RestoreConfig, Until};
+use surrealdb::kvs::Datastore;
+
+#[derive(Args, Debug)]
+pub struct RestoreCommandArguments {
+	#[arg(help = "Database path of the empty datastore to restore into")]
+	#[arg(env = "SURREAL_PATH", index = 1)]
+	#[arg(value_parser = crate::cli::validator::path_valid)]
+	path: String,
+	#[arg(
+		help = "Directories of a full binary backup, followed by any incremental backups in order"
+	)]
+	#[arg(index = 2, required = true, num_args = 1..)]
+	#[arg(value_parser = crate::cli::validator::dir_exists)]
+	dirs: Vec<PathBuf>,
+	#[arg(help = "Database path of a datastore whose change feeds are replayed after the backups")]
+	#[arg(long = "changes-from")]
+	#[arg(value_parser = crate::cli::validator::path_valid)]
+	changes_from: Option<String>,
+	#[arg(help = "Versionstamp or RFC 3339 datetime up to which changes are replayed")]
+	#[arg(long = "until")]
+	#[arg(value_parser = crate::cli::validator::restore_until)]
+	until: Option<Until>,
+}
+
+pub async fn init(
+	RestoreCommandArguments {
+		path,
+		dirs,
+		changes_from,
+		until,
+	}: RestoreCommandArguments,
+) -> Result<(), Error> {
+	// Clean the path
+	let endpoint = path.into_endpoint()?;
+	let path = if endpoint.path.is_empty() {
+		endpoint.url.to_string()
+	} else {
+		endpoint.path
+	};
+	// Open the datastore
+	let dbs = Datastore::new(&path).await?;
+	// Restore the backups
+	let cfg = RestoreConfig {
+		until: until.clone(),
+	};
+	let manifest = dbs.restore(&dirs, cfg).await?;
+	// Replay the changes made after the last backup
+	if let Some(source) = changes_from {
+		let endpoint = source.into_endpoint()?;
+		let source = if endpoint.path.is_empty() {
+			endpoint.url.to_string()
+		} else {
+			endpoint.path
+		};
+		let source = Datastore::new(&source).await?;
+		dbs.replay_changefeeds(&source, &manifest.versionstamps, until.as_ref()).await?;
+	}
+	// Log success
+	info!("The backup was restored successfully");
+	// All ok
+	Ok(())
+}
@@ -4,7 +4,7 @@ use crate::err::Error;
 use clap::Args;
 use futures_util::StreamExt;
 use surrealdb::engine::any::{connect, IntoEndpoint};
-use surrealdb::kvs::export::TableConfig;
+use surrealdb::kvs::export::{Format, TableConfig};
 use surrealdb::method::{Export, ExportConfig};
 use surrealdb::Connection;
 use tokio::io::{self, AsyncWriteExt};
@@ -47,6 +47,9 @@ struct ExportConfigArguments {
 	/// Whether records should be exported
 	#[arg(long, num_args = 0..=1, default_missing_value = "true")]
 	records: Option<bool>,
+	/// The format of the export, where arrow and parquet require a single table
+	#[arg(long, value_parser = super::validator::export_format)]
+	format: Option<Format>,
 }
 
 #[derive(Args, Debug)]
@@ -134,7 +137,7 @@ pub async fn init(
 	} else {
 		apply_config(config, client.export(file)).await?;
 	}
-	info!("The SurrealQL file was exported successfully");
+	info!("The file was exported successfully");
 	// Everything OK
 	Ok(())
 }
@@ -189,5 +192,9 @@ fn apply_config<C: Connection, R>(
 		export = export.records(value);
 	}
 
+	if let Some(value) = config.format {
+		export = export.format(value);
+	}
+
 	export
 }
@@ -3,6 +3,7 @@ use crate::cli::abstraction::{AuthArguments, DatabaseSelectionArguments};
 use crate::err::Error;
 use clap::Args;
 use surrealdb::engine::any::{connect, IntoEndpoint};
+use surrealdb::kvs::import::Format;
 use surrealdb::opt::{capabilities::Capabilities, Config};
 
 #[derive(Args, Debug)]
@@ -16,9 +17,22 @@ pub struct DatabaseConnectionArguments {
 
 #[derive(Args, Debug)]
 pub struct ImportCommandArguments {
-	#[arg(help = "Path to the SurrealQL file to import")]
+	#[arg(help = "Path to the file to import")]
 	#[arg(index = 1)]
 	file: String,
+	#[arg(help = "The format of the file to import")]
+	#[arg(long, default_value = "sql")]
+	#[arg(value_parser = super::validator::import_format)]
+	format: Format,
+	#[arg(help = "The table to import the records into, required by all formats but sql")]
+	#[arg(long)]
+	#[arg(required_if_eq_any = [
+		("format", "arrow"),
+		("format", "parquet"),
+		("format", "csv"),
+		("format", "ndjson"),
+	])]
+	table: Option<String>,
 	#[command(flatten)]
 	conn: DatabaseConnectionArguments,
 	#[command(flatten)]
@@ -30,6 +44,8 @@ pub struct ImportCommandArguments {
 pub async fn init(
 	ImportCommandArguments {
 		file,
+		format,
+		table,
 		conn: DatabaseConnectionArguments {
 			endpoint,
 		},
@@ -83,10 +99,14 @@ pub async fn init(
 	// Use the specified namespace / database
 	client.use_ns(namespace).use_db(database).await?;
 	// Import the data into the database
-	client.import(file).await.inspect_err(|_| {
+	let mut import = client.import(file).format(format);
+	if let Some(table) = table {
+		import = import.table(table);
+	}
+	import.await.inspect_err(|_| {
 		error!("Surreal import failed, import might only be partially completed or have failed entirely.")
 	})?;
-	info!("The SurrealQL file was imported successfully");
+	info!("The file was imported successfully");
 	// All ok
 	Ok(())
 }
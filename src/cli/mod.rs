@@ -1,4 +1,5 @@
 pub(crate) mod abstraction;
+mod backup;
 mod config;
 mod export;
 mod fix;
@@ -22,6 +23,7 @@ use crate::cli::version_client::VersionClient;
 use crate::cnf::DEBUG_BUILD_WARNING;
 use crate::cnf::{LOGO, PKG_VERSION};
 use crate::env::RELEASE;
+use backup::BackupCommand;
 use clap::{Parser, Subcommand};
 pub use config::CF;
 use export::ExportCommandArguments;
@@ -76,10 +78,8 @@ struct Cli {
 enum Commands {
 	#[command(about = "Start the database server")]
 	Start(StartCommandArguments),
-	/* Not implemented yet
-	#[command(about = "Backup data to or from an existing database")]
-	Backup(BackupCommandArguments),
-	*/
+	#[command(subcommand, about = "Back up or restore the data of a local datastore")]
+	Backup(BackupCommand),
 	#[command(about = "Import a SurrealQL script into an existing database")]
 	Import(ImportCommandArguments),
 	#[command(about = "Export an existing database as a SurrealQL script")]
@@ -144,6 +144,7 @@ pub async fn init() -> ExitCode {
 	// After version warning we can run the respective command
 	let output = match args.command {
 		Commands::Start(args) => start::init(args).await,
+		Commands::Backup(args) => backup::init(args).await,
 		Commands::Import(args) => import::init(args).await,
 		Commands::Export(args) => export::init(args).await,
 		Commands::Version(args) => version::init(args).await,
@@ -62,6 +62,31 @@ pub struct StartCommandArguments {
 	#[arg(env = "SURREAL_CHANGEFEED_GC_INTERVAL", long = "changefeed-gc-interval", value_parser = super::validator::duration)]
 	#[arg(default_value = "10s")]
 	changefeed_gc_interval: Duration,
+	#[arg(
+		help = "The interval at which to process queued asynchronous events",
+		help_heading = "Database"
+	)]
+	#[arg(env = "SURREAL_EVENT_QUEUE_INTERVAL", long = "event-queue-interval", value_parser = super::validator::duration)]
+	#[arg(default_value = "1s")]
+	event_queue_interval: Duration,
+	#[arg(
+		help = "The interval at which to deliver changefeed entries to sinks",
+		help_heading = "Database"
+	)]
+	#[arg(env = "SURREAL_SINK_INTERVAL", long = "sink-interval", value_parser = super::validator::duration)]
+	#[arg(default_value = "1s")]
+	sink_interval: Duration,
+	#[arg(help = "The interval at which to run scheduled jobs", help_heading = "Database")]
+	#[arg(env = "SURREAL_JOB_INTERVAL", long = "job-interval", value_parser = super::validator::duration)]
+	#[arg(default_value = "1s")]
+	job_interval: Duration,
+	#[arg(
+		help = "The time after its last heartbeat, after which a node can no longer lead the cluster",
+		help_heading = "Database"
+	)]
+	#[arg(env = "SURREAL_LEADER_TIMEOUT", long = "leader-timeout", value_parser = super::validator::duration)]
+	#[arg(default_value = "30s")]
+	leader_timeout: Duration,
 	//
 	// Authentication
 	//
@@ -159,6 +184,10 @@ pub async fn init(
 		node_membership_check_interval,
 		node_membership_cleanup_interval,
 		changefeed_gc_interval,
+		event_queue_interval,
+		sink_interval,
+		job_interval,
+		leader_timeout,
 		no_banner,
 		no_identification_headers,
 		..
@@ -186,7 +215,11 @@ pub async fn init(
 		.with_node_membership_refresh_interval(node_membership_refresh_interval)
 		.with_node_membership_check_interval(node_membership_check_interval)
 		.with_node_membership_cleanup_interval(node_membership_cleanup_interval)
-		.with_changefeed_gc_interval(changefeed_gc_interval);
+		.with_changefeed_gc_interval(changefeed_gc_interval)
+		.with_event_queue_interval(event_queue_interval)
+		.with_sink_interval(sink_interval)
+		.with_job_interval(job_interval)
+		.with_leader_timeout(leader_timeout);
 	// Configure the config
 	let config = Config {
 		bind: listen_addresses.first().cloned().unwrap(),
@@ -5,11 +5,14 @@ use std::{
 	time::Duration,
 };
 
+use chrono::{DateTime, Utc};
 use surrealdb::dbs::capabilities::{
 	ArbitraryQueryTarget, ExperimentalTarget, FuncTarget, MethodTarget, NetTarget, RouteTarget,
 	Targets,
 };
-use surrealdb::kvs::export::TableConfig;
+use surrealdb::kvs::backup::Until;
+use surrealdb::kvs::export::{Format as ExportFormat, TableConfig};
+use surrealdb::kvs::import::Format as ImportFormat;
 
 pub(crate) mod parser;
 
@@ -191,6 +194,24 @@ pub(crate) fn export_tables(value: &str) -> Result<TableConfig, String> {
 	Ok(TableConfig::Some(value.split(",").filter(|s| !s.is_empty()).map(str::to_string).collect()))
 }
 
+pub(crate) fn export_format(value: &str) -> Result<ExportFormat, String> {
+	value.parse().map_err(|_| String::from("expected one of sql, arrow or parquet"))
+}
+
+pub(crate) fn import_format(value: &str) -> Result<ImportFormat, String> {
+	value.parse().map_err(|_| String::from("expected one of sql, arrow, parquet, csv or ndjson"))
+}
+
+pub(crate) fn restore_until(value: &str) -> Result<Until, String> {
+	if let Ok(vs) = value.parse::<u64>() {
+		return Ok(Until::Versionstamp(vs));
+	}
+	match DateTime::parse_from_rfc3339(value) {
+		Ok(v) => Ok(Until::Timestamp(v.with_timezone(&Utc))),
+		Err(_) => Err(String::from("Provide a versionstamp, or an RFC 3339 datetime")),
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
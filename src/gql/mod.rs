@@ -8,16 +8,17 @@ use std::{
 };
 
 use async_graphql::{
-	http::{create_multipart_mixed_stream, is_accept_multipart_mixed},
-	Executor, ParseRequestError,
+	http::{create_multipart_mixed_stream, is_accept_multipart_mixed, ALL_WEBSOCKET_PROTOCOLS},
+	Data, Executor, ParseRequestError,
 };
 use async_graphql_axum::{
-	rejection::GraphQLRejection, GraphQLBatchRequest, GraphQLRequest, GraphQLResponse,
+	rejection::GraphQLRejection, GraphQLBatchRequest, GraphQLProtocol, GraphQLRequest,
+	GraphQLResponse, GraphQLWebSocket,
 };
 use axum::{
 	body::{Body, HttpBody},
-	extract::FromRequest,
-	http::{Request as HttpRequest, Response as HttpResponse},
+	extract::{FromRequest, FromRequestParts, WebSocketUpgrade},
+	http::{Method, Request as HttpRequest, Response as HttpResponse},
 	response::IntoResponse,
 	BoxError,
 };
@@ -27,6 +28,7 @@ use surrealdb::dbs::capabilities::RouteTarget;
 use surrealdb::dbs::Session;
 use surrealdb::gql::cache::{Invalidator, SchemaCache};
 use surrealdb::gql::error::resolver_error;
+use surrealdb::gql::Subscriptions;
 use surrealdb::kvs::Datastore;
 use tower_service::Service;
 
@@ -37,15 +39,17 @@ use crate::err::Error as SurrealError;
 pub struct GraphQL<I: Invalidator> {
 	cache: SchemaCache<I>,
 	// datastore: Arc<Datastore>,
+	subscriptions: Subscriptions,
 }
 
 impl<I: Invalidator> GraphQL<I> {
 	/// Create a GraphQL handler.
-	pub fn new(invalidator: I, datastore: Arc<Datastore>) -> Self {
+	pub fn new(invalidator: I, datastore: Arc<Datastore>, subscriptions: Subscriptions) -> Self {
 		let _ = invalidator;
 		GraphQL {
 			cache: SchemaCache::new(datastore),
 			// datastore,
+			subscriptions,
 		}
 	}
 }
@@ -67,6 +71,7 @@ where
 
 	fn call(&mut self, req: HttpRequest<B>) -> Self::Future {
 		let cache = self.cache.clone();
+		let subscriptions = self.subscriptions.clone();
 		let req = req.map(Body::new);
 
 		Box::pin(async move {
@@ -107,6 +112,26 @@ where
 					return Ok(to_rejection(e).into_response());
 				}
 			};
+			// Subscriptions are served over a graphql-ws WebSocket connection
+			if req.method() == Method::GET {
+				let (mut parts, _body) = req.into_parts();
+				let protocol = match GraphQLProtocol::from_request_parts(&mut parts, &()).await {
+					Ok(protocol) => protocol,
+					Err(err) => return Ok(err.into_response()),
+				};
+				let upgrade = match WebSocketUpgrade::from_request_parts(&mut parts, &()).await {
+					Ok(upgrade) => upgrade,
+					Err(err) => return Ok(err.into_response()),
+				};
+				// Allow the subscription resolvers to receive live query notifications
+				let mut data = Data::default();
+				data.insert(subscriptions);
+				let res = upgrade.protocols(ALL_WEBSOCKET_PROTOCOLS).on_upgrade(move |stream| {
+					GraphQLWebSocket::new(stream, executor, protocol).with_data(data).serve()
+				});
+				return Ok(res.into_response());
+			}
+
 			let is_accept_multipart_mixed = req
 				.headers()
 				.get("accept")
@@ -4,6 +4,7 @@ use crate::cnf::HTTP_MAX_IMPORT_BODY_SIZE;
 use crate::err::Error;
 use crate::net::output;
 use axum::extract::DefaultBodyLimit;
+use axum::extract::Query;
 use axum::extract::Request;
 use axum::response::IntoResponse;
 use axum::routing::post;
@@ -11,10 +12,13 @@ use axum::Extension;
 use axum::Router;
 use axum_extra::TypedHeader;
 use futures::TryStreamExt;
+use http::header::CONTENT_TYPE;
+use serde::Deserialize;
 use surrealdb::dbs::capabilities::RouteTarget;
 use surrealdb::dbs::Session;
 use surrealdb::iam::Action::Edit;
 use surrealdb::iam::ResourceKind::Any;
+use surrealdb::kvs::import::{Config, Format};
 use tower_http::limit::RequestBodyLimitLayer;
 
 pub(super) fn router<S>() -> Router<S>
@@ -27,10 +31,16 @@ where
 		.layer(RequestBodyLimitLayer::new(*HTTP_MAX_IMPORT_BODY_SIZE))
 }
 
+#[derive(Default, Deserialize, Debug, Clone)]
+struct QueryOptions {
+	table: Option<String>,
+}
+
 async fn handler(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
 	accept: Option<TypedHeader<Accept>>,
+	Query(query): Query<QueryOptions>,
 	request: Request,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
 	// Get the datastore reference
@@ -43,13 +53,27 @@ async fn handler(
 	// Check the permissions level
 	db.check(&session, Edit, Any.on_level(session.au.level().to_owned()))?;
 
+	// Columnar and row files are imported into the table given in the query
+	let content_type = request.headers().get(CONTENT_TYPE).and_then(|v| v.to_str().ok());
+	let format = match content_type.and_then(|v| v.split(';').next()).map(str::trim) {
+		Some("application/vnd.apache.arrow.stream") => Format::Arrow,
+		Some("application/vnd.apache.parquet") => Format::Parquet,
+		Some("text/csv") => Format::Csv,
+		Some("application/x-ndjson") => Format::Ndjson,
+		_ => Format::Sql,
+	};
+	let config = Config {
+		format,
+		table: query.table,
+	};
+
 	let body_stream = request
 		.into_body()
 		.into_data_stream()
 		.map_err(|e| surrealdb_core::err::Error::QueryStream(e.to_string()));
 
 	// Execute the sql query in the database
-	match db.import_stream(&session, body_stream).await {
+	match db.import_with_config(&session, config, body_stream).await {
 		Ok(res) => {
 			match accept.as_deref() {
 				// Simple serialization
@@ -167,6 +167,8 @@ pub async fn init(ds: Arc<Datastore>, ct: CancellationToken) -> Result<(), Error
 				.max_age(Duration::from_secs(86400)),
 		);
 
+	let rpc_state = Arc::new(RpcState::new());
+
 	let axum_app = Router::<Arc<RpcState>>::new()
 		// Redirect until we provide a UI
 		.route("/", get(|| async { Redirect::temporary(cnf::APP_ENDPOINT) }))
@@ -188,7 +190,7 @@ pub async fn init(ds: Arc<Datastore>, ct: CancellationToken) -> Result<(), Error
 		#[cfg(surrealdb_unstable)]
 		{
 			warn!("❌🔒IMPORTANT: GraphQL is a pre-release feature with known security flaws. This is not recommended for production use.🔒❌");
-			axum_app.merge(gql::router(ds.clone()).await)
+			axum_app.merge(gql::router(ds.clone(), rpc_state.gql_subscriptions.clone()).await)
 		}
 		#[cfg(not(surrealdb_unstable))]
 		{
@@ -204,8 +206,6 @@ pub async fn init(ds: Arc<Datastore>, ct: CancellationToken) -> Result<(), Error
 	// Get a new server handler
 	let handle = Handle::new();
 
-	let rpc_state = Arc::new(RpcState::new());
-
 	// Setup the graceful shutdown handler
 	let shutdown_handler = graceful_shutdown(rpc_state.clone(), ct.clone(), handle.clone());
 
@@ -12,6 +12,8 @@ use opentelemetry::Context as TelemetryContext;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+#[cfg(surrealdb_unstable)]
+use surrealdb::gql::Subscriptions;
 use surrealdb::kvs::Datastore;
 use tokio::sync::RwLock;
 use tokio_stream::StreamExt;
@@ -31,6 +33,9 @@ pub struct RpcState {
 	pub web_sockets: WebSockets,
 	/// Stores the currently initiated LIVE queries
 	pub live_queries: LiveQueries,
+	/// Stores the LIVE queries of GraphQL subscriptions
+	#[cfg(surrealdb_unstable)]
+	pub gql_subscriptions: Subscriptions,
 }
 
 impl RpcState {
@@ -38,6 +43,8 @@ impl RpcState {
 		RpcState {
 			web_sockets: WebSockets::default(),
 			live_queries: LiveQueries::default(),
+			#[cfg(surrealdb_unstable)]
+			gql_subscriptions: Subscriptions::default(),
 		}
 	}
 }
@@ -93,6 +100,10 @@ pub(crate) async fn notifications(
 							// Pus the future to the pipeline
 							futures.push(future);
 						}
+					} else {
+						// Otherwise deliver it to a GraphQL subscription
+						#[cfg(surrealdb_unstable)]
+						state.gql_subscriptions.notify(notification).await;
 					}
 				},
 			}
@@ -143,6 +143,40 @@ mod cli_integration {
 			assert_eq!(rest, "[\n\t{\n\t\tid: thing:one\n\t}\n]\n\n", "failed to send sql: {args}");
 		}
 
+		info!("* Export and import a table as parquet");
+		{
+			let exported = common::tmp_file("exported.parquet");
+			let args = format!("export --conn http://{addr} {creds} --ns {ns} --db {db} {exported} --only --tables thing --records --format parquet");
+			common::run(&args).output().unwrap_or_else(|_| panic!("failed to run export: {args}"));
+			let db3 = Ulid::new();
+			let args = format!("import --conn http://{addr} {creds} --ns {ns} --db {db3} {exported} --format parquet --table thing");
+			common::run(&args).output().unwrap_or_else(|_| panic!("failed to run import: {args}"));
+			let args =
+				format!("sql --conn http://{addr} {creds} --ns {ns} --db {db3} --hide-welcome");
+			let output = common::run(&args).input("SELECT * FROM thing;\n").output().unwrap();
+			assert!(output.contains("[{ id: thing:one }]"), "failed to query the import: {output}");
+		}
+
+		info!("* Import a table from csv, reporting rows which fail");
+		{
+			let file = common::tmp_file("import.csv");
+			std::fs::write(&file, "id,name\n1,one\n2,two,extra\n").unwrap();
+			let db4 = Ulid::new();
+			let args = format!("import --conn http://{addr} {creds} --ns {ns} --db {db4} {file} --format csv --table thing");
+			let output = common::run(&args).output().unwrap_err();
+			assert!(
+				output.contains("Unable to import row 3"),
+				"failed to report the row: {output}"
+			);
+			let args =
+				format!("sql --conn http://{addr} {creds} --ns {ns} --db {db4} --hide-welcome");
+			let output = common::run(&args).input("SELECT * FROM thing;\n").output().unwrap();
+			assert!(
+				output.contains("[{ id: thing:1, name: 'one' }]"),
+				"failed to query the import: {output}"
+			);
+		}
+
 		info!("* Advanced uncomputed variable to be computed before saving");
 		{
 			let args = format!(
@@ -508,4 +508,306 @@ mod graphql_integration {
 
 		Ok(())
 	}
+
+	#[test(tokio::test)]
+	async fn mutations() -> Result<(), Box<dyn std::error::Error>> {
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("http://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+                    DEFINE TABLE foo SCHEMAFUL;
+                    DEFINE FIELD val ON foo TYPE int;
+                    DEFINE FIELD name ON foo TYPE option<string>;
+                    DEFINE TABLE likes TYPE RELATION FROM foo TO foo SCHEMAFUL;
+                    DEFINE FIELD weight ON likes TYPE int DEFAULT 1;
+                "#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// create records
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"mutation{
+						a: create_foo(id: "foo:1", data: {val: 42}){id, val},
+						b: upsert_foo(id: "foo:2", data: {val: 43, name: "two"}){id, val, name}
+					}"#})
+					.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"a": {
+						"id": "foo:1",
+						"val": 42
+					},
+					"b": {
+						"id": "foo:2",
+						"val": 43,
+						"name": "two"
+					}
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// update a record
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"mutation{update_foo(id: "foo:1", data: {name: "one"}){id, val, name}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"update_foo": {
+						"id": "foo:1",
+						"val": 42,
+						"name": "one"
+					}
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// relate two records
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"mutation{relate_likes(id: "likes:1", in: "foo:1", out: "foo:2"){id, weight, out{id}}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"relate_likes": {
+						"id": "likes:1",
+						"weight": 1,
+						"out": {
+							"id": "foo:2"
+						}
+					}
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// delete a record
+		{
+			let res = client
+				.post(gql_url)
+				.body(json!({"query": r#"mutation{delete_foo(id: "foo:2")}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({"data":{"delete_foo":"foo:2"}});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		// check records belong to the mutated table
+		{
+			let res = client
+				.post(gql_url)
+				.body(
+					json!({"query": r#"mutation{update_foo(id: "likes:1", data: {val: 1}){id}}"#})
+						.to_string(),
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			assert!(body.contains("does not belong to table"), "body: {body}")
+		}
+
+		// check the results of the mutations
+		{
+			let res = client
+				.post(gql_url)
+				.body(json!({"query": r#"query{foo{id, val, name}}"#}).to_string())
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+			let body = res.text().await?;
+			let expected = json!({
+				"data": {
+					"foo": [
+						{
+							"id": "foo:1",
+							"val": 42,
+							"name": "one"
+						}
+					]
+				}
+			});
+			assert_eq!(expected.to_string(), body)
+		}
+
+		Ok(())
+	}
+
+	async fn read_ws_json<S>(
+		socket: &mut S,
+	) -> Result<serde_json::Value, Box<dyn std::error::Error>>
+	where
+		S: futures::Stream<
+				Item = Result<
+					tokio_tungstenite::tungstenite::Message,
+					tokio_tungstenite::tungstenite::Error,
+				>,
+			> + Unpin,
+	{
+		use futures::StreamExt;
+		use tokio_tungstenite::tungstenite::Message;
+
+		loop {
+			match tokio::time::timeout(Duration::from_secs(5), socket.next()).await? {
+				Some(Ok(Message::Text(text))) => return Ok(serde_json::from_str(&text)?),
+				Some(Ok(_)) => continue,
+				Some(Err(e)) => return Err(e.into()),
+				None => return Err("socket closed".into()),
+			}
+		}
+	}
+
+	#[test(tokio::test)]
+	async fn subscriptions() -> Result<(), Box<dyn std::error::Error>> {
+		use futures::{SinkExt, StreamExt};
+		use tokio_tungstenite::connect_async;
+		use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+		use tokio_tungstenite::tungstenite::Message;
+
+		let (addr, _server) = common::start_server_gql_without_auth().await.unwrap();
+		let gql_url = &format!("ws://{addr}/graphql");
+		let sql_url = &format!("http://{addr}/sql");
+
+		let mut headers = reqwest::header::HeaderMap::new();
+		let ns = Ulid::new().to_string();
+		let db = Ulid::new().to_string();
+		headers.insert("surreal-ns", ns.parse()?);
+		headers.insert("surreal-db", db.parse()?);
+		headers.insert(header::ACCEPT, "application/json".parse()?);
+		let client = reqwest::Client::builder()
+			.connect_timeout(Duration::from_millis(10))
+			.default_headers(headers)
+			.build()?;
+
+		// add schema
+		{
+			let res = client
+				.post(sql_url)
+				.body(
+					r#"
+					DEFINE CONFIG GRAPHQL AUTO;
+                    DEFINE TABLE foo SCHEMAFUL;
+                    DEFINE FIELD val ON foo TYPE int;
+                "#,
+				)
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		// subscribe to changes over graphql-ws
+		let mut req = gql_url.as_str().into_client_request()?;
+		req.headers_mut().insert("Sec-WebSocket-Protocol", "graphql-transport-ws".parse()?);
+		req.headers_mut().insert("surreal-ns", ns.parse()?);
+		req.headers_mut().insert("surreal-db", db.parse()?);
+		let (mut socket, _) = connect_async(req).await?;
+
+		socket.send(Message::Text(json!({"type": "connection_init"}).to_string())).await?;
+		let res = read_ws_json(&mut socket).await?;
+		assert_eq!(res["type"], "connection_ack", "message: {res}");
+
+		socket
+			.send(Message::Text(
+				json!({
+					"id": "1",
+					"type": "subscribe",
+					"payload": {
+						"query": r#"subscription{foo(filter: {val: {eq: 42}}){action, id, record{val}}}"#
+					}
+				})
+				.to_string(),
+			))
+			.await?;
+
+		// wait for the live query to be registered
+		tokio::time::sleep(Duration::from_millis(500)).await;
+
+		for sql in ["CREATE foo:1 SET val = 42", "CREATE foo:2 SET val = 43", "DELETE foo:1"] {
+			let res = client.post(sql_url).body(sql).send().await?;
+			assert_eq!(res.status(), 200);
+		}
+
+		let res = read_ws_json(&mut socket).await?;
+		let expected = json!({
+			"id": "1",
+			"type": "next",
+			"payload": {
+				"data": {
+					"foo": {
+						"action": "CREATE",
+						"id": "foo:1",
+						"record": {
+							"val": 42
+						}
+					}
+				}
+			}
+		});
+		assert_eq!(expected, res);
+
+		let res = read_ws_json(&mut socket).await?;
+		let expected = json!({
+			"id": "1",
+			"type": "next",
+			"payload": {
+				"data": {
+					"foo": {
+						"action": "DELETE",
+						"id": "foo:1",
+						"record": null
+					}
+				}
+			}
+		});
+		assert_eq!(expected, res);
+
+		Ok(())
+	}
 }